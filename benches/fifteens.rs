@@ -0,0 +1,75 @@
+//! Compares counting fifteens, and whole hands, with lookup tables against counting every
+//! combination.
+
+#[macro_use]
+extern crate criterion;
+extern crate libterminal_cribbage;
+
+use criterion::{black_box, Criterion};
+
+use libterminal_cribbage::cards::{
+    fifteens, fifteens_from_combinations, total, Card, Deck, Hand, ScoreCache,
+};
+
+/// Returns many hands, and their starters, from a seeded [`Deck`].
+fn hands_and_starters() -> Vec<(Hand, Card)> {
+    let mut deck = Deck::new();
+
+    deck.shuffle_with_seed(42);
+
+    deck.as_vec()
+        .chunks_exact(5)
+        .map(|cards| (Hand::from(cards[..4].to_vec()), cards[4].clone()))
+        .collect()
+}
+
+fn bench_fifteens(criterion: &mut Criterion) {
+    let hands_and_starters = hands_and_starters();
+
+    // Build the table before timing it.
+    let _ = fifteens(&hands_and_starters[0].0, &hands_and_starters[0].1);
+
+    criterion.bench_function("fifteens_from_combinations", |bencher| {
+        bencher.iter(|| {
+            for (hand, starter) in &hands_and_starters {
+                black_box(fifteens_from_combinations(
+                    black_box(hand),
+                    black_box(starter),
+                ));
+            }
+        });
+    });
+
+    criterion.bench_function("fifteens", |bencher| {
+        bencher.iter(|| {
+            for (hand, starter) in &hands_and_starters {
+                black_box(fifteens(black_box(hand), black_box(starter)));
+            }
+        });
+    });
+
+    criterion.bench_function("total", |bencher| {
+        bencher.iter(|| {
+            for (hand, starter) in &hands_and_starters {
+                black_box(total(black_box(hand), black_box(starter), false));
+            }
+        });
+    });
+}
+
+fn bench_score_cache(criterion: &mut Criterion) {
+    let hands_and_starters = hands_and_starters();
+
+    let cache = ScoreCache::new();
+
+    criterion.bench_function("score_cache_total", |bencher| {
+        bencher.iter(|| {
+            for (hand, starter) in &hands_and_starters {
+                black_box(cache.total(black_box(hand), black_box(starter), false));
+            }
+        });
+    });
+}
+
+criterion_group!(benches, bench_fifteens, bench_score_cache);
+criterion_main!(benches);
@@ -0,0 +1,188 @@
+//! Exports the step-driven [`Game`] to JavaScript, so a web page can play cribbage without any
+//! blocking [`Controller`].
+//!
+//! Build it for the browser with the `wasm` feature, which seeds the random numbers from
+//! `crypto.getRandomValues`, and generate the JavaScript glue with `wasm-bindgen`:
+//!
+//! ```text
+//! cargo build --release --example wasm_step --target wasm32-unknown-unknown \
+//!     --no-default-features --features wasm
+//! wasm-bindgen --target web --out-dir pkg \
+//!     target/wasm32-unknown-unknown/release/examples/wasm_step.wasm
+//! ```
+//!
+//! Then, from the page, answer each decision until the game is over:
+//!
+//! ```text
+//! import init from "./pkg/wasm_step.js";
+//!
+//! const cribbage = await init();
+//!
+//! let decision = cribbage.cribbage_new(42);
+//!
+//! while (decision !== OVER) {
+//!     // Ask player `cribbage.cribbage_player()` about their `cribbage.cribbage_hand_card(i)`s.
+//!     decision = cribbage.cribbage_apply(first, second);
+//! }
+//! ```
+//!
+//! [`Controller`]: libterminal_cribbage::game::Controller
+
+extern crate libterminal_cribbage;
+
+use std::cell::RefCell;
+
+use libterminal_cribbage::game::{
+    Decision, DecisionResponse, Game, NoOpDisplay, PlayerId, StepController,
+};
+
+type StepGame = Game<StepController, StepController, NoOpDisplay>;
+
+/// The [`Decision::Over`] code.
+const OVER: u32 = 0;
+/// The [`Decision::Cut`] code.
+const CUT: u32 = 1;
+/// The [`Decision::Discard`] code.
+const DISCARD: u32 = 2;
+/// The [`Decision::CutStarter`] code.
+const CUT_STARTER: u32 = 3;
+/// The [`Decision::Play`] code.
+const PLAY: u32 = 4;
+/// Returned when there is no [`Game`], or a response was rejected and the [`Decision`] stands.
+const REJECTED: u32 = u32::MAX;
+
+thread_local! {
+    /// The [`Game`] in progress, and the [`Decision`] it's waiting on.
+    static GAME: RefCell<Option<(StepGame, Decision)>> = const { RefCell::new(None) };
+}
+
+/// Returns the code of the [`Decision`].
+fn code(decision: &Decision) -> u32 {
+    match decision {
+        Decision::Over { .. } => OVER,
+        Decision::Cut { .. } => CUT,
+        Decision::Discard { .. } => DISCARD,
+        Decision::CutStarter { .. } => CUT_STARTER,
+        Decision::Play { .. } => PLAY,
+    }
+}
+
+/// Starts a new [`Game`] from the seed, and returns the code of its first [`Decision`].
+#[no_mangle]
+pub extern "C" fn cribbage_new(seed: u32) -> u32 {
+    let mut game = Game::new_step_driven().with_seed(u64::from(seed));
+
+    let Ok(decision) = game.next_decision() else {
+        return REJECTED;
+    };
+
+    let decision_code = code(&decision);
+
+    GAME.with(|state| *state.borrow_mut() = Some((game, decision)));
+
+    decision_code
+}
+
+/// Returns the code of the [`Decision`] the [`Game`] is waiting on.
+#[no_mangle]
+pub extern "C" fn cribbage_decision() -> u32 {
+    GAME.with(|state| {
+        state
+            .borrow()
+            .as_ref()
+            .map_or(REJECTED, |(_, decision)| code(decision))
+    })
+}
+
+/// Returns 1, or 2, for the player who decides, or 0 once the [`Game`] is over.
+#[no_mangle]
+pub extern "C" fn cribbage_player() -> u32 {
+    GAME.with(|state| {
+        let state = state.borrow();
+
+        match state.as_ref().and_then(|(_, decision)| decision.player()) {
+            Some(PlayerId::One) => 1,
+            Some(PlayerId::Two) => 2,
+            None => 0,
+        }
+    })
+}
+
+/// Returns how many cards are in the hand of the player who decides, to discard or play from.
+#[no_mangle]
+pub extern "C" fn cribbage_hand_len() -> u32 {
+    GAME.with(|state| {
+        let state = state.borrow();
+
+        match state.as_ref().map(|(_, decision)| decision) {
+            Some(Decision::Discard { hand, .. } | Decision::Play { hand, .. }) => hand.len() as u32,
+            _ => 0,
+        }
+    })
+}
+
+/// Returns the card at the index of the hand, from 0 for the A♥ to 51 for the K♣, or
+/// [`REJECTED`] if there's no such card.
+#[no_mangle]
+pub extern "C" fn cribbage_hand_card(index: u32) -> u32 {
+    GAME.with(|state| {
+        let state = state.borrow();
+
+        let card = match state.as_ref().map(|(_, decision)| decision) {
+            Some(Decision::Discard { hand, .. } | Decision::Play { hand, .. }) => {
+                hand.get(index as usize)
+            }
+            _ => None,
+        };
+
+        card.map_or(REJECTED, |card| u32::from(card.to_index()))
+    })
+}
+
+/// Returns the points of player 1, or 2.
+#[no_mangle]
+pub extern "C" fn cribbage_points(player: u32) -> u32 {
+    GAME.with(|state| {
+        state.borrow().as_ref().map_or(0, |(game, _)| {
+            let snapshot = game.snapshot();
+
+            if player == 1 {
+                snapshot.player_1_points
+            } else {
+                snapshot.player_2_points
+            }
+        })
+    })
+}
+
+/// Answers the [`Decision`] with the index, or both indices of a discard, and returns the code of
+/// the next [`Decision`], or [`REJECTED`] if the answer isn't allowed.
+#[no_mangle]
+pub extern "C" fn cribbage_apply(first: u32, second: u32) -> u32 {
+    GAME.with(|state| {
+        let mut state = state.borrow_mut();
+
+        let Some((game, decision)) = state.as_mut() else {
+            return REJECTED;
+        };
+
+        let (first, second) = (first as usize, second as usize);
+
+        let response = match decision {
+            Decision::Cut { .. } => DecisionResponse::Cut(first),
+            Decision::Discard { .. } => DecisionResponse::Discard([first, second]),
+            Decision::CutStarter { .. } => DecisionResponse::CutStarter(first),
+            Decision::Play { .. } => DecisionResponse::Play(first),
+            Decision::Over { .. } => return REJECTED,
+        };
+
+        match game.apply(response) {
+            Ok(next) => {
+                *decision = next;
+
+                code(decision)
+            }
+            Err(_) => REJECTED,
+        }
+    })
+}
@@ -0,0 +1,144 @@
+//! Prints the expected value of every way to discard two of six [`Card`]s to the crib.
+//!
+//! The six [`Card`]s are given on the command line, like `cribbage-analyze 5H 5C JS QD AS 9H`.
+//! For each discard, the kept hand is averaged over every starter that isn't one of the six, and
+//! the crib comes from the tables of [`crib_ev`] for the dealer and pone. The dealer keeps the
+//! crib, so it adds to their net, and the pone gives it away, so it takes from theirs.
+
+extern crate itertools;
+extern crate libterminal_cribbage;
+
+use std::env;
+use std::process;
+
+use itertools::Itertools;
+
+use libterminal_cribbage::cards::{Card, Deck, Hand};
+#[cfg(doc)]
+use libterminal_cribbage::game::crib_ev;
+use libterminal_cribbage::game::{discard_value_from_table, DiscardValue, CRIB_DISCARDS};
+
+/// The number of [`Card`]s dealt in the six-card game.
+const CARDS_DEALT: usize = 6;
+
+/// A way to discard to the crib, and its expected value as the dealer and pone.
+struct DiscardOption {
+    kept: Vec<Card>,
+    discards: Vec<Card>,
+    dealer: DiscardValue,
+    pone: DiscardValue,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let cards = match parse_hand(&args.join(" ")) {
+        Ok(cards) => cards,
+        Err(err) => {
+            eprintln!("{err}");
+            eprintln!("Usage: cribbage-analyze <six cards, like `5H 5C JS QD AS 9H`>");
+
+            process::exit(1);
+        }
+    };
+
+    let mut options = discard_options(&cards);
+
+    options.sort_by(|a, b| b.dealer.total().total_cmp(&a.dealer.total()));
+
+    println!("Hand: {}", Hand::from(cards));
+    println!();
+    println!(
+        "{:<22} {:<12} {:>8} {:>12} {:>10} {:>10} {:>8}",
+        "Keep", "Discard", "Hand EV", "Dealer Crib", "Dealer EV", "Pone Crib", "Pone EV"
+    );
+
+    for option in &options {
+        println!(
+            "{:<22} {:<12} {:>8.2} {:>12.2} {:>10.2} {:>10.2} {:>8.2}",
+            option.kept.iter().join(" "),
+            option.discards.iter().join(" "),
+            option.dealer.keep_ev,
+            option.dealer.crib_ev,
+            option.dealer.total(),
+            option.pone.crib_ev,
+            option.pone.total()
+        );
+    }
+
+    let best_pone = options
+        .iter()
+        .max_by(|a, b| a.pone.total().total_cmp(&b.pone.total()));
+
+    if let (Some(best_dealer), Some(best_pone)) = (options.first(), best_pone) {
+        println!();
+        println!(
+            "Best as dealer: discard {}",
+            best_dealer.discards.iter().join(" ")
+        );
+        println!(
+            "Best as pone: discard {}",
+            best_pone.discards.iter().join(" ")
+        );
+    }
+}
+
+/// Reads the six different [`Card`]s of the hand.
+fn parse_hand(input: &str) -> Result<Vec<Card>, String> {
+    let cards = input.parse::<Hand>()?.as_vec().clone();
+
+    if cards.len() != CARDS_DEALT {
+        return Err(format!(
+            "Expected {CARDS_DEALT} cards, got {}!",
+            cards.len()
+        ));
+    }
+
+    if cards
+        .iter()
+        .tuple_combinations()
+        .any(|(card, other)| card == other)
+    {
+        return Err(String::from("The cards must all be different!"));
+    }
+
+    Ok(cards)
+}
+
+/// Returns every way to discard [`CRIB_DISCARDS`] of the [`Card`]s, with its [`DiscardValue`] as
+/// the dealer and pone.
+fn discard_options(cards: &[Card]) -> Vec<DiscardOption> {
+    let unseen: Vec<Card> = Deck::new()
+        .as_vec()
+        .iter()
+        .filter(|card| !cards.contains(card))
+        .cloned()
+        .collect();
+
+    (0..cards.len())
+        .combinations(CRIB_DISCARDS)
+        .map(|discard_indices| {
+            let discards: Vec<Card> = discard_indices
+                .iter()
+                .map(|index| cards[*index].clone())
+                .collect();
+            let kept: Vec<Card> = cards
+                .iter()
+                .filter(|card| !discards.contains(card))
+                .cloned()
+                .collect();
+
+            let dealer =
+                discard_value_from_table(&kept, &discards, /*is_my_crib=*/ true, &unseen);
+            let pone =
+                discard_value_from_table(&kept, &discards, /*is_my_crib=*/ false, &unseen);
+
+            DiscardOption {
+                kept,
+                discards,
+                dealer,
+                pone,
+            }
+        })
+        .collect()
+}
@@ -1,7 +1,12 @@
 use std::fmt;
+use std::str::FromStr;
+
+#[cfg(doc)]
+use cards::{CardSet, Deck};
 
 /// [`Rank`] is a type the represents the rank of a playing card.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Rank {
     Ace,
     Two,
@@ -20,6 +25,7 @@ pub enum Rank {
 
 /// [`Suit`] is a type the represents the suit of a playing card.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Suit {
     Hearts,
     Spades,
@@ -28,7 +34,22 @@ pub enum Suit {
 }
 
 /// [`Card`] is a struct that holds the [`Rank`] and [`Suit`] type of a playing card.
+///
+/// A [`Card`] can be written with [`fmt::Display`], like `"[10♥]"`, and read back with
+/// [`FromStr`]. Plain ASCII, like `"10H"` from [`Card::to_ascii_string`], can also be read.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+///
+/// let card = Card::new(Rank::Ten, Suit::Hearts);
+///
+/// assert_eq!(card.to_string().parse(), Ok(card.clone()));
+/// assert_eq!("10H".parse(), Ok(card));
+/// ```
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Card {
     pub rank: Rank,
     pub suit: Suit,
@@ -82,11 +103,111 @@ impl Card {
             Rank::Ten | Rank::Jack | Rank::Queen | Rank::King => 10,
         }
     }
+
+    /// Returns the [`Card`] as a plain ASCII [`String`], with a letter for the [`Suit`].
+    ///
+    /// This is for terminals that render the [`Suit`] glyphs poorly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    ///
+    /// assert_eq!(Card::new(Rank::Five, Suit::Hearts).to_ascii_string(), "5H");
+    /// assert_eq!(Card::new(Rank::Ten, Suit::Clubs).to_ascii_string(), "10C");
+    /// assert_eq!(Card::new(Rank::King, Suit::Spades).to_ascii_string(), "KS");
+    /// ```
+    #[must_use]
+    pub fn to_ascii_string(&self) -> String {
+        format!("{}{}", self.rank_str(), self.suit.letter())
+    }
+
+    /// Returns the [`Card`] spelled out in words, like `"Five of Hearts"`.
+    ///
+    /// This is for screen readers, which read the [`Suit`] glyphs poorly, if at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    ///
+    /// assert_eq!(Card::new(Rank::Five, Suit::Hearts).to_spoken_string(), "Five of Hearts");
+    /// assert_eq!(Card::new(Rank::Ace, Suit::Spades).to_spoken_string(), "Ace of Spades");
+    /// ```
+    #[must_use]
+    pub fn to_spoken_string(&self) -> String {
+        format!("{} of {}", self.rank.name(), self.suit.name())
+    }
+
+    /// Returns the index of the [`Card`], from `0` to `51`, in the order of [`Deck::new`].
+    ///
+    /// This is the [`Suit`] index times 13, plus the [`Rank`] index, where [`Rank::Ace`] is `0`
+    /// and [`Suit::Hearts`] is `0`. It's compact, for lookup tables and a [`CardSet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Deck, Rank, Suit};
+    ///
+    /// assert_eq!(Card::new(Rank::Ace, Suit::Hearts).to_index(), 0);
+    /// assert_eq!(Card::new(Rank::Two, Suit::Spades).to_index(), 14);
+    /// assert_eq!(Card::new(Rank::King, Suit::Clubs).to_index(), 51);
+    ///
+    /// let card = Card::new(Rank::Jack, Suit::Diamonds);
+    ///
+    /// assert_eq!(Deck::new().as_vec()[usize::from(card.to_index())], card);
+    /// ```
+    #[must_use]
+    pub fn to_index(&self) -> u8 {
+        (self.suit as u8) * 13 + (self.rank as u8)
+    }
+
+    /// Returns the [`Card`] at the index from [`Card::to_index`], or [`None`] if the index is more
+    /// than `51`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    ///
+    /// assert_eq!(Card::from_index(14), Some(Card::new(Rank::Two, Suit::Spades)));
+    /// assert_eq!(Card::from_index(52), None);
+    /// ```
+    #[must_use]
+    pub fn from_index(index: u8) -> Option<Card> {
+        let suit = Suit::ALL.get(usize::from(index / 13))?;
+        let rank = Rank::ALL[usize::from(index % 13)];
+
+        Some(Card::new(rank, *suit))
+    }
+
+    /// The display [`str`] of the [`Rank`].
+    fn rank_str(&self) -> &'static str {
+        self.rank.as_str()
+    }
 }
 
-impl fmt::Display for Card {
-    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        let rank_str = match self.rank {
+impl Rank {
+    /// All of the [`Rank`]s, from [`Rank::Ace`] to [`Rank::King`].
+    pub(crate) const ALL: [Rank; 13] = [
+        Rank::Ace,
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+    ];
+
+    /// The display [`str`] of the [`Rank`].
+    fn as_str(self) -> &'static str {
+        match self {
             Rank::Two => "2",
             Rank::Three => "3",
             Rank::Four => "4",
@@ -100,19 +221,201 @@ impl fmt::Display for Card {
             Rank::Queen => "Q",
             Rank::King => "K",
             Rank::Ace => "A",
-        };
+        }
+    }
 
-        let suit_char = match self.suit {
+    /// The [`Rank`] spelled out in words.
+    fn name(self) -> &'static str {
+        match self {
+            Rank::Ace => "Ace",
+            Rank::Two => "Two",
+            Rank::Three => "Three",
+            Rank::Four => "Four",
+            Rank::Five => "Five",
+            Rank::Six => "Six",
+            Rank::Seven => "Seven",
+            Rank::Eight => "Eight",
+            Rank::Nine => "Nine",
+            Rank::Ten => "Ten",
+            Rank::Jack => "Jack",
+            Rank::Queen => "Queen",
+            Rank::King => "King",
+        }
+    }
+}
+
+impl Suit {
+    /// All of the [`Suit`]s, in [`Suit`] order.
+    const ALL: [Suit; 4] = [Suit::Hearts, Suit::Spades, Suit::Diamonds, Suit::Clubs];
+
+    /// Whether the [`Suit`] is printed in red, which is [`Suit::Hearts`] and [`Suit::Diamonds`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::Suit;
+    ///
+    /// assert!(Suit::Hearts.is_red());
+    /// assert!(!Suit::Spades.is_red());
+    /// ```
+    #[must_use]
+    pub fn is_red(self) -> bool {
+        matches!(self, Suit::Hearts | Suit::Diamonds)
+    }
+
+    /// The display glyph of the [`Suit`].
+    fn glyph(self) -> char {
+        match self {
             Suit::Hearts => '\u{2665}',
             Suit::Clubs => '\u{2663}',
             Suit::Diamonds => '\u{2666}',
             Suit::Spades => '\u{2660}',
+        }
+    }
+
+    /// The plain ASCII letter of the [`Suit`].
+    fn letter(self) -> char {
+        match self {
+            Suit::Hearts => 'H',
+            Suit::Clubs => 'C',
+            Suit::Diamonds => 'D',
+            Suit::Spades => 'S',
+        }
+    }
+
+    /// The [`Suit`] spelled out in words.
+    fn name(self) -> &'static str {
+        match self {
+            Suit::Hearts => "Hearts",
+            Suit::Clubs => "Clubs",
+            Suit::Diamonds => "Diamonds",
+            Suit::Spades => "Spades",
+        }
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Rank {
+    type Err = String;
+
+    /// Reads a [`Rank`] from its display, like `"A"` or `"10"`, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::Rank;
+    ///
+    /// assert_eq!("q".parse(), Ok(Rank::Queen));
+    /// assert_eq!(Rank::Ten.to_string().parse(), Ok(Rank::Ten));
+    /// assert!("11".parse::<Rank>().is_err());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Rank::ALL
+            .iter()
+            .find(|rank| rank.as_str().eq_ignore_ascii_case(input))
+            .copied()
+            .ok_or_else(|| format!("{input} is not a rank!"))
+    }
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.glyph())
+    }
+}
+
+impl FromStr for Suit {
+    type Err = String;
+
+    /// Reads a [`Suit`] from its glyph, like `"♥"`, or its letter, like `"H"`, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::Suit;
+    ///
+    /// assert_eq!("h".parse(), Ok(Suit::Hearts));
+    /// assert_eq!(Suit::Clubs.to_string().parse(), Ok(Suit::Clubs));
+    /// assert!("X".parse::<Suit>().is_err());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut chars = input.chars();
+
+        let possible_suit = match (chars.next(), chars.next()) {
+            (Some(suit_char), None) => Suit::ALL.iter().find(|suit| {
+                (suit.glyph() == suit_char) || suit.letter().eq_ignore_ascii_case(&suit_char)
+            }),
+            _ => None,
         };
 
-        write!(formatter, "[{rank_str}{suit_char}]")
+        possible_suit
+            .copied()
+            .ok_or_else(|| format!("{input} is not a suit!"))
     }
 }
 
+impl fmt::Display for Card {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "[{}{}]", self.rank, self.suit)
+    }
+}
+
+impl FromStr for Card {
+    type Err = String;
+
+    /// Reads a [`Card`] from its display, like `"[10♥]"`, or plain ASCII, like `"10H"`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        let unbracketed = trimmed
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .unwrap_or(trimmed);
+
+        let split_at = unbracketed
+            .char_indices()
+            .last()
+            .map_or(0, |(index, _)| index);
+        let (rank_str, suit_str) = unbracketed.split_at(split_at);
+
+        match (rank_str.parse(), suit_str.parse()) {
+            (Ok(rank), Ok(suit)) => Ok(Card::new(rank, suit)),
+            _ => Err(format!("{input} is not a card!")),
+        }
+    }
+}
+
+/// Renders the [`Card`] as lines of unicode box art, all of the same width.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{render_card_art, Card, Rank, Suit};
+///
+/// let art = render_card_art(&Card::new(Rank::Ten, Suit::Hearts));
+///
+/// assert_eq!(
+///     art,
+///     vec!["┌─────┐", "│10   │", "│  ♥  │", "│   10│", "└─────┘"]
+/// );
+/// ```
+#[must_use]
+pub fn render_card_art(card: &Card) -> Vec<String> {
+    let rank = card.rank_str();
+
+    vec![
+        String::from("\u{250c}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2510}"),
+        format!("\u{2502}{rank:<2}   \u{2502}"),
+        format!("\u{2502}  {}  \u{2502}", card.suit),
+        format!("\u{2502}   {rank:>2}\u{2502}"),
+        String::from("\u{2514}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2518}"),
+    ]
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -125,6 +428,40 @@ mod test {
         assert_eq!(test_card.suit, Suit::Clubs);
     }
 
+    #[test]
+    fn test_from_str_round_trips_every_card() {
+        for suit in Suit::ALL {
+            for rank in Rank::ALL {
+                let card = Card::new(rank, suit);
+
+                assert_eq!(card.to_string().parse(), Ok(card.clone()));
+                assert_eq!(card.to_ascii_string().parse(), Ok(card));
+            }
+        }
+    }
+
+    #[test]
+    fn test_index_round_trips_every_card() {
+        for index in 0..52 {
+            let card = Card::from_index(index).unwrap();
+
+            assert_eq!(card.to_index(), index);
+        }
+
+        assert_eq!(Card::from_index(52), None);
+        assert_eq!(Card::from_index(u8::MAX), None);
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert_eq!("".parse::<Card>(), Err(String::from(" is not a card!")));
+        assert_eq!("1H".parse::<Card>(), Err(String::from("1H is not a card!")));
+        assert_eq!(
+            "[AX]".parse::<Card>(),
+            Err(String::from("[AX] is not a card!"))
+        );
+    }
+
     #[test]
     fn test_score() {
         let playing_card_1 = Card::new(Rank::Ace, Suit::Spades);
@@ -133,4 +470,17 @@ mod test {
         assert_eq!(playing_card_1.score(), 1);
         assert_eq!(playing_card_2.score(), 10);
     }
+
+    #[test]
+    fn test_render_card_art_lines_are_the_same_width() {
+        for card in [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Ten, Suit::Diamonds),
+        ] {
+            let art = render_card_art(&card);
+
+            assert_eq!(art.len(), 5);
+            assert!(art.iter().all(|line| line.chars().count() == 7));
+        }
+    }
 }
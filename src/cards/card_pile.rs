@@ -0,0 +1,146 @@
+//! The shared behavior of piles of [`Card`]s, like a [`Deck`] or a [`Hand`].
+
+#[cfg(doc)]
+use cards::{Deck, Hand, Rank};
+
+use itertools::Itertools;
+
+use cards::Card;
+
+/// The `trait` for anything that is a pile of [`Card`]s, like a [`Deck`] or a [`Hand`].
+///
+/// This only covers what the piles have in common. The distinct semantics, like a [`Deck`]
+/// dealing from the back and a [`Hand`] discarding by index, are left to the implementors.
+pub trait CardPile {
+    /// Returns the [`Card`]s in the pile as a slice.
+    fn as_slice(&self) -> &[Card];
+
+    /// Removes, and returns, the [`Card`] at the index in the pile.
+    ///
+    /// # Errors
+    ///
+    /// If the index is out of bounds of the pile.
+    fn remove_at(&mut self, index: usize) -> Result<Card, String>;
+
+    /// Returns the number of [`Card`]s in the pile.
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Indicates if there are no [`Card`]s in the pile.
+    fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    /// Returns the display [`String`] of the pile, e.g. `"[ [A♥],[2♣] ]"`.
+    fn cards_string(&self) -> String {
+        let cards_str_joined = self
+            .as_slice()
+            .iter()
+            .map(std::string::ToString::to_string)
+            .join(",");
+
+        format!("[ {cards_str_joined} ]")
+    }
+}
+
+/// Reads the [`Card`]s of a pile from its display, like `"[ [A♥],[2♣] ]"`.
+///
+/// The [`Card`]s can also be plain ASCII separated by whitespace, like `"AH 2C"`.
+///
+/// # Errors
+///
+/// If any of the [`Card`]s can't be read.
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, String> {
+    input
+        .replace(',', " ")
+        .split_whitespace()
+        .filter(|token| (*token != "[") && (*token != "]"))
+        .map(str::parse)
+        .collect()
+}
+
+/// Returns the number of [`Card`]s of each [`Rank`] in the pile.
+///
+/// This uses the enum to usize conversion, where [`Rank::Ace`] is mapped to index 0 and
+/// [`Rank::King`] is mapped to index 12.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{count_ranks, Card, Deck, Hand, Rank, Suit};
+///
+/// let hand = Hand::from(vec![
+///     Card::new(Rank::Ace, Suit::Hearts),
+///     Card::new(Rank::Ace, Suit::Clubs),
+///     Card::new(Rank::King, Suit::Clubs),
+/// ]);
+///
+/// let hand_ranks = count_ranks(&hand);
+///
+/// assert_eq!(hand_ranks[Rank::Ace as usize], 2);
+/// assert_eq!(hand_ranks[Rank::King as usize], 1);
+///
+/// assert_eq!(count_ranks(&Deck::new()), [4; 13]);
+/// ```
+pub fn count_ranks(pile: &impl CardPile) -> [u32; 13] {
+    let mut ranks_found = [0; 13];
+
+    for card in pile.as_slice() {
+        ranks_found[card.rank as usize] += 1;
+    }
+
+    ranks_found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Deck, Hand, Rank, Suit};
+
+    #[test]
+    fn test_count_ranks_deck_and_hand() {
+        let cards = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+
+        let mut expected = [0; 13];
+
+        expected[Rank::Five as usize] = 2;
+        expected[Rank::Jack as usize] = 1;
+
+        assert_eq!(count_ranks(&Hand::from(cards.clone())), expected);
+        assert_eq!(count_ranks(&Deck::new_with_cards(cards)), expected);
+    }
+
+    #[test]
+    fn test_remove_at() {
+        let cards = vec![
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+
+        let mut hand = Hand::from(cards.clone());
+        let mut deck = Deck::new_with_cards(cards);
+
+        assert_eq!(hand.remove_at(1), Ok(Card::new(Rank::Two, Suit::Clubs)));
+        assert_eq!(deck.remove_at(0), Ok(Card::new(Rank::Ace, Suit::Hearts)));
+        assert!(hand.remove_at(5).is_err());
+        assert!(deck.remove_at(5).is_err());
+        assert_eq!(CardPile::len(&hand), 1);
+        assert_eq!(CardPile::len(&deck), 1);
+    }
+
+    #[test]
+    fn test_deck_hand_round_trip() {
+        let deck = Deck::new();
+
+        let round_trip = Deck::from(Hand::from(deck.clone()));
+
+        assert_eq!(round_trip, deck);
+        assert_eq!(round_trip.cards_string(), deck.to_string());
+    }
+}
@@ -0,0 +1,286 @@
+//! A compact set of [`Card`]s, as the bits of a [`u64`].
+
+#[cfg(doc)]
+use cards::Deck;
+
+use std::convert::TryFrom;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitOr, Sub};
+
+use cards::{Card, Suit};
+
+/// The bits of every [`Card`] in a full [`Deck`].
+const FULL_BITS: u64 = (1 << 52) - 1;
+
+/// The bits of every [`Card`] of [`Suit::Hearts`]. The other [`Suit`]s are shifted by 13 each.
+const SUIT_BITS: u64 = (1 << 13) - 1;
+
+/// A set of [`Card`]s, where each [`Card`] is the bit at its [`Card::to_index`].
+///
+/// It's [`Copy`], and the set operations are a single instruction, so it's meant for the hot paths
+/// of an AI, like tracking the [`Card`]s that haven't been seen. Iterating it goes in the order of
+/// [`Deck::new`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, CardSet, Rank, Suit};
+///
+/// let hand: CardSet = [
+///     Card::new(Rank::Five, Suit::Hearts),
+///     Card::new(Rank::Jack, Suit::Clubs),
+/// ]
+/// .iter()
+/// .collect();
+///
+/// let unseen = CardSet::full() - hand;
+///
+/// assert_eq!(unseen.len(), 50);
+/// assert!(!unseen.contains(&Card::new(Rank::Five, Suit::Hearts)));
+/// assert_eq!((unseen & CardSet::of_suit(Suit::Hearts)).len(), 12);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    /// Creates a new, empty, [`CardSet`].
+    #[must_use]
+    pub fn new() -> CardSet {
+        CardSet(0)
+    }
+
+    /// Creates a [`CardSet`] with all 52 [`Card`]s.
+    #[must_use]
+    pub fn full() -> CardSet {
+        CardSet(FULL_BITS)
+    }
+
+    /// Creates a [`CardSet`] with all 13 [`Card`]s of the [`Suit`].
+    #[must_use]
+    pub fn of_suit(suit: Suit) -> CardSet {
+        CardSet(SUIT_BITS << (suit as u8 * 13))
+    }
+
+    /// Returns the bits of the [`CardSet`], where bit `n` is the [`Card`] of [`Card::to_index`]
+    /// `n`.
+    #[must_use]
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Adds the [`Card`], returning `true` if it wasn't already in the [`CardSet`].
+    pub fn insert(&mut self, card: &Card) -> bool {
+        let had_card = self.contains(card);
+
+        self.0 |= Self::bit(card);
+
+        !had_card
+    }
+
+    /// Removes the [`Card`], returning `true` if it was in the [`CardSet`].
+    pub fn remove(&mut self, card: &Card) -> bool {
+        let had_card = self.contains(card);
+
+        self.0 &= !Self::bit(card);
+
+        had_card
+    }
+
+    /// Indicates if the [`Card`] is in the [`CardSet`].
+    #[must_use]
+    pub fn contains(self, card: &Card) -> bool {
+        self.0 & Self::bit(card) != 0
+    }
+
+    /// Returns how many [`Card`]s are in the [`CardSet`].
+    #[must_use]
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Indicates if there are no [`Card`]s in the [`CardSet`].
+    #[must_use]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the [`Card`]s in either [`CardSet`].
+    #[must_use]
+    pub fn union(self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    /// Returns the [`Card`]s in both [`CardSet`]s.
+    #[must_use]
+    pub fn intersection(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    /// Returns the [`Card`]s in this [`CardSet`], but not the other.
+    #[must_use]
+    pub fn difference(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    /// Indicates if every [`Card`] in this [`CardSet`] is in the other.
+    #[must_use]
+    pub fn is_subset(self, other: CardSet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Returns an iterator over the [`Card`]s, in the order of [`Deck::new`].
+    #[must_use]
+    pub fn iter(self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    /// The bit of the [`Card`].
+    fn bit(card: &Card) -> u64 {
+        1 << card.to_index()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, other: CardSet) -> CardSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, other: CardSet) -> CardSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, other: CardSet) -> CardSet {
+        self.difference(other)
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = &'a Card>>(cards: I) -> CardSet {
+        let mut set = CardSet::new();
+
+        for card in cards {
+            set.insert(card);
+        }
+
+        set
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> CardSet {
+        let mut set = CardSet::new();
+
+        for card in cards {
+            set.insert(&card);
+        }
+
+        set
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the [`Card`]s of a [`CardSet`], from [`CardSet::iter`].
+#[derive(Debug, Clone)]
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let index = self.0.trailing_zeros();
+
+        // Clear the lowest bit.
+        self.0 &= self.0 - 1;
+
+        Card::from_index(u8::try_from(index).unwrap())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Deck, Rank};
+
+    #[test]
+    fn test_insert_and_remove() {
+        let card = Card::new(Rank::Seven, Suit::Diamonds);
+
+        let mut set = CardSet::new();
+
+        assert!(set.insert(&card));
+        assert!(!set.insert(&card));
+        assert!(set.contains(&card));
+        assert_eq!(set.len(), 1);
+
+        assert!(set.remove(&card));
+        assert!(!set.remove(&card));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_full_iterates_in_deck_order() {
+        let cards: Vec<Card> = CardSet::full().iter().collect();
+
+        assert_eq!(&cards, Deck::new().as_vec());
+        assert_eq!(CardSet::full().iter().len(), 52);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let hearts = CardSet::of_suit(Suit::Hearts);
+        let fives: CardSet = [Suit::Hearts, Suit::Spades, Suit::Diamonds, Suit::Clubs]
+            .iter()
+            .map(|suit| Card::new(Rank::Five, *suit))
+            .collect();
+
+        assert_eq!((hearts | fives).len(), 16);
+        assert_eq!(
+            (hearts & fives).iter().collect::<Vec<_>>(),
+            vec![Card::new(Rank::Five, Suit::Hearts)]
+        );
+        assert_eq!((fives - hearts).len(), 3);
+        assert!((hearts & fives).is_subset(hearts));
+        assert!(!fives.is_subset(hearts));
+    }
+
+    #[test]
+    fn test_suits_partition_the_deck() {
+        let suits = [Suit::Hearts, Suit::Spades, Suit::Diamonds, Suit::Clubs]
+            .iter()
+            .map(|suit| CardSet::of_suit(*suit))
+            .fold(CardSet::new(), BitOr::bitor);
+
+        assert_eq!(suits, CardSet::full());
+    }
+}
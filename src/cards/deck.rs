@@ -1,14 +1,16 @@
 use std::fmt;
+use std::str::FromStr;
 
-use itertools::Itertools;
-use rand::seq::SliceRandom;
+use rand::{rngs::SmallRng, seq::SliceRandom, Rng, SeedableRng};
 
-use cards::{Card, Rank, Suit};
+use cards::card_pile::parse_cards;
+use cards::{Card, CardPile, Hand, Rank, Suit};
 
 /// The [`Deck`] struct is a wrapper for a vector of [`Card`]s.
 ///
 /// This wrapper is so the vector can be treated like an actual deck of [`Card`]s
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Deck(Vec<Card>);
 
 impl Deck {
@@ -93,9 +95,57 @@ impl Deck {
     /// println!("Shuffled deck of cards: {}", deck);
     /// ```
     pub fn shuffle(&mut self) {
-        let mut rng = rand::thread_rng();
+        self.shuffle_with_rng(&mut rand::thread_rng());
+    }
 
-        self.0.shuffle(&mut rng);
+    /// Shuffles the [`Card`]s in a [`Deck`] in place with a seeded RNG.
+    ///
+    /// The same seed will always shuffle a [`Deck`] the same way, which is useful for testing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::Deck;
+    ///
+    /// let mut deck_1 = Deck::new();
+    /// let mut deck_2 = Deck::new();
+    ///
+    /// deck_1.shuffle_with_seed(42);
+    /// deck_2.shuffle_with_seed(42);
+    ///
+    /// assert_eq!(deck_1, deck_2);
+    /// ```
+    pub fn shuffle_with_seed(&mut self, seed: u64) {
+        self.shuffle_with_rng(&mut SmallRng::seed_from_u64(seed));
+    }
+
+    /// Shuffles the [`Card`]s in a [`Deck`] in place with the given [`Rng`].
+    ///
+    /// Sharing one seeded [`Rng`] across many shuffles makes a whole sequence of deals
+    /// reproducible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate libterminal_cribbage;
+    /// extern crate rand;
+    ///
+    /// use libterminal_cribbage::cards::Deck;
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    ///
+    /// let mut deck = Deck::new();
+    ///
+    /// deck.shuffle_with_rng(&mut rng);
+    ///
+    /// assert_ne!(deck, Deck::new());
+    /// ```
+    pub fn shuffle_with_rng<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        self.0.shuffle(rng);
     }
 
     /// Deals a [`Card`] from the back of the [`Deck`].
@@ -115,6 +165,116 @@ impl Deck {
         self.0.pop()
     }
 
+    /// Deals `cards_each` [`Card`]s to each of `n_hands` [`Hand`]s, from the back of the [`Deck`].
+    ///
+    /// The [`Card`]s are dealt one at a time, going around the [`Hand`]s in order, so the first
+    /// [`Hand`] is dealt the first [`Card`] of every pass.
+    ///
+    /// # Errors
+    ///
+    /// If there are fewer than `n_hands * cards_each` [`Card`]s in the [`Deck`]. Then no
+    /// [`Card`]s are dealt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Deck, Card, Rank, Suit};
+    ///
+    /// let mut deck = Deck::new();
+    ///
+    /// let hands = deck.deal_hands(2, 6).unwrap();
+    ///
+    /// assert_eq!(hands.len(), 2);
+    /// assert_eq!(hands[0].as_vec()[0], Card::new(Rank::King, Suit::Clubs));
+    /// assert_eq!(hands[1].as_vec()[0], Card::new(Rank::Queen, Suit::Clubs));
+    /// assert_eq!(deck.as_vec().len(), 40);
+    ///
+    /// assert!(deck.deal_hands(4, 11).is_err());
+    /// assert_eq!(deck.as_vec().len(), 40);
+    /// ```
+    pub fn deal_hands(&mut self, n_hands: usize, cards_each: usize) -> Result<Vec<Hand>, String> {
+        if self.0.len() < n_hands * cards_each {
+            return Err("Not enough cards!".to_string());
+        }
+
+        let mut hands = vec![Hand::new(); n_hands];
+
+        for _ in 0..cards_each {
+            for hand in &mut hands {
+                hand.add_card(self.0.pop().expect("the deck had enough cards"));
+            }
+        }
+
+        Ok(hands)
+    }
+
+    /// Cuts the [`Deck`] `depth` [`Card`]s from the top, returning the top and bottom packets.
+    ///
+    /// The top of the [`Deck`] is the back, where [`Deck::deal`] takes from, so the top packet is
+    /// the last `depth` [`Card`]s. Both packets keep the order of their [`Card`]s, and the [`Deck`]
+    /// itself is left alone.
+    ///
+    /// # Errors
+    ///
+    /// If `depth` is more than the number of [`Card`]s in the [`Deck`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Deck, Card, Rank, Suit};
+    ///
+    /// let deck = Deck::new();
+    ///
+    /// let (mut top, mut bottom) = deck.cut(13).unwrap();
+    ///
+    /// assert_eq!(top.as_vec().len(), 13);
+    /// assert_eq!(bottom.as_vec().len(), 39);
+    /// assert_eq!(top.deal(), Some(Card::new(Rank::King, Suit::Clubs)));
+    /// assert_eq!(bottom.deal(), Some(Card::new(Rank::King, Suit::Diamonds)));
+    ///
+    /// assert!(deck.cut(53).is_err());
+    /// ```
+    pub fn cut(&self, depth: usize) -> Result<(Deck, Deck), String> {
+        if self.0.len() < depth {
+            return Err("Out of Bounds!".to_string());
+        }
+
+        let (bottom, top) = self.0.split_at(self.0.len() - depth);
+
+        Ok((Deck(top.to_vec()), Deck(bottom.to_vec())))
+    }
+
+    /// Cuts the [`Deck`] `depth` [`Card`]s from the top, and puts the bottom packet on top.
+    ///
+    /// This is how the [`Deck`] is put back together after a cut, so the [`Card`] that was just
+    /// below the cut is now on top, and is the next one dealt.
+    ///
+    /// # Errors
+    ///
+    /// If `depth` is more than the number of [`Card`]s in the [`Deck`]. Then the [`Deck`] is left
+    /// alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Deck, Card, Rank, Suit};
+    ///
+    /// let mut deck = Deck::new();
+    ///
+    /// deck.cut_and_restack(13).unwrap();
+    ///
+    /// assert_eq!(deck.as_vec().len(), 52);
+    /// assert_eq!(deck.deal(), Some(Card::new(Rank::King, Suit::Diamonds)));
+    /// ```
+    pub fn cut_and_restack(&mut self, depth: usize) -> Result<(), String> {
+        let (top, bottom) = self.cut(depth)?;
+
+        self.0 = top.0;
+        self.0.extend(bottom.0);
+
+        Ok(())
+    }
+
     /// Removes a [`Card`] from the [`Deck`].
     ///
     /// # Errors
@@ -165,15 +325,65 @@ impl Default for Deck {
     }
 }
 
+impl CardPile for Deck {
+    fn as_slice(&self) -> &[Card] {
+        &self.0
+    }
+
+    /// Removes, and returns, the [`Card`] at the index like [`Deck::remove`].
+    fn remove_at(&mut self, index: usize) -> Result<Card, String> {
+        self.remove(index)
+    }
+}
+
 impl fmt::Display for Deck {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        let cards_str_joined = self
-            .0
-            .iter()
-            .map(std::string::ToString::to_string)
-            .join(",");
+        write!(formatter, "{}", self.cards_string())
+    }
+}
 
-        write!(formatter, "[ {cards_str_joined} ]")
+/// Reads a [`Deck`] from its display, like `"[ [A♥],[2♣] ]"`, or plain ASCII, like `"AH 2C"`.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::Deck;
+///
+/// let deck = Deck::new();
+///
+/// assert_eq!(deck.to_string().parse(), Ok(deck));
+/// assert!("AH 2X".parse::<Deck>().is_err());
+/// ```
+impl FromStr for Deck {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_cards(input).map(Deck)
+    }
+}
+
+/// Converts a [`Hand`] to a [`Deck`], keeping the order of the [`Card`]s.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Deck, Hand, Rank, Suit};
+///
+/// let cards = vec![
+///     Card::new(Rank::Ace, Suit::Clubs),
+///     Card::new(Rank::Two, Suit::Spades),
+/// ];
+///
+/// let hand = Hand::from(cards.clone());
+///
+/// let deck = Deck::from(hand.clone());
+///
+/// assert_eq!(deck.as_vec(), &cards);
+/// assert_eq!(Hand::from(deck), hand);
+/// ```
+impl From<Hand> for Deck {
+    fn from(hand: Hand) -> Deck {
+        Deck(hand.as_vec().clone())
     }
 }
 
@@ -258,4 +468,77 @@ mod test {
 
         assert_eq!(dealt_card, None);
     }
+
+    #[test]
+    fn test_cut() {
+        let test_deck: Deck = "AH 2H 3H 4H 5H".parse().unwrap();
+
+        let (top, bottom) = test_deck.cut(2).unwrap();
+
+        assert_eq!(top, "4H 5H".parse().unwrap());
+        assert_eq!(bottom, "AH 2H 3H".parse().unwrap());
+        assert_eq!(test_deck.0.len(), 5);
+    }
+
+    #[test]
+    fn test_cut_whole_deck() {
+        let test_deck: Deck = "AH 2H 3H".parse().unwrap();
+
+        assert_eq!(test_deck.cut(0), Ok((Deck(Vec::new()), test_deck.clone())));
+        assert_eq!(test_deck.cut(3), Ok((test_deck.clone(), Deck(Vec::new()))));
+        assert_eq!(test_deck.cut(4), Err("Out of Bounds!".to_string()));
+    }
+
+    #[test]
+    fn test_cut_and_restack() {
+        let mut test_deck: Deck = "AH 2H 3H 4H 5H".parse().unwrap();
+
+        test_deck.cut_and_restack(2).unwrap();
+
+        assert_eq!(test_deck, "4H 5H AH 2H 3H".parse().unwrap());
+        assert_eq!(test_deck.deal(), Some(Card::new(Rank::Three, Suit::Hearts)));
+    }
+
+    #[test]
+    fn test_cut_and_restack_out_of_bounds() {
+        let mut test_deck: Deck = "AH 2H 3H".parse().unwrap();
+
+        assert!(test_deck.cut_and_restack(4).is_err());
+        assert_eq!(test_deck, "AH 2H 3H".parse().unwrap());
+    }
+
+    #[test]
+    fn test_deal_hands() {
+        let mut test_deck: Deck = "AH 2H 3H 4H 5H 6H 7H".parse().unwrap();
+
+        let hands = test_deck.deal_hands(3, 2).unwrap();
+
+        let expected = vec![
+            Hand::from(vec![
+                Card::new(Rank::Seven, Suit::Hearts),
+                Card::new(Rank::Four, Suit::Hearts),
+            ]),
+            Hand::from(vec![
+                Card::new(Rank::Six, Suit::Hearts),
+                Card::new(Rank::Three, Suit::Hearts),
+            ]),
+            Hand::from(vec![
+                Card::new(Rank::Five, Suit::Hearts),
+                Card::new(Rank::Two, Suit::Hearts),
+            ]),
+        ];
+
+        assert_eq!(hands, expected);
+        assert_eq!(test_deck.0, vec![Card::new(Rank::Ace, Suit::Hearts)]);
+    }
+
+    #[test]
+    fn test_deal_hands_not_enough_cards() {
+        let mut test_deck: Deck = "AH 2H 3H".parse().unwrap();
+
+        let result = test_deck.deal_hands(2, 2);
+
+        assert_eq!(result, Err("Not enough cards!".to_string()));
+        assert_eq!(test_deck.0.len(), 3);
+    }
 }
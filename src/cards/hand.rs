@@ -1,16 +1,22 @@
-use itertools::Itertools;
+use std::collections::BTreeMap;
 use std::fmt;
+use std::ops::Index;
+use std::slice;
+use std::str::FromStr;
+use std::vec;
 
+use cards::card_pile::parse_cards;
 use cards::score;
-use cards::Card;
+use cards::{Card, CardPile, Deck};
 
 #[cfg(doc)]
-use cards::card::Rank;
+use cards::card::{Rank, Suit};
 
 /// The [`Hand`] struct is a wrapper for a vector of [`Card`]s.
 ///
 /// This wrapper is so the vector can be treated like an actual hand of [`Card`]s
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hand(Vec<Card>);
 
 impl Hand {
@@ -178,6 +184,165 @@ impl Hand {
         score::total(self, starter, is_crib)
     }
 
+    /// Returns the score of the [`Hand`] on its own, before the starter [`Card`] is cut.
+    ///
+    /// See [`static_total`](cards::static_total) for what's counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand = Hand::from(vec![
+    ///     Card::new(Rank::Jack, Suit::Clubs),
+    ///     Card::new(Rank::Five, Suit::Diamonds),
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    ///     Card::new(Rank::Five, Suit::Spades),
+    /// ]);
+    ///
+    /// assert_eq!(hand.static_total(), 14);
+    /// ```
+    #[must_use]
+    pub fn static_total(&self) -> u32 {
+        score::static_total(self)
+    }
+
+    /// Returns the average score of the [`Hand`] over every starter [`Card`] that isn't in it.
+    ///
+    /// Every other [`Card`] of a [`Deck`] is equally likely to be the starter, since the
+    /// [`Hand`] is all that's been seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand = Hand::from(vec![
+    ///     Card::new(Rank::Jack, Suit::Clubs),
+    ///     Card::new(Rank::Five, Suit::Diamonds),
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    ///     Card::new(Rank::Five, Suit::Spades),
+    /// ]);
+    ///
+    /// let expected_total = hand.expected_total(/*is_crib=*/ false);
+    ///
+    /// // At least the 14 points already in the hand, and at most the 29 from the last Five.
+    /// assert!(14.0 < expected_total && expected_total < 29.0);
+    /// ```
+    #[must_use]
+    pub fn expected_total(&self, is_crib: bool) -> f64 {
+        let totals = self.starter_totals(is_crib);
+
+        if totals.is_empty() {
+            return 0.0;
+        }
+
+        let sum: u32 = totals.iter().sum();
+
+        f64::from(sum) / totals.len() as f64
+    }
+
+    /// Returns how many of the starter [`Card`]s that aren't in the [`Hand`] give each score.
+    ///
+    /// The keys are the scores, in order, and only scores that can happen are included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand = Hand::from(vec![
+    ///     Card::new(Rank::Jack, Suit::Clubs),
+    ///     Card::new(Rank::Five, Suit::Diamonds),
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    ///     Card::new(Rank::Five, Suit::Spades),
+    /// ]);
+    ///
+    /// let distribution = hand.score_distribution();
+    ///
+    /// // Only the Five of Clubs makes 29.
+    /// assert_eq!(distribution.get(&29), Some(&1));
+    /// assert_eq!(distribution.values().sum::<usize>(), 48);
+    /// ```
+    #[must_use]
+    pub fn score_distribution(&self) -> BTreeMap<u32, usize> {
+        let mut distribution = BTreeMap::new();
+
+        for total in self.starter_totals(/*is_crib=*/ false) {
+            *distribution.entry(total).or_insert(0) += 1;
+        }
+
+        distribution
+    }
+
+    /// Returns the score of the [`Hand`] with each starter [`Card`] of a [`Deck`] that isn't in it.
+    fn starter_totals(&self, is_crib: bool) -> Vec<u32> {
+        Deck::new()
+            .as_vec()
+            .iter()
+            .filter(|starter| !self.0.contains(starter))
+            .map(|starter| self.total(starter, is_crib))
+            .collect()
+    }
+
+    /// Sorts the [`Hand`] by [`Rank`], and then by [`Suit`] for [`Card`]s of the same [`Rank`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let mut hand = Hand::from(vec![
+    ///     Card::new(Rank::King, Suit::Clubs),
+    ///     Card::new(Rank::Five, Suit::Diamonds),
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    /// ]);
+    ///
+    /// hand.sort_by_rank();
+    ///
+    /// let expected = vec![
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    ///     Card::new(Rank::Five, Suit::Diamonds),
+    ///     Card::new(Rank::King, Suit::Clubs),
+    /// ];
+    ///
+    /// assert_eq!(hand.as_vec(), &expected);
+    /// ```
+    pub fn sort_by_rank(&mut self) {
+        self.0.sort();
+    }
+
+    /// Sorts the [`Hand`] by [`Suit`], and then by [`Rank`] within each [`Suit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let mut hand = Hand::from(vec![
+    ///     Card::new(Rank::King, Suit::Clubs),
+    ///     Card::new(Rank::Five, Suit::Diamonds),
+    ///     Card::new(Rank::Ace, Suit::Clubs),
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    /// ]);
+    ///
+    /// hand.sort_by_suit_then_rank();
+    ///
+    /// let expected = vec![
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    ///     Card::new(Rank::Five, Suit::Diamonds),
+    ///     Card::new(Rank::Ace, Suit::Clubs),
+    ///     Card::new(Rank::King, Suit::Clubs),
+    /// ];
+    ///
+    /// assert_eq!(hand.as_vec(), &expected);
+    /// ```
+    pub fn sort_by_suit_then_rank(&mut self) {
+        self.0.sort_by_key(|card| (card.suit, card.rank));
+    }
+
     /// Indicates if the [`Hand`] is empty.
     ///
     /// # Examples
@@ -224,6 +389,40 @@ impl Hand {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Indicates if the [`Card`] is in the [`Hand`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand: Hand = "JC 5D 5H 5S".parse().unwrap();
+    ///
+    /// assert!(hand.contains(&Card::new(Rank::Jack, Suit::Clubs)));
+    /// assert!(!hand.contains(&Card::new(Rank::Five, Suit::Clubs)));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, card: &Card) -> bool {
+        self.0.contains(card)
+    }
+
+    /// Returns an iterator over the [`Card`]s in the [`Hand`], in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Hand, Rank, Suit};
+    ///
+    /// let hand: Hand = "JC 5D 5H 5S".parse().unwrap();
+    ///
+    /// let fives = hand.iter().filter(|card| card.rank == Rank::Five).count();
+    ///
+    /// assert_eq!(fives, 3);
+    /// ```
+    pub fn iter(&self) -> slice::Iter<'_, Card> {
+        self.0.iter()
+    }
 }
 
 impl Default for Hand {
@@ -232,15 +431,93 @@ impl Default for Hand {
     }
 }
 
+/// Returns the [`Card`] at the index in the [`Hand`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Hand, Rank, Suit};
+///
+/// let hand: Hand = "JC 5D".parse().unwrap();
+///
+/// assert_eq!(hand[1], Card::new(Rank::Five, Suit::Diamonds));
+/// ```
+impl Index<usize> for Hand {
+    type Output = Card;
+
+    fn index(&self, index: usize) -> &Card {
+        &self.0[index]
+    }
+}
+
+/// Moves the [`Card`]s out of the [`Hand`], in order.
+impl IntoIterator for Hand {
+    type Item = Card;
+    type IntoIter = vec::IntoIter<Card>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Borrows the [`Card`]s in the [`Hand`], in order, like [`Hand::iter`].
+impl<'a> IntoIterator for &'a Hand {
+    type Item = &'a Card;
+    type IntoIter = slice::Iter<'a, Card>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl CardPile for Hand {
+    fn as_slice(&self) -> &[Card] {
+        &self.0
+    }
+
+    /// Removes, and returns, the [`Card`] at the index like [`Hand::discard`].
+    fn remove_at(&mut self, index: usize) -> Result<Card, String> {
+        self.discard(index)
+    }
+}
+
 impl fmt::Display for Hand {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        let cards_str_joined = self
-            .0
-            .iter()
-            .map(std::string::ToString::to_string)
-            .join(",");
+        write!(formatter, "{}", self.cards_string())
+    }
+}
+
+/// Reads a [`Hand`] from its display, like `"[ [A♥],[2♣] ]"`, or plain ASCII, like `"AH 2C"`.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Hand};
+///
+/// let hand: Hand = "AH 2C 3D 4S".parse().unwrap();
+///
+/// assert_eq!(hand.to_string().parse(), Ok(hand.clone()));
+///
+/// // A test fixture with a starter.
+/// let (hand_str, starter_str) = "AH 2C 3D 4S | 5H".split_once('|').unwrap();
+///
+/// let hand: Hand = hand_str.parse().unwrap();
+/// let starter: Card = starter_str.parse().unwrap();
+///
+/// assert_eq!(hand.total(&starter, /*is_crib=*/ false), 7);
+/// ```
+impl FromStr for Hand {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_cards(input).map(Hand::from)
+    }
+}
 
-        write!(formatter, "[ {cards_str_joined} ]")
+/// Converts a [`Deck`] to a [`Hand`], keeping the order of the [`Card`]s.
+impl From<Deck> for Hand {
+    fn from(deck: Deck) -> Hand {
+        Hand(deck.as_vec().clone())
     }
 }
 
@@ -395,4 +672,29 @@ mod test {
 
         assert_eq!(hand_as_vec, &cards);
     }
+
+    #[test]
+    fn test_iter() {
+        let hand: Hand = "AC 2S 3H".parse().unwrap();
+
+        let borrowed: Vec<&Card> = (&hand).into_iter().collect();
+
+        assert_eq!(hand.iter().collect::<Vec<_>>(), borrowed);
+        assert_eq!(
+            hand.into_iter().collect::<Vec<_>>(),
+            vec![
+                Card::new(Rank::Ace, Suit::Clubs),
+                Card::new(Rank::Two, Suit::Spades),
+                Card::new(Rank::Three, Suit::Hearts),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        let hand: Hand = "AC 2S 3H".parse().unwrap();
+
+        assert_eq!(hand[0], Card::new(Rank::Ace, Suit::Clubs));
+        assert_eq!(hand[2], Card::new(Rank::Three, Suit::Hearts));
+    }
 }
@@ -102,12 +102,20 @@
 //!
 //! ```
 
-pub use self::card::{Card, Rank, Suit};
+pub use self::card::{render_card_art, Card, Rank, Suit};
+pub use self::card_pile::{count_ranks, CardPile};
+pub use self::card_set::{CardSet, CardSetIter};
 pub use self::deck::Deck;
 pub use self::hand::Hand;
-pub use self::score::total;
+pub use self::score::{
+    all_hands_score_distribution, breakdown, fifteens, fifteens_from_combinations, his_heels,
+    runs_detail, static_total, total, ScoreBreakdown, ScoreCache, HAND_AND_STARTER_COMBINATIONS,
+};
 
 mod card;
+mod card_pile;
+mod card_set;
 mod deck;
 mod hand;
 mod score;
+pub mod tracker;
@@ -1,4 +1,5 @@
 //! This is just a table of all scores based on a [`Hand`] and "starter" [`Card`].
+//! [`all_hands_score_distribution`] counts that table for every [`Hand`] and starter.
 //!
 //! We can ignore single cards except if jack in [`Hand`] is same suit as "starter" [`Card`].
 //!
@@ -31,10 +32,13 @@
 //!   * Go (played last card) - 1pt
 //!   * His Heels (jack is starter and player is dealer) - 2pts
 use itertools::Itertools;
+use std::collections::BTreeMap;
 use std::convert::TryFrom;
+use std::fmt;
 use std::iter;
+use std::sync::OnceLock;
 
-use cards::{Card, Hand, Rank, Suit};
+use cards::{Card, CardSet, Deck, Hand, Rank, Suit};
 
 /// Returns the score of [`Hand`] and starter [`Card`], influenced if the [`Hand`] is a "crib".
 ///
@@ -85,20 +89,300 @@ pub fn total(hand: &Hand, starter: &Card, is_crib: bool) -> u32 {
         + nobs(hand, starter)
 }
 
+/// Returns the score of a [`Hand`] on its own, before the starter [`Card`] is cut.
+///
+/// This counts fifteens, pairs, and runs within the [`Hand`], and a flush if all 4 [`Card`]s
+/// share a [`Suit`]. Without a starter there are no nobs.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{static_total, Card, Hand, Rank, Suit};
+///
+/// let hand = Hand::from(vec![
+///     Card::new(Rank::Four, Suit::Hearts),
+///     Card::new(Rank::Five, Suit::Hearts),
+///     Card::new(Rank::Six, Suit::Hearts),
+///     Card::new(Rank::Jack, Suit::Hearts),
+/// ]);
+///
+/// // Fifteens - 4
+/// // Runs - 3
+/// // Flush - 4
+/// assert_eq!(static_total(&hand), 11);
+/// ```
+#[must_use]
+pub fn static_total(hand: &Hand) -> u32 {
+    let hand_vec = hand.as_vec();
+
+    let Some((last, rest)) = hand_vec.split_last() else {
+        return 0;
+    };
+
+    // Fifteens, pairs, and runs don't depend on which card is the starter.
+    let rest = Hand::from(rest.to_vec());
+
+    let flush = match hand_vec.first() {
+        Some(first)
+            if (MIN_FLUSH_SIZE <= hand_vec.len())
+                && hand_vec.iter().all(|card| card.suit == first.suit) =>
+        {
+            u32::try_from(hand_vec.len()).unwrap()
+        }
+        _ => 0,
+    };
+
+    fifteens(&rest, last) + pairs(&rest, last) + runs(&rest, last) + flush
+}
+
+/// Every scoring combination of a [`Hand`] with the starter [`Card`].
+///
+/// The [`Card`]s of each combination are in the order of the [`Hand`], with the starter last.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ScoreBreakdown {
+    /// Each combination of [`Card`]s totalling `15`, worth 2pts each.
+    pub fifteens: Vec<Vec<Card>>,
+    /// Each pair of [`Card`]s with matching [`Rank`]s, worth 2pts each.
+    pub pairs: Vec<Vec<Card>>,
+    /// Each run of [`Card`]s, see [`runs_detail`], worth 1pt per [`Card`].
+    pub runs: Vec<Vec<Card>>,
+    /// The [`Card`]s in a flush, worth 1pt per [`Card`], or empty if there isn't one.
+    pub flush: Vec<Card>,
+    /// The [`Rank::Jack`] matching the starter [`Suit`], worth 1pt, if there is one.
+    pub nobs: Option<Card>,
+}
+
+impl ScoreBreakdown {
+    /// Returns the total score of all the combinations, which matches [`total`].
+    #[must_use]
+    pub fn total(&self) -> u32 {
+        let cards_in_runs: usize = self.runs.iter().map(Vec::len).sum();
+
+        let score = 2 * self.fifteens.len()
+            + 2 * self.pairs.len()
+            + cards_in_runs
+            + self.flush.len()
+            + usize::from(self.nobs.is_some());
+
+        u32::try_from(score).unwrap()
+    }
+}
+
+impl fmt::Display for ScoreBreakdown {
+    /// Writes a line for each scoring combination, e.g. `"Fifteen for 2: [5♥] [J♣]"`.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let cards_string = |cards: &[Card]| cards.iter().join(" ");
+
+        let mut lines = Vec::new();
+
+        for fifteen in &self.fifteens {
+            lines.push(format!("Fifteen for 2: {}", cards_string(fifteen)));
+        }
+
+        for pair in &self.pairs {
+            lines.push(format!("Pair for 2: {}", cards_string(pair)));
+        }
+
+        for run in &self.runs {
+            lines.push(format!(
+                "Run of {} for {}: {}",
+                run.len(),
+                run.len(),
+                cards_string(run)
+            ));
+        }
+
+        if !self.flush.is_empty() {
+            lines.push(format!(
+                "Flush for {}: {}",
+                self.flush.len(),
+                cards_string(&self.flush)
+            ));
+        }
+
+        if let Some(ref jack) = self.nobs {
+            lines.push(format!("Nobs for 1: {jack}"));
+        }
+
+        lines.push(format!("Total: {}", self.total()));
+
+        write!(formatter, "{}", lines.join("\n"))
+    }
+}
+
+/// Returns the [`ScoreBreakdown`] of [`Hand`] and starter [`Card`], influenced if the [`Hand`] is
+/// a "crib".
+///
+/// This finds the same combinations as [`total`], but lists which [`Card`]s formed each one so
+/// they can be explained.
+///
+/// # Panics
+///
+/// Panics if there is a [`Rank`] variant who's enum value is greater than `12`.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{breakdown, total, Card, Hand, Rank, Suit};
+///
+/// let cards = vec![
+///     Card::new(Rank::Jack, Suit::Clubs),
+///     Card::new(Rank::Five, Suit::Diamonds),
+///     Card::new(Rank::Four, Suit::Hearts),
+///     Card::new(Rank::Six, Suit::Spades),
+/// ];
+///
+/// let starter = Card::new(Rank::Ace, Suit::Clubs);
+///
+/// let hand = Hand::from(cards);
+///
+/// let score_breakdown = breakdown(&hand, &starter, /*is_crib=*/ false);
+///
+/// let expected_fifteens = vec![
+///     vec![
+///         Card::new(Rank::Jack, Suit::Clubs),
+///         Card::new(Rank::Five, Suit::Diamonds),
+///     ],
+///     vec![
+///         Card::new(Rank::Jack, Suit::Clubs),
+///         Card::new(Rank::Four, Suit::Hearts),
+///         Card::new(Rank::Ace, Suit::Clubs),
+///     ],
+///     vec![
+///         Card::new(Rank::Five, Suit::Diamonds),
+///         Card::new(Rank::Four, Suit::Hearts),
+///         Card::new(Rank::Six, Suit::Spades),
+///     ],
+/// ];
+///
+/// assert_eq!(score_breakdown.fifteens, expected_fifteens);
+/// assert!(score_breakdown.pairs.is_empty());
+/// assert_eq!(score_breakdown.runs.len(), 1);
+/// assert_eq!(score_breakdown.nobs, Some(Card::new(Rank::Jack, Suit::Clubs)));
+/// assert_eq!(score_breakdown.total(), total(&hand, &starter, /*is_crib=*/ false));
+/// ```
+#[must_use]
+pub fn breakdown(hand: &Hand, starter: &Card, is_crib: bool) -> ScoreBreakdown {
+    let hand_starter_iter = hand.iter().chain(iter::once(starter));
+
+    let fifteens = (2..=5)
+        .flat_map(|combination_value| hand_starter_iter.clone().combinations(combination_value))
+        .filter(|combination| combination.iter().map(|card| card.score()).sum::<u32>() == 15)
+        .map(|combination| combination.into_iter().cloned().collect())
+        .collect();
+
+    let pairs = hand_starter_iter
+        .clone()
+        .tuple_combinations()
+        .filter(|(card_1, card_2)| card_1.rank == card_2.rank)
+        .map(|(card_1, card_2)| vec![card_1.clone(), card_2.clone()])
+        .collect();
+
+    let flush = match flushes(hand, starter, is_crib) {
+        0 => Vec::new(),
+        points if hand.len() < usize::try_from(points).unwrap() => {
+            hand_starter_iter.cloned().collect()
+        }
+        _ => hand.as_vec().clone(),
+    };
+
+    let target_jack = Card::new(Rank::Jack, starter.suit);
+
+    ScoreBreakdown {
+        fifteens,
+        pairs,
+        runs: runs_detail(hand, starter),
+        flush,
+        nobs: hand.iter().find(|card| **card == target_jack).cloned(),
+    }
+}
+
+/// The number of fifteens in up to 5 [`Card`]s, keyed by their sorted [`Card::score`]s.
+///
+/// See [`fifteens_key`] for the key. Missing [`Card`]s have a score of `0`, which is never part
+/// of a fifteen.
+static FIFTEENS_TABLE: OnceLock<Vec<u8>> = OnceLock::new();
+
+/// Returns the points for all combinations of [`Card`]s totalling to `15`, in a [`Hand`] with
+/// the starter [`Card`].
+///
+/// Each combination is 2 points. This looks up the combinations in a table, precomputed the first
+/// time it's needed, with every multiset of up to 5 [`Card::score`]s. [`Hand`]s of more than 4
+/// [`Card`]s fall back to [`fifteens_from_combinations`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{fifteens, Card, Hand, Rank, Suit};
+///
+/// let hand = Hand::from(vec![
+///     Card::new(Rank::Five, Suit::Clubs),
+///     Card::new(Rank::Five, Suit::Hearts),
+///     Card::new(Rank::Five, Suit::Diamonds),
+///     Card::new(Rank::Jack, Suit::Spades),
+/// ]);
+///
+/// let starter = Card::new(Rank::Five, Suit::Spades);
+///
+/// assert_eq!(fifteens(&hand, &starter), 16);
+/// ```
+#[must_use]
+pub fn fifteens(hand: &Hand, starter: &Card) -> u32 {
+    let hand_vec = hand.as_vec();
+
+    if 4 < hand_vec.len() {
+        return fifteens_from_combinations(hand, starter);
+    }
+
+    let mut scores = [0; 5];
+
+    for (score, card) in scores
+        .iter_mut()
+        .zip(hand_vec.iter().chain(iter::once(starter)))
+    {
+        *score = card.score();
+    }
+
+    scores.sort_unstable();
+
+    let table = FIFTEENS_TABLE.get_or_init(fifteens_table);
+
+    2 * u32::from(table[fifteens_key(&scores)])
+}
+
 /// Returns a positive score if combinations of [`Card`] scores in [`Hand`] total to `15`.
 ///
 /// # Panics
 ///
 /// Panics if this method finds more combinations adding to `15` then can fit into a [`u32`].
 ///
-/// This counts all combinations of 2, 3, 4, and 5 cards.
+/// This counts all combinations of 2, 3, 4, and 5 cards, which is slower than the table used by
+/// [`fifteens`].
 ///
 /// A [`Card`] score is based on [`Card::score`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{fifteens, fifteens_from_combinations, Card, Hand, Rank, Suit};
+///
+/// let hand = Hand::from(vec![
+///     Card::new(Rank::Seven, Suit::Clubs),
+///     Card::new(Rank::Eight, Suit::Hearts),
+///     Card::new(Rank::Eight, Suit::Diamonds),
+///     Card::new(Rank::Nine, Suit::Spades),
+/// ]);
+///
+/// let starter = Card::new(Rank::Six, Suit::Spades);
+///
+/// assert_eq!(fifteens_from_combinations(&hand, &starter), 6);
+/// assert_eq!(fifteens(&hand, &starter), 6);
+/// ```
 #[must_use]
-fn fifteens(hand: &Hand, starter: &Card) -> u32 {
+pub fn fifteens_from_combinations(hand: &Hand, starter: &Card) -> u32 {
     let score_per_fifteen = 2;
 
-    let hand_starter_iter = hand.as_vec().iter().chain(iter::once(starter));
+    let hand_starter_iter = hand.iter().chain(iter::once(starter));
 
     let number_of_fifteen_sums = (1..=5)
         .flat_map(|combination_value| hand_starter_iter.clone().combinations(combination_value))
@@ -109,6 +393,171 @@ fn fifteens(hand: &Hand, starter: &Card) -> u32 {
     score_per_fifteen * u32::try_from(number_of_fifteen_sums).unwrap()
 }
 
+/// Returns the index into the [`FIFTEENS_TABLE`] of 5 sorted [`Card::score`]s.
+///
+/// Each score, from `0` to `10`, is a digit of the key in base 11.
+fn fifteens_key(scores: &[u32; 5]) -> usize {
+    scores
+        .iter()
+        .fold(0, |key, score| key * 11 + *score as usize)
+}
+
+/// Returns the [`FIFTEENS_TABLE`], with the fifteens of every multiset of 5 scores from `0` to
+/// `10`.
+///
+/// # Panics
+///
+/// Panics if 5 scores have more than [`u8::MAX`] fifteens, which is impossible.
+fn fifteens_table() -> Vec<u8> {
+    let mut table = vec![0; 11_usize.pow(5)];
+
+    for scores in (0..=10).combinations_with_replacement(5) {
+        let non_zero_scores: Vec<u32> =
+            scores.iter().copied().filter(|score| *score != 0).collect();
+
+        let number_of_fifteen_sums = (1..=non_zero_scores.len())
+            .flat_map(|combination_value| non_zero_scores.iter().combinations(combination_value))
+            .filter(|combination| combination.iter().copied().sum::<u32>() == 15)
+            .count();
+
+        let sorted_scores = [scores[0], scores[1], scores[2], scores[3], scores[4]];
+
+        table[fifteens_key(&sorted_scores)] = u8::try_from(number_of_fifteen_sums).unwrap();
+    }
+
+    table
+}
+
+/// The points for fifteens, pairs, and runs of 5 [`Card`]s, keyed by their sorted [`Rank`]s.
+///
+/// Each [`Rank`], from `0` for [`Rank::Ace`] to `12` for [`Rank::King`], is a digit of the key in
+/// base 13.
+static RANK_SCORES_TABLE: OnceLock<Vec<u8>> = OnceLock::new();
+
+/// A precomputed cache of [`total`] scores for a [`Hand`] of 4 [`Card`]s with a starter.
+///
+/// Fifteens, pairs, and runs only depend on the [`Rank`]s, so they're looked up for every multiset
+/// of 5 [`Rank`]s. Only [`flushes`] and [`nobs`] are counted for each [`Hand`], with a
+/// [`CardSet`]. The table is shared, and built by the first [`ScoreCache::new`].
+///
+/// This is meant for the hot path of an AI, e.g. scoring a [`Hand`] with every possible starter.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{total, Card, Hand, Rank, ScoreCache, Suit};
+///
+/// let hand = Hand::from(vec![
+///     Card::new(Rank::Jack, Suit::Clubs),
+///     Card::new(Rank::Five, Suit::Diamonds),
+///     Card::new(Rank::Five, Suit::Hearts),
+///     Card::new(Rank::Five, Suit::Spades),
+/// ]);
+///
+/// let starter = Card::new(Rank::Five, Suit::Clubs);
+///
+/// let cache = ScoreCache::new();
+///
+/// assert_eq!(cache.total(&hand, &starter, /*is_crib=*/ false), 29);
+/// assert_eq!(
+///     cache.total(&hand, &starter, /*is_crib=*/ true),
+///     total(&hand, &starter, /*is_crib=*/ true)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ScoreCache {
+    rank_scores: &'static [u8],
+}
+
+impl ScoreCache {
+    /// Creates a new [`ScoreCache`], building the shared table if it isn't built yet.
+    #[must_use]
+    pub fn new() -> ScoreCache {
+        ScoreCache {
+            rank_scores: RANK_SCORES_TABLE.get_or_init(rank_scores_table),
+        }
+    }
+
+    /// Returns the score of [`Hand`] and starter [`Card`], influenced if the [`Hand`] is a "crib".
+    ///
+    /// This is the same as [`total`], which is used for [`Hand`]s without exactly 4 [`Card`]s.
+    #[must_use]
+    pub fn total(&self, hand: &Hand, starter: &Card, is_crib: bool) -> u32 {
+        let hand_vec = hand.as_vec();
+
+        if hand_vec.len() != 4 {
+            return total(hand, starter, is_crib);
+        }
+
+        let mut ranks = [0; 5];
+
+        for (rank, card) in ranks
+            .iter_mut()
+            .zip(hand_vec.iter().chain(iter::once(starter)))
+        {
+            *rank = card.rank as usize;
+        }
+
+        ranks.sort_unstable();
+
+        let hand_set: CardSet = hand_vec.iter().collect();
+
+        let flush = if !hand_set.is_subset(CardSet::of_suit(hand_vec[0].suit)) {
+            0
+        } else if starter.suit == hand_vec[0].suit {
+            5
+        } else if is_crib {
+            0
+        } else {
+            4
+        };
+
+        let nobs = u32::from(hand_set.contains(&Card::new(Rank::Jack, starter.suit)));
+
+        u32::from(self.rank_scores[rank_key(&ranks)]) + flush + nobs
+    }
+}
+
+impl Default for ScoreCache {
+    fn default() -> ScoreCache {
+        ScoreCache::new()
+    }
+}
+
+/// Returns the index into the [`RANK_SCORES_TABLE`] of 5 sorted [`Rank`] indices.
+fn rank_key(ranks: &[usize; 5]) -> usize {
+    ranks.iter().fold(0, |key, rank| key * 13 + rank)
+}
+
+/// Returns the [`RANK_SCORES_TABLE`], with the fifteens, pairs, and runs of every multiset of 5
+/// [`Rank`]s.
+///
+/// # Panics
+///
+/// Panics if 5 [`Rank`]s score more than [`u8::MAX`], which is impossible.
+fn rank_scores_table() -> Vec<u8> {
+    let mut table = vec![0; 13_usize.pow(5)];
+
+    for ranks in (0..13).combinations_with_replacement(5) {
+        // The suits don't matter, since flushes and nobs aren't counted.
+        let cards: Vec<Card> = ranks
+            .iter()
+            .map(|rank| Card::new(Rank::ALL[*rank], Suit::Hearts))
+            .collect();
+
+        let hand = Hand::from(cards[..4].to_vec());
+        let starter = &cards[4];
+
+        let score = fifteens(&hand, starter) + pairs(&hand, starter) + runs(&hand, starter);
+
+        let sorted_ranks = [ranks[0], ranks[1], ranks[2], ranks[3], ranks[4]];
+
+        table[rank_key(&sorted_ranks)] = u8::try_from(score).unwrap();
+    }
+
+    table
+}
+
 /// Returns a positive score if the [`Card`] in [`Hand`] with the starter match [`Rank`].
 ///
 /// # Panics
@@ -122,7 +571,6 @@ fn pairs(hand: &Hand, starter: &Card) -> u32 {
     let score_per_pair = 2;
 
     let number_of_matching_pairs = hand
-        .as_vec()
         .iter()
         .chain(iter::once(starter))
         .tuple_combinations()
@@ -146,17 +594,7 @@ fn runs(hand: &Hand, starter: &Card) -> u32 {
     let mut max_run = 0;
     let mut current_run = 0;
 
-    // This is a way to keep track of which ranks we have found using the enum to usize conversion.
-    // Rank::Ace is mapped to index 0 and Rank::King is mapped to index 12
-    let mut ranks_found = [0; 13];
-
-    hand.as_vec()
-        .iter()
-        .chain(iter::once(starter))
-        .for_each(|card| match ranks_found.get_mut(card.rank as usize) {
-            Some(count) => *count += 1,
-            None => panic!("Rank {:?} not handled", card.rank),
-        });
+    let ranks_found = rank_histogram(hand, starter);
 
     for current_multiplier in ranks_found {
         current_run += 1;
@@ -165,8 +603,9 @@ fn runs(hand: &Hand, starter: &Card) -> u32 {
         if current_multiplier == 0 {
             current_run = 0;
 
-            // If we also haven't found a run so far reset the max_multiplier.
+            // If we also haven't found a run so far, start over after the gap.
             if max_run < 3 {
+                max_run = 0;
                 max_multiplier = 1;
             }
         }
@@ -184,27 +623,135 @@ fn runs(hand: &Hand, starter: &Card) -> u32 {
     max_multiplier * score
 }
 
-/// Returns `0`, `4`, or `5` based on the [`Suit`]s of the [`Hand`] and starter [`Card`].
+/// Returns each run in the [`Hand`] with the starter [`Card`], sorted by [`Rank`].
+///
+/// Only maximal runs (3 or more [`Card`]s) are returned. If there are matching [`Rank`]s in the
+/// run, then there is a run for each combination of the matching [`Card`]s. For example, a double
+/// run of three returns two three [`Card`] runs.
+///
+/// # Panics
+///
+/// Panics if there is a [`Rank`] variant who's enum value is greater than `12`.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{runs_detail, Card, Hand, Rank, Suit};
+///
+/// let cards = vec![
+///     Card::new(Rank::Four, Suit::Spades),
+///     Card::new(Rank::Five, Suit::Hearts),
+///     Card::new(Rank::Five, Suit::Clubs),
+///     Card::new(Rank::Six, Suit::Diamonds),
+/// ];
+///
+/// let starter = Card::new(Rank::King, Suit::Clubs);
+///
+/// let hand = Hand::from(cards);
+///
+/// let expected = vec![
+///     vec![
+///         Card::new(Rank::Four, Suit::Spades),
+///         Card::new(Rank::Five, Suit::Hearts),
+///         Card::new(Rank::Six, Suit::Diamonds),
+///     ],
+///     vec![
+///         Card::new(Rank::Four, Suit::Spades),
+///         Card::new(Rank::Five, Suit::Clubs),
+///         Card::new(Rank::Six, Suit::Diamonds),
+///     ],
+/// ];
+///
+/// assert_eq!(runs_detail(&hand, &starter), expected);
+/// ```
+#[must_use]
+pub fn runs_detail(hand: &Hand, starter: &Card) -> Vec<Vec<Card>> {
+    let ranks_found = rank_histogram(hand, starter);
+
+    let mut result = Vec::new();
+    let mut run_start = 0;
+
+    // Going one past the last rank index closes off a run ending at Rank::King.
+    for rank_index in 0..=ranks_found.len() {
+        if ranks_found.get(rank_index).map_or(0, |count| *count) != 0 {
+            continue;
+        }
+
+        if 3 <= (rank_index - run_start) {
+            let runs = (run_start..rank_index)
+                .map(|run_rank_index| {
+                    hand.iter()
+                        .chain(iter::once(starter))
+                        .filter(|card| card.rank as usize == run_rank_index)
+                        .cloned()
+                        .collect::<Vec<Card>>()
+                })
+                .multi_cartesian_product();
+
+            result.extend(runs);
+        }
+
+        run_start = rank_index + 1;
+    }
+
+    result
+}
+
+/// Returns the number of [`Card`]s of each [`Rank`] in the [`Hand`] with the starter [`Card`].
+///
+/// This uses the enum to usize conversion, where [`Rank::Ace`] is mapped to index 0 and
+/// [`Rank::King`] is mapped to index 12.
+///
+/// # Panics
+///
+/// Panics if there is a [`Rank`] variant who's enum value is greater than `12`.
+fn rank_histogram(hand: &Hand, starter: &Card) -> [u32; 13] {
+    let mut ranks_found = [0; 13];
+
+    hand.iter().chain(iter::once(starter)).for_each(|card| {
+        match ranks_found.get_mut(card.rank as usize) {
+            Some(count) => *count += 1,
+            None => panic!("Rank {:?} not handled", card.rank),
+        }
+    });
+
+    ranks_found
+}
+
+/// The fewest [`Card`]s in a [`Hand`] that can score a flush, which is a three-card hand in the
+/// five-card game.
+const MIN_FLUSH_SIZE: usize = 3;
+
+/// Returns `0`, `4`, or `5` based on the [`Suit`]s of a 4 [`Card`] [`Hand`] and starter [`Card`].
 ///
 /// This is called a flush. If all the [`Card`]s in the [`Hand`] have the same [`Suit`],
 /// then the score is `4`. If the starter [`Card`] also matches that [`Suit`], then the
 /// score is `5`. However, if this is for a "crib" [`Hand`], then all [`Card`]s must match,
 /// including the starter; otherwise, the score is `0`, even if all [`Card`]s in the
 /// [`Hand`] match.
+///
+/// A flush scores a point per [`Card`], so a three-card hand scores `3` or `4` instead. Hands
+/// smaller than [`MIN_FLUSH_SIZE`] never score a flush.
 #[must_use]
 fn flushes(hand: &Hand, starter: &Card, is_crib: bool) -> u32 {
     let hand_vec = hand.as_vec();
 
-    let target_suit = hand_vec.get(0).map_or(Suit::Clubs, |card| card.suit);
+    if hand_vec.len() < MIN_FLUSH_SIZE {
+        return 0;
+    }
+
+    let hand_size = u32::try_from(hand_vec.len()).unwrap();
+
+    let target_suit = hand_vec[0].suit;
 
     let all_suits_match = hand_vec.iter().all(|card| card.suit == target_suit);
 
     let starter_suit_matches = starter.suit == target_suit;
 
     if all_suits_match && starter_suit_matches {
-        5
+        hand_size + 1
     } else if all_suits_match && !is_crib {
-        4
+        hand_size
     } else {
         0
     }
@@ -217,13 +764,172 @@ fn flushes(hand: &Hand, starter: &Card, is_crib: bool) -> u32 {
 fn nobs(hand: &Hand, starter: &Card) -> u32 {
     let target_jack = Card::new(Rank::Jack, starter.suit);
 
-    u32::from(hand.as_vec().iter().any(|card| *card == target_jack))
+    u32::from(hand.contains(&target_jack))
+}
+
+/// Returns the points the dealer scores for cutting the starter [`Card`]: `2` for a [`Rank::Jack`],
+/// and `0` otherwise.
+///
+/// This is called "His Heels", and is scored as soon as the starter is cut, so it can end the game
+/// before play.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{his_heels, Card, Rank, Suit};
+///
+/// assert_eq!(his_heels(&Card::new(Rank::Jack, Suit::Hearts)), 2);
+/// assert_eq!(his_heels(&Card::new(Rank::Queen, Suit::Hearts)), 0);
+/// ```
+#[must_use]
+pub fn his_heels(starter: &Card) -> u32 {
+    if starter.rank == Rank::Jack {
+        2
+    } else {
+        0
+    }
+}
+
+/// The number of ways to deal a [`Hand`] of 4 [`Card`]s and cut a starter, which is every 4 of the
+/// 52 [`Card`]s, times each of the other 48 as the starter.
+pub const HAND_AND_STARTER_COMBINATIONS: usize = 12_994_800;
+
+/// Returns how many of every [`Hand`] of 4 [`Card`]s, with every starter [`Card`], give each
+/// score, influenced if the [`Hand`]s are "cribs".
+///
+/// This scores all [`HAND_AND_STARTER_COMBINATIONS`] with a [`ScoreCache`]. The keys are the
+/// scores, in order, and only scores that can happen are included, so there is no `19`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use libterminal_cribbage::cards::{all_hands_score_distribution, HAND_AND_STARTER_COMBINATIONS};
+///
+/// let distribution = all_hands_score_distribution(/*is_crib=*/ false);
+///
+/// assert_eq!(distribution.get(&29), Some(&4));
+/// assert_eq!(distribution.get(&19), None);
+/// assert_eq!(distribution.values().sum::<usize>(), HAND_AND_STARTER_COMBINATIONS);
+/// ```
+#[must_use]
+pub fn all_hands_score_distribution(is_crib: bool) -> BTreeMap<u32, usize> {
+    let cache = ScoreCache::new();
+
+    let mut counts = [0; 30];
+
+    for cards in Deck::new().as_vec().iter().cloned().combinations(4) {
+        let hand_set: CardSet = cards.iter().collect();
+        let hand = Hand::from(cards);
+
+        for starter in CardSet::full() - hand_set {
+            counts[cache.total(&hand, &starter, is_crib) as usize] += 1;
+        }
+    }
+
+    counts
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| 0 < **count)
+        .map(|(score, count)| (u32::try_from(score).unwrap(), *count))
+        .collect()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use cards::{Card, Hand, Rank, Suit};
+    use cards::{Card, Deck, Hand, Rank, Suit};
+
+    #[test]
+    fn breakdown_matches_total() {
+        let deck = Deck::new();
+
+        // Every fourth hand from a sliding window, with the next card as the starter.
+        for start in (0..47).step_by(4) {
+            let cards = deck.as_vec()[start..(start + 4)].to_vec();
+            let starter = deck.as_vec()[start + 4].clone();
+
+            let hand = Hand::from(cards);
+
+            for is_crib in [false, true] {
+                assert_eq!(
+                    breakdown(&hand, &starter, is_crib).total(),
+                    total(&hand, &starter, is_crib)
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "exhaustive")]
+    fn all_hands_score_distribution_matches_known_counts() {
+        let expected = BTreeMap::from([
+            (0, 1_009_008),
+            (1, 99_792),
+            (2, 2_813_796),
+            (3, 505_008),
+            (4, 2_855_676),
+            (5, 697_508),
+            (6, 1_800_268),
+            (7, 751_324),
+            (8, 1_137_236),
+            (9, 361_224),
+            (10, 388_740),
+            (11, 51_680),
+            (12, 317_340),
+            (13, 19_656),
+            (14, 90_100),
+            (15, 9_168),
+            (16, 58_248),
+            (17, 11_196),
+            (18, 2_708),
+            (20, 8_068),
+            (21, 2_496),
+            (22, 444),
+            (23, 356),
+            (24, 3_680),
+            (28, 76),
+            (29, 4),
+        ]);
+
+        assert_eq!(all_hands_score_distribution(/*is_crib=*/ false), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "exhaustive")]
+    fn all_hands_score_distribution_crib_invariants() {
+        let distribution = all_hands_score_distribution(/*is_crib=*/ true);
+
+        assert_eq!(
+            distribution.values().sum::<usize>(),
+            HAND_AND_STARTER_COMBINATIONS
+        );
+        assert_eq!(distribution.keys().last(), Some(&29));
+        assert_eq!(distribution.get(&29), Some(&4));
+
+        for impossible in [19, 25, 26, 27] {
+            assert_eq!(distribution.get(&impossible), None);
+        }
+    }
+
+    #[test]
+    fn breakdown_display_29() {
+        let cards = vec![
+            Card::new(Rank::Jack, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Spades),
+        ];
+
+        let starter = Card::new(Rank::Five, Suit::Clubs);
+
+        let hand = Hand::from(cards);
+
+        let result = breakdown(&hand, &starter, /*is_crib=*/ false).to_string();
+
+        assert!(result.starts_with("Fifteen for 2: [J♣] [5♦]\n"));
+        assert!(result.contains("Pair for 2: [5♦] [5♥]\n"));
+        assert!(result.ends_with("Nobs for 1: [J♣]\nTotal: 29"));
+    }
 
     #[test]
     fn total_not_crib_29() {
@@ -408,6 +1114,83 @@ mod test {
         assert_eq!(score, 16);
     }
 
+    #[test]
+    fn fifteens_matches_combinations() {
+        let deck = Deck::new();
+
+        for cards in deck.as_vec().iter().step_by(3).cloned().combinations(5) {
+            let hand = Hand::from(cards[..4].to_vec());
+
+            assert_eq!(
+                fifteens(&hand, &cards[4]),
+                fifteens_from_combinations(&hand, &cards[4]),
+                "{:?}",
+                cards
+            );
+        }
+    }
+
+    #[test]
+    fn fifteens_more_than_4_cards() {
+        let cards = vec![
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Diamonds),
+            Card::new(Rank::Jack, Suit::Spades),
+            Card::new(Rank::King, Suit::Spades),
+        ];
+
+        let starter = Card::new(Rank::Five, Suit::Spades);
+
+        let hand = Hand::from(cards);
+
+        assert_eq!(fifteens(&hand, &starter), 24);
+    }
+
+    #[test]
+    fn score_cache_matches_total() {
+        let cache = ScoreCache::new();
+
+        let deck = Deck::new();
+
+        for cards in deck.as_vec().iter().step_by(3).cloned().combinations(5) {
+            let hand = Hand::from(cards[..4].to_vec());
+
+            for is_crib in [false, true] {
+                assert_eq!(
+                    cache.total(&hand, &cards[4], is_crib),
+                    total(&hand, &cards[4], is_crib),
+                    "{:?}",
+                    cards
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn score_cache_flush_and_nobs() {
+        let cards = vec![
+            Card::new(Rank::Jack, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Hearts),
+        ];
+
+        let hand = Hand::from(cards);
+
+        let cache = ScoreCache::new();
+
+        let starter = Card::new(Rank::Queen, Suit::Hearts);
+        let other_starter = Card::new(Rank::Queen, Suit::Clubs);
+
+        // Flush - 5
+        // Nobs - 1
+        assert_eq!(cache.total(&hand, &starter, /*is_crib=*/ true), 6);
+        // Flush - 4
+        assert_eq!(cache.total(&hand, &other_starter, /*is_crib=*/ false), 4);
+        assert_eq!(cache.total(&hand, &other_starter, /*is_crib=*/ true), 0);
+    }
+
     #[test]
     fn pairs_0() {
         let cards = vec![
@@ -588,6 +1371,25 @@ mod test {
         assert_eq!(score, 6);
     }
 
+    #[test]
+    fn runs_two_three_card_runs_after_an_ace_6() {
+        let cards = vec![
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Spades),
+            Card::new(Rank::Six, Suit::Spades),
+        ];
+
+        let starter = Card::new(Rank::Five, Suit::Hearts);
+
+        let hand = Hand::from(cards);
+
+        let score = runs(&hand, &starter);
+
+        assert_eq!(score, 6);
+        assert_eq!(total(&hand, &starter, /*is_crib=*/ false), 14);
+    }
+
     #[test]
     fn runs_four_three_card_runs_with_starter_12() {
         let cards = vec![
@@ -678,6 +1480,111 @@ mod test {
         assert_eq!(score, 5);
     }
 
+    #[test]
+    fn runs_detail_none() {
+        let cards = vec![
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Diamonds),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+
+        let starter = Card::new(Rank::Five, Suit::Spades);
+
+        let hand = Hand::from(cards);
+
+        assert!(runs_detail(&hand, &starter).is_empty());
+    }
+
+    #[test]
+    fn runs_detail_double_run_of_three_8() {
+        let cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+
+        let starter = Card::new(Rank::Six, Suit::Spades);
+
+        let hand = Hand::from(cards);
+
+        let expected = vec![
+            vec![
+                Card::new(Rank::Four, Suit::Clubs),
+                Card::new(Rank::Five, Suit::Hearts),
+                Card::new(Rank::Six, Suit::Diamonds),
+            ],
+            vec![
+                Card::new(Rank::Four, Suit::Clubs),
+                Card::new(Rank::Five, Suit::Hearts),
+                Card::new(Rank::Six, Suit::Spades),
+            ],
+        ];
+
+        let result = runs_detail(&hand, &starter);
+
+        let runs_score: usize = result.iter().map(Vec::len).sum();
+
+        assert_eq!(result, expected);
+        assert_eq!(runs_score, 6);
+        assert_eq!(runs(&hand, &starter) + pairs(&hand, &starter), 8);
+    }
+
+    #[test]
+    fn runs_detail_matches_runs_score() {
+        let cards = vec![
+            Card::new(Rank::Ten, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Hearts),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Spades),
+        ];
+
+        let starter = Card::new(Rank::King, Suit::Spades);
+
+        let hand = Hand::from(cards);
+
+        let result = runs_detail(&hand, &starter);
+
+        let runs_score: usize = result.iter().map(Vec::len).sum();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(runs_score, runs(&hand, &starter) as usize);
+    }
+
+    #[test]
+    fn flushes_three_card_flush_not_crib_4() {
+        let cards = vec![
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Clubs),
+        ];
+
+        let starter = Card::new(Rank::Five, Suit::Clubs);
+
+        let hand = Hand::from(cards);
+
+        let score = flushes(&hand, &starter, /*is_crib=*/ false);
+
+        assert_eq!(score, 4);
+    }
+
+    #[test]
+    fn flushes_two_cards_0() {
+        let cards = vec![
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Clubs),
+        ];
+
+        let starter = Card::new(Rank::Five, Suit::Clubs);
+
+        let hand = Hand::from(cards);
+
+        let score = flushes(&hand, &starter, /*is_crib=*/ false);
+
+        assert_eq!(score, 0);
+    }
+
     #[test]
     fn flushes_four_card_flush_not_crib_flush_on_starter_0() {
         let cards = vec![
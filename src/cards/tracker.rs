@@ -0,0 +1,175 @@
+//! Keeps track of which [`Card`]s have been seen, to infer what's left in the [`Deck`].
+
+#[cfg(doc)]
+use cards::{Deck, Rank};
+
+use cards::{Card, CardSet};
+
+/// The [`Card`]s of a full [`Deck`] that haven't been seen yet.
+///
+/// It starts with all 52 [`Card`]s, and each one is taken out as it's seen, like the [`Card`]s in
+/// hand, the starter, and the [`Card`]s played. What's left is what the opponent may hold, or what
+/// may be cut.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::tracker::CardTracker;
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+///
+/// let mut tracker = CardTracker::new();
+///
+/// tracker.mark_seen(&Card::new(Rank::King, Suit::Hearts));
+/// tracker.mark_seen(&Card::new(Rank::Five, Suit::Clubs));
+///
+/// assert_eq!(tracker.remaining_count(), 50);
+/// assert_eq!(tracker.remaining_with_value(10), 15);
+/// assert_eq!(tracker.prob_next_card_value(10), 15.0 / 50.0);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CardTracker {
+    remaining: CardSet,
+}
+
+impl CardTracker {
+    /// Creates a new [`CardTracker`] that hasn't seen any [`Card`]s.
+    #[must_use]
+    pub fn new() -> CardTracker {
+        CardTracker {
+            remaining: CardSet::full(),
+        }
+    }
+
+    /// Marks the [`Card`] as seen, returning `true` if it hadn't been already.
+    pub fn mark_seen(&mut self, card: &Card) -> bool {
+        self.remaining.remove(card)
+    }
+
+    /// Marks all of the [`Card`]s as seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::tracker::CardTracker;
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    ///
+    /// let hand = vec![
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::Ace, Suit::Hearts),
+    /// ];
+    ///
+    /// let mut tracker = CardTracker::new();
+    ///
+    /// tracker.mark_all_seen(&hand);
+    ///
+    /// assert!(tracker.is_seen(&hand[1]));
+    /// assert_eq!(tracker.remaining_with_value(1), 2);
+    /// ```
+    pub fn mark_all_seen<'a, I>(&mut self, cards: I)
+    where
+        I: IntoIterator<Item = &'a Card>,
+    {
+        for card in cards {
+            self.mark_seen(card);
+        }
+    }
+
+    /// Indicates that the [`Card`] has been seen.
+    #[must_use]
+    pub fn is_seen(&self, card: &Card) -> bool {
+        !self.remaining.contains(card)
+    }
+
+    /// Returns the [`Card`]s that haven't been seen, in the order of [`Deck::new`].
+    #[must_use]
+    pub fn remaining(&self) -> Vec<Card> {
+        self.remaining.iter().collect()
+    }
+
+    /// Returns the [`Card`]s that haven't been seen, as a [`CardSet`].
+    #[must_use]
+    pub fn remaining_set(&self) -> CardSet {
+        self.remaining
+    }
+
+    /// Returns how many [`Card`]s haven't been seen.
+    #[must_use]
+    pub fn remaining_count(&self) -> usize {
+        self.remaining.len()
+    }
+
+    /// Returns how many [`Card`]s that haven't been seen are worth the value when pegging, with
+    /// [`Card::score`]. For example, the value 10 is any unseen ten, [`Rank::Jack`],
+    /// [`Rank::Queen`], or [`Rank::King`].
+    #[must_use]
+    pub fn remaining_with_value(&self, value: u32) -> usize {
+        self.remaining
+            .iter()
+            .filter(|card| card.score() == value)
+            .count()
+    }
+
+    /// Returns the probability that the next [`Card`], drawn at random from those that haven't
+    /// been seen, is worth the value, or `0.0` if every [`Card`] has been seen.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn prob_next_card_value(&self, value: u32) -> f64 {
+        if self.remaining.is_empty() {
+            return 0.0;
+        }
+
+        self.remaining_with_value(value) as f64 / self.remaining.len() as f64
+    }
+}
+
+impl Default for CardTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Deck, Rank, Suit};
+
+    #[test]
+    fn test_mark_seen_twice() {
+        let card = Card::new(Rank::Seven, Suit::Diamonds);
+
+        let mut tracker = CardTracker::new();
+
+        assert!(tracker.mark_seen(&card));
+        assert!(!tracker.mark_seen(&card));
+        assert_eq!(tracker.remaining_count(), 51);
+        assert_eq!(tracker.remaining_with_value(7), 3);
+    }
+
+    #[test]
+    fn test_prob_next_card_value_sums_to_one() {
+        let mut tracker = CardTracker::new();
+
+        tracker.mark_all_seen(&[
+            Card::new(Rank::Jack, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+        ]);
+
+        let total: f64 = (1..=10)
+            .map(|value| tracker.prob_next_card_value(value))
+            .sum();
+
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_prob_next_card_value_all_seen() {
+        let mut tracker = CardTracker::new();
+
+        tracker.mark_all_seen(Deck::new().as_vec());
+
+        assert_eq!(tracker.remaining_count(), 0);
+        assert_eq!(tracker.prob_next_card_value(5), 0.0);
+    }
+}
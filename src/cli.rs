@@ -0,0 +1,233 @@
+//! The command-line arguments of the `terminal_cribbage` binary.
+//!
+//! They're parsed here, instead of in the binary, so that scripted use of the engine, like
+//! `--ai-vs-ai --games 100 --seed 7`, can be tested.
+
+#[cfg(doc)]
+use game::{DisplayConfig, Game, GameEvent, SpectatorDisplay, Theme, UiDisplay};
+
+use std::str::FromStr;
+
+use game::{AiDifficulty, DailyDate};
+
+/// How to use the binary, printed for `--help`, or after an invalid argument.
+pub const USAGE: &str = "Usage: terminal_cribbage [OPTIONS]
+
+Options:
+  --ai-vs-ai               Play AI against AI without the menu, printing each result
+  --games <N>              The number of games to play with --ai-vs-ai [default: 1]
+  --difficulty <LEVEL>     The difficulty of both AIs with --ai-vs-ai: easy, medium, or hard
+  --seed <SEED>            Seed the deals and the AI, to reproduce a game
+  --daily [YYYY-MM-DD]     Play the daily deal of the date, or today
+  --no-delay, --fast       Don't wait after each message
+  --delay <MILLIS>         How long to wait after each message while spectating
+  --theme <PATH>           Read the theme of the cards from a TOML file
+  --tui                    Play full-screen, if built with the tui feature
+  --profile <NAME>         The name of the profile to keep the career statistics in
+  --export <PATH>          Write every round to the path once it's over, as CSV or JSON
+  --export-history <PATH>  Write the history of every game to the path, in the game notation
+  --server <ADDRESS>       Serve a game over WebSockets, if built with the server feature
+  --help                   Print this help";
+
+/// Every command-line argument of the binary.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cli::CliArgs;
+/// use libterminal_cribbage::game::AiDifficulty;
+///
+/// let args = CliArgs::parse(["--ai-vs-ai", "--games", "100", "--difficulty", "hard"]).unwrap();
+///
+/// assert!(args.ai_vs_ai);
+/// assert_eq!(args.games, 100);
+/// assert_eq!(args.difficulty, AiDifficulty::Hard);
+/// assert_eq!(args.seed, None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CliArgs {
+    /// Plays [`CliArgs::games`] AI against AI [`Game`]s, without the menu.
+    pub ai_vs_ai: bool,
+    /// The number of [`Game`]s to play with [`CliArgs::ai_vs_ai`].
+    pub games: u32,
+    /// The [`AiDifficulty`] of both AIs with [`CliArgs::ai_vs_ai`].
+    pub difficulty: AiDifficulty,
+    pub seed: Option<u64>,
+    pub daily: Option<DailyDate>,
+    /// Uses the [`DisplayConfig::fast`], and no delay while spectating.
+    pub no_delay: bool,
+    /// The delay in milliseconds after each message of a [`SpectatorDisplay`].
+    pub delay_millis: Option<u64>,
+    /// The path of the TOML file of the [`Theme`].
+    pub theme: Option<String>,
+    /// Shows the [`Game`] full-screen, instead of scrolling it by with a [`UiDisplay`].
+    pub tui: bool,
+    pub profile: Option<String>,
+    /// The path to write every round to, as CSV or JSON.
+    pub export: Option<String>,
+    /// The path to write every [`GameEvent`] to, in the game notation.
+    pub export_history: Option<String>,
+    /// The address to serve a [`Game`] on, over WebSockets.
+    pub server: Option<String>,
+    pub help: bool,
+}
+
+impl CliArgs {
+    /// Parses the arguments, without the name of the binary.
+    ///
+    /// # Errors
+    ///
+    /// If an argument is unknown, is missing its value, or its value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cli::CliArgs;
+    ///
+    /// assert!(CliArgs::parse(["--seed", "seven"]).is_err());
+    /// assert!(CliArgs::parse(["--games"]).is_err());
+    /// assert!(CliArgs::parse(["--turbo"]).is_err());
+    /// ```
+    pub fn parse<I, S>(args: I) -> Result<CliArgs, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut parsed = CliArgs::default();
+        let mut args = args.into_iter().map(Into::into).peekable();
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("{arg} is missing its value!"))
+            };
+
+            match arg.as_str() {
+                "--ai-vs-ai" => parsed.ai_vs_ai = true,
+                "--games" => parsed.games = parse_value(&arg, &value()?)?,
+                "--difficulty" => parsed.difficulty = value()?.parse()?,
+                "--seed" => parsed.seed = Some(parse_value(&arg, &value()?)?),
+                "--daily" => {
+                    parsed.daily = match args.next_if(|next| !next.starts_with("--")) {
+                        Some(date) => Some(date.parse()?),
+                        None => Some(DailyDate::today()),
+                    };
+                }
+                "--no-delay" | "--fast" => parsed.no_delay = true,
+                "--delay" => parsed.delay_millis = Some(parse_value(&arg, &value()?)?),
+                "--theme" => parsed.theme = Some(value()?),
+                "--tui" => parsed.tui = true,
+                "--profile" => parsed.profile = Some(value()?),
+                "--export" => parsed.export = Some(value()?),
+                "--export-history" => parsed.export_history = Some(value()?),
+                "--server" => parsed.server = Some(value()?),
+                "--help" | "-h" => parsed.help = true,
+                _ => return Err(format!("{arg} is not an option!")),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+impl Default for CliArgs {
+    /// No arguments, so a single [`Game`] set up in the menu.
+    fn default() -> CliArgs {
+        CliArgs {
+            ai_vs_ai: false,
+            games: 1,
+            difficulty: AiDifficulty::default(),
+            seed: None,
+            daily: None,
+            no_delay: false,
+            delay_millis: None,
+            theme: None,
+            tui: false,
+            profile: None,
+            export: None,
+            export_history: None,
+            server: None,
+            help: false,
+        }
+    }
+}
+
+/// Parses the number given for the argument.
+fn parse_value<T>(arg: &str, value: &str) -> Result<T, String>
+where
+    T: FromStr,
+{
+    value
+        .parse()
+        .map_err(|_| format!("{value} is not a valid number for {arg}!"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_no_args() {
+        assert_eq!(CliArgs::parse(Vec::<String>::new()), Ok(CliArgs::default()));
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let args = CliArgs::parse([
+            "--ai-vs-ai",
+            "--games",
+            "3",
+            "--seed",
+            "42",
+            "--difficulty",
+            "1",
+            "--no-delay",
+            "--export-history",
+            "games.txt",
+            "--daily",
+            "2024-02-29",
+            "--profile",
+            "Alice",
+            "--tui",
+        ]);
+
+        let expected = CliArgs {
+            ai_vs_ai: true,
+            games: 3,
+            difficulty: AiDifficulty::Easy,
+            seed: Some(42),
+            daily: DailyDate::new(2024, 2, 29),
+            no_delay: true,
+            export_history: Some(String::from("games.txt")),
+            profile: Some(String::from("Alice")),
+            tui: true,
+            ..CliArgs::default()
+        };
+
+        assert_eq!(args, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_daily_without_date() {
+        let args = CliArgs::parse(["--daily", "--fast"]).unwrap();
+
+        assert_eq!(args.daily, Some(DailyDate::today()));
+        assert!(args.no_delay);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            CliArgs::parse(["--games", "lots"]),
+            Err(String::from("lots is not a valid number for --games!"))
+        );
+        assert_eq!(
+            CliArgs::parse(["--export-history"]),
+            Err(String::from("--export-history is missing its value!"))
+        );
+        assert_eq!(
+            CliArgs::parse(["--turbo"]),
+            Err(String::from("--turbo is not an option!"))
+        );
+    }
+}
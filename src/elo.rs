@@ -0,0 +1,187 @@
+//! Helpers for rating [`Controller`]s with the [Elo rating system] across many games.
+//!
+//! This is meant for comparing AIs against each other, e.g. after a tournament of [`Game`]s.
+//!
+//! [Elo rating system]: https://en.wikipedia.org/wiki/Elo_rating_system
+
+#[cfg(doc)]
+use game::{Controller, Game};
+
+/// The K-factor used by [`update_elo`], which is the maximum a rating can change after a game.
+pub const DEFAULT_K_FACTOR: f64 = 32.0;
+
+/// The rating a new [`Controller`] starts with.
+pub const DEFAULT_RATING: f64 = 1_500.0;
+
+/// Returns the expected score (between `0.0` and `1.0`) of a player with `rating_a` against a
+/// player with `rating_b`.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::elo::expected_score;
+///
+/// assert_eq!(expected_score(1_500.0, 1_500.0), 0.5);
+/// assert!(expected_score(1_700.0, 1_500.0) > 0.5);
+/// ```
+#[must_use]
+pub fn expected_score(rating_a: f64, rating_b: f64) -> f64 {
+    1.0 / (1.0 + 10_f64.powf((rating_b - rating_a) / 400.0))
+}
+
+/// Returns the new ratings of player A and player B, respectively, after a game between them.
+///
+/// This uses the [`DEFAULT_K_FACTOR`]. Use [`update_elo_with_k_factor`] for a different one.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::elo::update_elo;
+///
+/// let (rating_a, rating_b) = update_elo(1_500.0, 1_500.0, /*a_won=*/ true);
+///
+/// assert_eq!(rating_a, 1_516.0);
+/// assert_eq!(rating_b, 1_484.0);
+/// ```
+#[must_use]
+pub fn update_elo(rating_a: f64, rating_b: f64, a_won: bool) -> (f64, f64) {
+    update_elo_with_k_factor(rating_a, rating_b, a_won, DEFAULT_K_FACTOR)
+}
+
+/// Returns the new ratings of player A and player B, respectively, after a game between them.
+///
+/// The K-factor is the maximum a rating can change after a single game. Whatever player A gains,
+/// player B loses.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::elo::update_elo_with_k_factor;
+///
+/// let (rating_a, rating_b) = update_elo_with_k_factor(1_500.0, 1_500.0, false, 16.0);
+///
+/// assert_eq!(rating_a, 1_492.0);
+/// assert_eq!(rating_b, 1_508.0);
+/// ```
+#[must_use]
+pub fn update_elo_with_k_factor(
+    rating_a: f64,
+    rating_b: f64,
+    a_won: bool,
+    k_factor: f64,
+) -> (f64, f64) {
+    let actual_score_a = if a_won { 1.0 } else { 0.0 };
+
+    let change = k_factor * (actual_score_a - expected_score(rating_a, rating_b));
+
+    (rating_a + change, rating_b - change)
+}
+
+/// Returns the Elo difference that would give the expected score (between `0.0` and `1.0`), the
+/// inverse of [`expected_score`].
+///
+/// A perfect score is an infinite difference, either way.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::elo::{elo_difference, expected_score};
+///
+/// assert_eq!(elo_difference(0.5), 0.0);
+/// assert!((elo_difference(expected_score(1_700.0, 1_500.0)) - 200.0).abs() < 1e-9);
+/// assert_eq!(elo_difference(1.0), f64::INFINITY);
+/// ```
+#[must_use]
+pub fn elo_difference(score: f64) -> f64 {
+    -400.0 * (1.0 / score - 1.0).log10()
+}
+
+/// Returns the likelihood of superiority (between `0.0` and `1.0`), which is how likely it is
+/// that the player with the wins is really stronger than the one with the losses.
+///
+/// This is the significance test used for comparing chess engines, from the normal approximation
+/// of the wins and losses. Without any games, it's a coin flip.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::elo::likelihood_of_superiority;
+///
+/// assert!((likelihood_of_superiority(5, 5) - 0.5).abs() < 1e-6);
+/// assert!(0.99 < likelihood_of_superiority(70, 30));
+/// assert!(likelihood_of_superiority(4, 6) < 0.5);
+/// ```
+#[must_use]
+pub fn likelihood_of_superiority(wins: u32, losses: u32) -> f64 {
+    if wins + losses == 0 {
+        return 0.5;
+    }
+
+    let wins = f64::from(wins);
+    let losses = f64::from(losses);
+
+    0.5 * (1.0 + erf((wins - losses) / (2.0 * (wins + losses)).sqrt()))
+}
+
+/// Returns the error function of `x`, accurate to about `1.5e-7`.
+///
+/// This is the approximation 7.1.26 from Abramowitz and Stegun.
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.327_591_1 * x.abs());
+
+    let polynomial = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+
+    (1.0 - polynomial * (-x * x).exp()).copysign(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_score_sums_to_one() {
+        let expected_a = expected_score(1_650.0, 1_420.0);
+        let expected_b = expected_score(1_420.0, 1_650.0);
+
+        assert!((expected_a + expected_b - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_update_elo_upset_moves_more_than_expected_win() {
+        let (expected_win_a, _) = update_elo(1_700.0, 1_300.0, /*a_won=*/ true);
+        let (_, upset_win_b) = update_elo(1_700.0, 1_300.0, /*a_won=*/ false);
+
+        assert!(expected_win_a - 1_700.0 < upset_win_b - 1_300.0);
+    }
+
+    #[test]
+    fn test_update_elo_stronger_player_rises_monotonically() {
+        let mut rating_strong = DEFAULT_RATING;
+        let mut rating_weak = DEFAULT_RATING;
+
+        for _ in 0..100 {
+            let (new_strong, new_weak) =
+                update_elo(rating_strong, rating_weak, /*a_won=*/ true);
+
+            assert!(rating_strong < new_strong);
+            assert!(new_weak < rating_weak);
+
+            rating_strong = new_strong;
+            rating_weak = new_weak;
+        }
+
+        assert!((rating_strong + rating_weak - 2.0 * DEFAULT_RATING).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_likelihood_of_superiority_is_symmetric() {
+        let better = likelihood_of_superiority(30, 20);
+        let worse = likelihood_of_superiority(20, 30);
+
+        assert!((better + worse - 1.0).abs() < 1e-6);
+        assert!(0.9 < better);
+    }
+}
@@ -0,0 +1,475 @@
+//! The `trait` for players who answer a step-driven [`Game`] without blocking, and the
+//! [`Future`] that plays it.
+
+#[cfg(doc)]
+use game::{Player, RngController};
+
+use std::fmt;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use cards::{Card, Deck};
+use game::player::MAX_PLAY_ATTEMPTS;
+use game::{
+    Controller, Decision, DecisionResponse, Game, GameError, GameView, NoOpDisplay, PlayContext,
+    PlayData, PlayerId, StepController, SubRound,
+};
+
+/// The [`DecisionResponse`] an [`AsyncController`] will eventually give, or [`None`] if it has
+/// no move.
+///
+/// It's [`Send`], so it can be spawned on a multi-threaded executor.
+pub type DecisionFuture = Pin<Box<dyn Future<Output = Option<DecisionResponse>> + Send>>;
+
+/// The `trait` for players who answer a step-driven [`Game`] without blocking, like over a
+/// network, or from a GUI.
+///
+/// Unlike a [`Controller`], which is asked for each choice and blocks until it's made, an
+/// [`AsyncController`] is given the whole [`Decision`] and returns a [`DecisionFuture`], which
+/// can wait on the user for as long as it takes. The [`DecisionFuture`] doesn't borrow the
+/// [`AsyncController`], and is [`Send`], so it can be awaited on any executor. A [`Game`] is
+/// played with them through [`Game::play_async`].
+///
+/// To play with an existing [`Controller`], wrap it in a [`BlockingController`].
+///
+/// # Examples
+///
+/// ```
+/// use std::future;
+///
+/// use libterminal_cribbage::game::{
+///     AsyncController, Decision, DecisionFuture, DecisionResponse, GameView,
+/// };
+///
+/// /// Always chooses the first card, right away.
+/// struct FirstCard;
+///
+/// impl AsyncController for FirstCard {
+///     fn decide(&mut self, decision: &Decision, _view: &GameView) -> DecisionFuture {
+///         let response = match decision {
+///             Decision::Cut { .. } => Some(DecisionResponse::Cut(0)),
+///             Decision::Discard { .. } => Some(DecisionResponse::Discard([0, 1])),
+///             Decision::CutStarter { .. } => Some(DecisionResponse::CutStarter(0)),
+///             Decision::Play { .. } => Some(DecisionResponse::Play(0)),
+///             Decision::Over { .. } => None,
+///         };
+///
+///         Box::pin(future::ready(response))
+///     }
+/// }
+/// ```
+pub trait AsyncController {
+    /// Starts deciding the [`Decision`], knowing what the [`Player`] can see in the [`GameView`],
+    /// and returns the [`DecisionFuture`] of the answer.
+    ///
+    /// The [`Decision`] is never [`Decision::Over`].
+    fn decide(&mut self, decision: &Decision, view: &GameView) -> DecisionFuture;
+
+    /// Tells the [`AsyncController`] that the [`Card`] it chose to play would take the stack over
+    /// 31, so it will be asked to decide again, like [`Controller::play_rejected`].
+    ///
+    /// By default, this is ignored.
+    fn play_rejected(&mut self, _card: &Card) {}
+}
+
+/// A boxed [`AsyncController`] forwards to the [`AsyncController`] it holds.
+///
+/// This lets the [`AsyncController`]s for [`Game::play_async`] be chosen at runtime.
+impl<C> AsyncController for Box<C>
+where
+    C: AsyncController + ?Sized,
+{
+    fn decide(&mut self, decision: &Decision, view: &GameView) -> DecisionFuture {
+        (**self).decide(decision, view)
+    }
+
+    fn play_rejected(&mut self, card: &Card) {
+        (**self).play_rejected(card);
+    }
+}
+
+/// An [`AsyncController`] that answers right away with a blocking [`Controller`].
+///
+/// Each [`Decision`] is handed to the [`Controller`] method it stands in for, so the built-in AI
+/// can play against a network, or GUI, player with [`Game::play_async`]. The [`Controller`]
+/// still blocks while it chooses, so it should be one that doesn't wait on anything, like an
+/// [`RngController`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{
+///     AsyncController, BlockingController, Decision, DecisionResponse, Game, PlayerId,
+///     PredeterminedController,
+/// };
+///
+/// let game = Game::new_step_driven();
+///
+/// let mut controller = BlockingController::new(PredeterminedController::from(vec![7]));
+///
+/// let decision = Decision::Cut {
+///     player: PlayerId::One,
+///     deck_size: 52,
+/// };
+///
+/// // The future is ready as soon as it's created.
+/// let _answer = controller.decide(&decision, &game.view(/*player_1=*/ true));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct BlockingController<C> {
+    controller: C,
+}
+
+impl<C> BlockingController<C>
+where
+    C: Controller,
+{
+    /// Creates a new [`BlockingController`] answering with the [`Controller`].
+    #[must_use]
+    pub fn new(controller: C) -> BlockingController<C> {
+        BlockingController { controller }
+    }
+
+    /// Returns the [`Controller`] it answers with.
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.controller
+    }
+
+    /// Returns the [`DecisionResponse`] of the [`Controller`] to the [`Decision`].
+    fn respond(&mut self, decision: &Decision, view: &GameView) -> Option<DecisionResponse> {
+        match decision {
+            Decision::Cut { deck_size, .. } => {
+                // The cut is face down, so any cards stand in for the ones in the deck.
+                let deck_cards: Vec<Card> = Deck::new()
+                    .as_vec()
+                    .iter()
+                    .take(*deck_size)
+                    .cloned()
+                    .collect();
+
+                self.controller
+                    .get_cut_index(&deck_cards)
+                    .map(DecisionResponse::Cut)
+            }
+            Decision::Discard {
+                hand, is_own_crib, ..
+            } => self
+                .controller
+                .get_crib_discards(hand, *is_own_crib)
+                .map(DecisionResponse::Discard),
+            Decision::CutStarter { deck_size, .. } => self
+                .controller
+                .get_starter_cut_depth(*deck_size)
+                .map(DecisionResponse::CutStarter),
+            Decision::Play {
+                hand,
+                stack,
+                stack_score,
+                played_cards,
+                ..
+            } => {
+                let mut play_data = PlayData::from(stack.clone());
+
+                // Only the cards of the finished stacks are known, not who pegged what on them.
+                let finished = played_cards.len().saturating_sub(stack.len());
+
+                if 0 < finished {
+                    play_data.sub_rounds.push(SubRound {
+                        stack: played_cards[..finished].to_vec(),
+                        leader_points: 0,
+                        follower_points: 0,
+                    });
+                }
+
+                play_data.stack_score = *stack_score;
+                play_data.starter.clone_from(&view.starter);
+                play_data.target = view.target_score;
+
+                let context = PlayContext {
+                    play_data: &play_data,
+                    points: view.points,
+                    opponent_points: view.opponent_points,
+                    target: view.target_score,
+                    is_dealer: view.is_dealer,
+                };
+
+                self.controller
+                    .get_play_index(hand, &context)
+                    .map(DecisionResponse::Play)
+            }
+            Decision::Over { .. } => None,
+        }
+    }
+}
+
+impl<C> AsyncController for BlockingController<C>
+where
+    C: Controller,
+{
+    fn decide(&mut self, decision: &Decision, view: &GameView) -> DecisionFuture {
+        Box::pin(future::ready(self.respond(decision, view)))
+    }
+
+    fn play_rejected(&mut self, card: &Card) {
+        self.controller.play_rejected(card);
+    }
+}
+
+/// The [`Future`] of a step-driven [`Game`] played to the end with [`AsyncController`]s, from
+/// [`Game::play_async`].
+///
+/// It's ready with the [`PlayerId`] of the winner, once the [`Game`] is over.
+pub struct AsyncPlay<'a, A1, A2> {
+    game: &'a mut Game<StepController, StepController, NoOpDisplay>,
+    player_1: &'a mut A1,
+    player_2: &'a mut A2,
+    pending: Option<(PlayerId, DecisionFuture)>,
+    rejected_plays: usize,
+}
+
+impl<'a, A1, A2> AsyncPlay<'a, A1, A2> {
+    /// Creates a new [`AsyncPlay`] of the [`Game`], with nothing decided yet.
+    pub(crate) fn new(
+        game: &'a mut Game<StepController, StepController, NoOpDisplay>,
+        player_1: &'a mut A1,
+        player_2: &'a mut A2,
+    ) -> AsyncPlay<'a, A1, A2> {
+        AsyncPlay {
+            game,
+            player_1,
+            player_2,
+            pending: None,
+            rejected_plays: 0,
+        }
+    }
+}
+
+impl<A1, A2> Future for AsyncPlay<'_, A1, A2>
+where
+    A1: AsyncController,
+    A2: AsyncController,
+{
+    type Output = Result<PlayerId, GameError>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        let play = self.get_mut();
+
+        loop {
+            if play.pending.is_none() {
+                let decision = match play.game.next_decision() {
+                    Ok(decision) => decision,
+                    Err(error) => return Poll::Ready(Err(error)),
+                };
+
+                let Some(player) = decision.player() else {
+                    let Decision::Over { winner } = decision else {
+                        unreachable!("Only an Over decision has no player!");
+                    };
+
+                    return Poll::Ready(Ok(winner));
+                };
+
+                let view = play.game.view(player.is_player_1());
+
+                let answer = match player {
+                    PlayerId::One => play.player_1.decide(&decision, &view),
+                    PlayerId::Two => play.player_2.decide(&decision, &view),
+                };
+
+                play.pending = Some((player, answer));
+            }
+
+            let Some((player, answer)) = play.pending.as_mut() else {
+                continue;
+            };
+
+            let player = *player;
+
+            let response = match answer.as_mut().poll(context) {
+                Poll::Ready(response) => response,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            play.pending = None;
+
+            let Some(response) = response else {
+                return Poll::Ready(Err(GameError::out_of_moves(player.is_player_1())));
+            };
+
+            match play.game.apply(response) {
+                Ok(_) => play.rejected_plays = 0,
+                Err(GameError::InvalidPlay(card))
+                    if play.rejected_plays + 1 < MAX_PLAY_ATTEMPTS =>
+                {
+                    play.rejected_plays += 1;
+
+                    match player {
+                        PlayerId::One => play.player_1.play_rejected(&card),
+                        PlayerId::Two => play.player_2.play_rejected(&card),
+                    }
+                }
+                Err(error) => return Poll::Ready(Err(error)),
+            }
+        }
+    }
+}
+
+impl<A1, A2> fmt::Debug for AsyncPlay<'_, A1, A2> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("AsyncPlay")
+            .field(
+                "waiting_on",
+                &self.pending.as_ref().map(|(player, _)| player),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::task::Waker;
+
+    use cards::{Rank, Suit};
+    use game::{PredeterminedController, RngController};
+
+    /// Polls the [`Future`] until it's ready, without ever waiting.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let mut context = Context::from_waker(Waker::noop());
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    /// Answers each [`Decision`] like its [`BlockingController`], but only on the second poll.
+    struct SlowController {
+        controller: BlockingController<RngController>,
+        polls: Arc<AtomicU32>,
+    }
+
+    impl AsyncController for SlowController {
+        fn decide(&mut self, decision: &Decision, view: &GameView) -> DecisionFuture {
+            let mut response = Some(self.controller.respond(decision, view));
+            let polls = Arc::clone(&self.polls);
+
+            Box::pin(future::poll_fn(move |_| {
+                let poll = polls.fetch_add(1, Ordering::Relaxed) + 1;
+
+                if poll % 2 == 1 {
+                    Poll::Pending
+                } else {
+                    Poll::Ready(response.take().flatten())
+                }
+            }))
+        }
+
+        fn play_rejected(&mut self, card: &Card) {
+            self.controller.play_rejected(card);
+        }
+    }
+
+    /// Plays the first [`Card`], remembering what it saw, and what was rejected.
+    #[derive(Default)]
+    struct RecordingController {
+        played_cards: Vec<Card>,
+        rejected: Vec<Card>,
+    }
+
+    impl Controller for RecordingController {
+        fn get_card_index(&mut self, _available_cards: &[Card]) -> Option<usize> {
+            Some(0)
+        }
+
+        fn get_play_index(
+            &mut self,
+            _available_cards: &[Card],
+            context: &PlayContext,
+        ) -> Option<usize> {
+            self.played_cards = context.play_data.played_cards();
+
+            Some(0)
+        }
+
+        fn play_rejected(&mut self, card: &Card) {
+            self.rejected.push(card.clone());
+        }
+    }
+
+    #[test]
+    fn test_blocking_controller_plays_knowing_every_played_card() {
+        let game = Game::new_step_driven();
+
+        let played_cards = vec![
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Queen, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Clubs),
+        ];
+
+        let decision = Decision::Play {
+            player: PlayerId::One,
+            hand: vec![Card::new(Rank::Two, Suit::Clubs)],
+            stack: vec![Card::new(Rank::Five, Suit::Clubs)],
+            stack_score: 5,
+            played_cards: played_cards.clone(),
+        };
+
+        let mut controller = BlockingController::new(RecordingController::default());
+
+        let response = controller.respond(&decision, &game.view(/*player_1=*/ true));
+
+        controller.play_rejected(&Card::new(Rank::Two, Suit::Clubs));
+
+        let recorded = controller.into_inner();
+
+        assert_eq!(response, Some(DecisionResponse::Play(0)));
+        assert_eq!(recorded.played_cards, played_cards);
+        assert_eq!(recorded.rejected, vec![Card::new(Rank::Two, Suit::Clubs)]);
+    }
+
+    #[test]
+    fn test_play_async_to_the_end() {
+        let mut game = Game::new_step_driven().with_seed(11);
+
+        let mut player_1 = BlockingController::new(RngController::new_with_seed(1));
+        let mut player_2 = SlowController {
+            controller: BlockingController::new(RngController::new_with_seed(2)),
+            polls: Arc::new(AtomicU32::new(0)),
+        };
+
+        let winner = block_on(game.play_async(&mut player_1, &mut player_2)).unwrap();
+
+        let snapshot = game.snapshot();
+
+        let winner_points = if winner.is_player_1() {
+            snapshot.player_1_points
+        } else {
+            snapshot.player_2_points
+        };
+
+        assert!(121 <= winner_points);
+        assert!(0 < player_2.polls.load(Ordering::Relaxed));
+        assert_eq!(game.next_decision(), Ok(Decision::Over { winner }));
+    }
+
+    #[test]
+    fn test_play_async_out_of_moves() {
+        let mut game = Game::new_step_driven().with_seed(11);
+
+        let mut player_1 = BlockingController::new(RngController::new_with_seed(1));
+        let mut player_2 = BlockingController::new(PredeterminedController::from(vec![]));
+
+        let result = block_on(game.play_async(&mut player_1, &mut player_2));
+
+        assert_eq!(result, Err(GameError::out_of_moves(false)));
+    }
+}
@@ -0,0 +1,156 @@
+//! Where every [`Card`] of a [`Game`] is, to catch [`Card`]s being lost or duplicated.
+
+#[cfg(doc)]
+use cards::{Deck, Hand};
+#[cfg(doc)]
+use game::{Game, Player, Round};
+
+use std::collections::BTreeMap;
+
+use cards::{Card, CardSet};
+
+/// Where a [`Card`] is in a [`Game`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CardLocation {
+    /// In the [`Deck`].
+    Deck,
+    /// In the [`Hand`] of player 1, or player 2 otherwise.
+    Hand { player_1: bool },
+    /// Played, or discarded, by player 1, or player 2 otherwise.
+    Discarded { player_1: bool },
+    /// In the [`Round::crib`].
+    Crib,
+    /// The starter [`Card`].
+    Starter,
+}
+
+/// Every [`Card`] of a [`Game`], and where it is, from [`Game::card_census`].
+///
+/// There should be exactly one of each of the 52 [`Card`]s. Any [`Card`] that is
+/// [`CardCensus::missing`], or [`CardCensus::duplicated`], is a bug in the [`Game`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{CardCensus, CardLocation};
+///
+/// let five = Card::new(Rank::Five, Suit::Hearts);
+///
+/// let mut census = CardCensus::new();
+///
+/// census.add(five.clone(), CardLocation::Starter);
+///
+/// assert_eq!(census.location(&five), Some(CardLocation::Starter));
+/// assert_eq!(census.missing().len(), 51);
+/// assert!(!census.is_complete());
+///
+/// census.add(five.clone(), CardLocation::Crib);
+///
+/// assert_eq!(census.location(&five), None);
+/// assert_eq!(census.duplicated(), vec![five]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct CardCensus {
+    locations: BTreeMap<Card, Vec<CardLocation>>,
+}
+
+impl CardCensus {
+    /// Creates a new, empty, [`CardCensus`].
+    #[must_use]
+    pub fn new() -> CardCensus {
+        CardCensus::default()
+    }
+
+    /// Counts the [`Card`] at the [`CardLocation`].
+    pub fn add(&mut self, card: Card, location: CardLocation) {
+        self.locations.entry(card).or_default().push(location);
+    }
+
+    /// Counts every [`Card`] at the [`CardLocation`].
+    pub fn add_all<'a, I>(&mut self, cards: I, location: CardLocation)
+    where
+        I: IntoIterator<Item = &'a Card>,
+    {
+        for card in cards {
+            self.add(card.clone(), location);
+        }
+    }
+
+    /// Returns every [`CardLocation`] the [`Card`] was counted at.
+    #[must_use]
+    pub fn locations(&self, card: &Card) -> &[CardLocation] {
+        self.locations.get(card).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the [`CardLocation`] of the [`Card`], or [`None`] if it's missing or duplicated.
+    #[must_use]
+    pub fn location(&self, card: &Card) -> Option<CardLocation> {
+        match self.locations(card) {
+            [location] => Some(*location),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Card`]s that weren't counted anywhere, in the order of [`Deck::new`].
+    #[must_use]
+    pub fn missing(&self) -> Vec<Card> {
+        let counted: CardSet = self.locations.keys().collect();
+
+        (CardSet::full() - counted).iter().collect()
+    }
+
+    /// Returns the [`Card`]s that were counted more than once, in sorted order.
+    #[must_use]
+    pub fn duplicated(&self) -> Vec<Card> {
+        self.locations
+            .iter()
+            .filter(|(_, locations)| 1 < locations.len())
+            .map(|(card, _)| card.clone())
+            .collect()
+    }
+
+    /// Indicates if each of the 52 [`Card`]s was counted exactly once.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.missing().is_empty() && self.duplicated().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::Deck;
+
+    #[test]
+    fn test_full_deck_is_complete() {
+        let mut census = CardCensus::new();
+
+        census.add_all(Deck::new().as_vec(), CardLocation::Deck);
+
+        assert!(census.is_complete());
+        assert!(census.missing().is_empty());
+        assert!(census.duplicated().is_empty());
+    }
+
+    #[test]
+    fn test_locations_of_a_duplicate() {
+        let card = Deck::new().as_vec()[7].clone();
+
+        let mut census = CardCensus::new();
+
+        census.add(card.clone(), CardLocation::Hand { player_1: true });
+        census.add(card.clone(), CardLocation::Discarded { player_1: false });
+
+        assert_eq!(
+            census.locations(&card),
+            &[
+                CardLocation::Hand { player_1: true },
+                CardLocation::Discarded { player_1: false },
+            ]
+        );
+        assert_eq!(census.location(&card), None);
+    }
+}
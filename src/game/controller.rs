@@ -1,9 +1,19 @@
 //! The trait and structs for controlling how players choose their [`Card`]s from their [`Hand`].
 
 #[cfg(doc)]
-use cards::Hand;
+use cards::{Deck, Hand};
+#[cfg(doc)]
+use game::{Game, GameOptions, Player};
 
 use cards::Card;
+use game::{Phase, PlayData};
+
+/// The index a [`Controller`] returns to intentionally pass during play.
+///
+/// This is only honored when [`GameOptions::allow_intentional_pass`] (and therefore
+/// [`PlayData::allow_intentional_pass`]) is set. Otherwise, it is treated like any other out of
+/// bounds index.
+pub const PASS_INDEX: usize = usize::MAX;
 
 /// The `trait` for controlling how players choose their [`Card`]s from their [`Hand`].
 pub trait Controller {
@@ -11,5 +21,402 @@ pub trait Controller {
     ///
     /// This required `&mut self` because it is assumed that some internal
     /// state of the implementors needs to change to determine the indices.
+    ///
+    /// During play, [`PASS_INDEX`] may be returned to pass even though a [`Card`] could be played.
     fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize>;
+
+    /// Get a possible index for a [`Card`] from a given array of [`Card`]s, knowing the
+    /// [`GameContext`].
+    ///
+    /// This is the entry point for cuts and plays, through the defaults of
+    /// [`Controller::get_cut_index`] and [`Controller::get_play_index`], so an implementor can
+    /// reason about the starter, the stack, and the scores in one place. By default, the
+    /// [`GameContext`] is ignored and [`Controller::get_card_index`] is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, GameContext, Phase, PlayContext, PlayData};
+    ///
+    /// /// Plays the highest [`Card`] that fits on the stack.
+    /// struct HighCard;
+    ///
+    /// impl Controller for HighCard {
+    ///     fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+    ///         (!available_cards.is_empty()).then_some(0)
+    ///     }
+    ///
+    ///     fn choose(&mut self, context: &GameContext, available_cards: &[Card]) -> Option<usize> {
+    ///         if context.phase != Phase::Play {
+    ///             return self.get_card_index(available_cards);
+    ///         }
+    ///
+    ///         available_cards
+    ///             .iter()
+    ///             .enumerate()
+    ///             .filter(|(_, card)| context.stack_score + card.score() <= 31)
+    ///             .max_by_key(|(_, card)| card.score())
+    ///             .map(|(index, _)| index)
+    ///     }
+    /// }
+    ///
+    /// let available_cards = vec![
+    ///     Card::new(Rank::Two, Suit::Hearts),
+    ///     Card::new(Rank::Nine, Suit::Clubs),
+    ///     Card::new(Rank::King, Suit::Spades),
+    /// ];
+    ///
+    /// let play_data = PlayData::from(vec![
+    ///     Card::new(Rank::Queen, Suit::Diamonds),
+    ///     Card::new(Rank::Queen, Suit::Clubs),
+    ///     Card::new(Rank::Two, Suit::Clubs),
+    /// ]);
+    ///
+    /// let context = PlayContext {
+    ///     play_data: &play_data,
+    ///     points: 0,
+    ///     opponent_points: 0,
+    ///     target: 121,
+    ///     is_dealer: false,
+    /// };
+    ///
+    /// // The King goes over 31, so the Nine is played.
+    /// assert_eq!(HighCard.get_play_index(&available_cards, &context), Some(1));
+    /// ```
+    fn choose(&mut self, _context: &GameContext, available_cards: &[Card]) -> Option<usize> {
+        self.get_card_index(available_cards)
+    }
+
+    /// Get a possible index for a [`Card`] to cut from a given [`Deck`]'s [`Card`]s.
+    ///
+    /// The [`Deck`] is cut one [`Card`] deeper than the index, with [`Deck::cut`], and the bottom
+    /// [`Card`] of the lifted packet is shown. This is used to choose the dealer. By default,
+    /// [`Controller::choose`] is used, with a [`GameContext`] for [`Phase::Cut`]. The dealer is
+    /// chosen before any points are scored.
+    fn get_cut_index(&mut self, deck_cards: &[Card]) -> Option<usize> {
+        let context = GameContext {
+            phase: Phase::Cut,
+            starter: None,
+            stack: &[],
+            stack_score: 0,
+            points: 0,
+            opponent_points: 0,
+            is_dealer: false,
+        };
+
+        self.choose(&context, deck_cards)
+    }
+
+    /// Get how many [`Card`]s deep to cut a [`Deck`] of `deck_size` [`Card`]s, for the starter.
+    ///
+    /// Only the pone cuts for the starter, and the dealer reveals the [`Card`] just below the cut.
+    /// The depth must be less than `deck_size`. By default, the [`Deck`] is cut `0` deep, so the
+    /// top [`Card`] is the starter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Controller, PredeterminedController};
+    ///
+    /// let mut controller = PredeterminedController::from(vec![]);
+    ///
+    /// assert_eq!(controller.get_starter_cut_depth(40), Some(0));
+    /// ```
+    fn get_starter_cut_depth(&mut self, _deck_size: usize) -> Option<usize> {
+        Some(0)
+    }
+
+    /// Get a possible index for a [`Card`] to discard to the crib from a given array of [`Card`]s.
+    ///
+    /// Unlike [`Controller::get_card_index`], this knows if the crib belongs to the [`Player`]
+    /// discarding, so an implementor can discard differently as the dealer or the pone. By
+    /// default, this is ignored and [`Controller::get_card_index`] is used.
+    fn get_discard_index(&mut self, available_cards: &[Card], _is_my_crib: bool) -> Option<usize> {
+        self.get_card_index(available_cards)
+    }
+
+    /// Get `count` different indices for [`Card`]s from a given array of [`Card`]s, all at once.
+    ///
+    /// Every index is for the given `available_cards`, so the numbering doesn't shift as
+    /// [`Card`]s are chosen. By default, [`Controller::get_card_index`] is used `count` times,
+    /// each time without the [`Card`]s already chosen. An out of bounds index stays out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, PredeterminedController};
+    ///
+    /// let cards = vec![
+    ///     Card::new(Rank::Ace, Suit::Hearts),
+    ///     Card::new(Rank::Two, Suit::Hearts),
+    ///     Card::new(Rank::Three, Suit::Hearts),
+    ///     Card::new(Rank::Four, Suit::Hearts),
+    /// ];
+    ///
+    /// // Each index is for the cards left after the ones before it.
+    /// let mut controller = PredeterminedController::from(vec![1, 1, 0]);
+    ///
+    /// assert_eq!(controller.get_card_indices(&cards, 3), Some(vec![1, 2, 0]));
+    /// ```
+    fn get_card_indices(&mut self, available_cards: &[Card], count: usize) -> Option<Vec<usize>> {
+        let mut remaining: Vec<usize> = (0..available_cards.len()).collect();
+        let mut result = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let remaining_cards: Vec<Card> = remaining
+                .iter()
+                .map(|index| available_cards[*index].clone())
+                .collect();
+
+            let index = self.get_card_index(&remaining_cards)?;
+
+            if index < remaining.len() {
+                result.push(remaining.remove(index));
+            } else {
+                result.push(index.max(available_cards.len()));
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Get the indices of both [`Card`]s to discard to the crib from a given [`Hand`].
+    ///
+    /// Both indices are for the given `hand`, and should be different. Choosing both at once lets
+    /// an implementor weigh the discards together, knowing if the crib is their own. By default,
+    /// [`Controller::get_discard_index`] is used twice, the second time without the first
+    /// [`Card`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, PredeterminedController};
+    ///
+    /// let hand = vec![
+    ///     Card::new(Rank::Ace, Suit::Hearts),
+    ///     Card::new(Rank::Two, Suit::Hearts),
+    ///     Card::new(Rank::Three, Suit::Hearts),
+    /// ];
+    ///
+    /// // The second index is for the hand without the Ace.
+    /// let mut controller = PredeterminedController::from(vec![0, 1]);
+    ///
+    /// assert_eq!(controller.get_crib_discards(&hand, true), Some([0, 2]));
+    /// ```
+    fn get_crib_discards(&mut self, hand: &[Card], is_own_crib: bool) -> Option<[usize; 2]> {
+        let first = self.get_discard_index(hand, is_own_crib)?;
+
+        let mut remaining = hand.to_vec();
+
+        if first < remaining.len() {
+            remaining.remove(first);
+        }
+
+        let second = self.get_discard_index(&remaining, is_own_crib)?;
+
+        if first <= second {
+            Some([first, second + 1])
+        } else {
+            Some([first, second])
+        }
+    }
+
+    /// Get a possible index for a [`Card`] to play (peg) from a given array of [`Card`]s.
+    ///
+    /// Unlike [`Controller::get_card_index`], the [`PlayContext`] gives the stack and points, so
+    /// an implementor can play differently depending on the state of the game. By default,
+    /// [`Controller::choose`] is used, with the [`PlayContext::game_context`].
+    fn get_play_index(&mut self, available_cards: &[Card], context: &PlayContext) -> Option<usize> {
+        self.choose(&context.game_context(), available_cards)
+    }
+
+    /// Tells the [`Controller`] that the [`Card`] it chose to play would take the stack over 31,
+    /// so it will be asked again with [`Controller::get_play_index`].
+    ///
+    /// By default, this is ignored.
+    fn play_rejected(&mut self, _card: &Card) {}
+
+    /// Tells the [`Controller`] that none of its [`Card`]s can be played without going over 31,
+    /// so it says GO, and waits until it's acknowledged.
+    ///
+    /// This is only asked while the [`Player`] still has [`Card`]s in hand. By default, GO is
+    /// acknowledged right away, like an AI would.
+    fn acknowledge_go(&mut self, _available_cards: &[Card], _context: &PlayContext) {}
+
+    /// Indicates that the [`Card`]s just chosen should be taken back, before the opponent acts.
+    ///
+    /// This is only asked when [`GameOptions::allow_undo`] is set, after each crib discard and
+    /// each play, and the [`Player`] then chooses again. By default, nothing is ever undone.
+    fn undo_choice(&mut self, _chosen: &[Card]) -> bool {
+        false
+    }
+
+    /// Indicates that the last choice ran out of time, and was made for the [`Player`], and then
+    /// forgets it, so each timeout is only taken once.
+    ///
+    /// By default, nothing ever runs out of time.
+    fn take_timed_out(&mut self) -> bool {
+        false
+    }
+}
+
+/// A boxed [`Controller`] forwards to the [`Controller`] it holds.
+///
+/// This lets the [`Controller`] for a [`Player`] be chosen at runtime, with
+/// `Player<Box<dyn Controller>>`.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{Controller, PredeterminedController, RngController};
+///
+/// let predetermined = true;
+///
+/// let mut controller: Box<dyn Controller> = if predetermined {
+///     Box::new(PredeterminedController::from(vec![1]))
+/// } else {
+///     Box::new(RngController::new())
+/// };
+///
+/// let available_cards = vec![
+///     Card::new(Rank::Queen, Suit::Hearts),
+///     Card::new(Rank::King, Suit::Clubs),
+/// ];
+///
+/// assert_eq!(controller.get_card_index(&available_cards), Some(1));
+/// ```
+impl<C> Controller for Box<C>
+where
+    C: Controller + ?Sized,
+{
+    fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+        (**self).get_card_index(available_cards)
+    }
+
+    fn choose(&mut self, context: &GameContext, available_cards: &[Card]) -> Option<usize> {
+        (**self).choose(context, available_cards)
+    }
+
+    fn get_cut_index(&mut self, deck_cards: &[Card]) -> Option<usize> {
+        (**self).get_cut_index(deck_cards)
+    }
+
+    fn get_starter_cut_depth(&mut self, deck_size: usize) -> Option<usize> {
+        (**self).get_starter_cut_depth(deck_size)
+    }
+
+    fn get_card_indices(&mut self, available_cards: &[Card], count: usize) -> Option<Vec<usize>> {
+        (**self).get_card_indices(available_cards, count)
+    }
+
+    fn get_discard_index(&mut self, available_cards: &[Card], is_my_crib: bool) -> Option<usize> {
+        (**self).get_discard_index(available_cards, is_my_crib)
+    }
+
+    fn get_crib_discards(&mut self, hand: &[Card], is_own_crib: bool) -> Option<[usize; 2]> {
+        (**self).get_crib_discards(hand, is_own_crib)
+    }
+
+    fn get_play_index(&mut self, available_cards: &[Card], context: &PlayContext) -> Option<usize> {
+        (**self).get_play_index(available_cards, context)
+    }
+
+    fn play_rejected(&mut self, card: &Card) {
+        (**self).play_rejected(card);
+    }
+
+    fn acknowledge_go(&mut self, available_cards: &[Card], context: &PlayContext) {
+        (**self).acknowledge_go(available_cards, context);
+    }
+
+    fn undo_choice(&mut self, chosen: &[Card]) -> bool {
+        (**self).undo_choice(chosen)
+    }
+
+    fn take_timed_out(&mut self) -> bool {
+        (**self).take_timed_out()
+    }
+}
+
+/// What a [`Controller`] can see when choosing a [`Card`] to play (peg).
+#[derive(Debug, PartialEq, Clone)]
+pub struct PlayContext<'a> {
+    /// The current stack, and its score.
+    pub play_data: &'a PlayData,
+    /// The points of the [`Player`] choosing the [`Card`].
+    pub points: u32,
+    /// The points of their opponent.
+    pub opponent_points: u32,
+    /// The points needed to win the game.
+    pub target: u32,
+    /// Indicates if the [`Player`] choosing the [`Card`] is the dealer.
+    pub is_dealer: bool,
+}
+
+impl<'a> PlayContext<'a> {
+    /// Returns the [`GameContext`] for [`Phase::Play`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Phase, PlayContext, PlayData};
+    ///
+    /// let mut play_data = PlayData::from(vec![Card::new(Rank::Seven, Suit::Hearts)]);
+    ///
+    /// play_data.starter = Some(Card::new(Rank::Jack, Suit::Clubs));
+    ///
+    /// let context = PlayContext {
+    ///     play_data: &play_data,
+    ///     points: 12,
+    ///     opponent_points: 30,
+    ///     target: 121,
+    ///     is_dealer: true,
+    /// };
+    ///
+    /// let game_context = context.game_context();
+    ///
+    /// assert_eq!(game_context.phase, Phase::Play);
+    /// assert_eq!(game_context.starter, play_data.starter.as_ref());
+    /// assert_eq!(game_context.stack_score, 7);
+    /// assert_eq!(game_context.opponent_points, 30);
+    /// assert!(game_context.is_dealer);
+    /// ```
+    #[must_use]
+    pub fn game_context(&self) -> GameContext<'a> {
+        GameContext {
+            phase: Phase::Play,
+            starter: self.play_data.starter.as_ref(),
+            stack: &self.play_data.stack,
+            stack_score: self.play_data.stack_score,
+            points: self.points,
+            opponent_points: self.opponent_points,
+            is_dealer: self.is_dealer,
+        }
+    }
+}
+
+/// What a [`Controller`] can see of the [`Game`] when choosing any [`Card`].
+///
+/// Before the starter is cut, or outside of play, the starter is [`None`] and the stack is empty.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GameContext<'a> {
+    /// The [`Phase`] the [`Card`] is chosen in.
+    pub phase: Phase,
+    /// The starter, once it's cut.
+    pub starter: Option<&'a Card>,
+    /// The current stack during play.
+    pub stack: &'a [Card],
+    /// The score of the current stack.
+    pub stack_score: u32,
+    /// The points of the [`Player`] choosing the [`Card`].
+    pub points: u32,
+    /// The points of their opponent.
+    pub opponent_points: u32,
+    /// Indicates if the [`Player`] choosing the [`Card`] is the dealer.
+    pub is_dealer: bool,
 }
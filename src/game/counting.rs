@@ -0,0 +1,238 @@
+//! The counting (show) part of the game, generalized to any number of seats.
+//!
+//! Counting goes around the table clockwise, starting to the left of the dealer. The dealer
+//! counts last, first their [`Hand`] and then their crib. As soon as anyone reaches the target
+//! score, counting stops, since the game is over.
+
+use cards::{Card, Hand};
+use game::{Controller, Player};
+
+/// Returns the seats in the order they count their [`Hand`]s, where seats are in clockwise order.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::counting_order;
+///
+/// assert_eq!(counting_order(2, 0), vec![1, 0]);
+/// assert_eq!(counting_order(3, 1), vec![2, 0, 1]);
+/// ```
+#[must_use]
+pub fn counting_order(number_of_seats: usize, dealer_seat: usize) -> Vec<usize> {
+    (1..=number_of_seats)
+        .map(|offset| (dealer_seat + offset) % number_of_seats)
+        .collect()
+}
+
+/// Counts the [`Hand`]s, and the dealer's crib, of all the [`Player`]s at the table.
+///
+/// The [`Player`]s are seated in clockwise order, and their points are updated in
+/// [`counting_order`]. The dealer counts the crib last.
+///
+/// Returns the seat of the first [`Player`] to reach the target score, if any. No one counts after
+/// that [`Player`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Hand, Rank, Suit};
+/// use libterminal_cribbage::game::{count_hands, Player, PredeterminedController};
+///
+/// let controller = PredeterminedController::from(vec![]);
+///
+/// let starter = Card::new(Rank::Ace, Suit::Hearts);
+///
+/// // Dealer with a 2pt hand and a 2pt crib.
+/// let mut dealer = Player::new_with_cards(
+///     controller.clone(),
+///     vec![Card::new(Rank::Five, Suit::Diamonds), Card::new(Rank::King, Suit::Clubs)],
+/// );
+/// dealer.points = 118;
+///
+/// let crib = Hand::from(vec![Card::new(Rank::Two, Suit::Clubs), Card::new(Rank::Two, Suit::Spades)]);
+///
+/// // Pone with a 2pt hand.
+/// let mut pone = Player::new_with_cards(
+///     controller,
+///     vec![Card::new(Rank::Five, Suit::Hearts), Card::new(Rank::Queen, Suit::Clubs)],
+/// );
+///
+/// let mut players = vec![dealer, pone];
+///
+/// let winner = count_hands(&mut players, &crib, /*dealer_seat=*/ 0, &starter, /*target=*/ 121);
+///
+/// assert_eq!(winner, Some(0));
+/// assert_eq!(players[0].points, 122);
+/// assert_eq!(players[1].points, 2);
+/// ```
+pub fn count_hands<C>(
+    players: &mut [Player<C>],
+    crib: &Hand,
+    dealer_seat: usize,
+    starter: &Card,
+    target: u32,
+) -> Option<usize>
+where
+    C: Controller,
+{
+    counting_order(players.len(), dealer_seat)
+        .into_iter()
+        .find(|&seat| {
+            let possible_crib = (seat == dealer_seat).then_some(crib);
+
+            count_player(&mut players[seat], possible_crib, starter, target) >= target
+        })
+}
+
+/// Adds the score of the [`Player`]'s [`Hand`] to their points, and then the crib if they are
+/// the dealer (and so given it), stopping at the target score.
+///
+/// Returns the [`Player`]'s points afterwards.
+pub fn count_player<C>(
+    player: &mut Player<C>,
+    possible_crib: Option<&Hand>,
+    starter: &Card,
+    target: u32,
+) -> u32
+where
+    C: Controller,
+{
+    player.points += player.hand.total(starter, /*is_crib=*/ false);
+
+    if let Some(crib) = possible_crib.filter(|_| player.points < target) {
+        player.points += crib.total(starter, /*is_crib=*/ true);
+    }
+
+    player.points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Card, Hand, Rank, Suit};
+    use game::PredeterminedController;
+
+    #[test]
+    fn test_counting_order_dealer_last() {
+        assert_eq!(counting_order(4, 3), vec![0, 1, 2, 3]);
+        assert_eq!(counting_order(4, 0), vec![1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn test_count_hands_three_players() {
+        let controller = PredeterminedController::from(vec![]);
+
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        // Seat 0: Hand Score 12pts: 15 4pts, Pair 2pts, 2x 3-run 6pts
+        let seat_0_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let mut seat_0 = Player::new_with_cards(controller.clone(), seat_0_cards);
+        seat_0.points = 100;
+
+        // Seat 1 (dealer): Hand Score 6pts: 15 2pts, 3-run 3pts, Nobs 1pt
+        // Crib Score 13pts: 15 4pts, 4-run 4pts, 5-flush 5pts
+        let seat_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let crib = Hand::from(vec![
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ]);
+        let mut seat_1 = Player::new_with_cards(controller.clone(), seat_1_cards);
+        seat_1.points = 105;
+
+        // Seat 2: Hand Score 0pts
+        let seat_2_cards = vec![
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Spades),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Queen, Suit::Spades),
+        ];
+        let seat_2 = Player::new_with_cards(controller, seat_2_cards);
+
+        let mut players = vec![seat_0, seat_1, seat_2];
+
+        // Seat 2 counts first, then seat 0, then the dealer (seat 1) with their crib.
+        let winner = count_hands(
+            &mut players,
+            &crib,
+            /*dealer_seat=*/ 1,
+            &starter,
+            /*target=*/ 121,
+        );
+
+        assert_eq!(winner, Some(1));
+        assert_eq!(players[2].points, 0);
+        assert_eq!(players[0].points, 112);
+        assert_eq!(players[1].points, 124);
+    }
+
+    #[test]
+    fn test_count_hands_three_players_short_circuits() {
+        let controller = PredeterminedController::from(vec![]);
+
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        // Seat 0 (dealer): Hand Score 6pts, Crib Score 13pts
+        let seat_0_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let crib = Hand::from(vec![
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ]);
+        let mut seat_0 = Player::new_with_cards(controller.clone(), seat_0_cards);
+        seat_0.points = 118;
+
+        // Seat 1: Hand Score 12pts, which wins before anyone else counts.
+        let seat_1_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let mut seat_1 = Player::new_with_cards(controller.clone(), seat_1_cards);
+        seat_1.points = 110;
+
+        // Seat 2: Hand Score 12pts, but never counts.
+        let seat_2_cards = vec![
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Hearts),
+        ];
+        let mut seat_2 = Player::new_with_cards(controller, seat_2_cards);
+        seat_2.points = 115;
+
+        let mut players = vec![seat_0, seat_1, seat_2];
+
+        let winner = count_hands(
+            &mut players,
+            &crib,
+            /*dealer_seat=*/ 0,
+            &starter,
+            /*target=*/ 121,
+        );
+
+        assert_eq!(winner, Some(1));
+        assert_eq!(players[1].points, 122);
+        assert_eq!(players[2].points, 115);
+        assert_eq!(players[0].points, 118);
+    }
+}
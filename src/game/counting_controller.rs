@@ -0,0 +1,305 @@
+//! A [`Controller`] that counts the [`Card`]s it has seen, to peg against what's left.
+
+use cards::tracker::CardTracker;
+use cards::Card;
+use game::{Controller, ExpectimaxController, PlayContext, PlayData};
+
+/// A controller that pegs by counting [`Card`]s, and discards by expected value.
+///
+/// It keeps track of every [`Card`] it has seen: its own hand and crib discards, the starter, and
+/// every [`Card`] played this round, from [`PlayData::played_cards`]. The opponent holds some of
+/// the rest, so for each [`Card`] it could play, it works out how likely the opponent is to reply
+/// with a fifteen, 31, pair, or run. Then it plays the [`Card`] worth the most points, minus the
+/// points the opponent is expected to peg in reply.
+///
+/// Discards to the crib, and cuts, are left to an [`ExpectimaxController`].
+#[derive(Debug, Clone)]
+pub struct CountingController {
+    discarder: ExpectimaxController,
+    crib_discards: Vec<Card>,
+    hand_size: Option<usize>,
+}
+
+impl CountingController {
+    /// Creates a new [`CountingController`] with a seed for the few random choices, like cuts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::CountingController;
+    ///
+    /// let controller = CountingController::new(/*seed=*/ 42);
+    /// ```
+    #[must_use]
+    pub fn new(seed: u64) -> CountingController {
+        CountingController {
+            discarder: ExpectimaxController::new(seed),
+            crib_discards: Vec::new(),
+            hand_size: None,
+        }
+    }
+
+    /// Returns the [`Card`]s that haven't been seen this round, which the opponent may hold.
+    fn unseen_cards(&self, cards_in_hand: &[Card], play_data: &PlayData) -> Vec<Card> {
+        let mut tracker = CardTracker::new();
+
+        tracker.mark_all_seen(cards_in_hand);
+        tracker.mark_all_seen(&self.crib_discards);
+        tracker.mark_all_seen(&play_data.played_cards());
+        tracker.mark_all_seen(&play_data.starter);
+
+        tracker.remaining()
+    }
+
+    /// Returns how many [`Card`]s the opponent still holds.
+    ///
+    /// Both [`Player`](game::Player)s keep as many [`Card`]s as this one kept after discarding,
+    /// so whatever was played and isn't one of its own is the opponent's. If it hasn't discarded
+    /// this round, the opponent is assumed to hold as many [`Card`]s as it does.
+    fn opponent_cards(&self, cards_in_hand: usize, play_data: &PlayData) -> usize {
+        let Some(hand_size) = self.hand_size else {
+            return cards_in_hand;
+        };
+
+        let played_by_me = hand_size.saturating_sub(cards_in_hand);
+        let played_by_opponent = play_data.played_cards().len().saturating_sub(played_by_me);
+
+        hand_size.saturating_sub(played_by_opponent)
+    }
+
+    /// Returns the points the opponent is expected to peg on the stack, if they hold
+    /// `opponent_cards` of the `unseen` [`Card`]s at random, and reply with their best one.
+    fn expected_reply_points(play_data: &PlayData, unseen: &[Card], opponent_cards: usize) -> f64 {
+        let mut reply_points: Vec<u32> = unseen
+            .iter()
+            .map(|reply| {
+                if (play_data.stack_score + reply.score()) <= 31 {
+                    play_data.points_for(reply)
+                } else {
+                    0
+                }
+            })
+            .collect();
+
+        reply_points.sort_unstable_by(|a, b| b.cmp(a));
+
+        let number_unseen = reply_points.len();
+        let opponent_cards = opponent_cards.min(number_unseen);
+
+        if opponent_cards == 0 {
+            return 0.0;
+        }
+
+        let hands = choose(number_unseen, opponent_cards);
+
+        // The chance that a reply is the best the opponent holds is the chance they hold it, but
+        // none of the better ones.
+        reply_points
+            .iter()
+            .enumerate()
+            .map(|(index, points)| {
+                let best_held = choose(number_unseen - index - 1, opponent_cards - 1) / hands;
+
+                f64::from(*points) * best_held
+            })
+            .sum()
+    }
+}
+
+impl Controller for CountingController {
+    /// Returns a random index, from the [`ExpectimaxController`].
+    ///
+    /// Discards to the crib go through [`Controller::get_crib_discards`], and plays go through
+    /// [`Controller::get_play_index`], instead.
+    fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+        self.discarder.get_card_index(available_cards)
+    }
+
+    /// Returns the indices of the two [`Card`]s to discard, from the [`ExpectimaxController`].
+    ///
+    /// The discards, and how many [`Card`]s are kept, are remembered for pegging.
+    fn get_crib_discards(&mut self, hand: &[Card], is_own_crib: bool) -> Option<[usize; 2]> {
+        let discards = self.discarder.get_crib_discards(hand, is_own_crib)?;
+
+        self.crib_discards = discards
+            .iter()
+            .filter_map(|index| hand.get(*index).cloned())
+            .collect();
+        self.hand_size = Some(hand.len().saturating_sub(discards.len()));
+
+        Some(discards)
+    }
+
+    /// Returns the index of the playable [`Card`] worth the most points, minus the points the
+    /// opponent is expected to peg in reply.
+    ///
+    /// Returns [`None`] if no [`Card`] can be played without going over 31.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, CountingController, PlayContext, PlayData};
+    ///
+    /// let available_cards = vec![
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    ///     Card::new(Rank::Four, Suit::Clubs),
+    /// ];
+    ///
+    /// let play_data = PlayData::new();
+    ///
+    /// let context = PlayContext {
+    ///     play_data: &play_data,
+    ///     points: 0,
+    ///     opponent_points: 0,
+    ///     target: 121,
+    ///     is_dealer: false,
+    /// };
+    ///
+    /// let mut controller = CountingController::new(/*seed=*/ 42);
+    ///
+    /// // Leading the Five gives away fifteen to any ten-card.
+    /// assert_eq!(controller.get_play_index(&available_cards, &context), Some(1));
+    /// ```
+    fn get_play_index(&mut self, available_cards: &[Card], context: &PlayContext) -> Option<usize> {
+        let unseen = self.unseen_cards(available_cards, context.play_data);
+        let opponent_cards = self.opponent_cards(available_cards.len(), context.play_data);
+
+        let playable = available_cards
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| (context.play_data.stack_score + card.score()) <= 31);
+
+        let mut best: Option<(usize, f64)> = None;
+
+        for (index, card) in playable {
+            let points = context.play_data.points_for(card);
+
+            let mut play_data = PlayData::from(context.play_data.stack.clone());
+
+            play_data.add_card(card.clone());
+
+            let value = f64::from(points)
+                - Self::expected_reply_points(&play_data, &unseen, opponent_cards);
+
+            if best.is_none_or(|(_, best_value)| best_value < value) {
+                best = Some((index, value));
+            }
+        }
+
+        best.map(|(index, _)| index)
+    }
+}
+
+/// Returns the number of ways to choose `k` of `n` things.
+#[allow(clippy::cast_precision_loss)]
+fn choose(n: usize, k: usize) -> f64 {
+    if n < k {
+        return 0.0;
+    }
+
+    (0..k).fold(1.0, |ways, index| {
+        ways * (n - index) as f64 / (index + 1) as f64
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Rank, Suit};
+    use game::{Game, Player, SubRound};
+
+    #[test]
+    fn test_expected_reply_points_one_card_is_the_average() {
+        let play_data = PlayData::from(vec![Card::new(Rank::Seven, Suit::Hearts)]);
+
+        let unseen = vec![
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Clubs),
+        ];
+
+        let expected = (2.0 + 0.0 + 0.0 + 2.0) / 4.0;
+
+        let actual = CountingController::expected_reply_points(&play_data, &unseen, 1);
+
+        assert!((actual - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_expected_reply_points_takes_the_best_held() {
+        let play_data = PlayData::from(vec![Card::new(Rank::Seven, Suit::Hearts)]);
+
+        let unseen = vec![
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::King, Suit::Clubs),
+        ];
+
+        // Holding two of the three, they have the Eight two out of three times.
+        let expected = 2.0 * 2.0 / 3.0;
+
+        let actual = CountingController::expected_reply_points(&play_data, &unseen, 2);
+
+        assert!((actual - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_get_play_index_counts_played_cards() {
+        // Leading the Six gives away fifteen to a Nine, but they've all been played.
+        let available_cards = vec![
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+
+        let mut play_data = PlayData::new();
+
+        play_data.sub_rounds.push(SubRound {
+            stack: vec![
+                Card::new(Rank::Nine, Suit::Hearts),
+                Card::new(Rank::Nine, Suit::Spades),
+                Card::new(Rank::Nine, Suit::Diamonds),
+            ],
+            leader_points: 7,
+            follower_points: 2,
+        });
+        play_data.sub_rounds.push(SubRound {
+            stack: vec![
+                Card::new(Rank::Nine, Suit::Clubs),
+                Card::new(Rank::Six, Suit::Spades),
+                Card::new(Rank::Six, Suit::Diamonds),
+                Card::new(Rank::Six, Suit::Clubs),
+            ],
+            leader_points: 0,
+            follower_points: 9,
+        });
+
+        let context = PlayContext {
+            play_data: &play_data,
+            points: 0,
+            opponent_points: 0,
+            target: 121,
+            is_dealer: false,
+        };
+
+        let mut controller = CountingController::new(/*seed=*/ 42);
+
+        // The Two could be paired, but nothing can reply to the Six.
+        assert_eq!(
+            controller.get_play_index(&available_cards, &context),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_play_whole_game() {
+        let player_1 = Player::new(CountingController::new(/*seed=*/ 1));
+        let player_2 = Player::new(CountingController::new(/*seed=*/ 2));
+
+        let outcome = Game::new(player_1, player_2).play_quiet().unwrap();
+
+        assert!(121 <= outcome.player_1_points.max(outcome.player_2_points));
+    }
+}
@@ -0,0 +1,189 @@
+//! The date of a daily deal, where everyone playing on the same day gets the same [`Deck`].
+
+#[cfg(doc)]
+use cards::Deck;
+#[cfg(doc)]
+use game::Game;
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The number of seconds in a day.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// A day of the (proleptic Gregorian) calendar, in UTC, for [`Game::new_daily`].
+///
+/// A [`DailyDate`] is written as `YYYY-MM-DD` with [`fmt::Display`], and read back with
+/// [`FromStr`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::DailyDate;
+///
+/// let date: DailyDate = "2024-02-29".parse().unwrap();
+///
+/// assert_eq!(date, DailyDate::new(2024, 2, 29).unwrap());
+/// assert_eq!(date.to_string(), "2024-02-29");
+/// assert_eq!(date.seed(), 20_240_229);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub struct DailyDate {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+impl DailyDate {
+    /// Creates a new [`DailyDate`], or [`None`] if there's no such day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::DailyDate;
+    ///
+    /// assert!(DailyDate::new(2024, 2, 29).is_some());
+    /// assert!(DailyDate::new(2023, 2, 29).is_none());
+    /// assert!(DailyDate::new(2023, 13, 1).is_none());
+    /// ```
+    #[must_use]
+    pub fn new(year: u32, month: u32, day: u32) -> Option<DailyDate> {
+        if (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day) {
+            Some(DailyDate { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns today's [`DailyDate`] in UTC, so it's the same day everywhere.
+    ///
+    /// There's no clock on `wasm32-unknown-unknown`, so the page should pass its own date to
+    /// [`DailyDate::new`] there instead.
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    #[must_use]
+    pub fn today() -> DailyDate {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+
+        DailyDate::from_days_since_epoch(seconds / SECONDS_PER_DAY)
+    }
+
+    /// Returns the seed of the day's deals, which is the date as the number `YYYYMMDD`.
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        u64::from(self.year) * 10_000 + u64::from(self.month) * 100 + u64::from(self.day)
+    }
+
+    /// Returns the [`DailyDate`] the number of days after 1970-01-01.
+    #[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), allow(dead_code))]
+    fn from_days_since_epoch(days: u64) -> DailyDate {
+        let mut date = DailyDate {
+            year: 1970,
+            month: 1,
+            day: 1,
+        };
+        let mut days = days;
+
+        while days_in_year(date.year) <= days {
+            days -= days_in_year(date.year);
+            date.year += 1;
+        }
+
+        while u64::from(days_in_month(date.year, date.month)) <= days {
+            days -= u64::from(days_in_month(date.year, date.month));
+            date.month += 1;
+        }
+
+        // Fewer days are left than there are in the month, so this fits.
+        date.day += u32::try_from(days).unwrap_or_default();
+
+        date
+    }
+}
+
+impl fmt::Display for DailyDate {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{:04}-{:02}-{:02}",
+            self.year, self.month, self.day
+        )
+    }
+}
+
+impl FromStr for DailyDate {
+    type Err = String;
+
+    /// Reads a [`DailyDate`] written as `YYYY-MM-DD`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("{input} is not a date like 2024-02-29!");
+
+        let mut parts = input.trim().splitn(3, '-').map(str::parse);
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(year)), Some(Ok(month)), Some(Ok(day))) => {
+                DailyDate::new(year, month, day).ok_or_else(invalid)
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Indicates that the year has a February 29th.
+fn is_leap_year(year: u32) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+/// Returns the number of days in the year.
+fn days_in_year(year: u32) -> u64 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Returns the number of days in the month (1 to 12) of the year.
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_days_since_epoch() {
+        assert_eq!(
+            DailyDate::from_days_since_epoch(0),
+            DailyDate::new(1970, 1, 1).unwrap()
+        );
+        assert_eq!(
+            DailyDate::from_days_since_epoch(19_782),
+            DailyDate::new(2024, 2, 29).unwrap()
+        );
+        assert_eq!(
+            DailyDate::from_days_since_epoch(20_742),
+            DailyDate::new(2026, 10, 16).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert_eq!(
+            "2023-02-29".parse::<DailyDate>(),
+            Err(String::from("2023-02-29 is not a date like 2024-02-29!"))
+        );
+        assert!("today".parse::<DailyDate>().is_err());
+    }
+}
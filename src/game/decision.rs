@@ -0,0 +1,97 @@
+//! The choices a step-driven [`Game`] waits on, and the responses that answer them.
+
+#[cfg(doc)]
+use cards::{Deck, Hand};
+#[cfg(doc)]
+use game::{Controller, Game, Player, StepController};
+
+use cards::Card;
+use game::PlayerId;
+
+/// The next choice a step-driven [`Game`] needs from a [`Player`], from [`Game::next_decision`].
+///
+/// Each [`Decision`] is answered by one [`DecisionResponse`], see [`Decision::accepts`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{Decision, DecisionResponse, PlayerId};
+///
+/// let decision = Decision::CutStarter {
+///     player: PlayerId::Two,
+///     deck_size: 40,
+/// };
+///
+/// assert_eq!(decision.player(), Some(PlayerId::Two));
+/// assert!(decision.accepts(&DecisionResponse::CutStarter(12)));
+/// assert!(!decision.accepts(&DecisionResponse::Cut(12)));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Decision {
+    /// Cut a [`Card`] from the `deck_size` [`Card`]s of the [`Deck`], to choose the dealer.
+    Cut { player: PlayerId, deck_size: usize },
+    /// Discard two of the [`Card`]s in [`Hand`] to the crib, which is their own if `is_own_crib`.
+    Discard {
+        player: PlayerId,
+        hand: Vec<Card>,
+        is_own_crib: bool,
+    },
+    /// Cut the `deck_size` [`Card`]s of the [`Deck`] for the starter, as the pone.
+    CutStarter { player: PlayerId, deck_size: usize },
+    /// Play one of the [`Card`]s in [`Hand`] on the stack, which scores `stack_score`. Every
+    /// [`Card`] played this round is in `played_cards`, in order, ending with the stack.
+    Play {
+        player: PlayerId,
+        hand: Vec<Card>,
+        stack: Vec<Card>,
+        stack_score: u32,
+        played_cards: Vec<Card>,
+    },
+    /// Nothing is left to decide, since the [`Game`] is over.
+    Over { winner: PlayerId },
+}
+
+impl Decision {
+    /// Returns the [`Player`] who decides, or [`None`] once the [`Game`] is over.
+    #[must_use]
+    pub fn player(&self) -> Option<PlayerId> {
+        match self {
+            Decision::Cut { player, .. }
+            | Decision::Discard { player, .. }
+            | Decision::CutStarter { player, .. }
+            | Decision::Play { player, .. } => Some(*player),
+            Decision::Over { .. } => None,
+        }
+    }
+
+    /// Indicates that the [`DecisionResponse`] is the kind that answers this [`Decision`].
+    ///
+    /// Whether its indices are in bounds is only checked once it's applied.
+    #[must_use]
+    pub fn accepts(&self, response: &DecisionResponse) -> bool {
+        matches!(
+            (self, response),
+            (Decision::Cut { .. }, DecisionResponse::Cut(_))
+                | (Decision::Discard { .. }, DecisionResponse::Discard(_))
+                | (Decision::CutStarter { .. }, DecisionResponse::CutStarter(_))
+                | (Decision::Play { .. }, DecisionResponse::Play(_))
+        )
+    }
+}
+
+/// The answer to a [`Decision`], for [`Game::apply`].
+///
+/// The indices are the same as the [`Controller`] methods they stand in for.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DecisionResponse {
+    /// The index of the [`Card`] cut, like [`Controller::get_cut_index`].
+    Cut(usize),
+    /// The indices of the two [`Card`]s discarded, like [`Controller::get_crib_discards`].
+    Discard([usize; 2]),
+    /// How many [`Card`]s deep to cut, like [`Controller::get_starter_cut_depth`].
+    CutStarter(usize),
+    /// The index of the [`Card`] played, like [`Controller::get_play_index`].
+    Play(usize),
+}
@@ -0,0 +1,134 @@
+//! The difficulty levels of the built-in AI [`Controller`]s.
+
+use std::fmt;
+use std::str::FromStr;
+
+use game::{Controller, ExpectimaxController, GreedyController, RngController};
+
+/// How hard the built-in AI is to beat.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum AiDifficulty {
+    /// Every choice is random, with a [`RngController`].
+    Easy,
+    /// Pegs for the most immediate points, with a [`GreedyController`].
+    #[default]
+    Medium,
+    /// Searches every discard for the best expected value, with an [`ExpectimaxController`].
+    Hard,
+}
+
+impl AiDifficulty {
+    /// All of the [`AiDifficulty`]s, from easiest to hardest.
+    pub const ALL: [AiDifficulty; 3] =
+        [AiDifficulty::Easy, AiDifficulty::Medium, AiDifficulty::Hard];
+}
+
+impl fmt::Display for AiDifficulty {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            AiDifficulty::Easy => "Easy",
+            AiDifficulty::Medium => "Medium",
+            AiDifficulty::Hard => "Hard",
+        };
+
+        write!(formatter, "{name}")
+    }
+}
+
+impl FromStr for AiDifficulty {
+    type Err = String;
+
+    /// Parses an [`AiDifficulty`] from its name, ignoring case, or its number from 1 to 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::AiDifficulty;
+    ///
+    /// assert_eq!("hard".parse(), Ok(AiDifficulty::Hard));
+    /// assert_eq!("1".parse(), Ok(AiDifficulty::Easy));
+    /// assert!("impossible".parse::<AiDifficulty>().is_err());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+
+        AiDifficulty::ALL
+            .iter()
+            .enumerate()
+            .find(|(index, difficulty)| {
+                difficulty.to_string().eq_ignore_ascii_case(input)
+                    || (index + 1).to_string() == input
+            })
+            .map(|(_, difficulty)| *difficulty)
+            .ok_or_else(|| format!("Unknown difficulty: {input}"))
+    }
+}
+
+/// Returns the built-in AI [`Controller`] for the [`AiDifficulty`], with random seeds.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{make_ai_controller, AiDifficulty, Game, Player};
+///
+/// let player_1 = Player::new(make_ai_controller(AiDifficulty::Easy));
+/// let player_2 = Player::new(make_ai_controller(AiDifficulty::Medium));
+///
+/// let outcome = Game::new(player_1, player_2).play_quiet().unwrap();
+///
+/// assert!(121 <= outcome.player_1_points.max(outcome.player_2_points));
+/// ```
+#[must_use]
+pub fn make_ai_controller(difficulty: AiDifficulty) -> Box<dyn Controller> {
+    make_ai_controller_with_seed(difficulty, rand::random())
+}
+
+/// Returns the built-in AI [`Controller`] for the [`AiDifficulty`], seeded so its choices are
+/// reproducible.
+#[must_use]
+pub fn make_ai_controller_with_seed(difficulty: AiDifficulty, seed: u64) -> Box<dyn Controller> {
+    match difficulty {
+        AiDifficulty::Easy => Box::new(RngController::new_with_seed(seed)),
+        AiDifficulty::Medium => Box::new(GreedyController::new(seed)),
+        AiDifficulty::Hard => Box::new(ExpectimaxController::new(seed)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use game::{Game, Player};
+
+    #[test]
+    fn test_display_from_str_round_trip() {
+        for difficulty in AiDifficulty::ALL {
+            assert_eq!(difficulty.to_string().parse(), Ok(difficulty));
+            assert_eq!(
+                difficulty.to_string().to_lowercase().parse(),
+                Ok(difficulty)
+            );
+        }
+    }
+
+    #[test]
+    fn test_harder_beats_easier() {
+        let mut hard_wins = 0;
+
+        for seed in 0..10 {
+            let player_1 = Player::new(make_ai_controller_with_seed(AiDifficulty::Hard, seed));
+            let player_2 = Player::new(make_ai_controller_with_seed(AiDifficulty::Easy, seed));
+
+            let outcome = Game::new(player_1, player_2)
+                .with_seed(seed)
+                .play_quiet()
+                .unwrap();
+
+            if outcome.player_1_won {
+                hard_wins += 1;
+            }
+        }
+
+        assert!(7 <= hard_wins, "Only won {} of 10", hard_wins);
+    }
+}
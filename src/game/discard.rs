@@ -0,0 +1,672 @@
+//! Evaluating discards to the crib, for AI [`Controller`]s.
+
+#[cfg(doc)]
+use cards::Rank;
+#[cfg(doc)]
+use game::{Controller, ExpectimaxController};
+
+use itertools::Itertools;
+
+use cards::{Card, Hand, ScoreCache};
+
+/// The expected score of a crib, indexed by the [`Rank`]s of two of its discards.
+///
+/// This uses the enum to usize conversion, where [`Rank::Ace`] is mapped to index 0 and
+/// [`Rank::King`] is mapped to index 12. Each entry is the exact average crib score over every
+/// suit of the discards, starter, and pair of other discards from the rest of the
+/// [`Deck`](cards::Deck). The `exhaustive` feature recomputes them in the tests.
+const CRIB_EXPECTATIONS: [[f64; 13]; 13] = [
+    [
+        5.53, 4.45, 4.57, 5.47, 5.74, 4.26, 4.09, 4.13, 4.04, 3.96, 4.20, 3.86, 3.75,
+    ],
+    [
+        4.45, 5.83, 6.84, 4.85, 5.77, 4.37, 4.29, 4.24, 4.14, 4.08, 4.31, 3.97, 3.86,
+    ],
+    [
+        4.57, 6.84, 6.16, 5.50, 6.43, 4.28, 4.36, 4.29, 4.12, 4.15, 4.38, 4.05, 3.94,
+    ],
+    [
+        5.47, 4.85, 5.50, 6.14, 7.00, 4.97, 4.18, 4.31, 4.21, 4.15, 4.38, 4.04, 3.94,
+    ],
+    [
+        5.74, 5.77, 6.43, 7.00, 8.99, 7.10, 6.42, 5.76, 5.74, 7.03, 7.26, 6.93, 6.82,
+    ],
+    [
+        4.26, 4.37, 4.28, 4.97, 7.10, 6.29, 5.54, 4.91, 5.58, 3.84, 4.08, 3.74, 3.63,
+    ],
+    [
+        4.09, 4.29, 4.36, 4.18, 6.42, 5.54, 6.11, 6.77, 4.36, 3.73, 4.02, 3.69, 3.58,
+    ],
+    [
+        4.13, 4.24, 4.29, 4.31, 5.76, 4.91, 6.77, 5.63, 4.94, 4.31, 3.95, 3.67, 3.56,
+    ],
+    [
+        4.04, 4.14, 4.12, 4.21, 5.74, 5.58, 4.36, 4.94, 5.53, 4.85, 4.49, 3.56, 3.51,
+    ],
+    [
+        3.96, 4.08, 4.15, 4.15, 7.03, 3.84, 3.73, 4.31, 4.85, 5.46, 5.05, 4.12, 3.42,
+    ],
+    [
+        4.20, 4.31, 4.38, 4.38, 7.26, 4.08, 4.02, 3.95, 4.49, 5.05, 5.93, 5.01, 4.31,
+    ],
+    [
+        3.86, 3.97, 4.05, 4.04, 6.93, 3.74, 3.69, 3.67, 3.56, 4.12, 5.01, 5.25, 3.97,
+    ],
+    [
+        3.75, 3.86, 3.94, 3.94, 6.82, 3.63, 3.58, 3.56, 3.51, 3.42, 4.31, 3.97, 5.03,
+    ],
+];
+
+/// The expected score of the dealer's crib, indexed by the [`Rank`]s of the dealer's own two
+/// discards.
+///
+/// Like [`CRIB_EXPECTATIONS`], but the pone's discards aren't random. Each entry is the average of
+/// 20,000 seeded simulated cribs, where the pone balks by keeping the best hand, less the
+/// [`CRIB_EXPECTATIONS`] of its discards, so these are lower. The `exhaustive` feature
+/// regenerates them in the tests, and prints them.
+const DEALER_CRIB_EXPECTATIONS: [[f64; 13]; 13] = [
+    [
+        4.79, 3.89, 4.16, 4.84, 5.34, 3.53, 3.38, 3.56, 3.08, 3.04, 3.40, 3.01, 2.96,
+    ],
+    [
+        3.89, 5.36, 6.36, 4.36, 5.37, 3.69, 3.74, 3.53, 3.39, 3.28, 3.60, 3.25, 3.22,
+    ],
+    [
+        4.16, 6.36, 5.63, 4.70, 5.92, 3.41, 3.67, 3.83, 3.33, 3.45, 3.76, 3.48, 3.44,
+    ],
+    [
+        4.84, 4.36, 4.70, 5.31, 6.29, 3.54, 3.50, 3.78, 3.48, 3.38, 3.69, 3.33, 3.32,
+    ],
+    [
+        5.34, 5.37, 5.92, 6.29, 8.72, 6.38, 6.01, 5.51, 5.42, 6.34, 6.91, 6.33, 6.17,
+    ],
+    [
+        3.53, 3.69, 3.41, 3.54, 6.38, 5.47, 4.95, 5.02, 4.48, 3.01, 3.17, 2.82, 2.79,
+    ],
+    [
+        3.38, 3.74, 3.67, 3.50, 6.01, 4.95, 5.82, 5.92, 4.25, 3.01, 3.31, 2.99, 2.96,
+    ],
+    [
+        3.56, 3.53, 3.83, 3.78, 5.51, 5.02, 5.92, 5.37, 4.65, 3.71, 3.24, 3.03, 3.02,
+    ],
+    [
+        3.08, 3.39, 3.33, 3.48, 5.42, 4.48, 4.25, 4.65, 4.71, 3.88, 3.76, 2.71, 2.69,
+    ],
+    [
+        3.04, 3.28, 3.45, 3.38, 6.34, 3.01, 3.01, 3.71, 3.88, 4.32, 4.28, 3.09, 2.44,
+    ],
+    [
+        3.40, 3.60, 3.76, 3.69, 6.91, 3.17, 3.31, 3.24, 3.76, 4.28, 5.09, 4.43, 3.69,
+    ],
+    [
+        3.01, 3.25, 3.48, 3.33, 6.33, 2.82, 2.99, 3.03, 2.71, 3.09, 4.43, 4.13, 3.04,
+    ],
+    [
+        2.96, 3.22, 3.44, 3.32, 6.17, 2.79, 2.96, 3.02, 2.69, 2.44, 3.69, 3.04, 3.92,
+    ],
+];
+
+/// The expected score of the dealer's crib, indexed by the [`Rank`]s of the pone's two discards.
+///
+/// Like [`DEALER_CRIB_EXPECTATIONS`], but the dealer feeds their own crib by keeping the best hand,
+/// plus the [`CRIB_EXPECTATIONS`] of their discards, so these are higher.
+const PONE_CRIB_EXPECTATIONS: [[f64; 13]; 13] = [
+    [
+        5.70, 4.62, 4.75, 5.47, 6.00, 4.71, 4.41, 4.72, 4.51, 4.30, 4.54, 4.25, 4.10,
+    ],
+    [
+        4.62, 6.08, 6.67, 5.11, 6.03, 4.93, 4.84, 4.81, 4.65, 4.43, 4.68, 4.39, 4.26,
+    ],
+    [
+        4.75, 6.67, 6.41, 5.72, 6.51, 4.64, 4.86, 4.81, 4.46, 4.52, 4.85, 4.51, 4.38,
+    ],
+    [
+        5.47, 5.11, 5.72, 6.23, 6.99, 5.21, 4.53, 4.83, 4.69, 4.43, 4.71, 4.38, 4.29,
+    ],
+    [
+        6.00, 6.03, 6.51, 6.99, 9.12, 7.26, 6.80, 6.31, 6.23, 7.11, 7.34, 6.97, 6.79,
+    ],
+    [
+        4.71, 4.93, 4.64, 5.21, 7.26, 6.93, 6.21, 5.79, 5.89, 4.48, 4.61, 4.19, 4.12,
+    ],
+    [
+        4.41, 4.84, 4.86, 4.53, 6.80, 6.21, 6.75, 6.85, 5.25, 4.38, 4.53, 4.20, 4.09,
+    ],
+    [
+        4.72, 4.81, 4.81, 4.83, 6.31, 5.79, 6.85, 6.43, 5.71, 4.82, 4.46, 4.16, 4.04,
+    ],
+    [
+        4.51, 4.65, 4.46, 4.69, 6.23, 5.89, 5.25, 5.71, 6.25, 5.35, 4.92, 4.09, 3.99,
+    ],
+    [
+        4.30, 4.43, 4.52, 4.43, 7.11, 4.48, 4.38, 4.82, 5.35, 5.88, 5.49, 4.66, 3.97,
+    ],
+    [
+        4.54, 4.68, 4.85, 4.71, 7.34, 4.61, 4.53, 4.46, 4.92, 5.49, 6.30, 5.32, 4.72,
+    ],
+    [
+        4.25, 4.39, 4.51, 4.38, 6.97, 4.19, 4.20, 4.16, 4.09, 4.66, 5.32, 5.63, 4.41,
+    ],
+    [
+        4.10, 4.26, 4.38, 4.29, 6.79, 4.12, 4.09, 4.04, 3.99, 3.97, 4.72, 4.41, 5.34,
+    ],
+];
+
+/// The expected value of a discard, split into the kept [`Hand`] and the crib.
+///
+/// Keeping these separate lets a [`Controller`] weigh offense and defense however it wants.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct DiscardValue {
+    /// The expected score of the kept [`Hand`] (offense).
+    pub keep_ev: f64,
+    /// The expected score of the crib (defense), which is negative if it's the opponent's crib.
+    pub crib_ev: f64,
+}
+
+impl DiscardValue {
+    /// Returns the sum of the offense and defense.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::DiscardValue;
+    ///
+    /// let value = DiscardValue {
+    ///     keep_ev: 8.5,
+    ///     crib_ev: -4.0,
+    /// };
+    ///
+    /// assert_eq!(value.total(), 4.5);
+    /// ```
+    #[must_use]
+    pub fn total(&self) -> f64 {
+        self.keep_ev + self.crib_ev
+    }
+}
+
+/// Returns the [`DiscardValue`] of keeping `hand` and discarding `discards` to the crib.
+///
+/// The `unseen` [`Card`]s are the ones that could be the starter, or the other crib discards.
+/// The kept [`Hand`] is averaged over every starter, and the crib is averaged over every starter
+/// and pair of other discards from the `unseen` [`Card`]s.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::discard_value;
+///
+/// let hand = vec![
+///     Card::new(Rank::Five, Suit::Hearts),
+///     Card::new(Rank::Five, Suit::Clubs),
+///     Card::new(Rank::Jack, Suit::Spades),
+///     Card::new(Rank::Queen, Suit::Diamonds),
+/// ];
+/// let discards = vec![
+///     Card::new(Rank::Ace, Suit::Spades),
+///     Card::new(Rank::Nine, Suit::Hearts),
+/// ];
+/// let unseen = vec![
+///     Card::new(Rank::Two, Suit::Clubs),
+///     Card::new(Rank::Seven, Suit::Diamonds),
+///     Card::new(Rank::King, Suit::Hearts),
+/// ];
+///
+/// let value = discard_value(&hand, &discards, /*is_my_crib=*/ true, &unseen);
+///
+/// assert!(value.keep_ev > 0.0);
+/// assert!(value.crib_ev >= 0.0);
+/// ```
+#[must_use]
+pub fn discard_value(
+    hand: &[Card],
+    discards: &[Card],
+    is_my_crib: bool,
+    unseen: &[Card],
+) -> DiscardValue {
+    let keep_ev = keep_value(hand, unseen);
+
+    let cache = ScoreCache::new();
+
+    let crib_ev = average(
+        unseen
+            .iter()
+            .enumerate()
+            .flat_map(|(starter_index, starter)| {
+                unseen
+                    .iter()
+                    .enumerate()
+                    .filter(move |(index, _)| *index != starter_index)
+                    .map(|(_, card)| card)
+                    .tuple_combinations()
+                    .map(move |(other_discard_1, other_discard_2)| {
+                        let mut crib_cards = discards.to_vec();
+
+                        crib_cards.push(other_discard_1.clone());
+                        crib_cards.push(other_discard_2.clone());
+
+                        cache.total(&Hand::from(crib_cards), starter, /*is_crib=*/ true)
+                    })
+            }),
+    );
+
+    DiscardValue {
+        keep_ev,
+        crib_ev: if is_my_crib { crib_ev } else { -crib_ev },
+    }
+}
+
+/// Returns the [`DiscardValue`] of keeping `hand` and discarding `discards` to the crib, using
+/// [`crib_ev`] for the crib.
+///
+/// This is much faster than [`discard_value`], since only the kept [`Hand`] is averaged over every
+/// starter from the `unseen` [`Card`]s. But the crib ignores the `unseen` [`Card`]s, and assumes
+/// the opponent discards for the best hand, and crib, like an [`ExpectimaxController`].
+///
+/// # Panics
+///
+/// If there aren't exactly two `discards`.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{crib_ev, discard_value_from_table};
+///
+/// let hand = vec![
+///     Card::new(Rank::Jack, Suit::Spades),
+///     Card::new(Rank::Queen, Suit::Diamonds),
+/// ];
+/// let discards = vec![
+///     Card::new(Rank::Five, Suit::Hearts),
+///     Card::new(Rank::Five, Suit::Clubs),
+/// ];
+/// let unseen = vec![Card::new(Rank::Five, Suit::Diamonds)];
+///
+/// let value = discard_value_from_table(&hand, &discards, /*is_my_crib=*/ false, &unseen);
+///
+/// assert_eq!(value.keep_ev, 4.0);
+/// assert_eq!(
+///     value.crib_ev,
+///     crib_ev(&discards[0], &discards[1], /*is_own_crib=*/ false)
+/// );
+/// ```
+#[must_use]
+pub fn discard_value_from_table(
+    hand: &[Card],
+    discards: &[Card],
+    is_my_crib: bool,
+    unseen: &[Card],
+) -> DiscardValue {
+    assert_eq!(
+        discards.len(),
+        2,
+        "Expected two discards, got {}",
+        discards.len()
+    );
+
+    DiscardValue {
+        keep_ev: keep_value(hand, unseen),
+        crib_ev: crib_ev(&discards[0], &discards[1], is_my_crib),
+    }
+}
+
+/// Returns the expected value of the crib with the two discards, from a table of expectations for
+/// the dealer or pone.
+///
+/// This is positive if it's `is_own_crib`, as the dealer, or negative otherwise, as the pone. The
+/// opponent is assumed to discard for the best hand, weighing the crib by [`crib_expectation`],
+/// so the dealer's crib is worth less than [`crib_expectation`] when the pone balks, and more when
+/// the dealer feeds it.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{crib_ev, crib_expectation};
+///
+/// let five_hearts = Card::new(Rank::Five, Suit::Hearts);
+/// let five_clubs = Card::new(Rank::Five, Suit::Clubs);
+///
+/// let dealer_ev = crib_ev(&five_hearts, &five_clubs, /*is_own_crib=*/ true);
+/// let pone_ev = crib_ev(&five_hearts, &five_clubs, /*is_own_crib=*/ false);
+///
+/// assert!(0.0 < dealer_ev && dealer_ev < crib_expectation(&five_hearts, &five_clubs));
+/// assert!(pone_ev < -crib_expectation(&five_hearts, &five_clubs));
+/// ```
+#[must_use]
+pub fn crib_ev(discard_1: &Card, discard_2: &Card, is_own_crib: bool) -> f64 {
+    let (rank_1, rank_2) = (discard_1.rank as usize, discard_2.rank as usize);
+
+    if is_own_crib {
+        DEALER_CRIB_EXPECTATIONS[rank_1][rank_2]
+    } else {
+        -PONE_CRIB_EXPECTATIONS[rank_1][rank_2]
+    }
+}
+
+/// Returns the expected score of a crib with the two discards, from a table of expectations.
+///
+/// The other two discards, and the starter, are assumed to be random.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::crib_expectation;
+///
+/// let five_hearts = Card::new(Rank::Five, Suit::Hearts);
+/// let five_clubs = Card::new(Rank::Five, Suit::Clubs);
+/// let king_spades = Card::new(Rank::King, Suit::Spades);
+/// let ten_clubs = Card::new(Rank::Ten, Suit::Clubs);
+///
+/// // A pair of fives is the best discard, and a King and Ten is one of the worst.
+/// assert!(crib_expectation(&five_hearts, &five_clubs) > crib_expectation(&king_spades, &ten_clubs));
+/// assert_eq!(
+///     crib_expectation(&five_hearts, &king_spades),
+///     crib_expectation(&king_spades, &five_hearts)
+/// );
+/// ```
+#[must_use]
+pub fn crib_expectation(discard_1: &Card, discard_2: &Card) -> f64 {
+    CRIB_EXPECTATIONS[discard_1.rank as usize][discard_2.rank as usize]
+}
+
+/// Returns the average score of the kept [`Hand`] over every starter from the `unseen` [`Card`]s.
+fn keep_value(hand: &[Card], unseen: &[Card]) -> f64 {
+    let kept_hand = Hand::from(hand.to_vec());
+
+    let cache = ScoreCache::new();
+
+    average(
+        unseen
+            .iter()
+            .map(|starter| cache.total(&kept_hand, starter, /*is_crib=*/ false)),
+    )
+}
+
+/// Returns the average of the scores, or `0.0` if there are none.
+fn average(scores: impl Iterator<Item = u32>) -> f64 {
+    let (sum, count) = scores.fold((0.0, 0.0), |(sum, count), score| {
+        (sum + f64::from(score), count + 1.0)
+    });
+
+    if count == 0.0 {
+        0.0
+    } else {
+        sum / count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "exhaustive")]
+    use cards::Deck;
+    use cards::{Rank, Suit};
+
+    /// The number of simulated cribs averaged for each entry of [`DEALER_CRIB_EXPECTATIONS`] and
+    /// [`PONE_CRIB_EXPECTATIONS`].
+    #[cfg(feature = "exhaustive")]
+    const SAMPLES: u64 = 20_000;
+
+    #[test]
+    fn test_discard_value_pone_throwing_synergy() {
+        let hand = vec![
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Spades),
+            Card::new(Rank::Nine, Suit::Diamonds),
+        ];
+        let discards = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Clubs),
+        ];
+        let unseen = vec![
+            Card::new(Rank::Ten, Suit::Clubs),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Spades),
+            Card::new(Rank::Six, Suit::Hearts),
+        ];
+
+        let value = discard_value(&hand, &discards, /*is_my_crib=*/ false, &unseen);
+
+        // A double run of three with a fifteen is at least 12pts in hand.
+        assert!(value.keep_ev >= 12.0);
+        // A pair of fives is at least 2pts (the pair), plus fifteens with the ten cards.
+        assert!(value.crib_ev <= -2.0);
+
+        let dealer_value = discard_value(&hand, &discards, /*is_my_crib=*/ true, &unseen);
+
+        assert_eq!(dealer_value.keep_ev, value.keep_ev);
+        assert_eq!(dealer_value.crib_ev, -value.crib_ev);
+    }
+
+    #[test]
+    fn test_crib_expectations_symmetric() {
+        for table in [
+            &CRIB_EXPECTATIONS,
+            &DEALER_CRIB_EXPECTATIONS,
+            &PONE_CRIB_EXPECTATIONS,
+        ] {
+            for (rank_1, row) in table.iter().enumerate() {
+                for (rank_2, expectation) in row.iter().enumerate() {
+                    assert_eq!(*expectation, table[rank_2][rank_1]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_crib_ev_pone_feeds_dealer() {
+        // The dealer feeds their own crib, so it's worth more when the pone discards to it.
+        for (dealer_row, pone_row) in DEALER_CRIB_EXPECTATIONS
+            .iter()
+            .zip(PONE_CRIB_EXPECTATIONS.iter())
+        {
+            for (dealer_expectation, pone_expectation) in dealer_row.iter().zip(pone_row) {
+                assert!(dealer_expectation < pone_expectation);
+            }
+        }
+    }
+
+    /// Returns the exact average score of a crib with a [`Card`] of each [`Rank`] index, over
+    /// every suit of the second, every starter, and every pair of other discards.
+    ///
+    /// The first [`Card`] is always a spade, since every suit scores the same.
+    #[cfg(feature = "exhaustive")]
+    fn exact_crib_expectation(rank_1: usize, rank_2: usize) -> f64 {
+        let cache = ScoreCache::new();
+        let deck = Deck::new();
+
+        let discard_1 = deck
+            .as_vec()
+            .iter()
+            .find(|card| card.rank as usize == rank_1 && card.suit == Suit::Spades)
+            .unwrap();
+
+        let scores = deck
+            .as_vec()
+            .iter()
+            .filter(|card| card.rank as usize == rank_2 && *card != discard_1)
+            .flat_map(|discard_2| {
+                let rest: Vec<&Card> = deck
+                    .as_vec()
+                    .iter()
+                    .filter(|card| *card != discard_1 && *card != discard_2)
+                    .collect();
+
+                rest.iter()
+                    .enumerate()
+                    .flat_map(|(starter_index, starter)| {
+                        rest.iter()
+                            .enumerate()
+                            .filter(move |(index, _)| *index != starter_index)
+                            .map(|(_, card)| *card)
+                            .tuple_combinations()
+                            .map(move |(other_discard_1, other_discard_2)| {
+                                let crib = Hand::from(vec![
+                                    discard_1.clone(),
+                                    discard_2.clone(),
+                                    other_discard_1.clone(),
+                                    other_discard_2.clone(),
+                                ]);
+
+                                cache.total(&crib, starter, /*is_crib=*/ true)
+                            })
+                    })
+                    .collect::<Vec<u32>>()
+            });
+
+        average(scores)
+    }
+
+    /// Returns the average score of `samples` simulated cribs, where one player discards a
+    /// [`Card`] of each [`Rank`] index, and the other balks, or feeds, with the best discard by
+    /// [`keep_value`] and [`crib_expectation`].
+    ///
+    /// With `dealer_discards`, the dealer's [`Card`]s are the given ones, and the pone balks.
+    /// Otherwise, the pone's are, and the dealer feeds their own crib.
+    #[cfg(feature = "exhaustive")]
+    fn simulated_crib_expectation(
+        rank_1: usize,
+        rank_2: usize,
+        dealer_discards: bool,
+        samples: u64,
+    ) -> f64 {
+        use rand::rngs::SmallRng;
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let cache = ScoreCache::new();
+        let seed = (rank_1 * 13 + rank_2) as u64 + if dealer_discards { 0 } else { 169 };
+        let mut rng = SmallRng::seed_from_u64(seed);
+
+        average((0..samples).map(|_| {
+            let mut cards = Deck::new().as_vec().clone();
+
+            cards.shuffle(&mut rng);
+
+            let position_1 = cards
+                .iter()
+                .position(|card| card.rank as usize == rank_1)
+                .unwrap();
+            let discard_1 = cards.remove(position_1);
+
+            let position_2 = cards
+                .iter()
+                .position(|card| card.rank as usize == rank_2)
+                .unwrap();
+            let discard_2 = cards.remove(position_2);
+
+            // The other player is dealt six, and the starter is cut from what's left after the
+            // rest of the given player's hand.
+            let other_hand: Vec<Card> = cards.drain(..6).collect();
+            let starter = &cards[4];
+            let unseen: Vec<Card> = Deck::new()
+                .as_vec()
+                .iter()
+                .filter(|card| !other_hand.contains(card))
+                .cloned()
+                .collect();
+
+            let (other_discard_1, other_discard_2) = other_hand
+                .iter()
+                .tuple_combinations()
+                .max_by(|(discard_a, discard_b), (discard_c, discard_d)| {
+                    let value = |discard_1: &Card, discard_2: &Card| {
+                        let kept: Vec<Card> = other_hand
+                            .iter()
+                            .filter(|card| *card != discard_1 && *card != discard_2)
+                            .cloned()
+                            .collect();
+                        let crib = crib_expectation(discard_1, discard_2);
+
+                        keep_value(&kept, &unseen) + if dealer_discards { -crib } else { crib }
+                    };
+
+                    value(discard_a, discard_b).total_cmp(&value(discard_c, discard_d))
+                })
+                .unwrap();
+
+            let crib = Hand::from(vec![
+                discard_1.clone(),
+                discard_2.clone(),
+                other_discard_1.clone(),
+                other_discard_2.clone(),
+            ]);
+
+            cache.total(&crib, starter, /*is_crib=*/ true)
+        }))
+    }
+
+    /// Generates a table with the expectation of each pair of [`Rank`] indices, rounded to two
+    /// decimals, and prints it to paste over the constant.
+    #[cfg(feature = "exhaustive")]
+    fn generate_table<F>(name: &str, expectation: F) -> [[f64; 13]; 13]
+    where
+        F: Fn(usize, usize) -> f64,
+    {
+        let mut table = [[0.0; 13]; 13];
+
+        let pairs = (0..13).flat_map(|rank_1| (rank_1..13).map(move |rank_2| (rank_1, rank_2)));
+
+        for (rank_1, rank_2) in pairs {
+            let rounded = (expectation(rank_1, rank_2) * 100.0).round() / 100.0;
+
+            table[rank_1][rank_2] = rounded;
+            table[rank_2][rank_1] = rounded;
+        }
+
+        println!("const {name}: [[f64; 13]; 13] = [");
+
+        for row in &table {
+            println!(
+                "    [{}],",
+                row.iter()
+                    .map(|expectation| format!("{expectation:.2}"))
+                    .join(", ")
+            );
+        }
+
+        println!("];");
+
+        table
+    }
+
+    #[test]
+    #[cfg(feature = "exhaustive")]
+    fn crib_expectations_match_exact_averages() {
+        let table = generate_table("CRIB_EXPECTATIONS", exact_crib_expectation);
+
+        assert_eq!(table, CRIB_EXPECTATIONS);
+    }
+
+    #[test]
+    #[cfg(feature = "exhaustive")]
+    fn dealer_crib_expectations_match_simulation() {
+        let table = generate_table("DEALER_CRIB_EXPECTATIONS", |rank_1, rank_2| {
+            simulated_crib_expectation(rank_1, rank_2, /*dealer_discards=*/ true, SAMPLES)
+        });
+
+        assert_eq!(table, DEALER_CRIB_EXPECTATIONS);
+    }
+
+    #[test]
+    #[cfg(feature = "exhaustive")]
+    fn pone_crib_expectations_match_simulation() {
+        let table = generate_table("PONE_CRIB_EXPECTATIONS", |rank_1, rank_2| {
+            simulated_crib_expectation(rank_1, rank_2, /*dealer_discards=*/ false, SAMPLES)
+        });
+
+        assert_eq!(table, PONE_CRIB_EXPECTATIONS);
+    }
+
+    #[test]
+    fn test_discard_value_no_unseen() {
+        let value = discard_value(&[], &[], /*is_my_crib=*/ true, &[]);
+
+        assert_eq!(value, DiscardValue::default());
+    }
+}
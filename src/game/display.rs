@@ -2,10 +2,12 @@
 use std::io::{self, Write};
 
 #[cfg(doc)]
-use crate::cards::Hand;
+use crate::cards::{Deck, Hand, Rank};
 
 use crate::cards::Card;
-use crate::game::{Controller, PlayData, Player};
+use crate::game::{
+    Controller, Language, Pegs, Phase, PlayData, PlayScoreBreakdown, Player, Round, RoundSummary,
+};
 
 /// The `trait` for controlling how the game is displayed.
 pub trait Display {
@@ -18,6 +20,12 @@ pub trait Display {
         io::stdout().flush()
     }
 
+    /// The [`Language`] of the messages, which is also used by the controllers prompting with this
+    /// display.
+    fn language(&self) -> Language {
+        Language::default()
+    }
+
     /// Print message to `std::out` without a new line, a spacer, or a delay.
     fn print_no_spacer_no_delay(&self, message: &str);
 
@@ -27,24 +35,87 @@ pub trait Display {
     /// Print message with spacer to `std::out` using [`thread::sleep`] with a delay after printing.
     fn println(&self, message: &str);
 
-    /// The [`String`] display for both [`Player`]s [`Card`]s cut from the [`Deck`].
+    /// The [`String`] display for both named [`Player`]s [`Card`]s cut from the [`Deck`].
     #[must_use]
     fn game_after_cut_message(
         &self,
+        player_name: &str,
         player_cut: &Card,
+        opponent_name: &str,
         opponent_cut: &Card,
         player_won: bool,
     ) -> String;
 
-    /// The [`String`] display for both [`Player`]s and the starter [`Card`] before play.
+    /// The [`String`] display before both named [`Player`]s' cuts are revealed, which is followed
+    /// by the delay of [`Display::println`] for suspense.
+    #[must_use]
+    fn game_cut_reveal_message(&self, player_name: &str, opponent_name: &str) -> String;
+
+    /// The [`String`] display for both named [`Player`]s cutting the same [`Rank`], so they cut
+    /// again.
+    #[must_use]
+    fn game_recut_message(
+        &self,
+        player_name: &str,
+        player_cut: &Card,
+        opponent_name: &str,
+        opponent_cut: &Card,
+    ) -> String;
+
+    /// The [`String`] display for the face down [`Deck`] of `number_of_cards` [`Card`]s, to cut
+    /// for the dealer, with the `depth` marked if it's been chosen.
+    #[must_use]
+    fn cut_deck_message(&self, number_of_cards: usize, depth: Option<usize>) -> String;
+
+    /// The [`String`] display for the pone's cut for the starter, `depth` [`Card`]s deep into the
+    /// [`Deck`], attributed to the named `cutter`.
+    #[must_use]
+    fn game_starter_cut_message(&self, depth: usize, cutter: &str) -> String;
+
+    /// The [`String`] display for both [`Player`]s while discarding to the crib.
+    ///
+    /// This will show the opponent's and player's points, but only show the player's [`Hand`].
+    /// It also hints at whose crib the discards are going to, based on if the player is the dealer.
+    #[must_use]
+    fn game_discard_message<C1, C2>(
+        &self,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+        is_dealer: bool,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller;
+
+    /// The [`String`] display for both of a [`Player`]'s discards to the crib, attributed to the
+    /// named `discarder`, as the dealer or pone.
+    #[must_use]
+    fn game_crib_discards_message(
+        &self,
+        cards: &[Card],
+        discarder: &str,
+        is_dealer: bool,
+    ) -> String;
+
+    /// The [`String`] display for taking back the [`Card`]s just chosen, attributed to the named
+    /// `undoer`.
+    #[must_use]
+    fn game_undo_message(&self, chosen: &[Card], undoer: &str) -> String;
+
+    /// The [`String`] display for a hint of the [`Card`]s to choose in the [`Phase`], where no
+    /// [`Card`]s means there's no play.
+    #[must_use]
+    fn game_hint_message(&self, phase: Phase, hint: &[Card]) -> String;
+
+    /// The [`String`] display for both [`Player`]s and the [`Round`]'s starter [`Card`] before play.
     ///
     /// This will show the opponent's and player's points, but only show the player's [`Hand`] and
-    /// crib. If starter is [`None`], then `"[?]"`. The player's crib will only be displayed if they
-    /// have one.
+    /// crib. If starter is [`None`], then `"[?]"`. The crib will only be displayed if the player is
+    /// the dealer.
     #[must_use]
     fn game_before_play_message<C1, C2>(
         &self,
-        starter: Option<&Card>,
+        round: &Round,
         player: &Player<C1>,
         opponent: &Player<C2>,
     ) -> String
@@ -52,29 +123,30 @@ pub trait Display {
         C1: Controller,
         C2: Controller;
 
-    /// The [`String`] display for both [`Player`]s, the starter [`Card`], and [`PlayData`] during play.
+    /// The [`String`] display for both [`Player`]s, and the [`Round`]'s starter [`Card`] and
+    /// [`PlayData`] during play.
     ///
     /// This will show the opponent's and player's points, but only show the player's [`Hand`] and
-    /// crib. The player's crib will only be displayed if they have one.
+    /// crib. The crib will only be displayed if the player is the dealer.
     #[must_use]
     fn game_during_play_message<C1, C2>(
         &self,
-        starter: &Card,
+        round: &Round,
         player: &Player<C1>,
         opponent: &Player<C2>,
-        play_data: &PlayData,
     ) -> String
     where
         C1: Controller,
         C2: Controller;
 
-    /// The [`String`] display for both [`Player`]s and the starter [`Card`] during counting.
+    /// The [`String`] display for both [`Player`]s and the [`Round`]'s starter [`Card`] during
+    /// counting.
     ///
-    /// This will show the opponent's and player's points, [`Hand`]s and cribs.
+    /// This will show the opponent's and player's points, [`Hand`]s and the dealer's crib.
     #[must_use]
     fn game_during_counting_message<C1, C2>(
         &self,
-        starter: &Card,
+        round: &Round,
         player: &Player<C1>,
         opponent: &Player<C2>,
     ) -> String
@@ -82,7 +154,73 @@ pub trait Display {
         C1: Controller,
         C2: Controller;
 
-    /// The [`String`] display for game over.
+    /// The [`String`] display for the cribbage board, with both named [`Player`]s' [`Pegs`].
+    #[must_use]
+    fn board_message(
+        &self,
+        player_name: &str,
+        player: &Pegs,
+        opponent_name: &str,
+        opponent: &Pegs,
+    ) -> String;
+
+    /// The [`String`] display explaining the points scored by playing a [`Card`] (pegging).
+    ///
+    /// The points are attributed to the named `scorer`.
+    #[must_use]
+    fn play_score_message(&self, breakdown: &PlayScoreBreakdown, scorer: &str) -> String;
+
+    /// The [`String`] display for a [`Card`] played onto the stack by the named `player`, or their
+    /// GO if [`None`], with the running `count` of the stack.
+    #[must_use]
+    fn play_count_message(&self, player: &str, played: Option<&Card>, count: u32) -> String;
+
+    /// The [`String`] display for the stack starting over at 0, after a 31 if `thirty_one`, or a
+    /// GO otherwise.
+    #[must_use]
+    fn play_reset_message(&self, thirty_one: bool) -> String;
+
+    /// The [`String`] display for the [`RoundSummary`] at the end of a round, where the named
+    /// `player` is player 1.
+    #[must_use]
+    fn round_summary_message(
+        &self,
+        summary: &RoundSummary,
+        player_name: &str,
+        opponent_name: &str,
+    ) -> String;
+
+    /// The [`String`] prompt for the player to cut one of the `number_of_cards` [`Card`]s from the
+    /// [`Deck`], to choose the dealer.
+    #[must_use]
+    fn prompt_cut(&self, number_of_cards: usize) -> String;
+
+    /// The [`String`] prompt for the player to confirm cutting the [`Deck`] `depth` [`Card`]s
+    /// deep, to choose the dealer.
+    #[must_use]
+    fn prompt_confirm_cut(&self, depth: usize) -> String;
+
+    /// The [`String`] prompt for the player, as the pone, to cut the [`Deck`] of `number_of_cards`
+    /// [`Card`]s for the starter.
+    #[must_use]
+    fn prompt_starter_cut(&self, number_of_cards: usize) -> String;
+
+    /// The [`String`] prompt for the player to discard two of the `number_of_cards` [`Card`]s in
+    /// their [`Hand`] to the crib, which is theirs if `is_own_crib`.
+    #[must_use]
+    fn prompt_discard_to_crib(&self, number_of_cards: usize, is_own_crib: bool) -> String;
+
+    /// The [`String`] prompt for the player to play one of the `number_of_cards` [`Card`]s in their
+    /// [`Hand`] onto the stack, on their turn during play.
+    #[must_use]
+    fn prompt_play_card(&self, number_of_cards: usize, play_data: &PlayData) -> String;
+
+    /// The [`String`] prompt for the player to take back the [`Card`]s they just chose, or keep
+    /// them.
+    #[must_use]
+    fn prompt_undo(&self, chosen: &[Card]) -> String;
+
+    /// The [`String`] display for game over, won by the named `winner`.
     #[must_use]
-    fn game_over_message(&self, player_won: bool) -> String;
+    fn game_over_message(&self, winner: &str) -> String;
 }
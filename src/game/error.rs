@@ -0,0 +1,112 @@
+//! The errors that can happen while playing a [`Game`].
+
+#[cfg(doc)]
+use cards::{Deck, Hand};
+#[cfg(doc)]
+use game::{Controller, Decision, Game, Player};
+
+use std::error::Error;
+use std::fmt;
+
+use cards::Card;
+use game::{DecisionResponse, Phase};
+
+/// The errors that can happen while playing a [`Game`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GameError {
+    /// A [`Card`] was played that wasn't genuinely removed from the [`Player`]'s [`Hand`], or
+    /// was already played on the current stack. This indicates a bug in a [`Controller`] or the
+    /// [`Hand`] bookkeeping.
+    InvalidPlay(Card),
+    /// The named [`Player`]'s [`Controller`] ran out of moves.
+    OutOfMoves(String),
+    /// A [`Controller`] chose an index that is out of bounds of the `len` [`Card`]s it was given,
+    /// or chose the same index twice.
+    InvalidIndex { index: usize, len: usize },
+    /// The [`Deck`] ran out of [`Card`]s to deal.
+    EmptyDeck,
+    /// The play round took more than 100 turns, indicating that it is stuck.
+    TooManyTurns,
+    /// The [`Game`] took more than 1,000 rounds, indicating that it is stuck.
+    TooManyRounds,
+    /// A saved [`Game`] could not be read, because of the given line.
+    InvalidSnapshot(String),
+    /// A [`Game`] written in the notation could not be read, because of the given line.
+    InvalidNotation(String),
+    /// A step of the [`Game`] was taken out of turn.
+    WrongPhase { expected: Phase, actual: Phase },
+    /// The [`Controller`] of Player 1, or Player 2, ran out of moves in the middle of the
+    /// [`Phase`]. This is [`GameError::OutOfMoves`], but from [`Game::play_checked`].
+    ControllerExhausted { phase: Phase, player_1: bool },
+    /// A [`DecisionResponse`] was applied that doesn't answer the next [`Decision`] of the
+    /// [`Game`], or the [`Game`] is over.
+    UnexpectedResponse(DecisionResponse),
+}
+
+impl GameError {
+    /// Creates a [`GameError::OutOfMoves`] for Player 1, or Player 2.
+    pub(crate) fn out_of_moves(player_1: bool) -> GameError {
+        GameError::OutOfMoves(String::from(player_name(player_1)))
+    }
+
+    /// Turns a [`GameError::OutOfMoves`] for Player 1, or Player 2, into a
+    /// [`GameError::ControllerExhausted`] in the [`Phase`]. Any other [`GameError`] is kept.
+    pub(crate) fn exhausted_in(self, phase: Phase) -> GameError {
+        match self {
+            GameError::OutOfMoves(ref name) if name == player_name(true) => {
+                GameError::ControllerExhausted {
+                    phase,
+                    player_1: true,
+                }
+            }
+            GameError::OutOfMoves(ref name) if name == player_name(false) => {
+                GameError::ControllerExhausted {
+                    phase,
+                    player_1: false,
+                }
+            }
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameError::InvalidPlay(card) => write!(formatter, "Invalid play of {card}!"),
+            GameError::OutOfMoves(name) => write!(formatter, "{name} Controller has no moves!"),
+            GameError::InvalidIndex { index, len } => write!(
+                formatter,
+                "Controller chose an invalid index {index} for {len} cards!"
+            ),
+            GameError::EmptyDeck => write!(formatter, "There are not enough cards to deal!"),
+            GameError::TooManyTurns => write!(formatter, "Too many turns!"),
+            GameError::TooManyRounds => write!(formatter, "Play got stuck at round 1000!"),
+            GameError::InvalidSnapshot(line) => write!(formatter, "Invalid saved game: {line}"),
+            GameError::InvalidNotation(line) => write!(formatter, "Invalid game notation: {line}"),
+            GameError::WrongPhase { expected, actual } => write!(
+                formatter,
+                "Expected the {expected:?} phase, but it is the {actual:?} phase!"
+            ),
+            GameError::ControllerExhausted { phase, player_1 } => write!(
+                formatter,
+                "{} Controller ran out of moves in the {phase:?} phase!",
+                player_name(*player_1)
+            ),
+            GameError::UnexpectedResponse(response) => {
+                write!(formatter, "Unexpected response {response:?}!")
+            }
+        }
+    }
+}
+
+impl Error for GameError {}
+
+/// The name of Player 1, or Player 2, in a [`GameError`].
+fn player_name(player_1: bool) -> &'static str {
+    if player_1 {
+        "Player 1"
+    } else {
+        "Player 2"
+    }
+}
@@ -0,0 +1,73 @@
+//! The events of a [`Game`], kept as its history.
+
+#[cfg(doc)]
+use cards::Rank;
+#[cfg(doc)]
+use game::{Game, Player, Round};
+
+use cards::Card;
+
+/// Why a [`Player`] scored points.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ScoreReason {
+    /// The dealer cut a [`Rank::Jack`] as the starter.
+    HisHeels,
+    /// The pone's head start before the first deal, like three for last in the five-card game.
+    ThreeForLast,
+    /// Pegging during play, including a GO.
+    Pegging,
+    /// Counting the [`Player::hand`].
+    Hand,
+    /// Counting the [`Round::crib`].
+    Crib,
+}
+
+/// Something that happened in a [`Game`].
+///
+/// Every event with a `player_1` field is attributed to player 1 if it's `true`, and player 2
+/// otherwise.
+#[derive(Debug, PartialEq, Clone)]
+pub enum GameEvent {
+    /// Both [`Player`]s cut the deck to choose the dealer.
+    Cut {
+        player_1_card: Card,
+        player_2_card: Card,
+        player_1_is_dealer: bool,
+    },
+    /// The [`Card`]s dealt to both [`Player`]s.
+    Deal {
+        player_1_is_dealer: bool,
+        player_1_hand: Vec<Card>,
+        player_2_hand: Vec<Card>,
+    },
+    /// A [`Card`] discarded to the crib.
+    Discard { player_1: bool, card: Card },
+    /// The pone cut the deck `depth` [`Card`]s deep, for the dealer to reveal the starter.
+    CutForStarter { player_1: bool, depth: usize },
+    /// The starter [`Card`] was cut.
+    StarterCut { card: Card },
+    /// A [`Card`] played (pegged) on the stack.
+    PlayCard { player_1: bool, card: Card },
+    /// A [`Player`] with [`Card`]s in hand passed (GO) during play.
+    Go { player_1: bool },
+    /// A [`Player`] scored points.
+    ScorePoints {
+        player_1: bool,
+        points: u32,
+        reason: ScoreReason,
+    },
+    /// A hand, or crib, was counted with the starter.
+    HandCount {
+        player_1: bool,
+        cards: Vec<Card>,
+        is_crib: bool,
+        points: u32,
+    },
+    /// A [`Player`] ran out of time to choose, so the choice was made for them.
+    ///
+    /// This is recorded as soon as the choice is made, so it may come before the event of the
+    /// choice itself.
+    Timeout { player_1: bool },
+    /// The [`Game`] is over.
+    GameOver { player_1_won: bool },
+}
@@ -0,0 +1,208 @@
+//! A [`Controller`] that chooses the discards with the best expected value.
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+use cards::tracker::CardTracker;
+use cards::Card;
+use game::{discard_value_from_table, Controller};
+
+/// A controller that discards to the crib by expected value, and plays randomly otherwise.
+///
+/// All 15 pairs of discards from a 6 [`Card`] hand are evaluated by averaging the kept hand's
+/// score over all 46 possible starters, then adding the expected crib score if the crib is its
+/// own, or subtracting it if the crib is the opponent's. See
+/// [`crib_ev`](game::crib_ev) for the crib.
+#[derive(Debug, Clone)]
+pub struct ExpectimaxController {
+    rng: SmallRng,
+}
+
+impl ExpectimaxController {
+    /// Creates a new [`ExpectimaxController`] with a seed for the random choices, like cuts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::ExpectimaxController;
+    ///
+    /// let controller = ExpectimaxController::new(/*seed=*/ 42);
+    /// ```
+    #[must_use]
+    pub fn new(seed: u64) -> ExpectimaxController {
+        ExpectimaxController {
+            rng: SmallRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Returns the indices of the two discards with the best expected value.
+    fn best_discard_indices(available_cards: &[Card], is_my_crib: bool) -> [usize; 2] {
+        let mut tracker = CardTracker::new();
+
+        tracker.mark_all_seen(available_cards);
+
+        let unseen = tracker.remaining();
+
+        let mut best = [0, 1];
+        let mut best_total = f64::MIN;
+
+        for discard_1 in 0..available_cards.len() {
+            for discard_2 in (discard_1 + 1)..available_cards.len() {
+                let kept: Vec<Card> = available_cards
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| (*index != discard_1) && (*index != discard_2))
+                    .map(|(_, card)| card.clone())
+                    .collect();
+                let discards = [
+                    available_cards[discard_1].clone(),
+                    available_cards[discard_2].clone(),
+                ];
+
+                let total = discard_value_from_table(&kept, &discards, is_my_crib, &unseen).total();
+
+                if best_total < total {
+                    best = [discard_1, discard_2];
+                    best_total = total;
+                }
+            }
+        }
+
+        best
+    }
+}
+
+impl Controller for ExpectimaxController {
+    /// Returns a random index.
+    ///
+    /// Discards to the crib go through [`Controller::get_crib_discards`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, ExpectimaxController};
+    ///
+    /// let no_cards = vec![];
+    /// let available_cards = vec![
+    ///     Card::new(Rank::Queen, Suit::Hearts),
+    ///     Card::new(Rank::King, Suit::Clubs),
+    /// ];
+    ///
+    /// let mut controller = ExpectimaxController::new(/*seed=*/ 42);
+    ///
+    /// assert!(controller.get_card_index(&no_cards).is_none());
+    /// assert!(controller.get_card_index(&available_cards).is_some());
+    /// ```
+    fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+        match available_cards.len() {
+            0 => None,
+            length => Some(self.rng.gen_range(0..length)),
+        }
+    }
+
+    /// Returns the indices of the two [`Card`]s to discard to the crib, by expected value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, ExpectimaxController};
+    ///
+    /// let hand = vec![
+    ///     Card::new(Rank::King, Suit::Hearts),
+    ///     Card::new(Rank::Five, Suit::Clubs),
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    ///     Card::new(Rank::Eight, Suit::Diamonds),
+    ///     Card::new(Rank::Queen, Suit::Clubs),
+    /// ];
+    ///
+    /// let mut controller = ExpectimaxController::new(/*seed=*/ 42);
+    ///
+    /// // The Ace and Eight.
+    /// assert_eq!(controller.get_crib_discards(&hand, false), Some([2, 4]));
+    /// ```
+    fn get_crib_discards(&mut self, hand: &[Card], is_own_crib: bool) -> Option<[usize; 2]> {
+        if hand.len() < 2 {
+            return None;
+        }
+
+        Some(ExpectimaxController::best_discard_indices(
+            hand,
+            is_own_crib,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Deck, Rank, Suit};
+    use game::{Game, Player, RngController};
+
+    fn discards(controller: &mut ExpectimaxController, is_my_crib: bool) -> Vec<Card> {
+        let available_cards = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Spades),
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+
+        let mut result: Vec<Card> = controller
+            .get_crib_discards(&available_cards, is_my_crib)
+            .unwrap()
+            .iter()
+            .map(|index| available_cards[*index].clone())
+            .collect();
+
+        result.sort();
+
+        result
+    }
+
+    #[test]
+    fn test_get_crib_discards_dealer_and_pone() {
+        let mut controller = ExpectimaxController::new(/*seed=*/ 42);
+
+        // The dealer gives their own crib a fifteen.
+        let expected_dealer = vec![
+            Card::new(Rank::Seven, Suit::Spades),
+            Card::new(Rank::Eight, Suit::Diamonds),
+        ];
+        // The pone splits the fifteen, and gives away the King instead.
+        let expected_pone = vec![
+            Card::new(Rank::Seven, Suit::Spades),
+            Card::new(Rank::King, Suit::Clubs),
+        ];
+
+        assert_eq!(discards(&mut controller, true), expected_dealer);
+        assert_eq!(discards(&mut controller, false), expected_pone);
+    }
+
+    #[test]
+    fn test_beats_rng_controller() {
+        let mut expectimax_wins = 0;
+
+        for seed in 0..20 {
+            let mut deck = Deck::new();
+
+            deck.shuffle_with_seed(seed);
+
+            let player_1 = Player::new(ExpectimaxController::new(seed));
+            let player_2 = Player::new(RngController::new_with_seed(seed));
+
+            let outcome = Game::new_with_deck(player_1, player_2, deck)
+                .play_quiet()
+                .unwrap();
+
+            if outcome.player_1_won {
+                expectimax_wins += 1;
+            }
+        }
+
+        assert!(15 <= expectimax_wins, "Only won {} of 20", expectimax_wins);
+    }
+}
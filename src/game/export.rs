@@ -0,0 +1,513 @@
+//! Exports each round of a [`Game`], read from [`Game::history`], as CSV or JSON.
+//!
+//! This is for analyzing play in other tools, like a spreadsheet. Every round is one
+//! [`RoundRecord`], and the [`Card`]s are written in plain ASCII, like `10H`.
+
+#[cfg(doc)]
+use game::{Game, Player};
+
+use std::io::{self, Write};
+
+use itertools::Itertools;
+
+use cards::Card;
+use game::{GameEvent, ScoreReason};
+
+/// The header of the CSV written by [`write_csv`].
+pub const CSV_HEADER: &str = "round,dealer,player_1_hand,player_2_hand,player_1_discards,\
+player_2_discards,starter,pegging,player_1_pegging_points,player_2_pegging_points,\
+player_1_hand_points,player_2_hand_points,crib_points,player_1_total,player_2_total";
+
+/// A [`Card`] played during pegging, or a GO.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PeggingPlay {
+    /// If player 1 played, or player 2 otherwise.
+    pub player_1: bool,
+    /// The [`Card`] played, or [`None`] for a GO.
+    pub card: Option<Card>,
+}
+
+/// Everything that happened in a round of a [`Game`], from the deal to the counting.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RoundRecord {
+    /// The 1-based number of the round.
+    pub round: u32,
+    pub player_1_is_dealer: bool,
+    /// Player 1's [`Card`]s as dealt, before discarding.
+    pub player_1_hand: Vec<Card>,
+    /// Player 2's [`Card`]s as dealt, before discarding.
+    pub player_2_hand: Vec<Card>,
+    pub player_1_discards: Vec<Card>,
+    pub player_2_discards: Vec<Card>,
+    pub starter: Option<Card>,
+    /// Every play, and GO, in order.
+    pub pegging: Vec<PeggingPlay>,
+    /// The points player 1 pegged during play, including his heels.
+    pub player_1_pegging_points: u32,
+    /// The points player 2 pegged during play, including his heels.
+    pub player_2_pegging_points: u32,
+    pub player_1_hand_points: u32,
+    pub player_2_hand_points: u32,
+    /// The points of the dealer's crib.
+    pub crib_points: u32,
+    /// Player 1's points after the round.
+    pub player_1_total: u32,
+    /// Player 2's points after the round.
+    pub player_2_total: u32,
+}
+
+/// Returns a [`RoundRecord`] for every [`GameEvent::Deal`] in the history.
+///
+/// The totals count back from `player_1_points` and `player_2_points`, the [`Player::points`]
+/// at the end of the history, so they're right for a resumed [`Game`] too.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::export::round_records;
+/// use libterminal_cribbage::game::{Game, Player, RngController};
+///
+/// let player_1 = Player::new(RngController::new_with_seed(1));
+/// let player_2 = Player::new(RngController::new_with_seed(2));
+///
+/// let mut game = Game::new(player_1, player_2);
+///
+/// let outcome = game.play_quiet().unwrap();
+///
+/// let records = round_records(
+///     game.history(),
+///     outcome.player_1_points,
+///     outcome.player_2_points,
+/// );
+/// let last = records.last().unwrap();
+///
+/// assert_eq!(records.len(), outcome.rounds as usize);
+/// assert_eq!(last.player_1_total, outcome.player_1_points);
+/// assert_eq!(last.player_2_total, outcome.player_2_points);
+/// ```
+#[must_use]
+pub fn round_records(
+    history: &[GameEvent],
+    player_1_points: u32,
+    player_2_points: u32,
+) -> Vec<RoundRecord> {
+    let (player_1_scored, player_2_scored) = history.iter().fold(
+        (0, 0),
+        |(player_1_scored, player_2_scored), event| match event {
+            GameEvent::ScorePoints {
+                player_1: true,
+                points,
+                ..
+            } => (player_1_scored + points, player_2_scored),
+            GameEvent::ScorePoints {
+                player_1: false,
+                points,
+                ..
+            } => (player_1_scored, player_2_scored + points),
+            _ => (player_1_scored, player_2_scored),
+        },
+    );
+
+    let mut records: Vec<RoundRecord> = Vec::new();
+    let mut round = 0;
+    // A resumed game starts with the points scored before it was saved.
+    let mut player_1_total = player_1_points.saturating_sub(player_1_scored);
+    let mut player_2_total = player_2_points.saturating_sub(player_2_scored);
+
+    for event in history {
+        if let GameEvent::Deal {
+            player_1_is_dealer,
+            player_1_hand,
+            player_2_hand,
+        } = event
+        {
+            round += 1;
+
+            records.push(RoundRecord {
+                round,
+                player_1_is_dealer: *player_1_is_dealer,
+                player_1_hand: player_1_hand.clone(),
+                player_2_hand: player_2_hand.clone(),
+                player_1_total,
+                player_2_total,
+                ..RoundRecord::default()
+            });
+        }
+
+        if let GameEvent::ScorePoints {
+            player_1, points, ..
+        } = event
+        {
+            if *player_1 {
+                player_1_total += points;
+            } else {
+                player_2_total += points;
+            }
+        }
+
+        // Anything before the first deal, like three for last, only counts towards the totals.
+        let Some(record) = records.last_mut() else {
+            continue;
+        };
+
+        record.player_1_total = player_1_total;
+        record.player_2_total = player_2_total;
+
+        match event {
+            GameEvent::Discard { player_1, card } => {
+                if *player_1 {
+                    record.player_1_discards.push(card.clone());
+                } else {
+                    record.player_2_discards.push(card.clone());
+                }
+            }
+            GameEvent::StarterCut { card } => record.starter = Some(card.clone()),
+            GameEvent::PlayCard { player_1, card } => record.pegging.push(PeggingPlay {
+                player_1: *player_1,
+                card: Some(card.clone()),
+            }),
+            GameEvent::Go { player_1 } => record.pegging.push(PeggingPlay {
+                player_1: *player_1,
+                card: None,
+            }),
+            GameEvent::ScorePoints {
+                player_1,
+                points,
+                reason,
+            } => match (reason, player_1) {
+                (ScoreReason::Pegging | ScoreReason::HisHeels, true) => {
+                    record.player_1_pegging_points += points;
+                }
+                (ScoreReason::Pegging | ScoreReason::HisHeels, false) => {
+                    record.player_2_pegging_points += points;
+                }
+                (ScoreReason::Hand, true) => record.player_1_hand_points += points,
+                (ScoreReason::Hand, false) => record.player_2_hand_points += points,
+                (ScoreReason::Crib, _) => record.crib_points += points,
+                (ScoreReason::ThreeForLast, _) => {}
+            },
+            _ => {}
+        }
+    }
+
+    records
+}
+
+/// Writes the [`RoundRecord`]s as CSV, one row each after the [`CSV_HEADER`].
+///
+/// The [`Card`]s of a column are separated by spaces, and each play is written like `P1:5H`, or
+/// `P2:go` for a GO.
+///
+/// # Errors
+///
+/// Any [`io::Error`] from writing.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::export::{round_records, write_csv, CSV_HEADER};
+/// use libterminal_cribbage::game::{Game, Player, RngController};
+///
+/// let player_1 = Player::new(RngController::new_with_seed(1));
+/// let player_2 = Player::new(RngController::new_with_seed(2));
+///
+/// let mut game = Game::new(player_1, player_2);
+///
+/// let outcome = game.play_quiet().unwrap();
+///
+/// let records = round_records(
+///     game.history(),
+///     outcome.player_1_points,
+///     outcome.player_2_points,
+/// );
+///
+/// let mut csv = Vec::new();
+///
+/// write_csv(&mut csv, &records).unwrap();
+///
+/// let csv = String::from_utf8(csv).unwrap();
+///
+/// assert!(csv.starts_with(CSV_HEADER));
+/// assert_eq!(csv.lines().count(), records.len() + 1);
+/// ```
+pub fn write_csv<W>(writer: &mut W, records: &[RoundRecord]) -> io::Result<()>
+where
+    W: Write,
+{
+    writeln!(writer, "{CSV_HEADER}")?;
+
+    for record in records {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            record.round,
+            player_to_str(record.player_1_is_dealer),
+            cards_to_csv(&record.player_1_hand),
+            cards_to_csv(&record.player_2_hand),
+            cards_to_csv(&record.player_1_discards),
+            cards_to_csv(&record.player_2_discards),
+            record
+                .starter
+                .as_ref()
+                .map(Card::to_ascii_string)
+                .unwrap_or_default(),
+            record
+                .pegging
+                .iter()
+                .map(|play| format!(
+                    "{}:{}",
+                    player_to_str(play.player_1),
+                    play.card
+                        .as_ref()
+                        .map_or_else(|| String::from("go"), Card::to_ascii_string)
+                ))
+                .join(" "),
+            record.player_1_pegging_points,
+            record.player_2_pegging_points,
+            record.player_1_hand_points,
+            record.player_2_hand_points,
+            record.crib_points,
+            record.player_1_total,
+            record.player_2_total
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Writes the [`RoundRecord`]s as a JSON array, with one object per line.
+///
+/// The keys are the names of the [`RoundRecord`] fields, the dealer is `"P1"` or `"P2"`, and
+/// each play is an object with a `"player"` and a `"card"`, which is `null` for a GO.
+///
+/// # Errors
+///
+/// Any [`io::Error`] from writing.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::export::write_json;
+///
+/// let mut json = Vec::new();
+///
+/// write_json(&mut json, &[]).unwrap();
+///
+/// assert_eq!(String::from_utf8(json).unwrap(), "[\n]\n");
+/// ```
+pub fn write_json<W>(writer: &mut W, records: &[RoundRecord]) -> io::Result<()>
+where
+    W: Write,
+{
+    writeln!(writer, "[")?;
+
+    for (index, record) in records.iter().enumerate() {
+        let separator = if index + 1 < records.len() { "," } else { "" };
+
+        let pegging = record
+            .pegging
+            .iter()
+            .map(|play| {
+                format!(
+                    "{{\"player\":\"{}\",\"card\":{}}}",
+                    player_to_str(play.player_1),
+                    card_to_json(play.card.as_ref())
+                )
+            })
+            .join(",");
+
+        writeln!(
+            writer,
+            "  {{\"round\":{},\"dealer\":\"{}\",\"player_1_hand\":{},\"player_2_hand\":{},\
+             \"player_1_discards\":{},\"player_2_discards\":{},\"starter\":{},\"pegging\":[{}],\
+             \"player_1_pegging_points\":{},\"player_2_pegging_points\":{},\
+             \"player_1_hand_points\":{},\"player_2_hand_points\":{},\"crib_points\":{},\
+             \"player_1_total\":{},\"player_2_total\":{}}}{separator}",
+            record.round,
+            player_to_str(record.player_1_is_dealer),
+            cards_to_json(&record.player_1_hand),
+            cards_to_json(&record.player_2_hand),
+            cards_to_json(&record.player_1_discards),
+            cards_to_json(&record.player_2_discards),
+            card_to_json(record.starter.as_ref()),
+            pegging,
+            record.player_1_pegging_points,
+            record.player_2_pegging_points,
+            record.player_1_hand_points,
+            record.player_2_hand_points,
+            record.crib_points,
+            record.player_1_total,
+            record.player_2_total
+        )?;
+    }
+
+    writeln!(writer, "]")
+}
+
+/// Returns `P1` for player 1, or `P2` otherwise.
+fn player_to_str(player_1: bool) -> &'static str {
+    if player_1 {
+        "P1"
+    } else {
+        "P2"
+    }
+}
+
+/// Writes the [`Card`]s separated by spaces.
+fn cards_to_csv(cards: &[Card]) -> String {
+    cards.iter().map(Card::to_ascii_string).join(" ")
+}
+
+/// Writes the [`Card`] as a JSON string, or `null` if there isn't one.
+fn card_to_json(card: Option<&Card>) -> String {
+    card.map_or_else(
+        || String::from("null"),
+        |card| format!("\"{}\"", card.to_ascii_string()),
+    )
+}
+
+/// Writes the [`Card`]s as a JSON array of strings.
+fn cards_to_json(cards: &[Card]) -> String {
+    format!(
+        "[{}]",
+        cards.iter().map(|card| card_to_json(Some(card))).join(",")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Rank, Suit};
+
+    /// A short round, where player 2 deals.
+    fn history() -> Vec<GameEvent> {
+        let five = Card::new(Rank::Five, Suit::Hearts);
+        let ten = Card::new(Rank::Ten, Suit::Clubs);
+        let jack = Card::new(Rank::Jack, Suit::Spades);
+
+        vec![
+            GameEvent::ScorePoints {
+                player_1: true,
+                points: 3,
+                reason: ScoreReason::ThreeForLast,
+            },
+            GameEvent::Deal {
+                player_1_is_dealer: false,
+                player_1_hand: vec![five.clone(), jack.clone()],
+                player_2_hand: vec![ten.clone()],
+            },
+            GameEvent::Discard {
+                player_1: true,
+                card: jack.clone(),
+            },
+            GameEvent::StarterCut { card: jack },
+            GameEvent::ScorePoints {
+                player_1: false,
+                points: 2,
+                reason: ScoreReason::HisHeels,
+            },
+            GameEvent::PlayCard {
+                player_1: true,
+                card: five.clone(),
+            },
+            GameEvent::PlayCard {
+                player_1: false,
+                card: ten,
+            },
+            GameEvent::ScorePoints {
+                player_1: false,
+                points: 2,
+                reason: ScoreReason::Pegging,
+            },
+            GameEvent::Go { player_1: true },
+            GameEvent::ScorePoints {
+                player_1: true,
+                points: 4,
+                reason: ScoreReason::Hand,
+            },
+            GameEvent::ScorePoints {
+                player_1: false,
+                points: 1,
+                reason: ScoreReason::Crib,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_round_records() {
+        let expected = vec![RoundRecord {
+            round: 1,
+            player_1_is_dealer: false,
+            player_1_hand: vec![
+                Card::new(Rank::Five, Suit::Hearts),
+                Card::new(Rank::Jack, Suit::Spades),
+            ],
+            player_2_hand: vec![Card::new(Rank::Ten, Suit::Clubs)],
+            player_1_discards: vec![Card::new(Rank::Jack, Suit::Spades)],
+            player_2_discards: Vec::new(),
+            starter: Some(Card::new(Rank::Jack, Suit::Spades)),
+            pegging: vec![
+                PeggingPlay {
+                    player_1: true,
+                    card: Some(Card::new(Rank::Five, Suit::Hearts)),
+                },
+                PeggingPlay {
+                    player_1: false,
+                    card: Some(Card::new(Rank::Ten, Suit::Clubs)),
+                },
+                PeggingPlay {
+                    player_1: true,
+                    card: None,
+                },
+            ],
+            player_1_pegging_points: 0,
+            player_2_pegging_points: 4,
+            player_1_hand_points: 4,
+            player_2_hand_points: 0,
+            crib_points: 1,
+            player_1_total: 7,
+            player_2_total: 5,
+        }];
+
+        assert_eq!(round_records(&history(), 7, 5), expected);
+    }
+
+    #[test]
+    fn test_round_records_resumed() {
+        // Resumed from a save at 100 to 90.
+        let records = round_records(&history(), 107, 95);
+
+        assert_eq!(records[0].player_1_total, 107);
+        assert_eq!(records[0].player_2_total, 95);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut csv = Vec::new();
+
+        write_csv(&mut csv, &round_records(&history(), 7, 5)).unwrap();
+
+        let expected =
+            format!("{CSV_HEADER}\n1,P2,5H JS,10C,JS,,JS,P1:5H P2:10C P1:go,0,4,4,0,1,7,5\n");
+
+        assert_eq!(String::from_utf8(csv).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut json = Vec::new();
+
+        write_json(&mut json, &round_records(&history(), 7, 5)).unwrap();
+
+        let expected = "[\n  {\"round\":1,\"dealer\":\"P2\",\"player_1_hand\":[\"5H\",\"JS\"],\
+            \"player_2_hand\":[\"10C\"],\"player_1_discards\":[\"JS\"],\"player_2_discards\":[],\
+            \"starter\":\"JS\",\"pegging\":[{\"player\":\"P1\",\"card\":\"5H\"},\
+            {\"player\":\"P2\",\"card\":\"10C\"},{\"player\":\"P1\",\"card\":null}],\
+            \"player_1_pegging_points\":0,\"player_2_pegging_points\":4,\
+            \"player_1_hand_points\":4,\"player_2_hand_points\":0,\"crib_points\":1,\
+            \"player_1_total\":7,\"player_2_total\":5}\n]\n";
+
+        assert_eq!(String::from_utf8(json).unwrap(), expected);
+    }
+}
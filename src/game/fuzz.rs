@@ -0,0 +1,104 @@
+//! A fuzz-style harness for the [`Game`] loop, driving it with random [`Controller`] scripts and
+//! random [`Deck`]s, and checking it never panics or breaks its invariants.
+
+#[cfg(doc)]
+use cards::{Card, Deck};
+#[cfg(doc)]
+use game::Controller;
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use game::{Game, GameError, NoOpDisplay, Phase, Player, PredeterminedController};
+
+/// How many random [`Game`]s are played.
+const GAMES: u64 = 300;
+
+/// The longest random script of indices for a [`PredeterminedController`].
+const MAX_SCRIPT_LEN: usize = 400;
+
+type FuzzGame =
+    Game<PredeterminedController<NoOpDisplay>, PredeterminedController<NoOpDisplay>, NoOpDisplay>;
+
+/// Returns a random script of indices, mostly small enough to be valid, so some [`Game`]s get
+/// far, with the odd one out of bounds.
+fn random_script(rng: &mut SmallRng) -> Vec<usize> {
+    let len = rng.gen_range(0..=MAX_SCRIPT_LEN);
+
+    (0..len)
+        .map(|_| match rng.gen_range(0..1_000) {
+            0..=959 => 0,
+            960..=997 => 1,
+            _ => rng.gen_range(2..60),
+        })
+        .collect()
+}
+
+/// Returns the [`Game`] for the seed, with its random scripts and random [`Deck`].
+fn random_game(seed: u64) -> FuzzGame {
+    let mut rng = SmallRng::seed_from_u64(seed);
+
+    let player_1 = Player::new(PredeterminedController::from(random_script(&mut rng)));
+    let player_2 = Player::new(PredeterminedController::from(random_script(&mut rng)));
+
+    Game::new(player_1, player_2)
+        .with_seed(rng.gen())
+        .with_census_checks()
+}
+
+/// Steps through the [`Game`], checking that no [`Card`] is lost or duplicated, and that the
+/// points never go down, after every step.
+fn step_until_over(game: &mut FuzzGame) -> Result<Phase, GameError> {
+    let mut points = (0, 0);
+
+    loop {
+        let phase = game
+            .step()
+            .map_err(|error| error.exhausted_in(game.phase))?;
+
+        let census = game.card_census();
+
+        assert!(census.is_complete(), "in {:?}: {:?}", phase, census);
+
+        let new_points = (game.player_1.points, game.player_2.points);
+
+        assert!(points.0 <= new_points.0 && points.1 <= new_points.1);
+
+        points = new_points;
+
+        if phase == Phase::Over {
+            return Ok(phase);
+        }
+    }
+}
+
+#[test]
+fn test_random_games_keep_their_invariants() {
+    let mut games_over = 0;
+
+    for seed in 0..GAMES {
+        let mut stepped_game = random_game(seed);
+        let mut checked_game = random_game(seed);
+
+        let stepped = step_until_over(&mut stepped_game)
+            .map(|_| (stepped_game.player_1.points, stepped_game.player_2.points));
+        let checked = checked_game
+            .play_checked()
+            .map(|outcome| (outcome.player_1_points, outcome.player_2_points));
+
+        assert!(
+            !matches!(checked, Err(GameError::OutOfMoves(_))),
+            "seed {}: {:?}",
+            seed,
+            checked
+        );
+        assert_eq!(stepped, checked, "seed {}", seed);
+
+        if checked.is_ok() {
+            games_over += 1;
+        }
+    }
+
+    // Most scripts break or run out, but some should play a whole game.
+    assert!(0 < games_over);
+}
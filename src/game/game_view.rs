@@ -0,0 +1,63 @@
+//! A read-only snapshot of a [`Game`] from one [`Player`]'s seat, for external UIs.
+
+#[cfg(doc)]
+use game::{Controller, Game, Player, PlayerView};
+
+use cards::Card;
+use game::Phase;
+
+/// An owned snapshot of what one [`Player`] can see of a [`Game`].
+///
+/// Everything is cloned, so a [`GameView`] can be kept, or sent to another thread, without
+/// holding a borrow of the [`Game`]. Only what's face up at the table is included. The
+/// opponent's [`Card`]s and the crib are just counted, until they're shown for counting.
+///
+/// Unlike a [`PlayerView`], this is everything a frontend needs to render a turn, or a
+/// [`Controller`] needs to choose one.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{Game, Phase, Player, RngController};
+///
+/// let player_1 = Player::new(RngController::new_with_seed(1));
+/// let player_2 = Player::new(RngController::new_with_seed(2));
+///
+/// let mut game = Game::new(player_1, player_2);
+///
+/// game.cut_round().unwrap();
+/// game.deal_round().unwrap();
+///
+/// let view = game.view(/*player_1=*/ true);
+///
+/// assert_eq!(view.phase, Phase::Discard);
+/// assert_eq!(view.hand.len(), 6);
+/// assert_eq!(view.opponent_hand_size, 6);
+/// assert!(view.starter.is_none());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameView {
+    /// The next step of the [`Game`].
+    pub phase: Phase,
+    /// The points of the [`Player`] viewing.
+    pub points: u32,
+    /// The points of their opponent.
+    pub opponent_points: u32,
+    /// The points needed to win.
+    pub target_score: u32,
+    /// The [`Card`]s in the [`Player`]'s hand.
+    pub hand: Vec<Card>,
+    /// The number of [`Card`]s in the opponent's hand.
+    pub opponent_hand_size: usize,
+    /// The current stack of play, which is empty until play starts.
+    pub stack: Vec<Card>,
+    /// The score of the current stack.
+    pub stack_score: u32,
+    /// The starter, once it's cut.
+    pub starter: Option<Card>,
+    /// Indicates if the [`Player`] viewing is the dealer, and so owns the crib.
+    pub is_dealer: bool,
+    /// The number of [`Card`]s in the crib.
+    pub crib_size: usize,
+}
@@ -0,0 +1,247 @@
+//! A [`Controller`] that always takes the most points it can see right away.
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+use cards::{Card, Hand};
+use game::{Controller, PlayContext};
+
+/// A controller that pegs for the most immediate points, and keeps the best [`Hand`] on its own.
+///
+/// When pegging, ties are broken by avoiding a stack score of 5 or 21, where any ten-card gives
+/// the opponent fifteen or 31. Crib discards keep the 4 [`Card`]s with the highest
+/// [`Hand::static_total`], ignoring the starter and the crib.
+#[derive(Debug, Clone)]
+pub struct GreedyController {
+    rng: SmallRng,
+}
+
+impl GreedyController {
+    /// Creates a new [`GreedyController`] with a seed for the random choices, like cuts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::GreedyController;
+    ///
+    /// let controller = GreedyController::new(/*seed=*/ 42);
+    /// ```
+    #[must_use]
+    pub fn new(seed: u64) -> GreedyController {
+        GreedyController {
+            rng: SmallRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Controller for GreedyController {
+    /// Returns a random index.
+    ///
+    /// Discards to the crib go through [`Controller::get_crib_discards`], and plays go through
+    /// [`Controller::get_play_index`], instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, GreedyController};
+    ///
+    /// let no_cards = vec![];
+    /// let available_cards = vec![
+    ///     Card::new(Rank::Queen, Suit::Hearts),
+    ///     Card::new(Rank::King, Suit::Clubs),
+    /// ];
+    ///
+    /// let mut controller = GreedyController::new(/*seed=*/ 42);
+    ///
+    /// assert!(controller.get_card_index(&no_cards).is_none());
+    /// assert!(controller.get_card_index(&available_cards).is_some());
+    /// ```
+    fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+        match available_cards.len() {
+            0 => None,
+            length => Some(self.rng.gen_range(0..length)),
+        }
+    }
+
+    /// Returns the indices of the two [`Card`]s to discard, keeping the best [`Hand`] on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, GreedyController};
+    ///
+    /// let hand = vec![
+    ///     Card::new(Rank::King, Suit::Hearts),
+    ///     Card::new(Rank::Five, Suit::Clubs),
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    ///     Card::new(Rank::Eight, Suit::Diamonds),
+    ///     Card::new(Rank::Queen, Suit::Clubs),
+    /// ];
+    ///
+    /// let mut controller = GreedyController::new(/*seed=*/ 42);
+    ///
+    /// // The Ace and Eight.
+    /// assert_eq!(controller.get_crib_discards(&hand, true), Some([2, 4]));
+    /// ```
+    fn get_crib_discards(&mut self, hand: &[Card], _is_own_crib: bool) -> Option<[usize; 2]> {
+        let mut best: Option<([usize; 2], u32)> = None;
+
+        for discard_1 in 0..hand.len() {
+            for discard_2 in (discard_1 + 1)..hand.len() {
+                let kept: Vec<Card> = hand
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| (*index != discard_1) && (*index != discard_2))
+                    .map(|(_, card)| card.clone())
+                    .collect();
+
+                let total = Hand::from(kept).static_total();
+
+                if best.is_none_or(|(_, best_total)| best_total < total) {
+                    best = Some(([discard_1, discard_2], total));
+                }
+            }
+        }
+
+        best.map(|(discards, _)| discards)
+    }
+
+    /// Returns the index of the playable [`Card`] worth the most points right away.
+    ///
+    /// Between [`Card`]s worth the same points, one that doesn't leave the stack score at 5 or 21
+    /// is played. Returns [`None`] if no [`Card`] can be played without going over 31.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, GreedyController, PlayContext, PlayData};
+    ///
+    /// let available_cards = vec![
+    ///     Card::new(Rank::Two, Suit::Hearts),
+    ///     Card::new(Rank::Four, Suit::Clubs),
+    /// ];
+    ///
+    /// let play_data = PlayData::from(vec![Card::new(Rank::Three, Suit::Diamonds)]);
+    ///
+    /// let context = PlayContext {
+    ///     play_data: &play_data,
+    ///     points: 10,
+    ///     opponent_points: 10,
+    ///     target: 121,
+    ///     is_dealer: false,
+    /// };
+    ///
+    /// let mut controller = GreedyController::new(/*seed=*/ 42);
+    ///
+    /// // Neither scores, but the Two leaves the stack at 5.
+    /// assert_eq!(controller.get_play_index(&available_cards, &context), Some(1));
+    /// ```
+    fn get_play_index(&mut self, available_cards: &[Card], context: &PlayContext) -> Option<usize> {
+        let stack_score = context.play_data.stack_score;
+
+        available_cards
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| (stack_score + card.score()) <= 31)
+            .map(|(index, card)| {
+                let leaves_ten_card_target = matches!(stack_score + card.score(), 5 | 21);
+
+                (
+                    index,
+                    (context.play_data.points_for(card), !leaves_ten_card_target),
+                )
+            })
+            .fold(
+                None,
+                |best: Option<(usize, (u32, bool))>, (index, value)| match best {
+                    Some((_, best_value)) if value <= best_value => best,
+                    _ => Some((index, value)),
+                },
+            )
+            .map(|(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Deck, Rank, Suit};
+    use game::{Game, PlayData, Player, RngController};
+
+    fn context(play_data: &PlayData) -> PlayContext<'_> {
+        PlayContext {
+            play_data,
+            points: 0,
+            opponent_points: 0,
+            target: 121,
+            is_dealer: false,
+        }
+    }
+
+    #[test]
+    fn test_get_play_index_most_points() {
+        let available_cards = vec![
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::King, Suit::Spades),
+        ];
+
+        let play_data = PlayData::from(vec![
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Diamonds),
+        ]);
+
+        let mut controller = GreedyController::new(/*seed=*/ 42);
+
+        // The run of 3 is worth more than fifteen, even if it leaves the stack at 21.
+        assert_eq!(
+            controller.get_play_index(&available_cards, &context(&play_data)),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_get_play_index_none_playable() {
+        let available_cards = vec![Card::new(Rank::King, Suit::Hearts)];
+
+        let play_data = PlayData::from(vec![
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Clubs),
+        ]);
+
+        let mut controller = GreedyController::new(/*seed=*/ 42);
+
+        assert!(controller
+            .get_play_index(&available_cards, &context(&play_data))
+            .is_none());
+    }
+
+    #[test]
+    fn test_beats_rng_controller() {
+        let mut greedy_wins = 0;
+
+        for seed in 0..20 {
+            let mut deck = Deck::new();
+
+            deck.shuffle_with_seed(seed);
+
+            let player_1 = Player::new(GreedyController::new(seed));
+            let player_2 = Player::new(RngController::new_with_seed(seed));
+
+            let outcome = Game::new_with_deck(player_1, player_2, deck)
+                .play_quiet()
+                .unwrap();
+
+            if outcome.player_1_won {
+                greedy_wins += 1;
+            }
+        }
+
+        assert!(15 <= greedy_wins, "Only won {} of 20", greedy_wins);
+    }
+}
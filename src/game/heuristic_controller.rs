@@ -0,0 +1,305 @@
+//! A [`Controller`] that plays with simple heuristics, and can defend in the endgame.
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+use cards::tracker::CardTracker;
+use cards::{Card, Hand, ScoreCache};
+use game::{Controller, PlayContext, PlayData};
+
+/// How close the opponent needs to be to the target for the endgame to start.
+///
+/// A good [`Hand`], with some pegging, is about this many points.
+pub const ENDGAME_REACH: u32 = 20;
+
+/// A controller that discards to keep the best [`Hand`] and pegs for the most points.
+///
+/// If it's endgame aware, then once the opponent is within [`ENDGAME_REACH`] of the target, it
+/// pegs defensively instead. That is, it plays to reach the target if it can, and otherwise plays
+/// to minimize the points the opponent could peg in reply.
+#[derive(Debug, Clone)]
+pub struct HeuristicController {
+    rng: SmallRng,
+    endgame_aware: bool,
+    planned_discard: Option<Card>,
+}
+
+impl HeuristicController {
+    /// Creates a new [`HeuristicController`] with a seed for the few random choices, like cuts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::HeuristicController;
+    ///
+    /// let controller = HeuristicController::new(/*seed=*/ 42, /*endgame_aware=*/ true);
+    /// ```
+    #[must_use]
+    pub fn new(seed: u64, endgame_aware: bool) -> HeuristicController {
+        HeuristicController {
+            rng: SmallRng::seed_from_u64(seed),
+            endgame_aware,
+            planned_discard: None,
+        }
+    }
+
+    /// Returns the index of the first of the two discards that keep the best [`Hand`].
+    ///
+    /// The second discard is saved for the next call. The [`Hand`]s are compared by their
+    /// average score over every starter that isn't in the `available_cards`.
+    fn best_discard_index(&mut self, available_cards: &[Card]) -> usize {
+        let mut tracker = CardTracker::new();
+
+        tracker.mark_all_seen(available_cards);
+
+        let cache = ScoreCache::new();
+
+        let mut best = (0, 1);
+        let mut best_total = 0;
+
+        for discard_1 in 0..available_cards.len() {
+            for discard_2 in (discard_1 + 1)..available_cards.len() {
+                let kept = available_cards
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| (*index != discard_1) && (*index != discard_2))
+                    .map(|(_, card)| card.clone())
+                    .collect::<Vec<Card>>();
+
+                let kept_hand = Hand::from(kept);
+
+                let total: u32 = tracker
+                    .remaining_set()
+                    .iter()
+                    .map(|starter| cache.total(&kept_hand, &starter, /*is_crib=*/ false))
+                    .sum();
+
+                if best_total < total {
+                    best = (discard_1, discard_2);
+                    best_total = total;
+                }
+            }
+        }
+
+        self.planned_discard = available_cards.get(best.1).cloned();
+
+        best.0
+    }
+
+    /// Returns the average points the opponent would peg in reply to playing the [`Card`].
+    ///
+    /// The opponent could have any [`Card`] that isn't in the `available_cards` or on the stack.
+    fn reply_risk(available_cards: &[Card], card: &Card, context: &PlayContext) -> f64 {
+        let mut play_data = PlayData::from(context.play_data.stack.clone());
+
+        play_data.add_card(card.clone());
+
+        let mut tracker = CardTracker::new();
+
+        tracker.mark_all_seen(available_cards);
+        tracker.mark_all_seen(&play_data.stack);
+
+        let (total, count) = tracker
+            .remaining_set()
+            .iter()
+            .map(|reply| {
+                if (play_data.stack_score + reply.score()) <= 31 {
+                    play_data.points_for(&reply)
+                } else {
+                    0
+                }
+            })
+            .fold((0_u32, 0_u32), |(total, count), points| {
+                (total + points, count + 1)
+            });
+
+        if count == 0 {
+            0.0
+        } else {
+            f64::from(total) / f64::from(count)
+        }
+    }
+}
+
+impl Controller for HeuristicController {
+    /// Returns an index for a [`Card`] to discard, or a random index for a cut.
+    ///
+    /// With the 6 (or 5, in the five-card game) [`Card`]s dealt, both discards are chosen to keep
+    /// the best [`Hand`], and the second is returned on the next call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, HeuristicController};
+    ///
+    /// let mut available_cards = vec![
+    ///     Card::new(Rank::King, Suit::Hearts),
+    ///     Card::new(Rank::Five, Suit::Clubs),
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    ///     Card::new(Rank::Eight, Suit::Diamonds),
+    ///     Card::new(Rank::Queen, Suit::Clubs),
+    /// ];
+    ///
+    /// let mut controller = HeuristicController::new(/*seed=*/ 42, /*endgame_aware=*/ false);
+    ///
+    /// let index = controller.get_card_index(&available_cards).unwrap();
+    /// let first_discard = available_cards.remove(index);
+    ///
+    /// let index = controller.get_card_index(&available_cards).unwrap();
+    /// let second_discard = available_cards.remove(index);
+    ///
+    /// let mut discards = vec![first_discard, second_discard];
+    /// discards.sort();
+    ///
+    /// let expected = vec![
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::Eight, Suit::Diamonds),
+    /// ];
+    ///
+    /// assert_eq!(discards, expected);
+    /// ```
+    fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+        let planned_index = self.planned_discard.take().and_then(|card| {
+            available_cards
+                .iter()
+                .position(|available| *available == card)
+        });
+
+        match (planned_index, available_cards.len()) {
+            (_, 0) => None,
+            (Some(index), _) => Some(index),
+            (None, 5..=6) => Some(self.best_discard_index(available_cards)),
+            (None, length) => Some(self.rng.gen_range(0..length)),
+        }
+    }
+
+    /// Returns the index of the playable [`Card`] worth the most points.
+    ///
+    /// If endgame aware, and the opponent is within [`ENDGAME_REACH`] of the target, then a
+    /// [`Card`] reaching the target is played if possible. Otherwise, the points the opponent
+    /// could peg in reply are subtracted from each [`Card`]'s points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, HeuristicController, PlayContext, PlayData};
+    ///
+    /// let available_cards = vec![
+    ///     Card::new(Rank::Two, Suit::Hearts),
+    ///     Card::new(Rank::Eight, Suit::Clubs),
+    /// ];
+    ///
+    /// let play_data = PlayData::from(vec![Card::new(Rank::Seven, Suit::Diamonds)]);
+    ///
+    /// let context = PlayContext {
+    ///     play_data: &play_data,
+    ///     points: 10,
+    ///     opponent_points: 10,
+    ///     target: 121,
+    ///     is_dealer: false,
+    /// };
+    ///
+    /// let mut controller = HeuristicController::new(/*seed=*/ 42, /*endgame_aware=*/ true);
+    ///
+    /// // The Eight makes fifteen.
+    /// assert_eq!(controller.get_play_index(&available_cards, &context), Some(1));
+    /// ```
+    fn get_play_index(&mut self, available_cards: &[Card], context: &PlayContext) -> Option<usize> {
+        let is_endgame =
+            self.endgame_aware && (context.target <= context.opponent_points + ENDGAME_REACH);
+
+        let playable = available_cards
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| (context.play_data.stack_score + card.score()) <= 31);
+
+        let mut best: Option<(usize, f64)> = None;
+
+        for (index, card) in playable {
+            let points = context.play_data.points_for(card);
+
+            if is_endgame && (context.target <= context.points + points) {
+                return Some(index);
+            }
+
+            let risk = if is_endgame {
+                Self::reply_risk(available_cards, card, context)
+            } else {
+                0.0
+            };
+
+            let value = f64::from(points) - risk;
+
+            if best.is_none_or(|(_, best_value)| best_value < value) {
+                best = Some((index, value));
+            }
+        }
+
+        best.map(|(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::seq::SliceRandom;
+
+    use cards::Deck;
+    use game::{Game, GameSnapshot, Phase, Player};
+
+    /// Plays seeded games from close endgame positions, returning the endgame aware wins.
+    fn endgame_aware_wins(aware_is_player_1: bool, number_of_games: u64) -> u64 {
+        let snapshot = GameSnapshot {
+            phase: Phase::Deal,
+            player_1_points: 105,
+            player_2_points: 105,
+            player_1_is_dealer: true,
+            player_1_hand: Vec::new(),
+            player_2_hand: Vec::new(),
+            crib: Vec::new(),
+            starter: None,
+        };
+
+        (0..number_of_games)
+            .filter(|&seed| {
+                let mut cards = Deck::new().as_vec().clone();
+                cards.shuffle(&mut SmallRng::seed_from_u64(seed));
+
+                let player_1 = Player::new(HeuristicController::new(seed, aware_is_player_1));
+                let player_2 = Player::new(HeuristicController::new(seed, !aware_is_player_1));
+
+                let snapshot = GameSnapshot {
+                    player_1_is_dealer: seed % 2 == 0,
+                    ..snapshot.clone()
+                };
+
+                let mut game = Game::new_with_deck(player_1, player_2, Deck::new_with_cards(cards))
+                    .resume(&snapshot);
+
+                let outcome = game.play_quiet().unwrap();
+
+                outcome.player_1_won == aware_is_player_1
+            })
+            .count() as u64
+    }
+
+    #[test]
+    fn test_endgame_aware_wins_more_often() {
+        let number_of_games = 100;
+
+        let aware_wins =
+            endgame_aware_wins(true, number_of_games) + endgame_aware_wins(false, number_of_games);
+
+        let unaware_wins = (2 * number_of_games) - aware_wins;
+
+        assert!(
+            unaware_wins < aware_wins,
+            "Aware Wins: {}\nUnaware Wins: {}",
+            aware_wins,
+            unaware_wins
+        );
+    }
+}
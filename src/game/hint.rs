@@ -0,0 +1,168 @@
+//! Advice for human [`Player`]s, from the strongest built-in AI.
+
+#[cfg(all(doc, feature = "terminal"))]
+use game::IoController;
+#[cfg(doc)]
+use game::Player;
+
+use cards::Card;
+use game::{
+    Controller, ExpectimaxController, GameContext, HeuristicController, Phase, PlayContext,
+    PlayData,
+};
+
+/// Suggests the best discards or play for a [`GameContext`], like a coach looking over the
+/// [`Player`]'s shoulder.
+///
+/// The discards are chosen by an [`ExpectimaxController`], and the play by a
+/// [`HeuristicController`], since those are the strongest of the built-in AI at each. This is what
+/// [`IoController`] shows when `h` is entered at a prompt.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{GameContext, HintProvider, Phase};
+///
+/// let stack = vec![Card::new(Rank::Ten, Suit::Clubs)];
+///
+/// let context = GameContext {
+///     phase: Phase::Play,
+///     starter: None,
+///     stack: &stack,
+///     stack_score: 10,
+///     points: 0,
+///     opponent_points: 0,
+///     is_dealer: false,
+/// };
+///
+/// let hand = vec![
+///     Card::new(Rank::Two, Suit::Hearts),
+///     Card::new(Rank::Five, Suit::Spades),
+/// ];
+///
+/// let mut hints = HintProvider::new(/*seed=*/ 42);
+///
+/// // The Five makes fifteen for 2 points.
+/// assert_eq!(
+///     hints.hint(&context, &hand),
+///     Some(vec![Card::new(Rank::Five, Suit::Spades)])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct HintProvider {
+    discarder: ExpectimaxController,
+    pegger: HeuristicController,
+}
+
+impl HintProvider {
+    /// Creates a new [`HintProvider`] with a seed for the AI's few random choices.
+    #[must_use]
+    pub fn new(seed: u64) -> HintProvider {
+        HintProvider {
+            discarder: ExpectimaxController::new(seed),
+            pegger: HeuristicController::new(seed, /*endgame_aware=*/ false),
+        }
+    }
+
+    /// Returns the [`Card`]s from the `available_cards` that the AI would choose.
+    ///
+    /// In [`Phase::Discard`], these are the two [`Card`]s to discard to the crib, which is
+    /// the [`Player`]'s own if they're the dealer. In [`Phase::Play`], this is the [`Card`] to
+    /// play, or no [`Card`]s if none can be played. There are no hints for the other [`Phase`]s,
+    /// so [`None`] is returned.
+    pub fn hint(&mut self, context: &GameContext, available_cards: &[Card]) -> Option<Vec<Card>> {
+        let indices = match context.phase {
+            Phase::Discard => self
+                .discarder
+                .get_crib_discards(available_cards, context.is_dealer)?
+                .to_vec(),
+            Phase::Play => {
+                let mut play_data = PlayData::from(context.stack.to_vec());
+
+                play_data.starter = context.starter.cloned();
+
+                let play_context = PlayContext {
+                    play_data: &play_data,
+                    points: context.points,
+                    opponent_points: context.opponent_points,
+                    target: play_data.target,
+                    is_dealer: context.is_dealer,
+                };
+
+                self.pegger
+                    .get_play_index(available_cards, &play_context)
+                    .into_iter()
+                    .collect()
+            }
+            _ => return None,
+        };
+
+        indices
+            .into_iter()
+            .map(|index| available_cards.get(index).cloned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Rank, Suit};
+
+    fn context(phase: Phase, stack: &[Card]) -> GameContext<'_> {
+        GameContext {
+            phase,
+            starter: None,
+            stack,
+            stack_score: stack.iter().map(Card::score).sum(),
+            points: 0,
+            opponent_points: 0,
+            is_dealer: true,
+        }
+    }
+
+    #[test]
+    fn test_hint_discard_keeps_the_fives() {
+        let hand = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Spades),
+            Card::new(Rank::Nine, Suit::Hearts),
+        ];
+
+        let hint = HintProvider::new(1)
+            .hint(&context(Phase::Discard, &[]), &hand)
+            .unwrap();
+
+        assert_eq!(hint.len(), 2);
+        assert!(hint.iter().all(|card| card.rank != Rank::Five));
+    }
+
+    #[test]
+    fn test_hint_play_none_playable_is_go() {
+        let stack = vec![
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Queen, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+        ];
+        let hand = vec![Card::new(Rank::Three, Suit::Clubs)];
+
+        let hint = HintProvider::new(1).hint(&context(Phase::Play, &stack), &hand);
+
+        assert_eq!(hint, Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_hint_count_is_none() {
+        let hand = vec![Card::new(Rank::Three, Suit::Clubs)];
+
+        assert_eq!(
+            HintProvider::new(1).hint(&context(Phase::Count, &[]), &hand),
+            None
+        );
+    }
+}
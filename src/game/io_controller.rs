@@ -1,13 +1,62 @@
-use std::io::{self, Stdin};
+use std::io::{self, BufRead, Stdin};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use itertools::Itertools;
+
+#[cfg(doc)]
+use cards::{Deck, Hand};
+#[cfg(doc)]
+use game::{Game, GameError, PlainTextDisplay};
 
 use cards::Card;
-use game::{Controller, Display, UiDisplay};
+use game::{
+    Controller, Display, GameContext, HintProvider, Language, Message, Phase, PlayContext,
+    UiDisplay,
+};
 
 /// A controller that gets all of it's moves from stdin.
+///
+/// When discarding to the crib or playing, `h` can be entered for a hint from a [`HintProvider`].
+///
+/// With [`IoController::with_timeout`], each prompt only waits so long for an answer, like for
+/// network play or tournaments. After that, a legal choice is made for the user, which is the hint
+/// if there is one, and [`Controller::take_timed_out`] indicates it. Once the input is closed,
+/// no more choices are returned, so the [`Game`] ends with [`GameError::OutOfMoves`].
+///
+/// The prompts and hints are shown with a [`UiDisplay`], unless another [`Display`] is given with
+/// [`IoController::with_display`].
 #[derive(Debug)]
-pub struct IoController {
-    display: UiDisplay,
-    stdin: Stdin,
+pub struct IoController<D = UiDisplay>
+where
+    D: Display,
+{
+    display: D,
+    input: Input,
+    timeout: Option<Duration>,
+    timed_out: bool,
+    hints: HintProvider,
+}
+
+/// Where an [`IoController`] reads the user's lines from.
+#[derive(Debug)]
+enum Input {
+    /// Straight from stdin, waiting as long as it takes.
+    Stdin(Stdin),
+    /// The lines sent to the [`Receiver`], which can be waited on for only so long.
+    Lines(Receiver<String>),
+}
+
+/// What an [`IoController`] read from the user.
+#[derive(Debug, PartialEq, Eq)]
+enum Line {
+    /// The trimmed line the user entered.
+    Entered(String),
+    /// The timeout passed before the user entered anything.
+    TimedOut,
+    /// The input is closed, so the user can't enter anything else.
+    Closed,
 }
 
 impl IoController {
@@ -24,44 +73,370 @@ impl IoController {
     pub fn new() -> IoController {
         IoController {
             display: UiDisplay::new(),
-            stdin: io::stdin(),
+            input: Input::Stdin(io::stdin()),
+            timeout: None,
+            timed_out: false,
+            hints: HintProvider::new(rand::random()),
         }
     }
+}
 
-    /// Gets an index less than the given bound from the user via stdin.
+impl<D> IoController<D>
+where
+    D: Display,
+{
+    /// Shows the prompts and hints with the [`Display`], like a [`PlainTextDisplay`] for screen
+    /// readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    ///
+    /// use libterminal_cribbage::game::{Controller, IoController, PlainTextDisplay};
+    ///
+    /// let (sender, receiver) = mpsc::channel();
+    ///
+    /// sender.send(String::from("3")).unwrap();
+    ///
+    /// let mut controller = IoController::new()
+    ///     .with_display(PlainTextDisplay::new())
+    ///     .with_lines(receiver);
+    ///
+    /// assert_eq!(controller.get_starter_cut_depth(40), Some(2));
+    /// ```
+    #[must_use]
+    pub fn with_display<E>(self, display: E) -> IoController<E>
+    where
+        E: Display,
+    {
+        IoController {
+            display,
+            input: self.input,
+            timeout: self.timeout,
+            timed_out: self.timed_out,
+            hints: self.hints,
+        }
+    }
+
+    /// Reads the user's lines from the [`Receiver`] instead of stdin, like from a network
+    /// connection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    ///
+    /// use libterminal_cribbage::game::{Controller, IoController};
+    ///
+    /// let (sender, receiver) = mpsc::channel();
+    ///
+    /// sender.send(String::from("3")).unwrap();
+    ///
+    /// let mut controller = IoController::new().with_lines(receiver);
+    ///
+    /// assert_eq!(controller.get_starter_cut_depth(40), Some(2));
+    /// ```
+    #[must_use]
+    pub fn with_lines(mut self, lines: Receiver<String>) -> IoController<D> {
+        self.input = Input::Lines(lines);
+
+        self
+    }
+
+    /// Only waits the timeout for each answer, before a legal choice is made for the user.
+    ///
+    /// If the lines are still read from stdin, they're read on another thread from now on, so
+    /// they can be waited on for only so long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    /// use std::time::Duration;
+    ///
+    /// use libterminal_cribbage::game::{Controller, IoController};
+    ///
+    /// let (_sender, receiver) = mpsc::channel();
+    ///
+    /// let mut controller = IoController::new()
+    ///     .with_lines(receiver)
+    ///     .with_timeout(Duration::from_millis(1));
+    ///
+    /// // Nothing is entered, so the starter is cut at the top.
+    /// assert_eq!(controller.get_starter_cut_depth(40), Some(0));
+    /// assert!(controller.take_timed_out());
+    /// ```
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> IoController<D> {
+        if let Input::Stdin(_) = self.input {
+            let (sender, receiver) = mpsc::channel();
+
+            thread::spawn(move || {
+                for line in io::stdin().lock().lines() {
+                    let Ok(line) = line else {
+                        return;
+                    };
+
+                    if sender.send(line).is_err() {
+                        return;
+                    }
+                }
+            });
+
+            self.input = Input::Lines(receiver);
+        }
+
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Gets `count` different indices less than the given bound from the user's input.
+    ///
+    /// The numbers are separated by whitespace, like `"1 4"`, and are 1-based like
+    /// [`IoController::parse_index`]. Any error is in the [`Language`].
+    fn parse_indices(
+        input: &str,
+        upper_bound: usize,
+        count: usize,
+        language: Language,
+    ) -> Result<Vec<usize>, String> {
+        let indices = input
+            .split_whitespace()
+            .map(|number| Self::parse_index(number, upper_bound, language))
+            .collect::<Result<Vec<usize>, String>>()?;
+
+        if indices.len() != count {
+            return Err(language.format(Message::NotEnoughNumbers, &[&input, &count]));
+        }
+
+        if indices.iter().all_unique() {
+            Ok(indices)
+        } else {
+            Err(language.format(Message::ChooseDifferentCards, &[&count]))
+        }
+    }
+
+    /// Prompts the user until they choose `count` different indices for the `available_cards`,
+    /// all at once.
+    ///
+    /// If there's a [`GameContext`], the user can enter `h` for a hint from the
+    /// [`HintProvider`]. [`None`] is returned if there aren't `count` [`Card`]s to choose from, or
+    /// the input is closed.
+    ///
+    /// # Panics
+    ///
+    /// If stdout buffer could not be flushed.
+    fn prompt_for_indices(
+        &mut self,
+        prompt_message: &str,
+        available_cards: &[Card],
+        count: usize,
+        context: Option<&GameContext>,
+    ) -> Option<Vec<usize>> {
+        let mut result = None;
+
+        while (count <= available_cards.len()) && result.is_none() {
+            self.display.print_no_spacer_no_delay(prompt_message);
+
+            let input = match self.read_line_from_user() {
+                Line::Entered(input) => input,
+                Line::TimedOut => {
+                    self.timed_out = true;
+
+                    return Some(self.default_indices(context, available_cards, count));
+                }
+                Line::Closed => return None,
+            };
+
+            if let Some(hint_message) = self.hint_message(&input, context, available_cards) {
+                self.display.println_no_spacer_no_delay(&hint_message);
+
+                continue;
+            }
+
+            match Self::parse_indices(
+                &input,
+                available_cards.len(),
+                count,
+                self.display.language(),
+            ) {
+                Ok(indices) => result = Some(indices),
+                Err(err) => self.display.println_no_spacer_no_delay(&err),
+            }
+        }
+
+        result
+    }
+
+    /// Prompts the user until they choose an index for one of the `number_of_cards` [`Card`]s.
+    ///
+    /// If there's a [`GameContext`], the user can enter `h` for a hint from the
+    /// [`HintProvider`] for the `available_cards`. [`None`] is returned if there are no [`Card`]s
+    /// to choose from, or the input is closed.
     ///
-    /// The index is chosen by prompting the user to choose a card index from the available cards.
     /// To make it easy for non-SWEs, the selection from the user is 1-based, but is translated to
     /// 0-based when returning.
     ///
     /// # Panics
     ///
-    /// * If stdout buffer could not be flushed.
-    /// * If the user input from stdin could not be read.
-    fn get_index_from_user(&self, upper_bound: usize) -> Result<usize, String> {
-        let mut input = String::new();
+    /// If stdout buffer could not be flushed.
+    fn prompt_for_index(
+        &mut self,
+        prompt_message: &str,
+        number_of_cards: usize,
+        context: Option<&GameContext>,
+        available_cards: &[Card],
+    ) -> Option<usize> {
+        let mut result = None;
+
+        // Keep looping to get all
+        while (0 < number_of_cards) && result.is_none() {
+            self.display.print_no_spacer_no_delay(prompt_message);
+
+            let input = match self.read_line_from_user() {
+                Line::Entered(input) => input,
+                Line::TimedOut => {
+                    self.timed_out = true;
+
+                    return self
+                        .default_indices(context, available_cards, /*count=*/ 1)
+                        .first()
+                        .copied();
+                }
+                Line::Closed => return None,
+            };
+
+            if let Some(hint_message) = self.hint_message(&input, context, available_cards) {
+                self.display.println_no_spacer_no_delay(&hint_message);
+
+                continue;
+            }
+
+            match Self::parse_index(&input, number_of_cards, self.display.language()) {
+                Ok(index) => result = Some(index),
+                Err(err) => self.display.println_no_spacer_no_delay(&err),
+            }
+        }
+
+        result
+    }
+
+    /// Returns the hint for the `available_cards`, if the user entered `h` and there's a
+    /// [`GameContext`] to give a hint for.
+    fn hint_message(
+        &mut self,
+        input: &str,
+        context: Option<&GameContext>,
+        available_cards: &[Card],
+    ) -> Option<String> {
+        if !input.eq_ignore_ascii_case("h") {
+            return None;
+        }
+
+        let context = context?;
+        let hint = self.hints.hint(context, available_cards)?;
+
+        Some(self.display.game_hint_message(context.phase, &hint))
+    }
+
+    /// Returns the indices chosen for the user when they run out of time.
+    ///
+    /// These are the [`Card`]s of the hint, if there's a [`GameContext`] to give a hint for, or
+    /// the first `count` otherwise. Without a hint, there may be fewer than `count` indices, if
+    /// there aren't that many [`Card`]s.
+    fn default_indices(
+        &mut self,
+        context: Option<&GameContext>,
+        available_cards: &[Card],
+        count: usize,
+    ) -> Vec<usize> {
+        let hint = context
+            .and_then(|context| self.hints.hint(context, available_cards))
+            .unwrap_or_default();
+
+        let indices: Vec<usize> = hint
+            .iter()
+            .filter_map(|card| available_cards.iter().position(|other| other == card))
+            .collect();
 
+        if indices.len() == count {
+            indices
+        } else {
+            (0..count).collect()
+        }
+    }
+
+    /// Returns the trimmed line from the user, after flushing stdout.
+    ///
+    /// If there's a timeout, and it passes first, [`Line::TimedOut`] is returned. Only the
+    /// prompts for a move count it for [`Controller::take_timed_out`]. [`Line::Closed`] is
+    /// returned once stdin, or the [`Receiver`], can't be read from anymore.
+    ///
+    /// # Panics
+    ///
+    /// If stdout buffer could not be flushed.
+    fn read_line_from_user(&mut self) -> Line {
         self.display
             .flush_stdout()
             .expect("Could not flush the buffer!");
 
-        self.stdin
-            .read_line(&mut input)
-            .expect("Error reading from stdin!");
+        let input = match &self.input {
+            Input::Stdin(stdin) => {
+                let mut input = String::new();
 
-        input = input.trim().to_string();
+                match stdin.read_line(&mut input) {
+                    Ok(0) | Err(_) => return Line::Closed,
+                    Ok(_) => input,
+                }
+            }
+            Input::Lines(lines) => match self.timeout {
+                Some(timeout) => match lines.recv_timeout(timeout) {
+                    Ok(input) => input,
+                    Err(RecvTimeoutError::Timeout) => {
+                        let message = self.display.language().format(Message::OutOfTime, &[]);
+
+                        self.display
+                            .println_no_spacer_no_delay(&format!("\n{message}"));
+
+                        return Line::TimedOut;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => return Line::Closed,
+                },
+                None => match lines.recv() {
+                    Ok(input) => input,
+                    Err(_) => return Line::Closed,
+                },
+            },
+        };
+
+        Line::Entered(input.trim().to_string())
+    }
+
+    /// Returns if the input confirms a choice, which is an empty line or `y`.
+    fn is_confirmed(input: &str) -> bool {
+        input.is_empty() || input.eq_ignore_ascii_case("y")
+    }
 
+    /// Parses a 1-based number, less than or equal to the upper bound, into a 0-based index.
+    ///
+    /// Any error is in the [`Language`].
+    fn parse_index(input: &str, upper_bound: usize, language: Language) -> Result<usize, String> {
         match input.parse::<usize>() {
             Ok(index) if 0 < index && index <= upper_bound => Ok(index - 1),
-            Ok(oob_index) => Err(format!(
-                "{oob_index} is out of bounds. Please choose a number between 1 and {upper_bound}!"
-            )),
-            Err(_) => Err(format!("{input} is not a number!")),
+            Ok(oob_index) => {
+                Err(language.format(Message::OutOfBounds, &[&oob_index, &upper_bound]))
+            }
+            Err(_) => Err(language.format(Message::NotANumber, &[&input])),
         }
     }
 }
 
-impl Controller for IoController {
+impl<D> Controller for IoController<D>
+where
+    D: Display,
+{
     /// Returns a possible index for a [`Card`] for a given array of [`Card`]s.
     ///
     /// The index is chosen by prompting the user to choose a card index from the available cards.
@@ -83,25 +458,160 @@ impl Controller for IoController {
     ///
     /// controller.get_card_index(&available_cards);
     /// ```
-    #[must_use]
     fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
-        let mut result = None;
-
         let number_of_cards = available_cards.len();
 
-        let prompt_message = format!("Choose Card to Discard (1 to {number_of_cards}): ");
+        let prompt_message = self
+            .display
+            .language()
+            .format(Message::PromptChooseCard, &[&number_of_cards]);
 
-        // Keep looping to get all
-        while !available_cards.is_empty() && result.is_none() {
+        self.prompt_for_index(&prompt_message, number_of_cards, None, available_cards)
+    }
+
+    /// Returns the index for the [`Card`] to cut, prompting with [`Display::prompt_cut`].
+    ///
+    /// The face down [`Deck`] is shown with [`Display::cut_deck_message`], and the cut is only
+    /// returned once the user confirms it with [`Display::prompt_confirm_cut`]. Otherwise, they
+    /// choose again.
+    fn get_cut_index(&mut self, deck_cards: &[Card]) -> Option<usize> {
+        let number_of_cards = deck_cards.len();
+        let prompt_message = self.display.prompt_cut(number_of_cards);
+
+        loop {
+            let deck_message = self.display.cut_deck_message(number_of_cards, None);
+
+            self.display.println_no_spacer_no_delay(&deck_message);
+
+            let index =
+                self.prompt_for_index(&prompt_message, number_of_cards, None, deck_cards)?;
+            let depth = index + 1;
+
+            // There's no time left to confirm the cut made for the user.
+            if self.timed_out {
+                return Some(index);
+            }
+
+            let deck_message = self.display.cut_deck_message(number_of_cards, Some(depth));
+
+            self.display.println_no_spacer_no_delay(&deck_message);
+            self.display
+                .print_no_spacer_no_delay(&self.display.prompt_confirm_cut(depth));
+
+            // Running out of time confirms the cut.
+            match self.read_line_from_user() {
+                Line::Entered(input) if !Self::is_confirmed(&input) => {}
+                Line::Entered(_) | Line::TimedOut => return Some(index),
+                Line::Closed => return None,
+            }
+        }
+    }
+
+    /// Returns the depth to cut the deck for the starter, prompting with
+    /// [`Display::prompt_starter_cut`].
+    fn get_starter_cut_depth(&mut self, deck_size: usize) -> Option<usize> {
+        let prompt_message = self.display.prompt_starter_cut(deck_size);
+
+        self.prompt_for_index(&prompt_message, deck_size, None, &[])
+    }
+
+    /// Returns the index for the [`Card`] to play, prompting with [`Display::prompt_play_card`].
+    fn get_play_index(&mut self, available_cards: &[Card], context: &PlayContext) -> Option<usize> {
+        let prompt_message = self
+            .display
+            .prompt_play_card(available_cards.len(), context.play_data);
+
+        self.prompt_for_index(
+            &prompt_message,
+            available_cards.len(),
+            Some(&context.game_context()),
+            available_cards,
+        )
+    }
+
+    /// Tells the user the [`Card`] would go over 31, before they're prompted again.
+    fn play_rejected(&mut self, card: &Card) {
+        let message = self
+            .display
+            .language()
+            .format(Message::OverThirtyOne, &[card]);
+
+        self.display.println_no_spacer_no_delay(&message);
+    }
+
+    /// Waits for the user to say GO, by pressing enter or typing `go`, prompting again for anything
+    /// else.
+    ///
+    /// Running out of time, or the input closing, says GO for them.
+    fn acknowledge_go(&mut self, _available_cards: &[Card], context: &PlayContext) {
+        let prompt_message = self
+            .display
+            .language()
+            .format(Message::PromptGo, &[&context.play_data.stack_score]);
+
+        loop {
             self.display.print_no_spacer_no_delay(&prompt_message);
 
-            match self.get_index_from_user(number_of_cards) {
-                Ok(index) => result = Some(index),
-                Err(err) => self.display.println_no_spacer_no_delay(&err),
+            match self.read_line_from_user() {
+                Line::Entered(input) if !input.is_empty() && !input.eq_ignore_ascii_case("go") => {}
+                _ => return,
             }
         }
+    }
 
-        result
+    /// Returns `count` different indices for the [`Card`]s, prompting the user to choose them all
+    /// at once, like `"2 5"`, from the whole array of [`Card`]s.
+    fn get_card_indices(&mut self, available_cards: &[Card], count: usize) -> Option<Vec<usize>> {
+        let number_of_cards = available_cards.len();
+
+        let prompt_message = self
+            .display
+            .language()
+            .format(Message::PromptChooseCards, &[&count, &number_of_cards]);
+
+        self.prompt_for_indices(&prompt_message, available_cards, count, None)
+    }
+
+    /// Returns the indices for both [`Card`]s to discard to the crib, prompting with
+    /// [`Display::prompt_discard_to_crib`].
+    ///
+    /// The user is prompted to choose both at once, like `"1 4"`, from the whole [`Hand`], so the
+    /// numbering doesn't change between the discards. Entering `h` shows a hint.
+    fn get_crib_discards(&mut self, hand: &[Card], is_own_crib: bool) -> Option<[usize; 2]> {
+        let prompt_message = self.display.prompt_discard_to_crib(hand.len(), is_own_crib);
+
+        let context = GameContext {
+            phase: Phase::Discard,
+            starter: None,
+            stack: &[],
+            stack_score: 0,
+            points: 0,
+            opponent_points: 0,
+            is_dealer: is_own_crib,
+        };
+
+        self.prompt_for_indices(&prompt_message, hand, 2, Some(&context))
+            .map(|indices| [indices[0], indices[1]])
+    }
+
+    /// Returns whether the user takes back the [`Card`]s they just chose, prompting with
+    /// [`Display::prompt_undo`].
+    ///
+    /// Running out of time, or the input closing, keeps the [`Card`]s.
+    fn undo_choice(&mut self, chosen: &[Card]) -> bool {
+        let prompt_message = self.display.prompt_undo(chosen);
+
+        self.display.print_no_spacer_no_delay(&prompt_message);
+
+        matches!(
+            self.read_line_from_user(),
+            Line::Entered(input) if input.eq_ignore_ascii_case("u")
+        )
+    }
+
+    /// Indicates that the user ran out of time on their last choice, which was made for them.
+    fn take_timed_out(&mut self) -> bool {
+        std::mem::take(&mut self.timed_out)
     }
 }
 
@@ -111,11 +621,162 @@ impl Default for IoController {
     }
 }
 
-impl Clone for IoController {
-    fn clone(&self) -> Self {
-        IoController {
-            display: self.display.clone(),
-            stdin: io::stdin(),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Rank, Suit};
+    use game::PlayData;
+
+    /// Returns an [`IoController`] that runs out of time, as long as nothing is sent.
+    fn timed_out_controller() -> (mpsc::Sender<String>, IoController) {
+        let (sender, receiver) = mpsc::channel();
+
+        let controller = IoController::new()
+            .with_lines(receiver)
+            .with_timeout(Duration::from_millis(1));
+
+        (sender, controller)
+    }
+
+    #[test]
+    fn test_with_lines_retries_invalid_input() {
+        let (sender, receiver) = mpsc::channel();
+
+        for line in ["seven", "9", " 2 "] {
+            sender.send(String::from(line)).unwrap();
+        }
+
+        let mut controller = IoController::new()
+            .with_lines(receiver)
+            .with_timeout(Duration::from_secs(60));
+
+        let cards = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+
+        assert_eq!(controller.get_card_index(&cards), Some(1));
+        assert!(!controller.take_timed_out());
+    }
+
+    #[test]
+    fn test_timeout_discards_the_hint() {
+        let hand = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Spades),
+            Card::new(Rank::Nine, Suit::Hearts),
+        ];
+
+        let (_sender, mut controller) = timed_out_controller();
+
+        let [first, second] = controller.get_crib_discards(&hand, true).unwrap();
+
+        assert_ne!(first, second);
+        assert_ne!(hand[first].rank, Rank::Five);
+        assert_ne!(hand[second].rank, Rank::Five);
+        assert!(controller.take_timed_out());
+        assert!(!controller.take_timed_out());
+    }
+
+    #[test]
+    fn test_timeout_cuts_the_top() {
+        let deck_cards = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+
+        let (_sender, mut controller) = timed_out_controller();
+
+        assert_eq!(controller.get_cut_index(&deck_cards), Some(0));
+        assert!(controller.take_timed_out());
+    }
+
+    #[test]
+    fn test_acknowledge_go_waits_for_go() {
+        let (sender, receiver) = mpsc::channel();
+
+        for line in ["1", "GO", "never read"] {
+            sender.send(String::from(line)).unwrap();
         }
+
+        let mut controller = IoController::new()
+            .with_lines(receiver)
+            .with_timeout(Duration::from_secs(60));
+
+        let play_data = PlayData::from(vec![
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Diamonds),
+        ]);
+        let context = PlayContext {
+            play_data: &play_data,
+            points: 0,
+            opponent_points: 0,
+            target: 121,
+            is_dealer: false,
+        };
+
+        controller.acknowledge_go(&[Card::new(Rank::King, Suit::Spades)], &context);
+
+        assert!(!controller.take_timed_out());
+        assert_eq!(
+            controller.read_line_from_user(),
+            Line::Entered(String::from("never read"))
+        );
+    }
+
+    #[test]
+    fn test_timeout_says_go() {
+        let (_sender, mut controller) = timed_out_controller();
+
+        let play_data = PlayData::from(vec![Card::new(Rank::King, Suit::Clubs)]);
+        let context = PlayContext {
+            play_data: &play_data,
+            points: 0,
+            opponent_points: 0,
+            target: 121,
+            is_dealer: true,
+        };
+
+        controller.acknowledge_go(&[], &context);
+
+        // Saying GO isn't a move, so it isn't recorded as a timeout.
+        assert!(!controller.take_timed_out());
+    }
+
+    #[test]
+    fn test_timeout_keeps_the_choice() {
+        let (_sender, mut controller) = timed_out_controller();
+
+        assert!(!controller.undo_choice(&[Card::new(Rank::Five, Suit::Hearts)]));
+        assert!(!controller.take_timed_out());
+    }
+
+    #[test]
+    fn test_closed_input_has_no_choice() {
+        let (sender, receiver) = mpsc::channel();
+
+        drop(sender);
+
+        let mut controller = IoController::new().with_lines(receiver);
+
+        let cards = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+
+        assert_eq!(controller.get_card_index(&cards), None);
+        assert_eq!(controller.get_cut_index(&cards), None);
+        assert!(!controller.undo_choice(&cards));
+        assert!(!controller.take_timed_out());
+
+        let mut controller = controller.with_timeout(Duration::from_secs(60));
+
+        assert_eq!(controller.get_crib_discards(&cards, true), None);
+        assert!(!controller.take_timed_out());
     }
 }
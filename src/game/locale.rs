@@ -0,0 +1,558 @@
+//! The catalog of every message the terminal game shows, in each [`Language`].
+//!
+//! Each [`Message`] is a template, where `{0}`, `{1}`, and so on are filled in with
+//! [`Language::format`], so a translation can put them in whatever order reads best. Adding a
+//! [`Language`] only means adding its templates here, without touching the game logic.
+
+#[cfg(all(doc, feature = "terminal"))]
+use game::{IoController, UiDisplay};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A language the terminal game can be shown in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+/// Every message shown by a [`UiDisplay`] or an [`IoController`], to look up in a [`Language`].
+///
+/// The placeholders of each are listed in order, after the English.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Message {
+    /// `"{0} Cut: {1}"`: the name, and the [`Card`](crate::cards::Card) cut.
+    CutCard,
+    /// `"{0} Won Cut"`: the name.
+    WonCut,
+    /// `"{0} and {1} turn over their cuts..."`: both names.
+    CutReveal,
+    /// `"It's a tie, so both cut again"`.
+    Recut,
+    /// `"Deck ({0} cards, face down):"`: the number of cards.
+    DeckFaceDown,
+    /// `"{0} cuts the deck at card {1} for the starter"`: the name, and the 1-based depth.
+    StarterCut,
+    /// `"{0} Points: {1} | {2} Points: {3}"`: each name, and their points.
+    Points,
+    /// `"{0} Hand"`: the name.
+    HandLabel,
+    /// `"{0} Crib"`: the name.
+    CribLabel,
+    /// `"You are the dealer. Discard to your crib."`.
+    DiscardAsDealer,
+    /// `"You are the pone. Discard to your opponent's crib."`.
+    DiscardAsPone,
+    /// `"{0}, the dealer, discards {1} to crib"`: the name, and the cards.
+    DealerCribDiscards,
+    /// `"{0}, the pone, discards {1} to crib"`: the name, and the cards.
+    PoneCribDiscards,
+    /// `"{0} takes back {1}"`: the name, and the cards.
+    Undo,
+    /// `"[Hint] Discard {0} to the crib"`: the cards.
+    HintDiscard,
+    /// `"[Hint] You can't play, so it's a GO"`.
+    HintGo,
+    /// `"[Hint] Play {0}"`: the card.
+    HintPlay,
+    /// `"[Hint] There's nothing to choose"`.
+    HintNothing,
+    /// `"Starter: {0}"`: the card.
+    Starter,
+    /// `"{0} Hand Size: {1}"`: the name, and the number of cards.
+    HandSize,
+    /// `"{0} Last Played: {1}"`: the name, and the card.
+    LastPlayed,
+    /// `"Play Stack"`.
+    PlayStack,
+    /// `"Count: {0}"`: the count.
+    Count,
+    /// `"{0} Hand Score: {1}"`: the name, and the points.
+    HandScore,
+    /// `"{0} Crib Score: {1}"`: the name, and the points.
+    CribScore,
+    /// `"Board (# front peg, o back peg):"`.
+    Board,
+    /// `"- double skunk line -"`.
+    DoubleSkunkLine,
+    /// `"- skunk line -"`.
+    SkunkLine,
+    /// `"under the double skunk line"`.
+    UnderDoubleSkunkLine,
+    /// `"under the skunk line"`.
+    UnderSkunkLine,
+    /// `"past the skunk line"`.
+    PastSkunkLine,
+    /// `"Fifteen for 2!"`.
+    Fifteen,
+    /// `"Thirty-one for 2!"`.
+    ThirtyOne,
+    /// `"Pair for 2!"`.
+    Pair,
+    /// `"Three of a kind for 6!"`.
+    ThreeOfAKind,
+    /// `"Four of a kind for 12!"`.
+    FourOfAKind,
+    /// `"Run of {0} for {0}!"`: the length of the run.
+    Run,
+    /// `"Go for 1!"`.
+    Go,
+    /// `"{0} plays {1} | Count: {2}"`: the name, the card, and the count.
+    PlayCount,
+    /// `"{0} says GO | Count: {1}"`: the name, and the count.
+    SaysGo,
+    /// `"31! The count starts over at 0"`.
+    ResetAfterThirtyOne,
+    /// `"GO! The count starts over at 0"`.
+    ResetAfterGo,
+    /// `"Round Summary"`.
+    RoundSummary,
+    /// `"{0} Pegged: {1} | {2} Pegged: {3}"`: each name, and the points they pegged.
+    Pegged,
+    /// `"{0} Hand: {1} for {2}"`: the name, the cards, and the points.
+    HandCounted,
+    /// `"{0} Crib: {1} for {2}"`: the name, the cards, and the points.
+    CribCounted,
+    /// `"[Cut] Your turn to cut the deck for the deal (1 to {0}): "`: the number of cards.
+    PromptCut,
+    /// `"[Cut] Cut at card {0}? ..."`: the depth.
+    PromptConfirmCut,
+    /// `"[Cut] Your turn to cut the deck for the starter (1 to {0}): "`: the number of cards.
+    PromptStarterCut,
+    /// `"[Discard] Your turn to discard 2 cards to your crib ..."`: the number of cards.
+    PromptDiscardToOwnCrib,
+    /// `"[Discard] Your turn to discard 2 cards to your opponent's crib ..."`: the number of
+    /// cards.
+    PromptDiscardToOpponentCrib,
+    /// `"[Play] Your turn to play a card onto the stack at {0} ..."`: the count, and the number
+    /// of cards.
+    PromptPlayCard,
+    /// `"[Undo] Enter u to take back {0}, ..."`: the cards.
+    PromptUndo,
+    /// `"[GO] You can't play onto the stack at {0} ..."`: the count.
+    PromptGo,
+    /// `"{0} Won!"`: the name.
+    GameOver,
+    /// `"Choose Card to Discard (1 to {0}): "`: the number of cards.
+    PromptChooseCard,
+    /// `"Choose {0} Cards to Discard (1 to {1}), like \"1 4\": "`: how many, and the number of
+    /// cards.
+    PromptChooseCards,
+    /// `"{0} is not {1} numbers!"`: the input, and how many.
+    NotEnoughNumbers,
+    /// `"Please choose {0} different cards!"`: how many.
+    ChooseDifferentCards,
+    /// `"{0} is out of bounds. Please choose a number between 1 and {1}!"`: the number, and the
+    /// upper bound.
+    OutOfBounds,
+    /// `"{0} is not a number!"`: the input.
+    NotANumber,
+    /// `"{0} would go over 31! Please choose another card."`: the card.
+    OverThirtyOne,
+    /// `"Out of time! A choice was made for you."`.
+    OutOfTime,
+}
+
+impl Message {
+    /// Every [`Message`], in the order they're declared.
+    pub const ALL: [Message; 63] = [
+        Message::CutCard,
+        Message::WonCut,
+        Message::CutReveal,
+        Message::Recut,
+        Message::DeckFaceDown,
+        Message::StarterCut,
+        Message::Points,
+        Message::HandLabel,
+        Message::CribLabel,
+        Message::DiscardAsDealer,
+        Message::DiscardAsPone,
+        Message::DealerCribDiscards,
+        Message::PoneCribDiscards,
+        Message::Undo,
+        Message::HintDiscard,
+        Message::HintGo,
+        Message::HintPlay,
+        Message::HintNothing,
+        Message::Starter,
+        Message::HandSize,
+        Message::LastPlayed,
+        Message::PlayStack,
+        Message::Count,
+        Message::HandScore,
+        Message::CribScore,
+        Message::Board,
+        Message::DoubleSkunkLine,
+        Message::SkunkLine,
+        Message::UnderDoubleSkunkLine,
+        Message::UnderSkunkLine,
+        Message::PastSkunkLine,
+        Message::Fifteen,
+        Message::ThirtyOne,
+        Message::Pair,
+        Message::ThreeOfAKind,
+        Message::FourOfAKind,
+        Message::Run,
+        Message::Go,
+        Message::PlayCount,
+        Message::SaysGo,
+        Message::ResetAfterThirtyOne,
+        Message::ResetAfterGo,
+        Message::RoundSummary,
+        Message::Pegged,
+        Message::HandCounted,
+        Message::CribCounted,
+        Message::PromptCut,
+        Message::PromptConfirmCut,
+        Message::PromptStarterCut,
+        Message::PromptDiscardToOwnCrib,
+        Message::PromptDiscardToOpponentCrib,
+        Message::PromptPlayCard,
+        Message::PromptUndo,
+        Message::PromptGo,
+        Message::GameOver,
+        Message::PromptChooseCard,
+        Message::PromptChooseCards,
+        Message::NotEnoughNumbers,
+        Message::ChooseDifferentCards,
+        Message::OutOfBounds,
+        Message::NotANumber,
+        Message::OverThirtyOne,
+        Message::OutOfTime,
+    ];
+}
+
+impl Language {
+    /// Every [`Language`], in the order they're declared.
+    pub const ALL: [Language; 2] = [Language::English, Language::Spanish];
+
+    /// Returns the template of the [`Message`] in this [`Language`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Language, Message};
+    ///
+    /// assert_eq!(Language::English.template(Message::Count), "Count: {0}");
+    /// assert_eq!(Language::Spanish.template(Message::Count), "Cuenta: {0}");
+    /// ```
+    #[must_use]
+    pub fn template(self, message: Message) -> &'static str {
+        match self {
+            Language::English => english(message),
+            Language::Spanish => spanish(message),
+        }
+    }
+
+    /// Returns the [`Message`] in this [`Language`], with each `{N}` filled in by the `N`th of the
+    /// `args`.
+    ///
+    /// A placeholder without an argument is left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Language, Message};
+    ///
+    /// assert_eq!(
+    ///     Language::English.format(Message::PlayCount, &[&"Alice", &"[5♥]", &15]),
+    ///     "Alice plays [5♥] | Count: 15"
+    /// );
+    /// assert_eq!(
+    ///     Language::Spanish.format(Message::Run, &[&3]),
+    ///     "¡Escalera de 3 por 3!"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn format(self, message: Message, args: &[&dyn fmt::Display]) -> String {
+        let template = self.template(message);
+        let mut result = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(start) = rest.find('{') {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let argument = rest
+                .find('}')
+                .and_then(|end| Some((end, rest[1..end].parse::<usize>().ok()?)))
+                .and_then(|(end, index)| Some((end, args.get(index)?)));
+
+            match argument {
+                Some((end, arg)) => {
+                    result.push_str(&arg.to_string());
+                    rest = &rest[(end + 1)..];
+                }
+                None => {
+                    result.push('{');
+                    rest = &rest[1..];
+                }
+            }
+        }
+
+        result.push_str(rest);
+
+        result
+    }
+
+    /// The name of the [`Language`], in itself.
+    fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+        }
+    }
+
+    /// The ISO 639-1 code of the [`Language`].
+    fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.native_name())
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    /// Reads a [`Language`] from its ISO 639-1 code, like `"es"`, or its name, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::Language;
+    ///
+    /// assert_eq!("es".parse(), Ok(Language::Spanish));
+    /// assert_eq!("English".parse(), Ok(Language::English));
+    /// assert!("klingon".parse::<Language>().is_err());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Language::ALL
+            .iter()
+            .find(|language| {
+                language.code().eq_ignore_ascii_case(input)
+                    || language.native_name().eq_ignore_ascii_case(input)
+                    || format!("{language:?}").eq_ignore_ascii_case(input)
+            })
+            .copied()
+            .ok_or_else(|| format!("{input} is not a language!"))
+    }
+}
+
+/// The English template of the [`Message`].
+fn english(message: Message) -> &'static str {
+    match message {
+        Message::CutCard => "{0} Cut: {1}",
+        Message::WonCut => "{0} Won Cut",
+        Message::CutReveal => "{0} and {1} turn over their cuts...",
+        Message::Recut => "It's a tie, so both cut again",
+        Message::DeckFaceDown => "Deck ({0} cards, face down):",
+        Message::StarterCut => "{0} cuts the deck at card {1} for the starter",
+        Message::Points => "{0} Points: {1} | {2} Points: {3}",
+        Message::HandLabel => "{0} Hand",
+        Message::CribLabel => "{0} Crib",
+        Message::DiscardAsDealer => "You are the dealer. Discard to your crib.",
+        Message::DiscardAsPone => "You are the pone. Discard to your opponent's crib.",
+        Message::DealerCribDiscards => "{0}, the dealer, discards {1} to crib",
+        Message::PoneCribDiscards => "{0}, the pone, discards {1} to crib",
+        Message::Undo => "{0} takes back {1}",
+        Message::HintDiscard => "[Hint] Discard {0} to the crib",
+        Message::HintGo => "[Hint] You can't play, so it's a GO",
+        Message::HintPlay => "[Hint] Play {0}",
+        Message::HintNothing => "[Hint] There's nothing to choose",
+        Message::Starter => "Starter: {0}",
+        Message::HandSize => "{0} Hand Size: {1}",
+        Message::LastPlayed => "{0} Last Played: {1}",
+        Message::PlayStack => "Play Stack",
+        Message::Count => "Count: {0}",
+        Message::HandScore => "{0} Hand Score: {1}",
+        Message::CribScore => "{0} Crib Score: {1}",
+        Message::Board => "Board (# front peg, o back peg):",
+        Message::DoubleSkunkLine => "- double skunk line -",
+        Message::SkunkLine => "- skunk line -",
+        Message::UnderDoubleSkunkLine => "under the double skunk line",
+        Message::UnderSkunkLine => "under the skunk line",
+        Message::PastSkunkLine => "past the skunk line",
+        Message::Fifteen => "Fifteen for 2!",
+        Message::ThirtyOne => "Thirty-one for 2!",
+        Message::Pair => "Pair for 2!",
+        Message::ThreeOfAKind => "Three of a kind for 6!",
+        Message::FourOfAKind => "Four of a kind for 12!",
+        Message::Run => "Run of {0} for {0}!",
+        Message::Go => "Go for 1!",
+        Message::PlayCount => "{0} plays {1} | Count: {2}",
+        Message::SaysGo => "{0} says GO | Count: {1}",
+        Message::ResetAfterThirtyOne => "31! The count starts over at 0",
+        Message::ResetAfterGo => "GO! The count starts over at 0",
+        Message::RoundSummary => "Round Summary",
+        Message::Pegged => "{0} Pegged: {1} | {2} Pegged: {3}",
+        Message::HandCounted => "{0} Hand: {1} for {2}",
+        Message::CribCounted => "{0} Crib: {1} for {2}",
+        Message::PromptCut => "[Cut] Your turn to cut the deck for the deal (1 to {0}): ",
+        Message::PromptConfirmCut => {
+            "[Cut] Cut at card {0}? (Enter or `y` to confirm, anything else to choose again): "
+        }
+        Message::PromptStarterCut => "[Cut] Your turn to cut the deck for the starter (1 to {0}): ",
+        Message::PromptDiscardToOwnCrib => {
+            "[Discard] Your turn to discard 2 cards to your crib (1 to {0}, like `1 2`, or `h` for a hint): "
+        }
+        Message::PromptDiscardToOpponentCrib => {
+            "[Discard] Your turn to discard 2 cards to your opponent's crib (1 to {0}, like `1 2`, or `h` for a hint): "
+        }
+        Message::PromptPlayCard => {
+            "[Play] Your turn to play a card onto the stack at {0} (1 to {1}, or `h` for a hint): "
+        }
+        Message::PromptUndo => "[Undo] Enter `u` to take back {0}, or anything else to keep it: ",
+        Message::PromptGo => {
+            "[GO] You can't play onto the stack at {0}. Press enter, or type `go`, to say GO: "
+        }
+        Message::GameOver => "{0} Won!",
+        Message::PromptChooseCard => "Choose Card to Discard (1 to {0}): ",
+        Message::PromptChooseCards => "Choose {0} Cards to Discard (1 to {1}), like \"1 4\": ",
+        Message::NotEnoughNumbers => "{0} is not {1} numbers!",
+        Message::ChooseDifferentCards => "Please choose {0} different cards!",
+        Message::OutOfBounds => "{0} is out of bounds. Please choose a number between 1 and {1}!",
+        Message::NotANumber => "{0} is not a number!",
+        Message::OverThirtyOne => "{0} would go over 31! Please choose another card.",
+        Message::OutOfTime => "Out of time! A choice was made for you.",
+    }
+}
+
+/// The Spanish template of the [`Message`].
+fn spanish(message: Message) -> &'static str {
+    match message {
+        Message::CutCard => "Corte de {0}: {1}",
+        Message::WonCut => "{0} ganó el corte",
+        Message::CutReveal => "{0} y {1} muestran sus cortes...",
+        Message::Recut => "Es un empate, así que ambos cortan de nuevo",
+        Message::DeckFaceDown => "Mazo ({0} cartas, boca abajo):",
+        Message::StarterCut => "{0} corta el mazo en la carta {1} para la carta inicial",
+        Message::Points => "Puntos de {0}: {1} | Puntos de {2}: {3}",
+        Message::HandLabel => "Mano de {0}",
+        Message::CribLabel => "Cuna de {0}",
+        Message::DiscardAsDealer => "Eres quien reparte. Descarta a tu cuna.",
+        Message::DiscardAsPone => "Eres quien no reparte. Descarta a la cuna de tu oponente.",
+        Message::DealerCribDiscards => "{0}, quien reparte, descarta {1} a la cuna",
+        Message::PoneCribDiscards => "{0}, quien no reparte, descarta {1} a la cuna",
+        Message::Undo => "{0} retira {1}",
+        Message::HintDiscard => "[Pista] Descarta {0} a la cuna",
+        Message::HintGo => "[Pista] No puedes jugar, así que es GO",
+        Message::HintPlay => "[Pista] Juega {0}",
+        Message::HintNothing => "[Pista] No hay nada que elegir",
+        Message::Starter => "Carta inicial: {0}",
+        Message::HandSize => "Cartas en la mano de {0}: {1}",
+        Message::LastPlayed => "Última jugada de {0}: {1}",
+        Message::PlayStack => "Pila de juego",
+        Message::Count => "Cuenta: {0}",
+        Message::HandScore => "Puntuación de la mano de {0}: {1}",
+        Message::CribScore => "Puntuación de la cuna de {0}: {1}",
+        Message::Board => "Tablero (# clavija delantera, o clavija trasera):",
+        Message::DoubleSkunkLine => "- línea de doble skunk -",
+        Message::SkunkLine => "- línea de skunk -",
+        Message::UnderDoubleSkunkLine => "bajo la línea de doble skunk",
+        Message::UnderSkunkLine => "bajo la línea de skunk",
+        Message::PastSkunkLine => "pasó la línea de skunk",
+        Message::Fifteen => "¡Quince por 2!",
+        Message::ThirtyOne => "¡Treinta y uno por 2!",
+        Message::Pair => "¡Pareja por 2!",
+        Message::ThreeOfAKind => "¡Trío por 6!",
+        Message::FourOfAKind => "¡Póker por 12!",
+        Message::Run => "¡Escalera de {0} por {0}!",
+        Message::Go => "¡Go por 1!",
+        Message::PlayCount => "{0} juega {1} | Cuenta: {2}",
+        Message::SaysGo => "{0} dice GO | Cuenta: {1}",
+        Message::ResetAfterThirtyOne => "¡31! La cuenta vuelve a 0",
+        Message::ResetAfterGo => "¡GO! La cuenta vuelve a 0",
+        Message::RoundSummary => "Resumen de la ronda",
+        Message::Pegged => "{0} marcó: {1} | {2} marcó: {3}",
+        Message::HandCounted => "Mano de {0}: {1} por {2}",
+        Message::CribCounted => "Cuna de {0}: {1} por {2}",
+        Message::PromptCut => "[Corte] Te toca cortar el mazo para el reparto (1 a {0}): ",
+        Message::PromptConfirmCut => {
+            "[Corte] ¿Cortar en la carta {0}? (Enter o `y` para confirmar, cualquier otra cosa para elegir de nuevo): "
+        }
+        Message::PromptStarterCut => {
+            "[Corte] Te toca cortar el mazo para la carta inicial (1 a {0}): "
+        }
+        Message::PromptDiscardToOwnCrib => {
+            "[Descarte] Te toca descartar 2 cartas a tu cuna (1 a {0}, como `1 2`, o `h` para una pista): "
+        }
+        Message::PromptDiscardToOpponentCrib => {
+            "[Descarte] Te toca descartar 2 cartas a la cuna de tu oponente (1 a {0}, como `1 2`, o `h` para una pista): "
+        }
+        Message::PromptPlayCard => {
+            "[Juego] Te toca jugar una carta a la pila en {0} (1 a {1}, o `h` para una pista): "
+        }
+        Message::PromptUndo => {
+            "[Deshacer] Escribe `u` para retirar {0}, o cualquier otra cosa para mantenerla: "
+        }
+        Message::PromptGo => {
+            "[GO] No puedes jugar a la pila en {0}. Pulsa enter, o escribe `go`, para decir GO: "
+        }
+        Message::GameOver => "¡{0} ganó!",
+        Message::PromptChooseCard => "Elige una carta para descartar (1 a {0}): ",
+        Message::PromptChooseCards => {
+            "Elige {0} cartas para descartar (1 a {1}), como \"1 4\": "
+        }
+        Message::NotEnoughNumbers => "¡{0} no son {1} números!",
+        Message::ChooseDifferentCards => "¡Por favor, elige {0} cartas diferentes!",
+        Message::OutOfBounds => "{0} está fuera de rango. ¡Por favor, elige un número entre 1 y {1}!",
+        Message::NotANumber => "¡{0} no es un número!",
+        Message::OverThirtyOne => "¡{0} pasaría de 31! Por favor, elige otra carta.",
+        Message::OutOfTime => "¡Se acabó el tiempo! Se eligió por ti.",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The indices of every `{N}` placeholder in the template, sorted and without duplicates.
+    fn placeholders(template: &str) -> Vec<usize> {
+        let mut indices: Vec<usize> = template
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split('}').next()?.parse().ok())
+            .collect();
+
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices
+    }
+
+    #[test]
+    fn test_every_language_has_the_same_placeholders() {
+        for message in Message::ALL {
+            let expected = placeholders(Language::English.template(message));
+
+            for language in Language::ALL {
+                assert_eq!(
+                    placeholders(language.template(message)),
+                    expected,
+                    "{language} {message:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_missing_and_unmatched_placeholders() {
+        assert_eq!(
+            Language::English.format(Message::CutCard, &[&"Alice"]),
+            "Alice Cut: {1}"
+        );
+        assert_eq!(
+            Language::English.format(Message::NotANumber, &[&"{0}"]),
+            "{0} is not a number!"
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! A series of [`Game`]s, scored with skunks.
+
+use std::fmt;
+
+use game::{Controller, Display, Game, GameError, GameOutcome};
+
+/// The loser of a [`Game`] is skunked if they have fewer points than this.
+pub const SKUNK_LINE: u32 = 91;
+
+/// The loser of a [`Game`] is double skunked if they have fewer points than this.
+pub const DOUBLE_SKUNK_LINE: u32 = 61;
+
+/// The [`Game`] wins and match points of both players in a [`Match`].
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct MatchStandings {
+    pub player_1_games: u32,
+    pub player_2_games: u32,
+    pub player_1_match_points: u32,
+    pub player_2_match_points: u32,
+}
+
+impl fmt::Display for MatchStandings {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Player 1: {} match points ({} games) | Player 2: {} match points ({} games)",
+            self.player_1_match_points,
+            self.player_1_games,
+            self.player_2_match_points,
+            self.player_2_games
+        )
+    }
+}
+
+/// A best-of-N series of [`Game`]s.
+///
+/// Each [`Game`] won is worth 1 match point, 2 if the loser is skunked (under [`SKUNK_LINE`]), or
+/// 3 if the loser is double skunked (under [`DOUBLE_SKUNK_LINE`]). The [`Match`] is over once a
+/// player has a majority of the N match points.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{Game, Match, Player, RngController};
+///
+/// let mut cribbage_match = Match::new(/*best_of=*/ 3);
+///
+/// let standings = cribbage_match
+///     .play(|| {
+///         Game::new(
+///             Player::new(RngController::new()),
+///             Player::new(RngController::new()),
+///         )
+///     })
+///     .unwrap();
+///
+/// assert!(cribbage_match.is_over());
+/// assert!(2 <= standings.player_1_match_points.max(standings.player_2_match_points));
+///
+/// println!("{}", cribbage_match);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Match {
+    best_of: u32,
+    standings: MatchStandings,
+    outcomes: Vec<GameOutcome>,
+}
+
+impl Match {
+    /// Creates a new [`Match`] of the best of N match points.
+    #[must_use]
+    pub fn new(best_of: u32) -> Match {
+        Match {
+            best_of,
+            standings: MatchStandings::default(),
+            outcomes: Vec::new(),
+        }
+    }
+
+    /// Returns the match points the winner of the [`Game`] earns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{GameOutcome, Match};
+    ///
+    /// let mut outcome = GameOutcome {
+    ///     player_1_won: true,
+    ///     player_1_points: 121,
+    ///     player_2_points: 100,
+    ///     rounds: 10,
+    /// };
+    ///
+    /// assert_eq!(Match::match_points_for(&outcome), 1);
+    ///
+    /// outcome.player_2_points = 90;
+    ///
+    /// assert_eq!(Match::match_points_for(&outcome), 2);
+    ///
+    /// outcome.player_2_points = 60;
+    ///
+    /// assert_eq!(Match::match_points_for(&outcome), 3);
+    /// ```
+    #[must_use]
+    pub fn match_points_for(outcome: &GameOutcome) -> u32 {
+        let loser_points = if outcome.player_1_won {
+            outcome.player_2_points
+        } else {
+            outcome.player_1_points
+        };
+
+        if loser_points < DOUBLE_SKUNK_LINE {
+            3
+        } else if loser_points < SKUNK_LINE {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Records the [`GameOutcome`] of a [`Game`] played outside of the [`Match`].
+    pub fn record(&mut self, outcome: GameOutcome) {
+        let match_points = Match::match_points_for(&outcome);
+
+        if outcome.player_1_won {
+            self.standings.player_1_games += 1;
+            self.standings.player_1_match_points += match_points;
+        } else {
+            self.standings.player_2_games += 1;
+            self.standings.player_2_match_points += match_points;
+        }
+
+        self.outcomes.push(outcome);
+    }
+
+    /// Plays the [`Game`] to the end, and records its [`GameOutcome`].
+    ///
+    /// # Errors
+    ///
+    /// Any [`GameError`] from playing the [`Game`], in which case nothing is recorded.
+    pub fn play_game<C1, C2, D>(
+        &mut self,
+        game: &mut Game<C1, C2, D>,
+    ) -> Result<GameOutcome, GameError>
+    where
+        C1: Controller,
+        C2: Controller,
+        D: Display,
+    {
+        let outcome = game.play(&None)?;
+
+        self.record(outcome.clone());
+
+        Ok(outcome)
+    }
+
+    /// Plays new [`Game`]s until the [`Match`] is over, and returns the final [`MatchStandings`].
+    ///
+    /// # Errors
+    ///
+    /// Any [`GameError`] from playing a [`Game`].
+    pub fn play<C1, C2, D, F>(&mut self, mut new_game: F) -> Result<MatchStandings, GameError>
+    where
+        C1: Controller,
+        C2: Controller,
+        D: Display,
+        F: FnMut() -> Game<C1, C2, D>,
+    {
+        while !self.is_over() {
+            self.play_game(&mut new_game())?;
+        }
+
+        Ok(self.standings.clone())
+    }
+
+    /// Indicates if either player has a majority of the N match points.
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        let needed = self.best_of / 2 + 1;
+
+        (needed <= self.standings.player_1_match_points)
+            || (needed <= self.standings.player_2_match_points)
+    }
+
+    /// Returns the current [`MatchStandings`].
+    #[must_use]
+    pub fn standings(&self) -> &MatchStandings {
+        &self.standings
+    }
+
+    /// Returns the [`GameOutcome`]s of every [`Game`] in the [`Match`] so far.
+    #[must_use]
+    pub fn outcomes(&self) -> &[GameOutcome] {
+        &self.outcomes
+    }
+}
+
+impl fmt::Display for Match {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(formatter, "Best of {}", self.best_of)?;
+
+        for (index, outcome) in self.outcomes.iter().enumerate() {
+            writeln!(
+                formatter,
+                "Game {}: {} - {}",
+                index + 1,
+                outcome.player_1_points,
+                outcome.player_2_points
+            )?;
+        }
+
+        write!(formatter, "{}", self.standings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(player_1_points: u32, player_2_points: u32) -> GameOutcome {
+        GameOutcome {
+            player_1_won: player_2_points < player_1_points,
+            player_1_points,
+            player_2_points,
+            rounds: 10,
+        }
+    }
+
+    #[test]
+    fn test_record_and_is_over() {
+        let mut cribbage_match = Match::new(/*best_of=*/ 5);
+
+        cribbage_match.record(outcome(121, 100));
+        cribbage_match.record(outcome(85, 121));
+
+        assert!(!cribbage_match.is_over());
+
+        cribbage_match.record(outcome(121, 50));
+
+        let expected = MatchStandings {
+            player_1_games: 2,
+            player_2_games: 1,
+            player_1_match_points: 4,
+            player_2_match_points: 2,
+        };
+
+        assert!(cribbage_match.is_over());
+        assert_eq!(cribbage_match.standings(), &expected);
+        assert_eq!(cribbage_match.outcomes().len(), 3);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut cribbage_match = Match::new(/*best_of=*/ 3);
+
+        cribbage_match.record(outcome(121, 100));
+        cribbage_match.record(outcome(121, 60));
+
+        let expected = String::new()
+            + "Best of 3\n"
+            + "Game 1: 121 - 100\n"
+            + "Game 2: 121 - 60\n"
+            + "Player 1: 4 match points (2 games) | Player 2: 0 match points (0 games)";
+
+        assert_eq!(cribbage_match.to_string(), expected);
+    }
+}
@@ -2,55 +2,185 @@
 //! * Choose dealer
 //! * Deal [`Hand`]s to [`Player`]s
 //! * Discards from [`Player`]s for dealer crib
-//! * Get starter [`Card`] from [`Deck`]
+//! * Pone cuts the [`Deck`] for the starter [`Card`]
 //! * Play (peg)
 //! * Count [`Hand`]s
 //! * Repeat until one [`Player`] reaches 121pts
 
+mod async_controller;
+mod census;
 mod controller;
+mod counting;
+mod counting_controller;
+mod daily;
+mod decision;
+mod difficulty;
+mod discard;
 mod display;
+mod error;
+mod event;
+mod expectimax_controller;
+pub mod export;
+#[cfg(test)]
+mod fuzz;
+mod game_view;
+mod greedy_controller;
+mod heuristic_controller;
+mod hint;
+#[cfg(feature = "terminal")]
 mod io_controller;
+mod locale;
+mod match_play;
 mod noop_display;
+pub mod notation;
+mod options;
+mod outcome;
+mod pegging;
+mod pegs;
+mod phase;
+#[cfg(feature = "terminal")]
+mod plain_text_display;
 mod play_data;
+mod play_score;
 mod player;
+mod player_id;
+mod player_view;
 mod predetermined_controller;
+mod replay_controller;
 mod rng_controller;
+mod round;
+mod round_summary;
+mod rules;
+mod scoreboard;
+mod snapshot;
+#[cfg(feature = "terminal")]
+mod spectator_display;
+mod step_controller;
+mod sub_round;
+#[cfg(feature = "terminal")]
+mod theme;
+#[cfg(feature = "tui")]
+mod tui_display;
+#[cfg(feature = "terminal")]
 mod ui_display;
 
-pub use self::controller::Controller;
+pub use self::async_controller::{AsyncController, AsyncPlay, BlockingController, DecisionFuture};
+pub use self::census::{CardCensus, CardLocation};
+pub use self::controller::{Controller, GameContext, PlayContext, PASS_INDEX};
+pub use self::counting::{count_hands, count_player, counting_order};
+pub use self::counting_controller::CountingController;
+pub use self::daily::DailyDate;
+pub use self::decision::{Decision, DecisionResponse};
+pub use self::difficulty::{make_ai_controller, make_ai_controller_with_seed, AiDifficulty};
+pub use self::discard::{
+    crib_ev, crib_expectation, discard_value, discard_value_from_table, DiscardValue,
+};
 pub use self::display::Display;
+pub use self::error::GameError;
+pub use self::event::{GameEvent, ScoreReason};
+pub use self::expectimax_controller::ExpectimaxController;
+pub use self::game_view::GameView;
+pub use self::greedy_controller::GreedyController;
+pub use self::heuristic_controller::{HeuristicController, ENDGAME_REACH};
+pub use self::hint::HintProvider;
+#[cfg(feature = "terminal")]
 pub use self::io_controller::IoController;
+pub use self::locale::{Language, Message};
+pub use self::match_play::{Match, MatchStandings, DOUBLE_SKUNK_LINE, SKUNK_LINE};
 pub use self::noop_display::NoOpDisplay;
+pub use self::options::{DealDirection, FirstDealer, FirstDealtTo, GameOptions, HandSort};
+pub use self::outcome::GameOutcome;
+pub use self::pegging::{PeggingEvent, PeggingRound};
+pub use self::pegs::Pegs;
+pub use self::phase::Phase;
+#[cfg(feature = "terminal")]
+pub use self::plain_text_display::PlainTextDisplay;
 pub use self::play_data::PlayData;
-pub use self::player::Player;
+pub use self::play_score::PlayScoreBreakdown;
+pub use self::player::{Player, DEFAULT_OPPONENT_NAME, DEFAULT_PLAYER_NAME};
+pub use self::player_id::PlayerId;
+pub use self::player_view::PlayerView;
 pub use self::predetermined_controller::PredeterminedController;
+pub use self::replay_controller::ReplayController;
 pub use self::rng_controller::RngController;
-pub use self::ui_display::UiDisplay;
+pub use self::round::Round;
+pub use self::round_summary::{CountedHand, RoundSummary};
+pub use self::rules::{GameRules, CRIB_DISCARDS};
+pub use self::scoreboard::{Scoreboard, Winner};
+pub use self::snapshot::GameSnapshot;
+#[cfg(feature = "terminal")]
+pub use self::spectator_display::SpectatorDisplay;
+pub use self::step_controller::StepController;
+pub use self::sub_round::{Side, SubRound};
+#[cfg(feature = "terminal")]
+pub use self::theme::{CardFrame, Color, Theme};
+#[cfg(feature = "tui")]
+pub use self::tui_display::TuiDisplay;
+#[cfg(feature = "terminal")]
+pub use self::ui_display::{DisplayConfig, UiDisplay};
 
 #[cfg(doc)]
-use crate::cards::Suit;
+use crate::cards::{Rank, Suit};
+#[cfg(doc)]
+use std::future::Future;
+
+use std::collections::VecDeque;
+use std::mem;
+use std::slice;
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
 
-use crate::cards::{Card, Deck, Hand, Rank};
+use crate::cards::{his_heels, Card, Deck, Hand};
 
 /// The struct holding all the necessary data for playing a game of cribbage.
-#[derive(Debug, PartialEq)]
+///
+/// Each [`Player`] has their own [`Controller`] type, so a human can play against an NPC. For a
+/// [`Controller`] chosen at runtime, use `Box<dyn Controller>`.
+#[derive(Debug, PartialEq, Clone)]
 pub struct Game<C1, C2, D>
 where
-    C1: Controller + Clone + std::fmt::Debug,
-    C2: Controller + Clone + std::fmt::Debug,
+    C1: Controller,
+    C2: Controller,
     D: Display,
 {
     player_1: Player<C1>,
     player_2: Player<C2>,
-    player_1_is_dealer: bool,
+    round: Round,
     deck: Deck,
     display: D,
+    options: GameOptions,
+    phase: Phase,
+    player_1_pegs: Pegs,
+    player_2_pegs: Pegs,
+    history: Vec<GameEvent>,
+    replay_deals: VecDeque<ReplayDeal>,
+    undo_stack: Vec<UndoEntry>,
+    rng: SmallRng,
+    census_checks: bool,
+}
+
+/// A recorded deal, and its starter cut `starter_depth` deep, for a replayed [`Game`].
+#[derive(Debug, PartialEq, Clone)]
+struct ReplayDeal {
+    player_1_hand: Vec<Card>,
+    player_2_hand: Vec<Card>,
+    starter: Card,
+    starter_depth: usize,
+}
+
+/// A [`Player`]'s [`Card`]s and the stack from before their last choice, so it can be undone.
+#[derive(Debug, PartialEq, Clone)]
+struct UndoEntry {
+    player_1: bool,
+    hand: Hand,
+    discarded: Vec<Card>,
+    play_data: PlayData,
 }
 
 impl<C1, C2> Game<C1, C2, NoOpDisplay>
 where
-    C1: Controller + Clone + std::fmt::Debug,
-    C2: Controller + Clone + std::fmt::Debug,
+    C1: Controller,
+    C2: Controller,
 {
     /// Creates a new [`Game`] with given [`Player`]s.
     ///
@@ -69,17 +199,12 @@ where
     /// let game = Game::new(player_1, player_2);
     /// ```
     pub fn new(player_1: Player<C1>, player_2: Player<C2>) -> Game<C1, C2, NoOpDisplay> {
+        let mut rng = SmallRng::from_entropy();
         let mut deck = Deck::new();
 
-        deck.shuffle();
+        deck.shuffle_with_rng(&mut rng);
 
-        Game {
-            player_1,
-            player_2,
-            player_1_is_dealer: true,
-            deck,
-            display: NoOpDisplay::new(),
-        }
+        Game::from_parts(player_1, player_2, deck, NoOpDisplay::new(), rng)
     }
 
     /// Creates a new [`Game`] with given [`Player`]s and [`Deck`].
@@ -111,20 +236,410 @@ where
         player_2: Player<C2>,
         deck: Deck,
     ) -> Game<C1, C2, NoOpDisplay> {
-        Game {
+        Game::from_parts(
             player_1,
             player_2,
-            player_1_is_dealer: true,
             deck,
-            display: NoOpDisplay::new(),
+            NoOpDisplay::new(),
+            SmallRng::from_entropy(),
+        )
+    }
+
+    /// Creates a new daily deal [`Game`] between the player and the AI, shuffled with the
+    /// [`DailyDate::seed`], so everyone playing on the same day gets the same deals.
+    ///
+    /// The AI should be seeded with the [`DailyDate::seed`] too, so it plays the same way for
+    /// everyone, and the final scores can be compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{DailyDate, Game, Player, RngController};
+    ///
+    /// let date = DailyDate::new(2024, 2, 29).unwrap();
+    ///
+    /// let mut game_1 = Game::new_daily(
+    ///     date,
+    ///     Player::new(RngController::new_with_seed(1)),
+    ///     Player::new(RngController::new_with_seed(date.seed())),
+    /// );
+    /// let mut game_2 = Game::new_daily(
+    ///     date,
+    ///     Player::new(RngController::new_with_seed(1)),
+    ///     Player::new(RngController::new_with_seed(date.seed())),
+    /// );
+    ///
+    /// assert_eq!(game_1.play_quiet(), game_2.play_quiet());
+    /// assert_eq!(game_1.history(), game_2.history());
+    /// ```
+    pub fn new_daily(
+        date: DailyDate,
+        player: Player<C1>,
+        ai: Player<C2>,
+    ) -> Game<C1, C2, NoOpDisplay> {
+        let mut rng = SmallRng::seed_from_u64(date.seed());
+        let mut deck = Deck::new();
+
+        deck.shuffle_with_rng(&mut rng);
+
+        Game::from_parts(player, ai, deck, NoOpDisplay::new(), rng)
+    }
+
+    /// Play the full game without printing, sleeping, or panicking.
+    ///
+    /// This is the same as [`Game::play_default`], but instead of panicking, any [`GameError`] is
+    /// returned. Since the [`Game`] uses a [`NoOpDisplay`], nothing is printed and there are no
+    /// delays, which makes this suitable for embedding the [`Game`] in something like a server.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::OutOfMoves`] if a [`Player::controller`] runs out of moves while cutting or
+    ///   discarding.
+    /// * [`GameError::EmptyDeck`] if the [`Deck`] runs out of [`Card`]s.
+    /// * [`GameError::InvalidPlay`] if a [`Player`] makes an invalid play.
+    /// * [`GameError::InvalidIndex`] if a [`Player::controller`] returns an out of bounds index.
+    /// * [`GameError::TooManyTurns`] or [`GameError::TooManyRounds`] if the [`Game`] is stuck.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, Player, RngController};
+    ///
+    /// let player_1 = Player::new(RngController::new());
+    /// let player_2 = Player::new(RngController::new());
+    ///
+    /// let mut game = Game::new(player_1, player_2);
+    ///
+    /// let outcome = game.play_quiet().unwrap();
+    ///
+    /// if outcome.player_1_won {
+    ///     assert!(121 <= outcome.player_1_points);
+    /// } else {
+    ///     assert!(121 <= outcome.player_2_points);
+    /// }
+    /// ```
+    pub fn play_quiet(&mut self) -> Result<GameOutcome, GameError> {
+        self.play(&None)
+    }
+}
+
+impl<D> Game<ReplayController, ReplayController, D>
+where
+    D: Display,
+{
+    /// Creates a new [`Game`] that replays the recorded [`GameEvent`]s from [`Game::history`].
+    ///
+    /// Both [`Player`]s get a [`ReplayController`], and the [`Deck`] is arranged before every
+    /// deal so the same [`Hand`]s and starters are dealt. Then, stepping through the [`Game`] with
+    /// [`Game::step`] re-drives it just like it was played. The recorded [`Game`] should have
+    /// started from the cut, and used the same [`GameOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, NoOpDisplay, Player, RngController};
+    ///
+    /// let player_1 = Player::new(RngController::new());
+    /// let player_2 = Player::new(RngController::new());
+    ///
+    /// let mut game = Game::new(player_1, player_2);
+    ///
+    /// let outcome = game.play_quiet().unwrap();
+    ///
+    /// let mut replay = Game::replay(game.history(), NoOpDisplay::new());
+    ///
+    /// assert_eq!(replay.play_quiet(), Ok(outcome));
+    /// assert_eq!(replay.history(), game.history());
+    /// ```
+    pub fn replay(events: &[GameEvent], display: D) -> Game<ReplayController, ReplayController, D> {
+        let player_1 = Player::new(ReplayController::from_events(
+            events, /*player_1=*/ true,
+        ));
+        let player_2 = Player::new(ReplayController::from_events(
+            events, /*player_1=*/ false,
+        ));
+
+        let mut game = Game::new_with_deck_default(player_1, player_2, Deck::new(), display);
+
+        let mut hands = None;
+        let mut starter_depth = 0;
+
+        for event in events {
+            match event {
+                GameEvent::Deal {
+                    player_1_hand,
+                    player_2_hand,
+                    ..
+                } => hands = Some((player_1_hand.clone(), player_2_hand.clone())),
+                GameEvent::CutForStarter { depth, .. } => starter_depth = *depth,
+                GameEvent::StarterCut { card } => {
+                    if let Some((player_1_hand, player_2_hand)) = hands.take() {
+                        game.replay_deals.push_back(ReplayDeal {
+                            player_1_hand,
+                            player_2_hand,
+                            starter: card.clone(),
+                            starter_depth,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        game
+    }
+}
+
+impl Game<StepController, StepController, NoOpDisplay> {
+    /// Creates a new step-driven [`Game`], where each [`Player`] gets a [`StepController`].
+    ///
+    /// Instead of calling into [`Controller`]s, which may block, the [`Game`] is driven from
+    /// outside with [`Game::next_decision`] and [`Game::apply`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Decision, Game, Phase, PlayerId};
+    ///
+    /// let mut game = Game::new_step_driven();
+    ///
+    /// let expected = Decision::Cut {
+    ///     player: PlayerId::One,
+    ///     deck_size: 52,
+    /// };
+    ///
+    /// assert_eq!(game.next_decision(), Ok(expected));
+    /// assert_eq!(game.phase(), Phase::Cut);
+    /// ```
+    #[must_use]
+    pub fn new_step_driven() -> Game<StepController, StepController, NoOpDisplay> {
+        let player_1 = Player::new(StepController::new(PlayerId::One));
+        let player_2 = Player::new(StepController::new(PlayerId::Two));
+
+        Game::new(player_1, player_2)
+    }
+
+    /// Takes steps until a [`Player`] has to decide something, and returns that [`Decision`], or
+    /// [`Decision::Over`] once the [`Game`] is over.
+    ///
+    /// A step that can't be finished without a [`DecisionResponse`] is undone, and taken again
+    /// from the start once the [`Decision`] is applied, so the [`Game`] is always between steps.
+    /// Calling this again, without applying anything, returns the same [`Decision`].
+    ///
+    /// Since a step may be taken more than once, a step-driven [`Game`] only has a
+    /// [`NoOpDisplay`]. Follow it with the [`Game::history`] instead.
+    ///
+    /// # Errors
+    ///
+    /// Any [`GameError`] from [`Game::step`], other than running out of moves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{
+    ///     Decision, DecisionResponse, FirstDealer, Game, GameOptions, Phase, PlayerId,
+    /// };
+    ///
+    /// let options = GameOptions {
+    ///     first_dealer: FirstDealer::Fixed { player_1: true },
+    ///     ..GameOptions::default()
+    /// };
+    ///
+    /// let mut game = Game::new_step_driven().with_options(options).with_seed(3);
+    ///
+    /// let decision = game.next_decision().unwrap();
+    ///
+    /// assert_eq!(game.phase(), Phase::Discard);
+    /// assert_eq!(decision.player(), Some(PlayerId::Two));
+    /// assert!(decision.accepts(&DecisionResponse::Discard([0, 1])));
+    /// assert_eq!(game.next_decision(), Ok(decision));
+    /// ```
+    pub fn next_decision(&mut self) -> Result<Decision, GameError> {
+        loop {
+            if let Some(decision) = self.pending_decision() {
+                return Ok(decision);
+            }
+
+            if self.phase == Phase::Over {
+                let player_1_won = self.player_1.points >= self.options.target_score;
+
+                return Ok(Decision::Over {
+                    winner: PlayerId::from_player_1(player_1_won),
+                });
+            }
+
+            // Only these phases ask the players, so only their steps can stop part-way on a
+            // Decision and need to be undone.
+            let possible_before = matches!(self.phase, Phase::Cut | Phase::Discard | Phase::Play)
+                .then(|| self.clone());
+
+            match self.step() {
+                Ok(_) => {
+                    self.player_1.controller_mut().clear_responses();
+                    self.player_2.controller_mut().clear_responses();
+                }
+                Err(error) => {
+                    let pending = self.player_1.controller_mut().take_pending();
+                    let pending = pending.or_else(|| self.player_2.controller_mut().take_pending());
+
+                    if let Some(before) = possible_before {
+                        *self = before;
+                    }
+
+                    let decision = pending.ok_or(error)?;
+
+                    if let Some(controller) = self.step_controller(decision.player()) {
+                        controller.wait_on(decision);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Answers the next [`Decision`] with the [`DecisionResponse`], and returns the [`Decision`]
+    /// after it, see [`Game::next_decision`].
+    ///
+    /// If the [`DecisionResponse`] is out of bounds, or isn't allowed, the [`Game`] is left as it
+    /// was, still waiting on the same [`Decision`].
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::UnexpectedResponse`] if the [`DecisionResponse`] doesn't answer the next
+    ///   [`Decision`].
+    /// * [`GameError::InvalidPlay`] if the [`Card`] played would take the stack over 31.
+    /// * Any [`GameError`] from [`Game::step`] with the [`DecisionResponse`], like
+    ///   [`GameError::InvalidIndex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Decision, DecisionResponse, Game, Phase};
+    ///
+    /// let mut game = Game::new_step_driven().with_seed(3);
+    ///
+    /// let mut decision = game.next_decision().unwrap();
+    ///
+    /// while decision.player().is_some() {
+    ///     let response = match decision {
+    ///         Decision::Cut { .. } => DecisionResponse::Cut(0),
+    ///         Decision::Discard { .. } => DecisionResponse::Discard([0, 1]),
+    ///         Decision::CutStarter { deck_size, .. } => DecisionResponse::CutStarter(deck_size / 2),
+    ///         Decision::Play {
+    ///             ref hand,
+    ///             stack_score,
+    ///             ..
+    ///         } => {
+    ///             let playable = hand.iter().position(|card| stack_score + card.score() <= 31);
+    ///
+    ///             DecisionResponse::Play(playable.unwrap())
+    ///         }
+    ///         Decision::Over { .. } => unreachable!(),
+    ///     };
+    ///
+    ///     decision = game.apply(response).unwrap();
+    /// }
+    ///
+    /// assert_eq!(game.phase(), Phase::Over);
+    /// ```
+    pub fn apply(&mut self, response: DecisionResponse) -> Result<Decision, GameError> {
+        let decision = self.next_decision()?;
+
+        if !decision.accepts(&response) {
+            return Err(GameError::UnexpectedResponse(response));
+        }
+
+        if let (
+            Decision::Play {
+                hand, stack_score, ..
+            },
+            DecisionResponse::Play(index),
+        ) = (&decision, &response)
+        {
+            if let Some(card) = hand.get(*index) {
+                if 31 < stack_score + card.score() {
+                    return Err(GameError::InvalidPlay(card.clone()));
+                }
+            }
+        }
+
+        if let Some(controller) = self.step_controller(decision.player()) {
+            controller.respond(response);
+        }
+
+        self.next_decision().inspect_err(|_| {
+            if let Some(controller) = self.step_controller(decision.player()) {
+                controller.take_back(decision);
+            }
+        })
+    }
+
+    /// Plays the [`Game`] to the end, asking the [`AsyncController`] of each [`Player`] for every
+    /// [`Decision`], and returns the [`Future`] of the winner.
+    ///
+    /// Nothing blocks while an [`AsyncController`] waits on its user, so the [`Game`] can be
+    /// played on any executor, or in the browser. Each [`DecisionResponse`] is applied with
+    /// [`Game::apply`].
+    ///
+    /// # Errors
+    ///
+    /// The [`Future`] is ready with an error if:
+    ///
+    /// * An [`AsyncController`] has no move, with [`GameError::OutOfMoves`].
+    /// * Any [`GameError`] from [`Game::next_decision`], or [`Game::apply`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// use libterminal_cribbage::game::{BlockingController, Game, RngController};
+    ///
+    /// let mut game = Game::new_step_driven().with_seed(5);
+    ///
+    /// let mut player_1 = BlockingController::new(RngController::new_with_seed(1));
+    /// let mut player_2 = BlockingController::new(RngController::new_with_seed(2));
+    ///
+    /// let mut play = pin!(game.play_async(&mut player_1, &mut player_2));
+    ///
+    /// // Neither player ever waits, so the game is played in one poll.
+    /// let mut context = Context::from_waker(Waker::noop());
+    ///
+    /// assert!(matches!(play.as_mut().poll(&mut context), Poll::Ready(Ok(_))));
+    /// ```
+    pub fn play_async<'a, A1, A2>(
+        &'a mut self,
+        player_1: &'a mut A1,
+        player_2: &'a mut A2,
+    ) -> AsyncPlay<'a, A1, A2>
+    where
+        A1: AsyncController,
+        A2: AsyncController,
+    {
+        AsyncPlay::new(self, player_1, player_2)
+    }
+
+    /// Returns the [`Decision`] either [`StepController`] is waiting on, if any.
+    fn pending_decision(&mut self) -> Option<Decision> {
+        let pending = self.player_1.controller_mut().pending().cloned();
+
+        pending.or_else(|| self.player_2.controller_mut().pending().cloned())
+    }
+
+    /// Returns the [`StepController`] of the [`PlayerId`], if any.
+    fn step_controller(&mut self, player: Option<PlayerId>) -> Option<&mut StepController> {
+        match player? {
+            PlayerId::One => Some(self.player_1.controller_mut()),
+            PlayerId::Two => Some(self.player_2.controller_mut()),
         }
     }
 }
 
 impl<C1, C2, D> Game<C1, C2, D>
 where
-    C1: Controller + Clone + std::fmt::Debug,
-    C2: Controller + Clone + std::fmt::Debug,
+    C1: Controller,
+    C2: Controller,
     D: Display,
 {
     /// Creates a new [`Game`] with given [`Player`]s.
@@ -145,17 +660,12 @@ where
     /// let game = Game::new_default(player_1, player_2, display);
     /// ```
     pub fn new_default(player_1: Player<C1>, player_2: Player<C2>, display: D) -> Game<C1, C2, D> {
+        let mut rng = SmallRng::from_entropy();
         let mut deck = Deck::new();
 
-        deck.shuffle();
+        deck.shuffle_with_rng(&mut rng);
 
-        Game {
-            player_1,
-            player_2,
-            player_1_is_dealer: true,
-            deck,
-            display,
-        }
+        Game::from_parts(player_1, player_2, deck, display, rng)
     }
 
     /// Creates a new [`Game`] with given [`Player`]s and [`Deck`].
@@ -189,547 +699,3813 @@ where
         deck: Deck,
         display: D,
     ) -> Game<C1, C2, D> {
-        Game {
-            player_1,
-            player_2,
-            player_1_is_dealer: true,
-            deck,
-            display,
-        }
+        Game::from_parts(player_1, player_2, deck, display, SmallRng::from_entropy())
     }
 
-    /// Play the default game.
-    ///
-    /// This is simply calls [`Game::play`], but with `reset_with_deck` set to [`None`].
-    ///
-    /// # Panics
+    /// Creates a new daily deal [`Game`] between the player and the AI, shown with the
+    /// [`Display`].
     ///
-    /// * If there have been 1,000 rounds, indicating that the game is broken and can't end loop.
-    /// * If the [`Player::controller`] returns an index that is out of bounds of the [`Deck`].
+    /// See [`Game::new_daily`].
+    pub fn new_daily_default(
+        date: DailyDate,
+        player: Player<C1>,
+        ai: Player<C2>,
+        display: D,
+    ) -> Game<C1, C2, D> {
+        let mut rng = SmallRng::seed_from_u64(date.seed());
+        let mut deck = Deck::new();
+
+        deck.shuffle_with_rng(&mut rng);
+
+        Game::from_parts(player, ai, deck, display, rng)
+    }
+
+    /// Sets the [`GameOptions`] for the [`Game`].
     ///
     /// # Examples
     ///
-    /// ```should_panic
-    /// use libterminal_cribbage::game::{Game, Player, PredeterminedController};
+    /// ```
+    /// use libterminal_cribbage::game::{Game, GameOptions, Player, PredeterminedController};
     ///
     /// let controller = PredeterminedController::from(vec![0, 1, 2]);
     ///
     /// let player_1 = Player::new(controller.clone());
     /// let player_2 = Player::new(controller);
     ///
-    /// let mut game = Game::new(player_1, player_2);
+    /// let options = GameOptions {
+    ///     allow_intentional_pass: true,
+    ///     ..GameOptions::default()
+    /// };
     ///
-    /// // Panics because the controller does not have enough moves to play a game.
-    /// game.play_default();
+    /// let game = Game::new(player_1, player_2).with_options(options);
     /// ```
-    pub fn play_default(&mut self) {
-        self.play(&None);
+    #[must_use]
+    pub fn with_options(mut self, options: GameOptions) -> Game<C1, C2, D> {
+        self.options = options;
+
+        self
     }
 
-    /// Play the full game.
+    /// Checks the [`Game::card_census`] after every [`Game::step`], panicking if a [`Card`] was lost
+    /// or duplicated.
     ///
-    /// The `reset_with_deck` parameter is for testing. If [`Some`], then instead of using
-    /// [`Game::reset_deck`] and shuffling, it will just set [`Game::deck`] to the given
-    /// [`Option<Deck>`].
+    /// This is for tests and fuzzing, since the [`Game`] has to have been started with all 52
+    /// [`Card`]s.
     ///
-    /// How the play works:
-    /// * Each [`Player`] chooses a random [`Card`] from [`Deck`]. The highest value [`Card`] wins,
-    ///   and [`Card`] suit order is [`Suit::Hearts`], [`Suit::Spades`], [`Suit::Diamonds`],
-    ///   [`Suit::Clubs`]. The winner is the dealer who gets the crib.
-    /// * The [`Deck`] is shuffled and each [`Player`] is dealt 6 [`Card`]s.
-    /// * The [`Player`]s choose 2 [`Card`]s to discard. These [`Card`]s are put into a new
-    ///   [`Hand`], and given to the dealer [`Player`] as their crib.
-    /// * The top of the [`Deck`] is popped and stored as the starter [`Card`].
-    /// * If this [`Card`] is a [`Rank::Jack`], the dealer gets two points.
-    /// * Starting with the non-dealer (Pone) each [`Player`] puts a [`Card`] from their [`Hand`]
-    ///   on the stack and the score is counted incrementally. All [`Player`]s must play as long as
-    ///   the running score is not 31 or over. If one [`Player`] can't make a move, they pass (GO)
-    ///   to the next [`Player`]. If both can't make a move, the running score is reset to zero, and
-    ///   the last [`Player`] to put down a [`Card`] gets to put down another [`Card`]. This is
-    ///   until all [`Card`]s are laid out.
-    /// * Afterwards the [`Player`]s [`Hand`]s/cribs are scored, with the starter [`Card`], starting
-    ///   with the Pone.
-    /// * If neither [`Player`]s score is 121, then switch dealer and loop from dealing [`Card`]s
-    ///   step.
+    /// # Examples
     ///
-    /// # Panics
+    /// ```
+    /// use libterminal_cribbage::game::{Game, Player, RngController};
     ///
-    /// If there have been 1,000 rounds, indicating that the game is broken and can't end loop.
-    pub fn play(&mut self, reset_with_deck: &Option<Deck>) {
-        let mut round = 0;
-
-        self.choose_dealer();
-
-        loop {
-            self.run_deal_and_discard_round();
-
-            let starter = self.get_starter();
-
-            if self.player_has_won() {
-                break;
-            }
-
-            self.run_play_round(&starter);
-
-            if self.player_has_won() {
-                break;
-            }
-
-            self.run_counting_round(&starter);
-
-            if self.player_has_won() {
-                break;
-            }
-
-            match reset_with_deck {
-                Some(ref deck) => self.reset_deck_with(deck.clone()),
-                None => self.reset_deck(starter),
-            }
-
-            self.swap_dealer_and_pone();
-
-            round += 1;
-
-            assert!(1_000 >= round, "Play got stuck at round 1000!");
-        }
-
-        let player_1_won = self.player_1.points >= 121;
+    /// let player_1 = Player::new(RngController::new_with_seed(1));
+    /// let player_2 = Player::new(RngController::new_with_seed(2));
+    ///
+    /// let mut game = Game::new(player_1, player_2).with_census_checks();
+    ///
+    /// assert!(game.play_quiet().is_ok());
+    /// ```
+    #[must_use]
+    pub fn with_census_checks(mut self) -> Game<C1, C2, D> {
+        self.census_checks = true;
 
-        self.display
-            .println(&self.display.game_over_message(player_1_won));
+        self
     }
 
-    /// Chose dealer and pone.
+    /// Seeds the shuffling for the [`Game`], so the deals are the same every time.
     ///
-    /// This is done by having each [`Player`] choose a [`Card`] from the [`Deck`]
-    /// and the dealer is the highest value [`Card`].
-    /// * The highest value [`Card`] wins.
-    /// * Card suit order is [`Suit::Hearts`], [`Suit::Spades`], [`Suit::Diamonds`],
-    ///   [`Suit::Clubs`].
+    /// The [`Game::deck`] is replaced with a new [`Deck`], shuffled with the seed, and it's
+    /// reshuffled with the same RNG between rounds. This is meant to be called before the
+    /// [`Game`] starts, for AI benchmarking, replays, and reproducing bugs.
     ///
-    /// # Panics
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, Player, RngController};
+    ///
+    /// let mut game_1 = Game::new(
+    ///     Player::new(RngController::new_with_seed(1)),
+    ///     Player::new(RngController::new_with_seed(2)),
+    /// )
+    /// .with_seed(42);
+    /// let mut game_2 = Game::new(
+    ///     Player::new(RngController::new_with_seed(1)),
+    ///     Player::new(RngController::new_with_seed(2)),
+    /// )
+    /// .with_seed(42);
     ///
-    /// If the [`Player::controller`] returns an index that is out of bounds of the [`Deck`].
-    fn choose_dealer(&mut self) {
-        let mut temp_deck = self.deck.clone();
+    /// assert_eq!(game_1.play_quiet(), game_2.play_quiet());
+    /// assert_eq!(game_1.history(), game_2.history());
+    /// ```
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Game<C1, C2, D> {
+        self.rng = SmallRng::seed_from_u64(seed);
 
-        let player_1_chosen_card = self.player_1.choose_card_for_cut(&mut temp_deck).unwrap();
-        let player_2_chosen_card = self.player_2.choose_card_for_cut(&mut temp_deck).unwrap();
+        let mut deck = Deck::new();
 
-        self.player_1_is_dealer = player_1_chosen_card > player_2_chosen_card;
+        deck.shuffle_with_rng(&mut self.rng);
 
-        let message = self.display.game_after_cut_message(
-            &player_1_chosen_card,
-            &player_2_chosen_card,
-            self.player_1_is_dealer,
-        );
+        self.deck = deck;
 
-        self.display.println(&message);
+        self
     }
 
-    /// Indicates that the game is won by [`Deck::dealer`] or [`Deck::pone`].
+    /// Returns owned [`PlayerView`]s of player 1 and player 2, respectively.
     ///
-    /// If either [`Player`] has at least 121 points, the game is won for them.
-    fn player_has_won(&self) -> bool {
-        (121 <= self.player_1.points) || (121 <= self.player_2.points)
-    }
-
-    /// This method facilitates the [`Player`]s discarding for cribs.
+    /// # Examples
     ///
-    /// Each [`Player`] is dealt 6 [`Card`]s. Then [`Player`]s choose 2 [`Card`]s to discard.
-    /// These [`Card`]s are put into a new [`Hand`], and given to the dealer [`Player`] as
-    /// their crib. The dealer is dealt first even though that is wrong.
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{
+    ///     Game, Player, PredeterminedController, DEFAULT_OPPONENT_NAME,
+    /// };
     ///
-    /// # Panics
+    /// let controller = PredeterminedController::from(vec![0, 1, 2]);
     ///
-    /// * If there are not enough [`Card`]s in the [`Deck`] to deal 12 [`Card`]s.
-    /// * If either [`Player::controller`] chooses a discard out of bounds of their [`Hand`]s.
-    fn run_deal_and_discard_round(&mut self) {
-        for _ in 0..6 {
-            match (self.deck.deal(), self.deck.deal()) {
-                (Some(card_1), Some(card_2)) => {
-                    self.player_1.add_card(card_1);
-                    self.player_2.add_card(card_2);
-                }
-                _ => panic!("There are not enough cards to deal!"),
-            }
-        }
+    /// let cards = vec![Card::new(Rank::Ace, Suit::Hearts), Card::new(Rank::Ace, Suit::Clubs)];
+    ///
+    /// let player_1 = Player::new_with_cards(controller.clone(), cards.clone()).with_name("Alice");
+    /// let player_2 = Player::new(controller);
+    ///
+    /// let game = Game::new(player_1, player_2);
+    ///
+    /// let (player_1_view, player_2_view) = game.player_views();
+    ///
+    /// drop(game);
+    ///
+    /// assert_eq!(player_1_view.name, "Alice");
+    /// assert_eq!(player_2_view.name, DEFAULT_OPPONENT_NAME);
+    /// assert_eq!(player_1_view.hand, cards);
+    /// assert!(player_2_view.hand.is_empty());
+    /// ```
+    #[must_use]
+    pub fn player_views(&self) -> (PlayerView, PlayerView) {
+        let player_1_view = PlayerView {
+            name: self.name(/*player_1=*/ true).to_string(),
+            points: self.player_1.points,
+            hand: self.player_1.hand_cards(),
+            crib: self
+                .round
+                .crib_of(/*player_1=*/ true)
+                .map_or_else(Vec::new, |crib| crib.as_vec().clone()),
+            is_dealer: self.round.player_1_is_dealer,
+        };
 
-        let mut discards = vec![];
+        let player_2_view = PlayerView {
+            name: self.name(/*player_1=*/ false).to_string(),
+            points: self.player_2.points,
+            hand: self.player_2.hand_cards(),
+            crib: self
+                .round
+                .crib_of(/*player_1=*/ false)
+                .map_or_else(Vec::new, |crib| crib.as_vec().clone()),
+            is_dealer: !self.round.player_1_is_dealer,
+        };
 
-        for _ in 0..2 {
-            let message = self.display.game_before_play_message(
-                /*starter=*/ None,
-                &self.player_1,
-                &self.player_2,
-            );
+        (player_1_view, player_2_view)
+    }
 
-            self.display.println(&message);
+    /// Returns a [`GameView`] of what player 1 (if `player_1`, or player 2 otherwise) can see.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Game, Phase, Player, PredeterminedController};
+    ///
+    /// let controller = PredeterminedController::from(vec![]);
+    ///
+    /// let cards = vec![Card::new(Rank::Ace, Suit::Hearts), Card::new(Rank::Ace, Suit::Clubs)];
+    ///
+    /// let mut player_2 = Player::new_with_cards(controller.clone(), cards.clone());
+    /// player_2.points = 7;
+    ///
+    /// let game = Game::new(Player::new(controller), player_2);
+    ///
+    /// let view = game.view(/*player_1=*/ false);
+    ///
+    /// assert_eq!(view.phase, Phase::Cut);
+    /// assert_eq!(view.points, 7);
+    /// assert_eq!(view.hand, cards);
+    /// assert_eq!(view.opponent_hand_size, 0);
+    /// assert!(!view.is_dealer);
+    /// ```
+    #[must_use]
+    pub fn view(&self, player_1: bool) -> GameView {
+        let (points, opponent_points, hand, opponent_hand_size) = if player_1 {
+            (
+                self.player_1.points,
+                self.player_2.points,
+                self.player_1.hand_cards(),
+                self.player_2.hand.len(),
+            )
+        } else {
+            (
+                self.player_2.points,
+                self.player_1.points,
+                self.player_2.hand_cards(),
+                self.player_1.hand.len(),
+            )
+        };
 
-            discards.push(
-                self.player_2
-                    .remove_card()
-                    .expect("Player 2 Controller has no moves for first discard!"),
-            );
-            discards.push(
-                self.player_1
-                    .remove_card()
-                    .expect("Player 1 Controller has no moves for first discard!"),
-            );
+        GameView {
+            phase: self.phase,
+            points,
+            opponent_points,
+            target_score: self.options.target_score,
+            hand,
+            opponent_hand_size,
+            stack: self.round.play_data.stack.clone(),
+            stack_score: self.round.play_data.stack_score,
+            starter: self.round.starter.clone(),
+            is_dealer: self.round.is_dealer(player_1),
+            crib_size: self.round.crib.len(),
         }
-        let message = self.display.game_before_play_message(
-            /*starter=*/ None,
-            &self.player_1,
-            &self.player_2,
-        );
-
-        self.display.println(&message);
+    }
 
-        let crib = Hand::from(discards);
+    /// Returns the current [`Phase`] of the [`Game`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, Phase, Player, PredeterminedController};
+    ///
+    /// let controller = PredeterminedController::from(vec![0, 1, 2]);
+    ///
+    /// let player_1 = Player::new(controller.clone());
+    /// let player_2 = Player::new(controller);
+    ///
+    /// let game = Game::new(player_1, player_2);
+    ///
+    /// assert_eq!(game.phase(), Phase::Cut);
+    /// ```
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
 
-        if self.player_1_is_dealer {
-            self.player_1.crib = crib;
-        } else {
-            self.player_2.crib = crib;
+    /// Returns the [`Player`] whose [`Controller`] is asked first in the next [`Game::step`], or
+    /// [`None`] if nobody is asked.
+    ///
+    /// * [`Phase::Cut`]: player 1 cuts first, unless [`GameOptions::first_dealer`] doesn't cut.
+    /// * [`Phase::Deal`]: nobody, since the deal is automatic.
+    /// * [`Phase::Discard`]: the pone discards to the crib first, and then cuts the starter.
+    /// * [`Phase::Play`]: the pone leads.
+    /// * [`Phase::Count`] and [`Phase::Over`]: nobody.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{
+    ///     FirstDealer, Game, GameOptions, Phase, Player, PlayerId, RngController,
+    /// };
+    ///
+    /// let player_1 = Player::new(RngController::new_with_seed(1));
+    /// let player_2 = Player::new(RngController::new_with_seed(2));
+    ///
+    /// let options = GameOptions {
+    ///     first_dealer: FirstDealer::Fixed { player_1: true },
+    ///     ..GameOptions::default()
+    /// };
+    ///
+    /// let mut game = Game::new(player_1, player_2).with_options(options);
+    ///
+    /// assert_eq!(game.to_act(), None);
+    ///
+    /// game.step().unwrap();
+    /// game.step().unwrap();
+    ///
+    /// assert_eq!(game.phase(), Phase::Discard);
+    /// assert_eq!(game.to_act(), Some(PlayerId::Two));
+    /// ```
+    #[must_use]
+    pub fn to_act(&self) -> Option<PlayerId> {
+        let pone = PlayerId::from_player_1(!self.round.player_1_is_dealer);
+
+        match self.phase {
+            Phase::Cut if self.options.first_dealer.cuts() => Some(PlayerId::One),
+            Phase::Discard | Phase::Play => Some(pone),
+            Phase::Cut | Phase::Deal | Phase::Count | Phase::Over => None,
         }
     }
 
-    /// Return starter [`Card`], which is the [`Card`] at the top of the [`Deck`].
+    /// Returns the starter [`Card`], if it has been drawn this round.
     ///
-    /// If the starter is a [`Rank::Jack`], give 2 points to the dealer.
+    /// The starter is [`None`] until it's drawn in [`Game::discard_round`], and it's reset to
+    /// [`None`] at the start of each round.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// If [`Deck`] is empty.
-    fn get_starter(&mut self) -> Card {
-        let starter = self
-            .deck
-            .deal()
-            .expect("Could not get starter from empty deck!");
+    /// ```
+    /// use libterminal_cribbage::game::{Game, Player, RngController};
+    ///
+    /// let player_1 = Player::new(RngController::new());
+    /// let player_2 = Player::new(RngController::new());
+    ///
+    /// let mut game = Game::new(player_1, player_2);
+    ///
+    /// game.cut_round().unwrap();
+    /// game.deal_round().unwrap();
+    ///
+    /// assert!(game.starter().is_none());
+    ///
+    /// game.discard_round().unwrap();
+    ///
+    /// assert!(game.starter().is_some());
+    /// ```
+    pub fn starter(&self) -> Option<&Card> {
+        self.round.starter.as_ref()
+    }
 
-        if starter.rank == Rank::Jack {
-            self.player_1.points += 2;
-        }
+    /// Returns where every [`Card`] of the [`Game`] is, in the [`Deck`], a [`Hand`], a
+    /// [`Player::discarded`] pile, the [`Round::crib`], or the starter.
+    ///
+    /// After counting, the starter is back in the [`Deck`] for the next deal, so it's only
+    /// counted there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{CardLocation, Game, Player, RngController};
+    ///
+    /// let player_1 = Player::new(RngController::new_with_seed(1));
+    /// let player_2 = Player::new(RngController::new_with_seed(2));
+    ///
+    /// let mut game = Game::new(player_1, player_2);
+    ///
+    /// game.step().unwrap();
+    /// game.step().unwrap();
+    /// game.step().unwrap();
+    ///
+    /// let census = game.card_census();
+    ///
+    /// assert!(census.is_complete());
+    /// assert_eq!(
+    ///     census.location(game.starter().unwrap()),
+    ///     Some(CardLocation::Starter)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn card_census(&self) -> CardCensus {
+        let mut census = CardCensus::new();
 
-        let message =
-            self.display
-                .game_before_play_message(Some(&starter), &self.player_1, &self.player_2);
+        census.add_all(self.deck.as_vec(), CardLocation::Deck);
 
-        self.display.println(&message);
+        census.add_all(
+            self.player_1.hand.iter(),
+            CardLocation::Hand { player_1: true },
+        );
+        census.add_all(
+            &self.player_1.discarded,
+            CardLocation::Discarded { player_1: true },
+        );
+        census.add_all(
+            self.player_2.hand.iter(),
+            CardLocation::Hand { player_1: false },
+        );
+        census.add_all(
+            &self.player_2.discarded,
+            CardLocation::Discarded { player_1: false },
+        );
+
+        census.add_all(self.round.crib.iter(), CardLocation::Crib);
+
+        if self.phase != Phase::Deal {
+            census.add_all(&self.round.starter, CardLocation::Starter);
+        }
 
-        starter
+        census
     }
 
-    /// This method facilitates the play round.
+    /// Returns every [`GameEvent`] of the [`Game`] so far, in order.
     ///
-    /// Starting with the non-dealer (Pone) each [`Player`] puts a [`Card`] from his [`Hand`]
-    /// on the stack and the score is counted incrementally. All [`Player`]s must play as long as
-    /// the running score is not 31 or over. If one [`Player`] can't make a move, they pass (GO) to
-    /// the next [`Player`]. If both can't make a move, the running score is reset to zero, and the
-    /// last [`Player`] to put down a [`Card`] gets to put down another [`Card`]. This is until all
-    /// [`Card`]s are laid out
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, GameEvent, Player, RngController};
+    ///
+    /// let player_1 = Player::new(RngController::new());
+    /// let player_2 = Player::new(RngController::new());
+    ///
+    /// let mut game = Game::new(player_1, player_2);
+    ///
+    /// assert!(game.history().is_empty());
+    ///
+    /// let outcome = game.play_quiet().unwrap();
+    ///
+    /// let expected = GameEvent::GameOver {
+    ///     player_1_won: outcome.player_1_won,
+    /// };
+    ///
+    /// assert_eq!(game.history().last(), Some(&expected));
+    /// ```
+    #[must_use]
+    pub fn history(&self) -> &[GameEvent] {
+        &self.history
+    }
+
+    /// Takes the next step of the [`Game`], based on the current [`Phase`].
+    ///
+    /// Returns the [`Phase`] after the step. Once the [`Game`] is over, the game over message is
+    /// displayed, and any further steps do nothing.
+    ///
+    /// # Errors
+    ///
+    /// Any [`GameError`] from the step taken, see [`Game::cut_round`], [`Game::deal_round`],
+    /// [`Game::discard_round`], [`Game::play_round`], and [`Game::count_round`].
     ///
     /// # Panics
     ///
-    /// * If something goes wrong with counting turns or if this method exceeded 100 turns.
-    /// * If either [`Player::controller`] chooses a discard out of bounds of their [`Hand`]s.
-    fn run_play_round(&mut self, starter: &Card) {
-        let mut turn: usize = 0;
-        let mut play_data = PlayData::new();
+    /// If the [`Game`] has [`Game::with_census_checks`], and a [`Card`] was lost or duplicated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, Phase, Player, RngController};
+    ///
+    /// let player_1 = Player::new(RngController::new());
+    /// let player_2 = Player::new(RngController::new());
+    ///
+    /// let mut game = Game::new(player_1, player_2);
+    ///
+    /// assert_eq!(game.step(), Ok(Phase::Deal));
+    /// assert_eq!(game.step(), Ok(Phase::Discard));
+    /// ```
+    pub fn step(&mut self) -> Result<Phase, GameError> {
+        match self.phase {
+            Phase::Cut => self.cut_round()?,
+            Phase::Deal => self.deal_round()?,
+            Phase::Discard => self.discard_round()?,
+            Phase::Play => self.play_round()?,
+            Phase::Count => self.count_round()?,
+            Phase::Over => return Ok(Phase::Over),
+        }
 
-        while self.player_1.has_cards_in_hand() || self.player_2.has_cards_in_hand() {
-            let turn_is_odd = (turn % 2) == 1;
+        if self.census_checks {
+            let census = self.card_census();
 
-            let message = self.display.game_during_play_message(
-                starter,
-                &self.player_1,
-                &self.player_2,
-                &play_data,
+            assert!(
+                census.is_complete(),
+                "Cards were lost or duplicated in the {:?} phase! Missing: {:?}, duplicated: {:?}",
+                self.phase,
+                census.missing(),
+                census.duplicated()
             );
+        }
 
-            // Player 1's turn (i.e. TURN_IS_ODD XNOR PLAYER_1_IS_DEALER).
-            if turn_is_odd == self.player_1_is_dealer {
-                if self.player_1.has_cards_in_hand() {
-                    self.display.println(&message);
-                }
+        if self.phase == Phase::Over {
+            let player_1_won = self.player_1.points >= self.options.target_score;
 
-                play_data.play_once(&mut self.player_1, &self.player_2);
-            } else {
-                if self.player_2.has_cards_in_hand() {
-                    self.display.println(&message);
-                }
+            self.history.push(GameEvent::GameOver { player_1_won });
 
-                play_data.play_once(&mut self.player_2, &self.player_1);
-            }
+            let message = self.display.game_over_message(self.name(player_1_won));
 
-            if (121 <= self.player_1.points) || (121 <= self.player_2.points) {
-                break;
-            }
+            self.display.println(&message);
+        }
 
-            let message = self.display.game_during_play_message(
-                starter,
-                &self.player_1,
-                &self.player_2,
-                &play_data,
-            );
+        Ok(self.phase)
+    }
 
-            let reset = play_data.reset_if_needed(&self.player_1, &self.player_2);
+    /// Returns a [`GameSnapshot`] of the points, dealer, and [`Card`]s in play, so the [`Game`]
+    /// can be resumed later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, GameSnapshot, Phase, Player, PredeterminedController};
+    ///
+    /// let controller = PredeterminedController::from(vec![0, 1, 2]);
+    ///
+    /// let mut player_1 = Player::new(controller.clone());
+    /// player_1.points = 12;
+    ///
+    /// let player_2 = Player::new(controller);
+    ///
+    /// let game = Game::new(player_1, player_2);
+    ///
+    /// let expected = GameSnapshot {
+    ///     phase: Phase::Cut,
+    ///     player_1_points: 12,
+    ///     player_2_points: 0,
+    ///     player_1_is_dealer: true,
+    ///     player_1_hand: Vec::new(),
+    ///     player_2_hand: Vec::new(),
+    ///     crib: Vec::new(),
+    ///     starter: None,
+    /// };
+    ///
+    /// assert_eq!(game.snapshot(), expected);
+    /// ```
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            phase: self.phase,
+            player_1_points: self.player_1.points,
+            player_2_points: self.player_2.points,
+            player_1_is_dealer: self.round.player_1_is_dealer,
+            player_1_hand: self.player_1.hand_cards(),
+            player_2_hand: self.player_2.hand_cards(),
+            crib: self.round.crib.as_vec().clone(),
+            starter: self.round.starter.clone(),
+        }
+    }
 
-            if reset && (self.player_1.has_cards_in_hand() || self.player_2.has_cards_in_hand()) {
-                self.display.println(&(message + "\nGO!"));
-            } else if !reset {
-                turn += 1;
-            }
+    /// Resumes the [`Game`] from a [`GameSnapshot`].
+    ///
+    /// The points, dealer, hands, crib, and starter are restored, and the [`Game`] continues from
+    /// the [`GameSnapshot::phase`]. The [`Card`]s in play are taken out of the [`Deck`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Game, GameSnapshot, Phase, Player, RngController};
+    ///
+    /// let player_1 = Player::new(RngController::new_with_seed(1));
+    /// let player_2 = Player::new(RngController::new_with_seed(2));
+    ///
+    /// let hand = |suit| -> Vec<Card> {
+    ///     [Rank::Ace, Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+    ///         .iter()
+    ///         .map(|&rank| Card::new(rank, suit))
+    ///         .collect()
+    /// };
+    ///
+    /// let snapshot = GameSnapshot {
+    ///     phase: Phase::Discard,
+    ///     player_1_points: 42,
+    ///     player_2_points: 67,
+    ///     player_1_is_dealer: false,
+    ///     player_1_hand: hand(Suit::Hearts),
+    ///     player_2_hand: hand(Suit::Clubs),
+    ///     crib: Vec::new(),
+    ///     starter: None,
+    /// };
+    ///
+    /// let mut game = Game::new(player_1, player_2).with_seed(3).resume(&snapshot);
+    ///
+    /// assert_eq!(game.phase(), Phase::Discard);
+    /// assert_eq!(game.snapshot(), snapshot);
+    ///
+    /// // The same hands are discarded from, instead of a fresh deal.
+    /// game.step().unwrap();
+    ///
+    /// assert_eq!(game.phase(), Phase::Play);
+    /// assert!(game.card_census().is_complete());
+    /// ```
+    #[must_use]
+    pub fn resume(mut self, snapshot: &GameSnapshot) -> Game<C1, C2, D> {
+        self.player_1.points = snapshot.player_1_points;
+        self.player_2.points = snapshot.player_2_points;
+        self.round.player_1_is_dealer = snapshot.player_1_is_dealer;
+        self.phase = snapshot.phase;
 
-            // Panic if too many turns has taken place.
-            assert!(
-                100 >= turn,
-                "Too many turns!\nTurn: {}\nPlayData: {:?}\nDealer: {:?}\nPone: {:?}",
-                turn,
-                play_data,
-                self.player_1,
-                self.player_2
-            );
+        self.player_1.reset();
+        self.player_2.reset();
+
+        for card in &snapshot.player_1_hand {
+            self.player_1.add_card(card.clone());
         }
 
-        let message = self.display.game_during_play_message(
-            starter,
-            &self.player_1,
-            &self.player_2,
-            &play_data,
-        );
+        for card in &snapshot.player_2_hand {
+            self.player_2.add_card(card.clone());
+        }
 
-        self.display.println(&message);
+        self.round.crib = Hand::from(snapshot.crib.clone());
+        self.round.starter.clone_from(&snapshot.starter);
 
-        self.player_1.gather_discarded();
-        self.player_2.gather_discarded();
+        let in_play: Vec<&Card> = snapshot
+            .player_1_hand
+            .iter()
+            .chain(&snapshot.player_2_hand)
+            .chain(&snapshot.crib)
+            .chain(&snapshot.starter)
+            .collect();
+
+        let deck_cards = self
+            .deck
+            .as_vec()
+            .iter()
+            .filter(|card| !in_play.contains(card))
+            .cloned()
+            .collect();
+
+        self.deck = Deck::new_with_cards(deck_cards);
+
+        self.player_1_pegs.move_to(snapshot.player_1_points);
+        self.player_2_pegs.move_to(snapshot.player_2_points);
+
+        self
     }
 
-    /// This method facilitates the scoring round.
+    /// Cuts the [`Deck`] to choose the dealer, see [`Game::play`].
     ///
-    /// The [`Player`]s [`Hand`]s/cribs are scored, with the starter [`Card`], starting with the
-    /// Pone.
-    fn run_counting_round(&mut self, starter: &Card) {
-        let pone_points = if self.player_1_is_dealer {
-            self.player_2.points += self.player_2.hand.total(starter, /*is_crib=*/ false);
+    /// The pone then pegs the [`GameRules::pone_head_start`], like three for last in the five-card
+    /// game. Moves the [`Game`] from [`Phase::Cut`] to [`Phase::Deal`], or [`Phase::Over`] if that
+    /// wins.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::WrongPhase`] if the [`Game`] is not in [`Phase::Cut`].
+    /// * [`GameError::OutOfMoves`] if either [`Player::controller`] has no moves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Deck, Rank, Suit};
+    /// use libterminal_cribbage::game::{Game, Phase, Player, PredeterminedController};
+    ///
+    /// let controller = PredeterminedController::from(vec![0, 0]);
+    ///
+    /// let player_1 = Player::new(controller.clone());
+    /// let player_2 = Player::new(controller);
+    ///
+    /// let deck = Deck::new_with_cards(vec![
+    ///     Card::new(Rank::Ace, Suit::Hearts),
+    ///     Card::new(Rank::Two, Suit::Hearts),
+    /// ]);
+    ///
+    /// let mut game = Game::new_with_deck(player_1, player_2, deck);
+    ///
+    /// assert_eq!(game.cut_round(), Ok(()));
+    /// assert_eq!(game.phase(), Phase::Deal);
+    /// ```
+    pub fn cut_round(&mut self) -> Result<(), GameError> {
+        self.check_phase(Phase::Cut)?;
 
-            self.player_2.points
-        } else {
-            self.player_1.points += self.player_1.hand.total(starter, /*is_crib=*/ false);
+        self.choose_dealer()?;
+
+        self.add_points(
+            !self.round.player_1_is_dealer,
+            self.options.rules.pone_head_start,
+            ScoreReason::ThreeForLast,
+        );
 
-            self.player_1.points
+        self.show_board_if_scored();
+
+        self.phase = if self.player_has_won() {
+            Phase::Over
+        } else {
+            Phase::Deal
         };
 
-        // Skip counting dealer's hand if Pone has won.
-        if 121 <= pone_points {
-            let message =
-                self.display
-                    .game_during_counting_message(starter, &self.player_1, &self.player_2);
+        Ok(())
+    }
 
-            self.display.println(&message);
+    /// Deals the [`Hand`]s, see [`Game::play`].
+    ///
+    /// Moves the [`Game`] from [`Phase::Deal`] to [`Phase::Discard`].
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::WrongPhase`] if the [`Game`] is not in [`Phase::Deal`].
+    /// * [`GameError::EmptyDeck`] if there are not enough [`Card`]s in the [`Deck`].
+    pub fn deal_round(&mut self) -> Result<(), GameError> {
+        self.check_phase(Phase::Deal)?;
 
-            return;
-        }
+        self.round = Round::new(self.round.player_1_is_dealer);
 
-        // Player 1 is dealer.
-        if self.player_1_is_dealer {
-            self.player_1.points += self.player_1.hand.total(starter, /*is_crib=*/ false);
-            self.player_1.points += self.player_1.crib.total(starter, /*is_crib=*/ true);
-        } else {
-            self.player_2.points += self.player_2.hand.total(starter, /*is_crib=*/ false);
-            self.player_2.points += self.player_2.crib.total(starter, /*is_crib=*/ true);
+        if let Some(deal) = self.replay_deals.pop_front() {
+            self.arrange_deck_for(&deal);
         }
 
-        let message =
-            self.display
-                .game_during_counting_message(starter, &self.player_1, &self.player_2);
+        self.run_deal_round()?;
 
-        self.display.println(&message);
+        self.history.push(GameEvent::Deal {
+            player_1_is_dealer: self.round.player_1_is_dealer,
+            player_1_hand: self.player_1.hand_cards(),
+            player_2_hand: self.player_2.hand_cards(),
+        });
+
+        self.phase = Phase::Discard;
+
+        Ok(())
     }
 
-    /// Resets the [`Deck`].
+    /// Discards to the crib and cuts for the starter [`Card`], see [`Game::play`].
     ///
-    /// This will drain all the [`Card`]s from the dealer's and pone's [`Hand`] and
-    /// [`Player::crib`]. In addition to adding back in the starter [`Card`].
+    /// Moves the [`Game`] from [`Phase::Discard`] to [`Phase::Play`], or [`Phase::Over`] if the
+    /// dealer wins from a [`Rank::Jack`] starter.
     ///
-    /// Theoretically, this should be fine since all the [`Card`]s that the [`Player`]s have
-    /// came from the [`Deck`]. Same goes for the starter.
-    fn reset_deck(&mut self, starter: Card) {
-        let mut remaining_deck_cards = self.deck.as_vec().clone();
+    /// # Errors
+    ///
+    /// * [`GameError::WrongPhase`] if the [`Game`] is not in [`Phase::Discard`].
+    /// * [`GameError::OutOfMoves`] if either [`Player::controller`] has no moves.
+    /// * [`GameError::InvalidIndex`] if either [`Player::controller`] chooses a discard out of
+    ///   bounds of their [`Hand`]s.
+    /// * [`GameError::EmptyDeck`] if the [`Deck`] is empty.
+    pub fn discard_round(&mut self) -> Result<(), GameError> {
+        self.check_phase(Phase::Discard)?;
 
-        remaining_deck_cards.append(&mut self.player_1.remove_all());
+        self.run_discard_round()?;
 
-        remaining_deck_cards.append(&mut self.player_2.remove_all());
+        self.get_starter()?;
 
-        remaining_deck_cards.push(starter);
+        self.phase = if self.player_has_won() {
+            Phase::Over
+        } else {
+            Phase::Play
+        };
 
-        self.deck = Deck::new_with_cards(remaining_deck_cards);
+        Ok(())
     }
 
-    /// Resets the [`Game::deck`] with a given [`Deck`].
+    /// Plays (pegs) the [`Hand`]s, see [`Game::play`].
     ///
-    /// This will drain all the [`Card`]s from the dealer's and pone's [`Hand`] and
-    /// [`Player::crib`].
-    fn reset_deck_with(&mut self, deck: Deck) {
-        self.deck = deck;
+    /// Moves the [`Game`] from [`Phase::Play`] to [`Phase::Count`], or [`Phase::Over`] if a
+    /// [`Player`] wins.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::WrongPhase`] if the [`Game`] is not in [`Phase::Play`].
+    /// * [`GameError::TooManyTurns`] if the play round is stuck.
+    /// * [`GameError::InvalidPlay`] if either [`Player`] makes an invalid play.
+    /// * [`GameError::OutOfMoves`] or [`GameError::InvalidIndex`] if either [`Player::controller`]
+    ///   can't choose a [`Card`] in their [`Hand`]s.
+    pub fn play_round(&mut self) -> Result<(), GameError> {
+        self.check_phase(Phase::Play)?;
 
-        self.player_1.reset();
-        self.player_2.reset();
-    }
+        let starter = self.current_starter()?;
 
-    /// Alternate [`Deck::player_1_is_dealer`].
-    fn swap_dealer_and_pone(&mut self) {
-        self.player_1_is_dealer = !self.player_1_is_dealer;
-    }
-}
+        self.run_play_round(&starter)?;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        self.phase = if self.player_has_won() {
+            Phase::Over
+        } else {
+            Phase::Count
+        };
 
-    use crate::cards::{Card, Deck, Rank, Suit};
-    use crate::game::{Player, PredeterminedController};
+        Ok(())
+    }
 
-    #[test]
-    fn test_game_choose_dealer_player_1_wins_higher_value() {
+    /// Counts the [`Hand`]s and crib, see [`Game::play`].
+    ///
+    /// Moves the [`Game`] from [`Phase::Count`] to [`Phase::Over`] if a [`Player`] wins.
+    /// Otherwise, the [`Deck`] is reset, the dealer and pone are swapped, and the [`Game`] moves
+    /// to [`Phase::Deal`].
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::WrongPhase`] if the [`Game`] is not in [`Phase::Count`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, GameError, Phase, Player, PredeterminedController};
+    ///
+    /// let controller = PredeterminedController::from(vec![0, 1, 2]);
+    ///
+    /// let player_1 = Player::new(controller.clone());
+    /// let player_2 = Player::new(controller);
+    ///
+    /// let mut game = Game::new(player_1, player_2);
+    ///
+    /// let expected = Err(GameError::WrongPhase {
+    ///     expected: Phase::Count,
+    ///     actual: Phase::Cut,
+    /// });
+    ///
+    /// assert_eq!(game.count_round(), expected);
+    /// ```
+    pub fn count_round(&mut self) -> Result<(), GameError> {
+        self.check_phase(Phase::Count)?;
+
+        let starter = self.current_starter()?;
+
+        self.run_counting_round(&starter);
+
+        if self.player_has_won() {
+            self.phase = Phase::Over;
+        } else {
+            self.reset_deck(starter);
+            self.swap_dealer_and_pone();
+
+            self.phase = Phase::Deal;
+        }
+
+        Ok(())
+    }
+
+    /// Play the default game.
+    ///
+    /// This is simply calls [`Game::play`], but with `reset_with_deck` set to [`None`].
+    ///
+    /// # Errors
+    ///
+    /// Any [`GameError`] from [`Game::play`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, GameError, Player, PredeterminedController};
+    ///
+    /// let controller = PredeterminedController::from(vec![0, 1, 2]);
+    ///
+    /// let player_1 = Player::new(controller.clone());
+    /// let player_2 = Player::new(controller);
+    ///
+    /// let mut game = Game::new(player_1, player_2);
+    ///
+    /// // The controller does not have enough moves to play a game.
+    /// let result = game.play_default();
+    ///
+    /// assert!(matches!(result, Err(GameError::OutOfMoves(_))));
+    /// ```
+    pub fn play_default(&mut self) -> Result<GameOutcome, GameError> {
+        self.play(&None)
+    }
+
+    /// Play the default game, like [`Game::play_default`], but a [`Player::controller`] running
+    /// out of moves is a [`GameError::ControllerExhausted`], with the [`Phase`] and [`Player`].
+    ///
+    /// This is for scripted [`Controller`]s, like [`PredeterminedController`], so a script that
+    /// is too short says where it ran out.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::ControllerExhausted`] if a [`Player::controller`] runs out of moves.
+    /// * Any other [`GameError`] from [`Game::play`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, GameError, Phase, Player, PredeterminedController};
+    ///
+    /// let controller = PredeterminedController::from(vec![0, 1, 2]);
+    ///
+    /// let player_1 = Player::new(controller.clone());
+    /// let player_2 = Player::new(controller);
+    ///
+    /// let mut game = Game::new(player_1, player_2).with_seed(7);
+    ///
+    /// let expected = Err(GameError::ControllerExhausted {
+    ///     phase: Phase::Play,
+    ///     player_1: true,
+    /// });
+    ///
+    /// assert_eq!(game.play_checked(), expected);
+    /// ```
+    pub fn play_checked(&mut self) -> Result<GameOutcome, GameError> {
+        let result = self.play(&None);
+
+        result.map_err(|error| error.exhausted_in(self.phase))
+    }
+
+    /// Play the full game.
+    ///
+    /// The `reset_with_deck` parameter is for testing. If [`Some`], then instead of using
+    /// [`Game::reset_deck`] and shuffling, it will just set [`Game::deck`] to the given
+    /// [`Option<Deck>`].
+    ///
+    /// How the play works:
+    /// * Each [`Player`] chooses a random [`Card`] from [`Deck`]. The lowest value [`Card`] deals,
+    ///   and gets the crib, unless [`GameOptions::first_dealer`] says otherwise. [`Card`] suit
+    ///   order is [`Suit::Hearts`], [`Suit::Spades`], [`Suit::Diamonds`], [`Suit::Clubs`].
+    /// * The [`Deck`] is shuffled and each [`Player`] is dealt 6 [`Card`]s.
+    /// * The [`Player`]s choose 2 [`Card`]s to discard. These [`Card`]s are put into the
+    ///   [`Round::crib`], which belongs to the dealer.
+    /// * The pone cuts the [`Deck`], and the [`Card`] just below the cut is the starter [`Card`].
+    /// * If this [`Card`] is a [`Rank::Jack`], the dealer gets two points.
+    /// * Starting with the non-dealer (Pone) each [`Player`] puts a [`Card`] from their [`Hand`]
+    ///   on the stack and the score is counted incrementally. All [`Player`]s must play as long as
+    ///   the running score is not 31 or over. If one [`Player`] can't make a move, they pass (GO)
+    ///   to the next [`Player`]. If both can't make a move, or the running score is 31, the running
+    ///   score is reset to zero, and the other [`Player`] from the last to put down a [`Card`]
+    ///   leads the new count. This is until all [`Card`]s are laid out.
+    /// * Afterwards the [`Player`]s [`Hand`]s/cribs are scored, with the starter [`Card`], starting
+    ///   with the Pone.
+    /// * If neither [`Player`]s score is the [`GameOptions::target_score`] (121 by default), then
+    ///   switch dealer and loop from dealing [`Card`]s step.
+    ///
+    /// # Errors
+    ///
+    /// Any [`GameError`] from a [`Game::step`], or [`GameError::TooManyRounds`] if there have been
+    /// 1,000 rounds, indicating that the game is broken and can't end loop.
+    pub fn play(&mut self, reset_with_deck: &Option<Deck>) -> Result<GameOutcome, GameError> {
+        let mut round = 0;
+
+        while self.phase != Phase::Over {
+            let previous_phase = self.phase;
+
+            if previous_phase == Phase::Deal {
+                round += 1;
+            }
+
+            self.step()?;
+
+            if (previous_phase == Phase::Count) && (self.phase == Phase::Deal) {
+                if let Some(ref deck) = reset_with_deck {
+                    self.reset_deck_with(deck.clone());
+                }
+
+                if 1_000 <= round {
+                    return Err(GameError::TooManyRounds);
+                }
+            }
+        }
+
+        Ok(self.outcome(round))
+    }
+
+    /// Returns the [`GameOutcome`] after the number of rounds, from the points of each [`Player`].
+    pub(crate) fn outcome(&self, rounds: u32) -> GameOutcome {
+        GameOutcome {
+            player_1_won: self.player_1.points >= self.options.target_score,
+            player_1_points: self.player_1.points,
+            player_2_points: self.player_2.points,
+            rounds,
+        }
+    }
+
+    /// Creates a new [`Game`] in [`Phase::Cut`] from its parts, with the default
+    /// [`GameOptions`].
+    ///
+    /// Every constructor builds on this, so a new field only has to be set here.
+    fn from_parts(
+        player_1: Player<C1>,
+        player_2: Player<C2>,
+        deck: Deck,
+        display: D,
+        rng: SmallRng,
+    ) -> Game<C1, C2, D> {
+        Game {
+            player_1,
+            player_2,
+            round: Round::default(),
+            deck,
+            display,
+            options: GameOptions::default(),
+            phase: Phase::Cut,
+            player_1_pegs: Pegs::default(),
+            player_2_pegs: Pegs::default(),
+            history: Vec::new(),
+            replay_deals: VecDeque::new(),
+            undo_stack: Vec::new(),
+            rng,
+            census_checks: false,
+        }
+    }
+
+    /// Returns [`GameError::WrongPhase`] if the [`Game`] is not in the expected [`Phase`].
+    fn check_phase(&self, expected: Phase) -> Result<(), GameError> {
+        if self.phase == expected {
+            Ok(())
+        } else {
+            Err(GameError::WrongPhase {
+                expected,
+                actual: self.phase,
+            })
+        }
+    }
+
+    /// Returns the starter [`Card`] cut in the [`Phase::Discard`].
+    fn current_starter(&self) -> Result<Card, GameError> {
+        self.round.starter.clone().ok_or(GameError::WrongPhase {
+            expected: Phase::Discard,
+            actual: self.phase,
+        })
+    }
+
+    /// Chose dealer and pone.
+    ///
+    /// Unless [`GameOptions::first_dealer`] is [`FirstDealer::Fixed`] or [`FirstDealer::Random`],
+    /// this is done by having each [`Player`] choose a [`Card`] from the [`Deck`].
+    /// * The lowest value [`Card`] deals, or the highest for [`FirstDealer::CutWinner`].
+    /// * If both [`Card`]s have the same [`Rank`], both [`Player`]s cut again from the whole
+    ///   [`Deck`]. Only the cut that chose the dealer is recorded as a [`GameEvent::Cut`].
+    /// * Unless [`GameRules::suit_breaks_cut_ties`] is set, where the higher [`Suit`] wins
+    ///   instead.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::OutOfMoves`] if either [`Player::controller`] has no moves.
+    /// * [`GameError::InvalidIndex`] if either [`Player::controller`] returns an index that is out
+    ///   of bounds of the [`Deck`].
+    fn choose_dealer(&mut self) -> Result<(), GameError> {
+        match self.options.first_dealer {
+            FirstDealer::Fixed { player_1 } => {
+                self.round.player_1_is_dealer = player_1;
+
+                return Ok(());
+            }
+            FirstDealer::Random => {
+                self.round.player_1_is_dealer = self.rng.gen();
+
+                return Ok(());
+            }
+            FirstDealer::CutLoser | FirstDealer::CutWinner => {}
+        }
+
+        let (player_1_chosen_card, player_2_chosen_card) = loop {
+            let mut temp_deck = self.deck.clone();
+
+            let player_1_chosen_card = self
+                .player_1
+                .choose_card_for_cut(&mut temp_deck)?
+                .ok_or_else(|| GameError::out_of_moves(/*player_1=*/ true))?;
+            let player_2_chosen_card = self
+                .player_2
+                .choose_card_for_cut(&mut temp_deck)?
+                .ok_or_else(|| GameError::out_of_moves(/*player_1=*/ false))?;
+
+            self.record_timeouts();
+
+            let message = self.display.game_cut_reveal_message(
+                self.name(/*player_1=*/ true),
+                self.name(/*player_1=*/ false),
+            );
+
+            self.display.println(&message);
+
+            if self.options.rules.suit_breaks_cut_ties
+                || player_1_chosen_card.rank != player_2_chosen_card.rank
+            {
+                break (player_1_chosen_card, player_2_chosen_card);
+            }
+
+            let message = self.display.game_recut_message(
+                self.name(/*player_1=*/ true),
+                &player_1_chosen_card,
+                self.name(/*player_1=*/ false),
+                &player_2_chosen_card,
+            );
+
+            self.display.println(&message);
+        };
+
+        let player_1_cut_higher = player_1_chosen_card > player_2_chosen_card;
+
+        self.round.player_1_is_dealer =
+            player_1_cut_higher == (self.options.first_dealer == FirstDealer::CutWinner);
+
+        self.history.push(GameEvent::Cut {
+            player_1_card: player_1_chosen_card.clone(),
+            player_2_card: player_2_chosen_card.clone(),
+            player_1_is_dealer: self.round.player_1_is_dealer,
+        });
+
+        let message = self.display.game_after_cut_message(
+            self.name(/*player_1=*/ true),
+            &player_1_chosen_card,
+            self.name(/*player_1=*/ false),
+            &player_2_chosen_card,
+            self.round.player_1_is_dealer,
+        );
+
+        self.display.println(&message);
+
+        Ok(())
+    }
+
+    /// Indicates that the game is won by [`Deck::dealer`] or [`Deck::pone`].
+    ///
+    /// If either [`Player`] has at least [`GameOptions::target_score`] points, the game is won for
+    /// them.
+    fn player_has_won(&self) -> bool {
+        self.player_1.points.max(self.player_2.points) >= self.options.target_score
+    }
+
+    /// Returns the seats (`0` for player 1 and `1` for player 2) in the order they are dealt to.
+    ///
+    /// Clockwise, the deal starts at [`GameOptions::first_dealt_to`]. Counter-clockwise, the same
+    /// order is reversed, so [`GameOptions::first_dealt_to`] is dealt to last.
+    fn deal_order(&self) -> Vec<usize> {
+        let number_of_seats = 2;
+        let dealer_seat = usize::from(!self.round.player_1_is_dealer);
+
+        let first_seat = match self.options.first_dealt_to {
+            FirstDealtTo::Pone => (dealer_seat + 1) % number_of_seats,
+            FirstDealtTo::Dealer => dealer_seat,
+            FirstDealtTo::PlayerOne => 0,
+            FirstDealtTo::PlayerTwo => 1,
+        };
+
+        let mut deal_order: Vec<usize> = (0..number_of_seats)
+            .map(|offset| (first_seat + offset) % number_of_seats)
+            .collect();
+
+        if self.options.deal_direction == DealDirection::CounterClockwise {
+            deal_order.reverse();
+        }
+
+        deal_order
+    }
+
+    /// This method deals the [`Player`]s their [`Hand`]s.
+    ///
+    /// Each [`Player`] is dealt [`GameRules::cards_dealt`] [`Card`]s, one at a time, in the order
+    /// from [`Game::deal_order`], which starts with the pone by default. Then each [`Hand`] is
+    /// sorted by [`GameOptions::hand_sort`].
+    ///
+    /// # Errors
+    ///
+    /// [`GameError::EmptyDeck`] if there are not enough [`Card`]s in the [`Deck`] to deal to both
+    /// [`Player`]s.
+    fn run_deal_round(&mut self) -> Result<(), GameError> {
+        let deal_order = self.deal_order();
+
+        let hands = self
+            .deck
+            .deal_hands(deal_order.len(), self.options.rules.cards_dealt)
+            .map_err(|_| GameError::EmptyDeck)?;
+
+        for (seat, hand) in deal_order.iter().zip(hands) {
+            for card in hand {
+                if *seat == 0 {
+                    self.player_1.add_card(card);
+                } else {
+                    self.player_2.add_card(card);
+                }
+            }
+        }
+
+        self.options.hand_sort.sort(&mut self.player_1.hand);
+        self.options.hand_sort.sort(&mut self.player_2.hand);
+
+        Ok(())
+    }
+
+    /// Arranges the [`Deck`] so the [`ReplayDeal`] is dealt, and its starter is cut at the same
+    /// depth.
+    fn arrange_deck_for(&mut self, deal: &ReplayDeal) {
+        let deal_order = self.deal_order();
+
+        let mut dealt = Vec::new();
+
+        for index in 0..self.options.rules.cards_dealt {
+            for seat in &deal_order {
+                let hand = if *seat == 0 {
+                    &deal.player_1_hand
+                } else {
+                    &deal.player_2_hand
+                };
+
+                dealt.extend(hand.get(index).cloned());
+            }
+        }
+
+        let mut cards: Vec<Card> = self
+            .deck
+            .as_vec()
+            .iter()
+            .filter(|card| !dealt.contains(card) && (**card != deal.starter))
+            .cloned()
+            .collect();
+
+        // The starter is cut from the back, below the recorded depth.
+        let starter_index = cards.len().saturating_sub(deal.starter_depth);
+
+        cards.insert(starter_index, deal.starter.clone());
+
+        // The deck is dealt from the back.
+        cards.extend(dealt.into_iter().rev());
+
+        self.deck = Deck::new_with_cards(cards);
+    }
+
+    /// This method facilitates the [`Player`]s discarding for cribs.
+    ///
+    /// Like at the table, the pone discards first, and then the dealer. Each [`Player`] chooses
+    /// both [`Card`]s to discard at once, which are put into the [`Round::crib`], which belongs to
+    /// the dealer, before the other [`Player`] chooses.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::OutOfMoves`] if either [`Player::controller`] has no moves.
+    /// * [`GameError::InvalidIndex`] if either [`Player::controller`] chooses a discard out of
+    ///   bounds of their [`Hand`]s.
+    fn run_discard_round(&mut self) -> Result<(), GameError> {
+        let message = self.display.game_discard_message(
+            &self.player_1,
+            &self.player_2,
+            self.round.player_1_is_dealer,
+        );
+
+        self.display.println(&message);
+
+        let pone_is_player_1 = !self.round.player_1_is_dealer;
+
+        self.discard_to_crib(pone_is_player_1)?;
+        self.discard_to_crib(!pone_is_player_1)?;
+
+        let message =
+            self.display
+                .game_before_play_message(&self.round, &self.player_1, &self.player_2);
+
+        self.display.println(&message);
+
+        Ok(())
+    }
+
+    /// Has player 1 (if `player_1`, or player 2 otherwise) discard both [`Card`]s to the
+    /// [`Round::crib`] in one decision, which may be taken back if [`GameOptions::allow_undo`] is
+    /// set.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::OutOfMoves`] if the [`Player::controller`] has no moves.
+    /// * [`GameError::InvalidIndex`] if the [`Player::controller`] chooses a discard out of
+    ///   bounds of their [`Hand`].
+    fn discard_to_crib(&mut self, player_1: bool) -> Result<(), GameError> {
+        let is_dealer = self.round.is_dealer(player_1);
+
+        let discards = loop {
+            self.push_undo(player_1);
+
+            let discards = if player_1 {
+                self.player_1.remove_cards_for_crib(is_dealer)?
+            } else {
+                self.player_2.remove_cards_for_crib(is_dealer)?
+            };
+
+            let discards = discards.ok_or_else(|| GameError::out_of_moves(player_1))?;
+
+            self.record_timeouts();
+
+            if !self.undo_if_wanted(player_1, &discards) {
+                break discards;
+            }
+        };
+
+        if self.options.show_crib_discards {
+            let message =
+                self.display
+                    .game_crib_discards_message(&discards, self.name(player_1), is_dealer);
+
+            self.display.println(&message);
+        }
+
+        for card in discards {
+            self.history.push(GameEvent::Discard {
+                player_1,
+                card: card.clone(),
+            });
+
+            self.round.crib.add_card(card);
+        }
+
+        Ok(())
+    }
+
+    /// Return starter [`Card`], which is the [`Card`] just below where the pone cuts the [`Deck`].
+    ///
+    /// The starter is also kept for [`Game::starter`]. If the starter is a [`Rank::Jack`], give 2
+    /// points to the dealer.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::EmptyDeck`] if the [`Deck`] is empty.
+    /// * [`GameError::OutOfMoves`] if the pone's [`Player::controller`] has no depth to cut.
+    /// * [`GameError::InvalidIndex`] if the pone cuts deeper than the [`Deck`].
+    fn get_starter(&mut self) -> Result<Card, GameError> {
+        let len = self.deck.as_vec().len();
+
+        if len == 0 {
+            return Err(GameError::EmptyDeck);
+        }
+
+        let pone_is_player_1 = !self.round.player_1_is_dealer;
+
+        let possible_depth = if pone_is_player_1 {
+            self.player_1.choose_starter_cut_depth(&self.deck)?
+        } else {
+            self.player_2.choose_starter_cut_depth(&self.deck)?
+        };
+
+        let depth = possible_depth.ok_or_else(|| GameError::out_of_moves(pone_is_player_1))?;
+
+        if len <= depth {
+            return Err(GameError::InvalidIndex { index: depth, len });
+        }
+
+        self.history.push(GameEvent::CutForStarter {
+            player_1: pone_is_player_1,
+            depth,
+        });
+
+        self.record_timeouts();
+
+        let message = self
+            .display
+            .game_starter_cut_message(depth, self.name(pone_is_player_1));
+
+        self.display.println(&message);
+
+        // The dealer turns the top card of the bottom packet, once it's back on top.
+        self.deck
+            .cut_and_restack(depth)
+            .map_err(|_| GameError::InvalidIndex { index: depth, len })?;
+
+        let starter = self.deck.deal().ok_or(GameError::EmptyDeck)?;
+
+        self.history.push(GameEvent::StarterCut {
+            card: starter.clone(),
+        });
+
+        self.add_points(
+            self.round.player_1_is_dealer,
+            his_heels(&starter),
+            ScoreReason::HisHeels,
+        );
+
+        self.round.starter = Some(starter.clone());
+
+        let message =
+            self.display
+                .game_before_play_message(&self.round, &self.player_1, &self.player_2);
+
+        self.display.println(&message);
+
+        self.show_board_if_scored();
+
+        Ok(starter)
+    }
+
+    /// This method facilitates the play round.
+    ///
+    /// Starting with the non-dealer (Pone) each [`Player`] puts a [`Card`] from his [`Hand`]
+    /// on the stack and the score is counted incrementally. All [`Player`]s must play as long as
+    /// the running score is not 31 or over. If one [`Player`] can't make a move, they pass (GO) to
+    /// the next [`Player`]. If both can't make a move, or the running score is 31, the running
+    /// score is reset to zero, and the other [`Player`] from the last to put down a [`Card`] leads
+    /// the new count. This is until all [`Card`]s are laid out. The turns are taken by a
+    /// [`PeggingRound`].
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::TooManyTurns`] if this method exceeded 100 turns.
+    /// * [`GameError::InvalidPlay`] if either [`Player`] makes an invalid play.
+    /// * [`GameError::OutOfMoves`] or [`GameError::InvalidIndex`] if either [`Player::controller`]
+    ///   can't choose a [`Card`] in their [`Hand`]s.
+    fn run_play_round(&mut self, starter: &Card) -> Result<(), GameError> {
+        self.round.play_data = PlayData::new();
+        self.round.play_data.allow_intentional_pass = self.options.allow_intentional_pass;
+        self.round.play_data.target = self.options.target_score;
+        self.round.play_data.starter = Some(starter.clone());
+
+        let mut pegging = PeggingRound::new(self.round.player_1_is_dealer);
+
+        while !pegging.is_over(&self.player_1, &self.player_2) {
+            let player_1_turn = pegging.player_1_turn();
+
+            let message =
+                self.display
+                    .game_during_play_message(&self.round, &self.player_1, &self.player_2);
+
+            self.push_undo(player_1_turn);
+
+            let has_cards_in_hand = if player_1_turn {
+                self.player_1.has_cards_in_hand()
+            } else {
+                self.player_2.has_cards_in_hand()
+            };
+
+            if has_cards_in_hand {
+                self.display.println(&message);
+            }
+
+            let possible_event = pegging.take_turn(
+                &mut self.round.play_data,
+                &mut self.player_1,
+                &mut self.player_2,
+            )?;
+
+            self.record_timeouts();
+
+            if let Some(card) = possible_event.as_ref().and_then(PeggingEvent::card) {
+                if self.undo_if_wanted(player_1_turn, slice::from_ref(card)) {
+                    continue;
+                }
+            }
+
+            if let Some(ref event) = possible_event {
+                let message = self.display.play_count_message(
+                    self.name(player_1_turn),
+                    event.card(),
+                    self.round.play_data.stack_score,
+                );
+
+                self.display.println(&message);
+
+                if let PeggingEvent::Play { ref breakdown, .. } = event {
+                    if breakdown.total() != 0 {
+                        let message = self
+                            .display
+                            .play_score_message(breakdown, self.name(player_1_turn));
+
+                        self.display.println(&message);
+                    }
+                }
+
+                self.record_play(player_1_turn, event.card().cloned());
+            }
+
+            let points = possible_event.as_ref().map_or(0, PeggingEvent::points);
+
+            let winner = self.add_points(player_1_turn, points, ScoreReason::Pegging);
+
+            self.show_board_if_scored();
+
+            if winner.is_some() {
+                break;
+            }
+
+            let possible_reset =
+                pegging.end_turn(&mut self.round.play_data, &self.player_1, &self.player_2);
+
+            if let Some(PeggingEvent::Reset { thirty_one }) = possible_reset {
+                if self.player_1.has_cards_in_hand() || self.player_2.has_cards_in_hand() {
+                    let message = self.display.play_reset_message(thirty_one);
+
+                    self.display.println(&message);
+                }
+            }
+        }
+
+        let message =
+            self.display
+                .game_during_play_message(&self.round, &self.player_1, &self.player_2);
+
+        self.display.println(&message);
+
+        self.player_1.gather_discarded();
+        self.player_2.gather_discarded();
+
+        Ok(())
+    }
+
+    /// This method facilitates the scoring round.
+    ///
+    /// The [`Player`]s [`Hand`]s and the [`Round::crib`] are scored, with the starter [`Card`], in
+    /// [`counting_order`]. That is starting with the Pone, and ending with the dealer's crib. If a
+    /// [`Player`] reaches 121 points, no one else counts.
+    fn run_counting_round(&mut self, starter: &Card) {
+        let dealer_seat = usize::from(!self.round.player_1_is_dealer);
+
+        for seat in counting_order(/*number_of_seats=*/ 2, dealer_seat) {
+            let is_dealer = seat == dealer_seat;
+            let player_1 = seat == 0;
+
+            let hand = if player_1 {
+                self.player_1.hand_cards()
+            } else {
+                self.player_2.hand_cards()
+            };
+            let crib = self.round.crib.as_vec().clone();
+
+            let hand_points = self.record_count(player_1, hand, starter, /*is_crib=*/ false);
+
+            let mut winner = self.add_points(player_1, hand_points, ScoreReason::Hand);
+
+            // The crib is only counted if the hand didn't win.
+            if is_dealer && !crib.is_empty() && winner.is_none() {
+                let crib_points =
+                    self.record_count(player_1, crib, starter, /*is_crib=*/ true);
+
+                winner = self.add_points(player_1, crib_points, ScoreReason::Crib);
+            }
+
+            self.show_board_if_scored();
+
+            if winner.is_some() {
+                break;
+            }
+        }
+
+        let message =
+            self.display
+                .game_during_counting_message(&self.round, &self.player_1, &self.player_2);
+
+        self.display.println(&message);
+
+        if let Some(summary) =
+            RoundSummary::from_history(&self.history, self.player_1.points, self.player_2.points)
+        {
+            let message = self.display.round_summary_message(
+                &summary,
+                self.name(/*player_1=*/ true),
+                self.name(/*player_1=*/ false),
+            );
+
+            self.display.println(&message);
+        }
+    }
+
+    /// Adds the points to player 1 if `player_1`, or player 2 otherwise, with a [`Scoreboard`].
+    ///
+    /// Returns the [`Winner`] as soon as either [`Player`] reaches
+    /// [`GameOptions::target_score`].
+    fn add_points(&mut self, player_1: bool, points: u32, reason: ScoreReason) -> Option<Winner> {
+        Scoreboard::new(
+            &mut self.player_1.points,
+            &mut self.player_2.points,
+            &mut self.history,
+            self.options.target_score,
+        )
+        .add_points(player_1, points, reason)
+    }
+
+    /// Records a [`GameEvent::PlayCard`], or [`GameEvent::Go`].
+    fn record_play(&mut self, player_1: bool, played_card: Option<Card>) {
+        match played_card {
+            Some(card) => self.history.push(GameEvent::PlayCard { player_1, card }),
+            None => self.history.push(GameEvent::Go { player_1 }),
+        }
+    }
+
+    /// Records a [`GameEvent::Timeout`] for each [`Player`] whose last choice ran out of time.
+    fn record_timeouts(&mut self) {
+        if self.player_1.take_timed_out() {
+            self.history.push(GameEvent::Timeout { player_1: true });
+        }
+
+        if self.player_2.take_timed_out() {
+            self.history.push(GameEvent::Timeout { player_1: false });
+        }
+    }
+
+    /// Records a [`GameEvent::HandCount`], returning the points to score.
+    fn record_count(
+        &mut self,
+        player_1: bool,
+        cards: Vec<Card>,
+        starter: &Card,
+        is_crib: bool,
+    ) -> u32 {
+        let points = Hand::from(cards.clone()).total(starter, is_crib);
+
+        self.history.push(GameEvent::HandCount {
+            player_1,
+            cards,
+            is_crib,
+            points,
+        });
+
+        points
+    }
+
+    /// Moves the [`Pegs`] of any [`Player`] who scored, and displays the board if any moved.
+    fn show_board_if_scored(&mut self) {
+        let player_1_moved = self.player_1_pegs.move_to(self.player_1.points);
+        let player_2_moved = self.player_2_pegs.move_to(self.player_2.points);
+
+        if player_1_moved || player_2_moved {
+            let message = self.display.board_message(
+                self.name(/*player_1=*/ true),
+                &self.player_1_pegs,
+                self.name(/*player_1=*/ false),
+                &self.player_2_pegs,
+            );
+
+            self.display.println(&message);
+        }
+    }
+
+    /// Returns the name of player 1 (if `player_1`, or player 2 otherwise) to display, which is
+    /// [`DEFAULT_PLAYER_NAME`] or [`DEFAULT_OPPONENT_NAME`] if they haven't been named.
+    fn name(&self, player_1: bool) -> &str {
+        if player_1 {
+            self.player_1.name_or(DEFAULT_PLAYER_NAME)
+        } else {
+            self.player_2.name_or(DEFAULT_OPPONENT_NAME)
+        }
+    }
+
+    /// Pushes player 1's (if `player_1`, or player 2's otherwise) [`Card`]s and the stack onto the
+    /// undo stack, before they choose, if [`GameOptions::allow_undo`] is set.
+    fn push_undo(&mut self, player_1: bool) {
+        if !self.options.allow_undo {
+            return;
+        }
+
+        let (hand, discarded) = if player_1 {
+            (self.player_1.hand.clone(), self.player_1.discarded.clone())
+        } else {
+            (self.player_2.hand.clone(), self.player_2.discarded.clone())
+        };
+
+        self.undo_stack.push(UndoEntry {
+            player_1,
+            hand,
+            discarded,
+            play_data: self.round.play_data.clone(),
+        });
+    }
+
+    /// Asks player 1 (if `player_1`, or player 2 otherwise) if they want to take back the
+    /// [`Card`]s just chosen, and if so, restores them from the undo stack.
+    ///
+    /// Returns `true` if the choice was undone, and the [`Player`] should choose again. Otherwise,
+    /// the choice is final, so the undo stack is cleared before the opponent acts.
+    fn undo_if_wanted(&mut self, player_1: bool, chosen: &[Card]) -> bool {
+        let wants_undo = self.options.allow_undo
+            && if player_1 {
+                self.player_1.wants_undo(chosen)
+            } else {
+                self.player_2.wants_undo(chosen)
+            };
+
+        self.record_timeouts();
+
+        let entry = match self.undo_stack.pop() {
+            Some(entry) if wants_undo && (entry.player_1 == player_1) => entry,
+            _ => {
+                self.undo_stack.clear();
+
+                return false;
+            }
+        };
+
+        if player_1 {
+            self.player_1.hand = entry.hand;
+            self.player_1.discarded = entry.discarded;
+        } else {
+            self.player_2.hand = entry.hand;
+            self.player_2.discarded = entry.discarded;
+        }
+
+        self.round.play_data = entry.play_data;
+
+        let message = self.display.game_undo_message(chosen, self.name(player_1));
+
+        self.display.println(&message);
+
+        true
+    }
+
+    /// Resets the [`Deck`] for the next round, gathering every [`Card`] with
+    /// [`Game::gather_deck`], and then shuffling all of them with the [`Game`]'s RNG.
+    ///
+    /// Like a real deal, the next [`Hand`]s don't depend on the last ones. The RNG is seeded by
+    /// [`Game::with_seed`], so a seeded [`Game`] still deals the same every time.
+    fn reset_deck(&mut self, starter: Card) {
+        self.gather_deck(starter);
+
+        self.deck.shuffle_with_rng(&mut self.rng);
+    }
+
+    /// Gathers the [`Deck`], without shuffling it.
+    ///
+    /// This will drain all the [`Card`]s from the dealer's and pone's [`Hand`] and the
+    /// [`Round::crib`]. In addition to adding back in the starter [`Card`]. The order is the
+    /// [`Deck`], then the [`Hand`]s, the crib, and the starter, so tests can predict it.
+    ///
+    /// Theoretically, this should be fine since all the [`Card`]s that the [`Player`]s have
+    /// came from the [`Deck`]. Same goes for the starter.
+    fn gather_deck(&mut self, starter: Card) {
+        let mut remaining_deck_cards = self.deck.as_vec().clone();
+
+        remaining_deck_cards.append(&mut self.player_1.remove_all());
+
+        remaining_deck_cards.append(&mut self.player_2.remove_all());
+
+        remaining_deck_cards.extend(mem::take(&mut self.round.crib));
+
+        remaining_deck_cards.push(starter);
+
+        self.deck = Deck::new_with_cards(remaining_deck_cards);
+    }
+
+    /// Resets the [`Game::deck`] with a given [`Deck`].
+    ///
+    /// This will drain all the [`Card`]s from the dealer's and pone's [`Hand`] and the
+    /// [`Round::crib`].
+    fn reset_deck_with(&mut self, deck: Deck) {
+        self.deck = deck;
+
+        self.round.crib = Hand::new();
+
+        self.player_1.reset();
+        self.player_2.reset();
+    }
+
+    /// Alternate [`Round::player_1_is_dealer`], without moving or cloning either [`Player`].
+    fn swap_dealer_and_pone(&mut self) {
+        self.round.player_1_is_dealer = !self.round.player_1_is_dealer;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::cards::{Card, Deck, Rank, Suit};
+    use crate::game::{Player, PredeterminedController, ReplayController, RngController};
+
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    /// A [`Display`] that records every printed message, but returns only the crib discard
+    /// messages.
+    #[derive(Debug, Default)]
+    struct RecordingDisplay {
+        messages: RefCell<Vec<String>>,
+    }
+
+    impl RecordingDisplay {
+        fn crib_discard_messages(&self) -> Vec<String> {
+            self.messages
+                .borrow()
+                .iter()
+                .filter(|message| {
+                    !message.is_empty()
+                        && !message.starts_with("board")
+                        && !message.starts_with("play ")
+                        && !message.starts_with("starter cut")
+                        && !message.starts_with("undo")
+                        && !message.starts_with("round summary")
+                        && !message.starts_with("recut")
+                })
+                .cloned()
+                .collect()
+        }
+
+        fn round_summary_messages(&self) -> Vec<String> {
+            self.messages
+                .borrow()
+                .iter()
+                .filter(|message| message.starts_with("round summary"))
+                .cloned()
+                .collect()
+        }
+
+        fn play_count_messages(&self) -> Vec<String> {
+            self.messages
+                .borrow()
+                .iter()
+                .filter(|message| {
+                    message.starts_with("play count") || message.starts_with("play reset")
+                })
+                .cloned()
+                .collect()
+        }
+
+        fn board_messages(&self) -> Vec<String> {
+            self.messages
+                .borrow()
+                .iter()
+                .filter(|message| message.starts_with("board"))
+                .cloned()
+                .collect()
+        }
+
+        fn starter_cut_messages(&self) -> Vec<String> {
+            self.messages
+                .borrow()
+                .iter()
+                .filter(|message| message.starts_with("starter cut"))
+                .cloned()
+                .collect()
+        }
+
+        fn undo_messages(&self) -> Vec<String> {
+            self.messages
+                .borrow()
+                .iter()
+                .filter(|message| message.starts_with("undo"))
+                .cloned()
+                .collect()
+        }
+    }
+
+    impl Display for RecordingDisplay {
+        fn print_no_spacer_no_delay(&self, message: &str) {
+            self.println(message);
+        }
+
+        fn println_no_spacer_no_delay(&self, message: &str) {
+            self.println(message);
+        }
+
+        fn println(&self, message: &str) {
+            self.messages.borrow_mut().push(message.to_string());
+        }
+
+        fn game_after_cut_message(&self, _: &str, _: &Card, _: &str, _: &Card, _: bool) -> String {
+            String::new()
+        }
+
+        fn game_cut_reveal_message(&self, _: &str, _: &str) -> String {
+            String::new()
+        }
+
+        fn game_recut_message(
+            &self,
+            _: &str,
+            player_cut: &Card,
+            _: &str,
+            opponent_cut: &Card,
+        ) -> String {
+            format!("recut {} {}", player_cut, opponent_cut)
+        }
+
+        fn cut_deck_message(&self, _: usize, _: Option<usize>) -> String {
+            String::new()
+        }
+
+        fn game_starter_cut_message(&self, depth: usize, cutter: &str) -> String {
+            format!("starter cut {} {}", cutter, depth)
+        }
+
+        fn game_discard_message<C1, C2>(&self, _: &Player<C1>, _: &Player<C2>, _: bool) -> String
+        where
+            C1: Controller,
+            C2: Controller,
+        {
+            String::new()
+        }
+
+        fn game_crib_discards_message(
+            &self,
+            cards: &[Card],
+            discarder: &str,
+            is_dealer: bool,
+        ) -> String {
+            format!(
+                "{} {} {}",
+                discarder,
+                is_dealer,
+                cards
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
+        }
+
+        fn game_undo_message(&self, chosen: &[Card], undoer: &str) -> String {
+            format!("undo {} {}", undoer, chosen.len())
+        }
+
+        fn game_hint_message(&self, _: Phase, _: &[Card]) -> String {
+            String::new()
+        }
+
+        fn game_before_play_message<C1, C2>(
+            &self,
+            _: &Round,
+            _: &Player<C1>,
+            _: &Player<C2>,
+        ) -> String
+        where
+            C1: Controller,
+            C2: Controller,
+        {
+            String::new()
+        }
+
+        fn game_during_play_message<C1, C2>(
+            &self,
+            _: &Round,
+            _: &Player<C1>,
+            _: &Player<C2>,
+        ) -> String
+        where
+            C1: Controller,
+            C2: Controller,
+        {
+            String::new()
+        }
+
+        fn game_during_counting_message<C1, C2>(
+            &self,
+            _: &Round,
+            _: &Player<C1>,
+            _: &Player<C2>,
+        ) -> String
+        where
+            C1: Controller,
+            C2: Controller,
+        {
+            String::new()
+        }
+
+        fn board_message(&self, _: &str, player: &Pegs, _: &str, opponent: &Pegs) -> String {
+            format!(
+                "board {}-{} {}-{}",
+                player.back, player.front, opponent.back, opponent.front
+            )
+        }
+
+        fn play_score_message(&self, breakdown: &PlayScoreBreakdown, scorer: &str) -> String {
+            format!("play score {} {}", scorer, breakdown.total())
+        }
+
+        fn play_count_message(&self, player: &str, played: Option<&Card>, count: u32) -> String {
+            match played {
+                Some(card) => format!("play count {} {} {}", player, card, count),
+                None => format!("play count {} GO {}", player, count),
+            }
+        }
+
+        fn play_reset_message(&self, thirty_one: bool) -> String {
+            format!("play reset {}", thirty_one)
+        }
+
+        fn round_summary_message(&self, summary: &RoundSummary, _: &str, _: &str) -> String {
+            format!(
+                "round summary {} {} {} {} {}",
+                summary.player_1_pegging,
+                summary.player_2_pegging,
+                summary.counts.len(),
+                summary.player_1_total,
+                summary.player_2_total
+            )
+        }
+
+        fn prompt_cut(&self, _: usize) -> String {
+            String::new()
+        }
+
+        fn prompt_confirm_cut(&self, _: usize) -> String {
+            String::new()
+        }
+
+        fn prompt_starter_cut(&self, _: usize) -> String {
+            String::new()
+        }
+
+        fn prompt_discard_to_crib(&self, _: usize, _: bool) -> String {
+            String::new()
+        }
+
+        fn prompt_play_card(&self, _: usize, _: &PlayData) -> String {
+            String::new()
+        }
+
+        fn prompt_undo(&self, _: &[Card]) -> String {
+            String::new()
+        }
+
+        fn game_over_message(&self, _: &str) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn test_game_choose_dealer_player_1_wins_higher_value() {
         // Chose King of Diamonds
         let player_1_controller = PredeterminedController::from(vec![1, 32]);
         let player_1 = Player::new(player_1_controller);
 
-        // Chose Eight of Clubs
-        let player_2_controller = PredeterminedController::from(vec![2, 69]);
-        let player_2 = Player::new(player_2_controller);
+        // Chose Eight of Clubs
+        let player_2_controller = PredeterminedController::from(vec![2, 69]);
+        let player_2 = Player::new(player_2_controller);
+
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck.clone());
+
+        let expected_player_1_controller = PredeterminedController::from(vec![32]);
+        let expected_player_1 = Player::new(expected_player_1_controller);
+
+        let expected_player_2_controller = PredeterminedController::from(vec![69]);
+        let expected_player_2 = Player::new(expected_player_2_controller);
+
+        game.options.first_dealer = FirstDealer::CutWinner;
+
+        game.choose_dealer().unwrap();
+
+        assert!(!game.round.player_1_is_dealer);
+        assert_eq!(game.deck, deck);
+        assert_eq!(game.player_1, expected_player_1);
+        assert_eq!(game.player_2, expected_player_2);
+    }
+
+    #[test]
+    fn test_game_choose_dealer_same_rank_recuts_player_1_wins() {
+        // Chose Eight of Clubs, then King of Diamonds
+        let player_1_controller = PredeterminedController::from(vec![0, 2, 32]);
+        let player_1 = Player::new(player_1_controller);
+
+        // Chose Eight of Diamonds, then Eight of Diamonds again
+        let player_2_controller = PredeterminedController::from(vec![2, 0, 69]);
+        let player_2 = Player::new(player_2_controller);
+
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let mut game = Game::new_with_deck_default(
+            player_1,
+            player_2,
+            deck.clone(),
+            RecordingDisplay::default(),
+        );
+
+        let expected_player_1_controller = PredeterminedController::from(vec![32]);
+        let expected_player_2_controller = PredeterminedController::from(vec![69]);
+
+        game.options.first_dealer = FirstDealer::CutWinner;
+
+        game.choose_dealer().unwrap();
+
+        assert!(game.round.player_1_is_dealer);
+        assert_eq!(game.deck, deck);
+        assert_eq!(game.player_1, Player::new(expected_player_1_controller));
+        assert_eq!(game.player_2, Player::new(expected_player_2_controller));
+        assert_eq!(
+            *game.display.messages.borrow(),
+            vec![
+                String::new(),
+                String::from("recut [8♣] [8♦]"),
+                String::new(),
+                String::new(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_game_choose_dealer_suit_breaks_tie_player_1_wins() {
+        // Chose Eight of Clubs
+        let player_1_controller = PredeterminedController::from(vec![0, 32]);
+        let player_1 = Player::new(player_1_controller);
+
+        // Chose Eight of Diamonds
+        let player_2_controller = PredeterminedController::from(vec![2, 69]);
+        let player_2 = Player::new(player_2_controller);
+
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let rules = GameRules {
+            suit_breaks_cut_ties: true,
+            ..GameRules::six_card()
+        };
+        let options = GameOptions::default().with_rules(rules);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck).with_options(options);
+
+        game.options.first_dealer = FirstDealer::CutWinner;
+
+        game.choose_dealer().unwrap();
+
+        assert!(game.round.player_1_is_dealer);
+    }
+
+    #[test]
+    fn test_game_choose_dealer_player_2_wins_higher_value() {
+        // Chose Eight of Diamonds
+        let player_1_controller = PredeterminedController::from(vec![3, 32]);
+        let player_1 = Player::new(player_1_controller);
+
+        // Chose King of Diamonds
+        let player_2_controller = PredeterminedController::from(vec![2, 69]);
+        let player_2 = Player::new(player_2_controller);
+
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck.clone());
+
+        let expected_player_1_controller = PredeterminedController::from(vec![32]);
+        let expected_player_1 = Player::new(expected_player_1_controller);
+
+        let expected_player_2_controller = PredeterminedController::from(vec![69]);
+        let expected_player_2 = Player::new(expected_player_2_controller);
+
+        game.options.first_dealer = FirstDealer::CutWinner;
+
+        game.choose_dealer().unwrap();
+
+        assert_eq!(game.deck, deck);
+        assert_eq!(game.player_1, expected_player_1);
+        assert_eq!(game.player_2, expected_player_2);
+    }
+
+    #[test]
+    fn test_game_choose_dealer_same_rank_recuts_player_2_wins() {
+        // Chose Eight of Diamonds, then Six of Clubs
+        let player_1_controller = PredeterminedController::from(vec![3, 1, 32]);
+        let player_1 = Player::new(player_1_controller);
+
+        // Chose Eight of Clubs, then Eight of Clubs again
+        let player_2_controller = PredeterminedController::from(vec![0, 2, 69]);
+        let player_2 = Player::new(player_2_controller);
+
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck.clone());
+
+        let expected_player_1_controller = PredeterminedController::from(vec![32]);
+        let expected_player_2_controller = PredeterminedController::from(vec![69]);
+
+        game.options.first_dealer = FirstDealer::CutWinner;
+
+        game.choose_dealer().unwrap();
+
+        assert!(!game.round.player_1_is_dealer);
+        assert_eq!(game.deck, deck);
+        assert_eq!(game.player_1, Player::new(expected_player_1_controller));
+        assert_eq!(game.player_2, Player::new(expected_player_2_controller));
+        assert_eq!(
+            game.history(),
+            [GameEvent::Cut {
+                player_1_card: Card::new(Rank::Six, Suit::Clubs),
+                player_2_card: Card::new(Rank::Eight, Suit::Clubs),
+                player_1_is_dealer: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_game_choose_dealer_suit_breaks_tie_player_2_wins() {
+        // Chose Eight of Diamonds
+        let player_1_controller = PredeterminedController::from(vec![3, 32]);
+        let player_1 = Player::new(player_1_controller);
+
+        // Chose Eight of Clubs
+        let player_2_controller = PredeterminedController::from(vec![0, 69]);
+        let player_2 = Player::new(player_2_controller);
+
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let rules = GameRules {
+            suit_breaks_cut_ties: true,
+            ..GameRules::six_card()
+        };
+        let options = GameOptions::default().with_rules(rules);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck).with_options(options);
+
+        game.options.first_dealer = FirstDealer::CutWinner;
+
+        game.choose_dealer().unwrap();
+
+        assert!(!game.round.player_1_is_dealer);
+    }
+
+    #[test]
+    #[cfg(feature = "terminal")]
+    fn test_game_choose_dealer_records_timeout() {
+        use crate::game::IoController;
+
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        let (_sender, receiver) = mpsc::channel();
+
+        // Runs out of time, so cuts one deep, showing the Six of Clubs on top.
+        let player_1_controller = IoController::new()
+            .with_lines(receiver)
+            .with_timeout(Duration::from_millis(1));
+        let player_1 = Player::new(player_1_controller);
+
+        // Chose Eight of Diamonds
+        let player_2_controller = PredeterminedController::from(vec![1]);
+        let player_2 = Player::new(player_2_controller);
+
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck);
+
+        game.options.first_dealer = FirstDealer::CutWinner;
+
+        game.choose_dealer().unwrap();
+
+        let expected = vec![
+            GameEvent::Timeout { player_1: true },
+            GameEvent::Cut {
+                player_1_card: Card::new(Rank::Six, Suit::Clubs),
+                player_2_card: Card::new(Rank::Eight, Suit::Diamonds),
+                player_1_is_dealer: false,
+            },
+        ];
+
+        assert_eq!(game.history(), expected);
+    }
+
+    #[test]
+    fn test_game_choose_dealer_lower_cut_deals_by_default() {
+        // Chose King of Diamonds
+        let player_1 = Player::new(PredeterminedController::from(vec![1]));
+        // Chose Six of Clubs
+        let player_2 = Player::new(PredeterminedController::from(vec![1]));
+
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck);
+
+        game.choose_dealer().unwrap();
+
+        assert!(!game.round.player_1_is_dealer);
+        assert_eq!(
+            game.history(),
+            [GameEvent::Cut {
+                player_1_card: Card::new(Rank::King, Suit::Diamonds),
+                player_2_card: Card::new(Rank::Six, Suit::Clubs),
+                player_1_is_dealer: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_game_choose_dealer_without_cutting() {
+        let controller = PredeterminedController::from(Vec::new());
+
+        let mut game = Game::new(Player::new(controller.clone()), Player::new(controller));
+
+        game.options.first_dealer = FirstDealer::Fixed { player_1: true };
+
+        game.choose_dealer().unwrap();
+
+        assert!(game.round.player_1_is_dealer);
+
+        let dealers: Vec<bool> = (0..20)
+            .map(|seed| {
+                let controller = PredeterminedController::from(Vec::new());
+
+                let mut game = Game::new(Player::new(controller.clone()), Player::new(controller))
+                    .with_seed(seed);
+
+                game.options.first_dealer = FirstDealer::Random;
+
+                game.choose_dealer().unwrap();
+
+                game.round.player_1_is_dealer
+            })
+            .collect();
+
+        assert!(dealers.contains(&true) && dealers.contains(&false));
+        assert!(game.history().is_empty());
+    }
+
+    #[test]
+    fn test_game_deal_order() {
+        let controller = PredeterminedController::from(Vec::new());
+
+        let player_1 = Player::new(controller.clone());
+        let player_2 = Player::new(controller);
+
+        let mut game = Game::new(player_1, player_2);
+
+        // Player 1 is the dealer, so player 2 is the pone.
+        assert_eq!(game.deal_order(), vec![1, 0]);
+
+        game.options.first_dealt_to = FirstDealtTo::Dealer;
+
+        assert_eq!(game.deal_order(), vec![0, 1]);
+
+        game.options.first_dealt_to = FirstDealtTo::PlayerTwo;
+        game.swap_dealer_and_pone();
+
+        assert_eq!(game.deal_order(), vec![1, 0]);
+
+        game.options.first_dealt_to = FirstDealtTo::Pone;
+
+        assert_eq!(game.deal_order(), vec![0, 1]);
+
+        // Counter-clockwise, the pone (player 1) is dealt to last.
+        game.options.deal_direction = DealDirection::CounterClockwise;
+
+        assert_eq!(game.deal_order(), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_game_deal_direction_reverses_the_hands() {
+        let deal = |deal_direction| {
+            let controller = PredeterminedController::from(Vec::new());
+
+            let player_1 = Player::new(controller.clone());
+            let player_2 = Player::new(controller);
+
+            let mut game = Game::new_with_deck(player_1, player_2, Deck::new());
+
+            game.options.deal_direction = deal_direction;
+
+            game.run_deal_round().unwrap();
+
+            (game.player_1.hand_cards(), game.player_2.hand_cards())
+        };
+
+        let (clockwise_1, clockwise_2) = deal(DealDirection::Clockwise);
+        let (counter_clockwise_1, counter_clockwise_2) = deal(DealDirection::CounterClockwise);
+
+        assert_eq!(clockwise_1, counter_clockwise_2);
+        assert_eq!(clockwise_2, counter_clockwise_1);
+    }
+
+    #[test]
+    fn test_game_new_daily_same_date_same_hands() {
+        let deal = |date| {
+            let player = Player::new(RngController::new_with_seed(1));
+            let ai = Player::new(RngController::new_with_seed(2));
+
+            let mut game = Game::new_daily(date, player, ai);
+
+            game.run_deal_round().unwrap();
+
+            (game.player_1.hand_cards(), game.player_2.hand_cards())
+        };
+
+        let date = DailyDate::new(2024, 2, 29).unwrap();
+        let next_date = DailyDate::new(2024, 3, 1).unwrap();
+
+        assert_eq!(deal(date), deal(date));
+        assert_ne!(deal(date), deal(next_date));
+    }
+
+    #[test]
+    fn test_game_run_deal_and_discard_round() {
+        // Discard Four of Clubs and Six of Clubs to crib
+        let player_1_controller = PredeterminedController::from(vec![0, 3, 32]);
+        let player_1 = Player::new(player_1_controller);
+
+        // Discard Seven of Clubs and Eight of Clubs to crib
+        let player_2_controller = PredeterminedController::from(vec![2, 3, 69]);
+        let player_2 = Player::new(player_2_controller);
+
+        // Deck is dealt in reverse!
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Hearts),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        // Player 2 is the pone, so they are dealt to first.
+        let mut game = Game::new_with_deck(player_1, player_2, deck);
+
+        let expected_player_1_cards = vec![
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Diamonds),
+        ];
+        // The pone (player 2) discards both first.
+        let expected_crib = vec![
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Clubs),
+        ];
+        let expected_player_1_controller = PredeterminedController::from(vec![32]);
+        let expected_player_1 =
+            Player::new_with_cards(expected_player_1_controller, expected_player_1_cards);
+
+        let expected_player_2_cards = vec![
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let expected_player_2_controller = PredeterminedController::from(vec![69]);
+        let expected_player_2 =
+            Player::new_with_cards(expected_player_2_controller, expected_player_2_cards);
+
+        game.run_deal_round().unwrap();
+        game.run_discard_round().unwrap();
+
+        assert_eq!(game.deck, Deck::new_with_cards(Vec::new()));
+        assert_eq!(game.player_1, expected_player_1);
+        assert_eq!(game.player_2, expected_player_2);
+        assert_eq!(game.round.crib, Hand::from(expected_crib));
+    }
+
+    #[test]
+    fn test_game_run_deal_and_discard_round_dealer_dealt_first() {
+        // Discard Six of Hearts and Eight of Clubs to crib
+        let player_1_controller = PredeterminedController::from(vec![0, 3, 32]);
+        let player_1 = Player::new(player_1_controller);
+
+        // Discard Five of Clubs and Six of Clubs to crib
+        let player_2_controller = PredeterminedController::from(vec![2, 3, 69]);
+        let player_2 = Player::new(player_2_controller);
+
+        // Deck is dealt in reverse!
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Hearts),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let options = GameOptions {
+            first_dealt_to: FirstDealtTo::Dealer,
+            ..GameOptions::default()
+        };
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck).with_options(options);
+
+        let expected_player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let expected_crib = vec![
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let expected_player_1_controller = PredeterminedController::from(vec![32]);
+        let expected_player_1 =
+            Player::new_with_cards(expected_player_1_controller, expected_player_1_cards);
+
+        let expected_player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Diamonds),
+        ];
+        let expected_player_2_controller = PredeterminedController::from(vec![69]);
+        let expected_player_2 =
+            Player::new_with_cards(expected_player_2_controller, expected_player_2_cards);
+
+        game.run_deal_round().unwrap();
+        game.run_discard_round().unwrap();
+
+        assert_eq!(game.deck, Deck::new_with_cards(Vec::new()));
+        assert_eq!(game.player_1, expected_player_1);
+        assert_eq!(game.player_2, expected_player_2);
+        assert_eq!(game.round.crib, Hand::from(expected_crib));
+    }
+
+    #[test]
+    fn test_game_run_deal_round_hand_sort() {
+        let player_1 = Player::new(PredeterminedController::from(vec![]));
+        let player_2 = Player::new(PredeterminedController::from(vec![]));
+
+        // Deck is dealt in reverse!
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Hearts),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let options = GameOptions {
+            first_dealt_to: FirstDealtTo::Dealer,
+            hand_sort: HandSort::SuitThenRank,
+            ..GameOptions::default()
+        };
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck).with_options(options);
+
+        let expected_player_1_cards = vec![
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let expected_player_2_cards = vec![
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Clubs),
+        ];
+
+        game.run_deal_round().unwrap();
+
+        assert_eq!(game.player_1.hand_cards(), expected_player_1_cards);
+        assert_eq!(game.player_2.hand_cards(), expected_player_2_cards);
+    }
+
+    #[test]
+    fn test_game_get_starter_not_jack() {
+        // Discard Six of Hearts and Eight of Clubs to crib
+        let player_1_controller = PredeterminedController::from(vec![0, 3, 32]);
+        let player_1 = Player::new(player_1_controller);
+
+        // Discard Five of Clubs and Six of Clubs to crib
+        let player_2_controller = PredeterminedController::from(vec![2, 3, 69]);
+        let player_2 = Player::new(player_2_controller);
+
+        // Deck is dealt in reverse!
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Hearts),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck);
+
+        let expected_deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+        ];
+        let expected_dealer_points = 0;
+        let expected_pone_points = 0;
+
+        assert_eq!(game.starter(), None);
+
+        let starter = game.get_starter().unwrap();
+
+        assert_eq!(starter, Card::new(Rank::Six, Suit::Hearts));
+        assert_eq!(game.starter(), Some(&starter));
+        assert_eq!(game.deck.as_vec(), &expected_deck_cards);
+        assert_eq!(game.player_1.points, expected_dealer_points);
+        assert_eq!(game.player_2.points, expected_pone_points);
+    }
+
+    #[test]
+    fn test_game_get_starter_jack() {
+        // Discard Six of Hearts and Eight of Clubs to crib
+        let player_1_controller = PredeterminedController::from(vec![0, 3, 32]);
+        let player_1 = Player::new(player_1_controller);
+
+        // Discard Five of Clubs and Six of Clubs to crib
+        let player_2_controller = PredeterminedController::from(vec![2, 3, 69]);
+        let player_2 = Player::new(player_2_controller);
+
+        // Deck is dealt in reverse!
+        let deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Diamonds),
+        ];
+        let deck = Deck::new_with_cards(deck_cards);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck);
+
+        let expected_deck_cards = vec![
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Hearts),
+        ];
+        let expected_dealer_points = 2;
+        let expected_pone_points = 0;
+
+        let starter = game.get_starter().unwrap();
+
+        assert_eq!(starter, Card::new(Rank::Jack, Suit::Diamonds));
+        assert_eq!(game.deck.as_vec(), &expected_deck_cards);
+        assert_eq!(game.player_1.points, expected_dealer_points);
+        assert_eq!(game.player_2.points, expected_pone_points);
+    }
+
+    #[test]
+    fn test_game_get_starter_jack_player_2_dealer() {
+        let player_1 = Player::new(PredeterminedController::from(vec![]));
+        let player_2 = Player::new(PredeterminedController::from(vec![]));
+
+        let deck = Deck::new_with_cards(vec![Card::new(Rank::Jack, Suit::Spades)]);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck);
+        game.round.player_1_is_dealer = false;
+
+        game.get_starter().unwrap();
+
+        assert_eq!(game.player_1.points, 0);
+        assert_eq!(game.player_2.points, 2);
+        assert_eq!(
+            game.history().last(),
+            Some(&GameEvent::ScorePoints {
+                player_1: false,
+                points: 2,
+                reason: ScoreReason::HisHeels,
+            })
+        );
+    }
+
+    #[test]
+    fn test_game_step_his_heels_ends_game_before_play() {
+        let cards = vec![
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Clubs),
+        ];
+
+        let player_2_cards = cards
+            .iter()
+            .map(|card| Card::new(card.rank, Suit::Hearts))
+            .collect();
+
+        let player_1 = Player::new_with_cards(PredeterminedController::from(vec![0, 0]), cards);
+
+        let mut player_2 =
+            Player::new_with_cards(PredeterminedController::from(vec![0, 0]), player_2_cards);
+        player_2.points = 119;
+
+        let deck = Deck::new_with_cards(vec![Card::new(Rank::Jack, Suit::Spades)]);
+
+        let mut game = Game::new_with_deck(player_1, player_2, deck);
+        game.round.player_1_is_dealer = false;
+        game.phase = Phase::Discard;
+
+        assert_eq!(game.step(), Ok(Phase::Over));
+        assert_eq!(game.player_2.points, 121);
+        assert_eq!(
+            &game.history()[game.history().len() - 2..],
+            &[
+                GameEvent::ScorePoints {
+                    player_1: false,
+                    points: 2,
+                    reason: ScoreReason::HisHeels,
+                },
+                GameEvent::GameOver {
+                    player_1_won: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_game_run_play_round() {
+        // Play stack (start with p2)
+        //     * Stack 1 -> 7D(p2, 0pt, 7), 7C(p1, 2pt, 14), 8D(p2, 0pt, 22), 6D(p2, 3pt, 28),
+        //                  GO(p2, 1pt, 28)
+        //     * Stack 2 -> JD(p1, 0pt, 10), 4C(p2, 0pt, 14), QD(p1, 0pt, 24), GO(p1, 1pt, 24)
+        //     * Stack 3 -> KD(p1, 0pt, 10), GO (p1, 1pt, 10)
+        //
+        // Score at end: p1 = 4 (pair and 2 GOs), p2 = 4 (run of 3 and a GO)
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        // Discard: 7C, JD, QD, KD
+        let player_1_controller = PredeterminedController::from(vec![1, 0, 0, 0, 32]);
+        let player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let player_1 = Player::new_with_cards(player_1_controller, player_1_cards);
+
+        // Discard: 7D, 8D, 6D, 4C
+        let player_2_controller = PredeterminedController::from(vec![2, 2, 1, 0, 69]);
+        let player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Diamonds),
+        ];
+        let player_2 = Player::new_with_cards(player_2_controller, player_2_cards);
+
+        let mut game = Game::new(player_1, player_2);
+
+        let expected_dealer_points = 4;
+        let expected_pone_points = 4;
+
+        game.run_play_round(&starter).unwrap();
+
+        assert_eq!(game.player_1.points, expected_dealer_points);
+        assert_eq!(game.player_2.points, expected_pone_points);
+
+        // assert that the [`Hand`]s were reset
+        assert_eq!(game.player_1.hand.len(), 4);
+        assert_eq!(game.player_2.hand.len(), 4);
+        assert!(game.player_1.discarded.is_empty());
+        assert!(game.player_2.discarded.is_empty());
+    }
+
+    #[test]
+    fn test_game_run_play_round_shows_count() {
+        // Same as `test_game_run_play_round_31_other_player_leads`.
+        let starter = Card::new(Rank::Eight, Suit::Hearts);
+
+        let player_1_controller = PredeterminedController::from(vec![0, 0, 0]);
+        let player_1_cards = vec![
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Clubs),
+        ];
+        let player_1 = Player::new_with_cards(player_1_controller, player_1_cards);
+
+        let player_2_controller = PredeterminedController::from(vec![0, 0, 0]);
+        let player_2_cards = vec![
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Ten, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Diamonds),
+        ];
+        let player_2 = Player::new_with_cards(player_2_controller, player_2_cards);
+
+        let mut game = Game::new_default(player_1, player_2, RecordingDisplay::default());
+
+        game.run_play_round(&starter).unwrap();
+
+        let expected = vec![
+            String::from("play count Opponent [K♦] 10"),
+            String::from("play count Player [Q♣] 20"),
+            String::from("play count Opponent [10♦] 30"),
+            String::from("play count Player [A♣] 31"),
+            String::from("play reset true"),
+            String::from("play count Opponent [5♦] 5"),
+            String::from("play count Player [4♣] 9"),
+        ];
+
+        assert_eq!(game.display.play_count_messages(), expected);
+    }
+
+    #[test]
+    fn test_game_run_play_round_31_other_player_leads() {
+        // Play stack (start with p2)
+        //     * Stack 1 -> KD(p2, 0pt, 10), QC(p1, 0pt, 20), 10D(p2, 0pt, 30), AC(p1, 2pt, 31)
+        //     * Stack 2 -> 5D(p2, 0pt, 5), 4C(p1, 0pt, 9), GO(p1, 1pt, 9)
+        //
+        // Score at end: p1 = 3 (31 and a GO), p2 = 0
+        let starter = Card::new(Rank::Eight, Suit::Hearts);
+
+        // Discard: QC, AC, 4C
+        let player_1_controller = PredeterminedController::from(vec![0, 0, 0]);
+        let player_1_cards = vec![
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Clubs),
+        ];
+        let player_1 = Player::new_with_cards(player_1_controller, player_1_cards);
+
+        // Discard: KD, 10D, 5D
+        let player_2_controller = PredeterminedController::from(vec![0, 0, 0]);
+        let player_2_cards = vec![
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Ten, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Diamonds),
+        ];
+        let player_2 = Player::new_with_cards(player_2_controller, player_2_cards);
+
+        let mut game = Game::new(player_1, player_2);
+
+        game.run_play_round(&starter).unwrap();
+
+        // If player 1 led after their 31, player 2 would get the GO instead.
+        assert_eq!(game.player_1.points, 3);
+        assert_eq!(game.player_2.points, 0);
+    }
+
+    #[test]
+    fn test_game_run_play_round_player_1_hit_121_before_first_reset() {
+        // Play stack (start with p2) p1.points = 118 && p2.points = 120
+        //     * Stack 1 -> 7D(p2, 0pt, 7), 7C(p1, 2pt, 14), 8D(p2, 0pt, 22), 6D(p2, 3pt, 28),
+        //                  GO(p2, 1pt, 28)
+        //     * p1 hit 121 break
+        //
+        // Score at end: p1 = 120 (pair), p2 = 124 (run of 3 and a GO)
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        // Discard: 7C, JD, QD, KD
+        let player_1_controller = PredeterminedController::from(vec![1, 0, 0, 0, 32]);
+        let player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let mut player_1 = Player::new_with_cards(player_1_controller, player_1_cards);
+        player_1.points = 118;
+
+        // Discard: 7D, 8D, 6D, 4C
+        let player_2_controller = PredeterminedController::from(vec![2, 2, 1, 0, 69]);
+        let player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Diamonds),
+        ];
+        let mut player_2 = Player::new_with_cards(player_2_controller, player_2_cards);
+        player_2.points = 120;
+
+        let mut game = Game::new(player_1, player_2);
+
+        let expected_dealer_points = 120;
+        let expected_pone_points = 124;
+
+        game.run_play_round(&starter).unwrap();
+
+        assert_eq!(game.player_1.points, expected_dealer_points);
+        assert_eq!(game.player_2.points, expected_pone_points);
+
+        // assert that the hands were reset
+        assert_eq!(game.player_1.hand.len(), 4);
+        assert_eq!(game.player_2.hand.len(), 4);
+        assert!(game.player_1.discarded.is_empty());
+        assert!(game.player_2.discarded.is_empty());
+    }
+
+    #[test]
+    fn test_game_run_play_round_intentional_pass() {
+        // Play stack (start with p2)
+        //     * Stack 1 -> PASS(p2, 0pt, 0), 5C(p1, 0pt, 5), 6C(p2, 0pt, 11), GO(p2, 1pt, 11)
+        //
+        // Score at end: p1 = 0, p2 = 1 (a GO)
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        let player_1_controller = PredeterminedController::from(vec![0]);
+        let player_1_cards = vec![Card::new(Rank::Five, Suit::Clubs)];
+        let player_1 = Player::new_with_cards(player_1_controller, player_1_cards);
+
+        let player_2_controller = PredeterminedController::from(vec![PASS_INDEX, 0]);
+        let player_2_cards = vec![Card::new(Rank::Six, Suit::Clubs)];
+        let player_2 = Player::new_with_cards(player_2_controller, player_2_cards);
+
+        let options = GameOptions {
+            allow_intentional_pass: true,
+            ..GameOptions::default()
+        };
+
+        let mut game = Game::new(player_1, player_2).with_options(options);
+
+        game.run_play_round(&starter).unwrap();
+
+        assert_eq!(game.player_1.points, 0);
+        assert_eq!(game.player_2.points, 1);
+    }
+
+    #[test]
+    fn test_game_get_starter_cut_by_pone() {
+        let player_1 = Player::new(PredeterminedController::from(Vec::new()));
+        let player_2 = Player::new(RngController::new_with_seed(3));
+
+        // Player 1 is the dealer, so player 2 cuts.
+        let mut game = Game::new_with_deck_default(
+            player_1,
+            player_2,
+            Deck::new(),
+            RecordingDisplay::default(),
+        );
+
+        let deck_cards = game.deck.as_vec().clone();
+
+        let starter = game.get_starter().unwrap();
+
+        let depth = match game.history()[0] {
+            GameEvent::CutForStarter {
+                player_1: false,
+                depth,
+            } => depth,
+            ref event => panic!("Expected the pone's cut, but got {:?}", event),
+        };
+
+        assert_eq!(starter, deck_cards[deck_cards.len() - 1 - depth]);
+        assert_eq!(game.deck.as_vec().len(), 51);
+        assert_eq!(
+            game.display.starter_cut_messages(),
+            vec![format!("starter cut Opponent {}", depth)]
+        );
+    }
+
+    #[test]
+    fn test_game_get_starter_too_deep_leaves_history_unchanged() {
+        let events = vec![GameEvent::CutForStarter {
+            player_1: false,
+            depth: 5,
+        }];
+
+        let player_1 = Player::new(PredeterminedController::from(Vec::new()));
+        let player_2 = Player::new(ReplayController::from_events(
+            &events, /*player_1=*/ false,
+        ));
+
+        // Player 1 is the dealer, so player 2 cuts, but deeper than the Deck.
+        let deck = Deck::new_with_cards(vec![
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Hearts),
+        ]);
+
+        let mut game =
+            Game::new_with_deck_default(player_1, player_2, deck, RecordingDisplay::default());
+
+        let result = game.get_starter();
+
+        assert_eq!(result, Err(GameError::InvalidIndex { index: 5, len: 2 }));
+        assert!(game.history().is_empty());
+        assert!(game.display.starter_cut_messages().is_empty());
+        assert_eq!(game.deck.as_vec().len(), 2);
+    }
+
+    #[test]
+    fn test_game_view_before_play() {
+        let player_1 = Player::new(RngController::new_with_seed(1));
+        let player_2 = Player::new(RngController::new_with_seed(2));
+
+        let options = GameOptions {
+            first_dealer: FirstDealer::Fixed { player_1: false },
+            ..GameOptions::default()
+        };
+
+        let mut game = Game::new(player_1, player_2)
+            .with_options(options)
+            .with_seed(1);
+
+        while game.phase() != Phase::Play {
+            game.step().unwrap();
+        }
+
+        let player_1_view = game.view(/*player_1=*/ true);
+        let player_2_view = game.view(/*player_1=*/ false);
+
+        assert_eq!(player_1_view.hand, game.player_1.hand_cards());
+        assert_eq!(player_1_view.opponent_hand_size, 4);
+        assert_eq!(player_1_view.starter, game.round.starter);
+        assert_eq!(player_1_view.crib_size, 4);
+        assert!(player_1_view.stack.is_empty());
+        assert!(!player_1_view.is_dealer);
+        assert!(player_2_view.is_dealer);
+        assert_eq!(player_2_view.points, player_1_view.opponent_points);
+    }
+
+    #[test]
+    fn test_game_run_counting_round() {
+        let controller = PredeterminedController::from(Vec::new());
+
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        // Hand Score 6pts: 15 2pts, 3-run 3pts, Nobs 1pt
+        // Crib Score 13pts: 15 4pts, 4-run 4pts, 5-flush 5pts
+        // Total Score 19pts
+        let player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let crib = vec![
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        // Hand Score 12pts: 15 4pts, Pair 2pts, 2x 3-run 6pts
+        let player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        let mut game = Game::new(player_1, player_2);
+
+        game.round.crib = Hand::from(crib);
+
+        let expected_dealer_points = 19;
+        let expected_pone_points = 12;
+
+        game.run_counting_round(&starter);
+
+        assert_eq!(game.player_1.points, expected_dealer_points);
+        assert_eq!(game.player_2.points, expected_pone_points);
+    }
+
+    #[test]
+    fn test_game_run_counting_round_shows_board() {
+        let controller = PredeterminedController::from(Vec::new());
+
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        // Hand and Crib Score 19pts, see above.
+        let player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let crib = vec![
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ];
+        let mut player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        player_1.points = 10;
+
+        // Hand Score 12pts, see above.
+        let player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        let mut game = Game::new_default(player_1, player_2, RecordingDisplay::default());
+
+        game.round.crib = Hand::from(crib);
+
+        // The pone (player 2) counts first, and player 1's pegs jump from the start.
+        let expected = vec![
+            String::from("board 0-10 0-12"),
+            String::from("board 10-29 0-12"),
+        ];
+
+        game.run_counting_round(&starter);
+
+        assert_eq!(game.display.board_messages(), expected);
+    }
+
+    #[test]
+    fn test_game_run_counting_round_history() {
+        let controller = PredeterminedController::from(Vec::new());
+
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        // Hand and Crib Score 19pts, see above.
+        let player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let crib = vec![
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards.clone());
+
+        // Hand Score 12pts, see above.
+        let player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let player_2 = Player::new_with_cards(controller, player_2_cards.clone());
+
+        let mut game = Game::new(player_1, player_2);
+
+        game.round.crib = Hand::from(crib.clone());
+
+        let expected = vec![
+            GameEvent::HandCount {
+                player_1: false,
+                cards: player_2_cards,
+                is_crib: false,
+                points: 12,
+            },
+            GameEvent::ScorePoints {
+                player_1: false,
+                points: 12,
+                reason: ScoreReason::Hand,
+            },
+            GameEvent::HandCount {
+                player_1: true,
+                cards: player_1_cards,
+                is_crib: false,
+                points: 6,
+            },
+            GameEvent::ScorePoints {
+                player_1: true,
+                points: 6,
+                reason: ScoreReason::Hand,
+            },
+            GameEvent::HandCount {
+                player_1: true,
+                cards: crib,
+                is_crib: true,
+                points: 13,
+            },
+            GameEvent::ScorePoints {
+                player_1: true,
+                points: 13,
+                reason: ScoreReason::Crib,
+            },
+        ];
+
+        game.run_counting_round(&starter);
+
+        assert_eq!(game.history(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_game_run_counting_round_shows_summary() {
+        let controller = PredeterminedController::from(Vec::new());
+
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        // Hand and Crib Score 19pts, see above.
+        let player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let crib = vec![
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards.clone());
+
+        // Hand Score 12pts, see above.
+        let player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let player_2 = Player::new_with_cards(controller, player_2_cards.clone());
+
+        let mut game = Game::new_default(player_1, player_2, RecordingDisplay::default());
+
+        game.round.crib = Hand::from(crib);
+        game.history.push(GameEvent::Deal {
+            player_1_is_dealer: true,
+            player_1_hand: player_1_cards,
+            player_2_hand: player_2_cards,
+        });
+        game.add_points(/*player_1=*/ false, 3, ScoreReason::Pegging);
+
+        game.run_counting_round(&starter);
+
+        // Player 2 pegged 3, and all 3 hands were counted.
+        let expected = vec![String::from("round summary 0 3 3 19 15")];
+
+        assert_eq!(game.display.round_summary_messages(), expected);
+    }
+
+    #[test]
+    fn test_game_run_counting_round_player_2_dealer_counts_crib() {
+        let controller = PredeterminedController::from(Vec::new());
+
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        // Hand Score 6pts, see above.
+        let player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        // Hand Score 12pts, and Crib Score 13pts, see above.
+        let player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let crib = vec![
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ];
+        let player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        let mut game = Game::new(player_1, player_2);
+
+        game.round = Round::new(/*player_1_is_dealer=*/ false);
+        game.round.crib = Hand::from(crib);
+
+        game.run_counting_round(&starter);
+
+        assert_eq!(game.player_1.points, 6);
+        assert_eq!(game.player_2.points, 25);
+    }
+
+    #[test]
+    fn test_game_run_counting_round_player_2_hit_121_before_player_1_can_count() {
+        let controller = PredeterminedController::from(Vec::new());
+
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        // Hand Score 6pts: 15 2pts, 3-run 3pts, Nobs 1pt
+        // Crib Score 13pts: 15 4pts, 4-run 4pts, 5-flush 5pts
+        // Total Score 19pts
+        let player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let crib = vec![
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        // Hand Score 12pts: 15 4pts, Pair 2pts, 2x 3-run 6pts
+        let player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let mut player_2 = Player::new_with_cards(controller, player_2_cards);
+        player_2.points = 110;
+
+        let mut game = Game::new(player_1, player_2);
+
+        game.round.crib = Hand::from(crib);
+
+        let expected_dealer_points = 0;
+        let expected_pone_points = 122;
+
+        game.run_counting_round(&starter);
+
+        assert_eq!(game.player_1.points, expected_dealer_points);
+        assert_eq!(game.player_2.points, expected_pone_points);
+    }
+
+    #[test]
+    fn test_game_gather_deck() {
+        let controller = PredeterminedController::from(Vec::new());
+
+        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+
+        let player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let crib = vec![
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        let player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+        ];
+        let player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        let deck = Deck::new_with_cards(Vec::new());
+        let mut game = Game::new_with_deck(player_1, player_2, deck);
+
+        game.round.crib = Hand::from(crib);
+
+        let expected_deck_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Diamonds),
+        ];
+        let expected_deck = Deck::new_with_cards(expected_deck_cards);
+
+        game.gather_deck(starter);
+
+        assert_eq!(game.deck, expected_deck);
+        assert!(game.round.crib.is_empty());
+    }
+
+    #[test]
+    fn test_game_reset_deck_reshuffles_with_the_seed() {
+        let new_game = || {
+            let controller = PredeterminedController::from(Vec::new());
+
+            let mut game =
+                Game::new(Player::new(controller.clone()), Player::new(controller)).with_seed(9);
+
+            let starter = game.deck.deal().unwrap();
+            let mut gathered = game.deck.as_vec().clone();
+
+            gathered.push(starter.clone());
+
+            game.reset_deck(starter);
+
+            (game.deck, gathered)
+        };
+
+        let (deck, gathered) = new_game();
+
+        assert_eq!(deck.as_vec().len(), 52);
+        assert_ne!(deck.as_vec(), &gathered);
+        assert_eq!(new_game().0, deck);
+    }
+
+    #[test]
+    fn test_game_reset_deck_with() {
+        let controller = PredeterminedController::from(Vec::new());
+
+        let player_1_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let crib = vec![
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
 
-        let deck_cards = vec![
-            Card::new(Rank::Eight, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-            Card::new(Rank::Six, Suit::Clubs),
+        let player_2_cards = vec![
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
             Card::new(Rank::Eight, Suit::Clubs),
         ];
-        let deck = Deck::new_with_cards(deck_cards);
+        let player_2 = Player::new_with_cards(controller, player_2_cards);
 
-        let mut game = Game::new_with_deck(player_1, player_2, deck.clone());
+        let deck = Deck::new_with_cards(Vec::new());
+        let mut game = Game::new_with_deck(player_1, player_2, deck);
 
-        let expected_player_1_controller = PredeterminedController::from(vec![32]);
-        let expected_player_1 = Player::new(expected_player_1_controller);
+        game.round.crib = Hand::from(crib);
 
-        let expected_player_2_controller = PredeterminedController::from(vec![69]);
-        let expected_player_2 = Player::new(expected_player_2_controller);
+        let expected_deck_cards = vec![
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Diamonds),
+        ];
+        let expected_deck = Deck::new_with_cards(expected_deck_cards);
 
-        game.choose_dealer();
+        game.reset_deck_with(expected_deck.clone());
 
-        assert_eq!(game.deck, deck);
-        assert_eq!(game.player_1, expected_player_1);
-        assert_eq!(game.player_2, expected_player_2);
+        assert_eq!(game.deck, expected_deck);
+        assert!(!game.player_1.has_cards());
+        assert!(!game.player_2.has_cards());
     }
 
     #[test]
-    fn test_game_choose_dealer_player_1_wins_same_value_higher_suit() {
-        // Chose Eight of Clubs
-        let player_1_controller = PredeterminedController::from(vec![3, 32]);
-        let player_1 = Player::new(player_1_controller);
+    fn test_game_play() {
+        // The maximum number of points that can be scored in a single round by the dealer is 78.
+        //     * Pegging: 29pts
+        //     * Hand: 20pts
+        //     * Crib: 29pts
+        //
+        // This is achieved by dealing the following
+        //     * Pone: 3H, 3S, 4H, 4S, 5H, JC
+        //     * Dealer: 3D, 3C, 4D, 4C, 5S, 5D
+        //
+        // So the state after dealing, discarding, and getting the starter:
+        //     * Pone Hand: 3H, 3S, 4H, 4S
+        //         * Discarded/Removed: JC, 5H
+        //     * Dealer Hand: 3D, 3C, 4D, 4C
+        //         * Discarded/Removed: 5D, 5S
+        //         * Indices: 5, 4
+        //     * Dealer Crib: JC, 5D, 5H, 5S
+        //     * Starter Card: 5C
+        //         * Note: The Pone's Jack matches suit of the starter 5 (Clubs).
+        //     * So deck has to atleast be (in exact order):
+        //         * 5C, JC, 5D, 5H, 5S, 4S, 4C, 4H, 4D, 3S, 3C, 3H, 3D
+        //     * Both players removed indices: 5, 4
+        //
+        // Then the Pegging (for the 29 dealer score) would be:
+        //     * 3H(P,0), 3D(D,2), 3S(P,6), 3C(D,12), 4H(P,0), 4D(D,2), 4S(P,6), 4C(D,12),GO(D,1)
+        //     * (P,110), (D,112), (P,116), (D,124*) if P and D start w/ 110pts
+        //         * D = Dealer played.
+        //         * P = Pone played.
+        //         * # = Points earned.
+        //     * Dealer Scores: 29pts
+        //     * Pone Scores: 12pts
+        //     * Both players play indices: 0, 0, 0, 0
+        //
+        // Counting scores:
+        //     * Pone Hand: 3H, 3S, 4H, 4S, 5C (Starter)
+        //         * Score w/ Starter: 20pts
+        //             * 2x15s (4pts) + 2xPairs (4pts) + 4xrun-of-3 (12pts)
+        //     * Dealer Hand: 3D, 3C, 4D, 4C, 5C (Starter)
+        //         * Score w/ Starter: 20pts
+        //             * 2x15s (4pts) + 2xPairs (4pts) + 4xrun-of-3 (12pts)
+        //     * Dealer Crib: JC, 5D, 5H, 5S, 5C (Starter)
+        //         * Score w/ Starter: 29pts
+        //             * 8x15s (16pts) + 6xPairs (12pts) + Nobs (1pt)
+        //
+        // Total Points for Players:
+        //     * Pone: 32pts
+        //         * Peggings (12pts) + Hand (20pts)
+        //     * Dealer: 78pts
+        //         * Peggings (29pts) + Hand (20pts) + Crib (29pts)
+        //
+        // If deck is doesn't change between rounds, but dealers alternate:
+        //     * P1 chooses JC for cut, P2 chooses 5D for cut
+        //         * P1 wins and is first dealer
+        //     * Round 1 (P1 = Dealer, P2 = Pone):
+        //         * P1: 78pts
+        //         * P2: 32pts
+        //         * Both players chose indices for discarding and pegging: 5,4,0,0,0,0
+        //     * Round 2 (P1 = Pone, P2 = Dealer):
+        //         * P1: 110pts
+        //         * P2: 110pts
+        //         * Both players chose indices for discarding and pegging: 5,4,0,0,0,0
+        //     * Round 3 (P1 = Dealer, P2 = Pone):
+        //         * P1: 124pts
+        //         * P2: 116pts
+        //         * Both players chose indices for discarding and pegging: 5,4,0,0
+        //         * Game ends when dealers plays their 3C during pegging.
+        //     * For all rounds both players chose the following indices for discarding and pegging:
+        //         * 5,4,0,0,0,0,5,4,0,0,0,0,5,4,0,0
+        let controller =
+            PredeterminedController::from(vec![11, 5, 4, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 5, 4, 0, 0]);
 
-        // Chose Eight of Diamonds
-        let player_2_controller = PredeterminedController::from(vec![0, 69]);
-        let player_2 = Player::new(player_2_controller);
+        let player_1 = Player::new(controller.clone());
+        let player_2 = Player::new(controller);
 
         let deck_cards = vec![
-            Card::new(Rank::Eight, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-            Card::new(Rank::Six, Suit::Clubs),
-            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Spades),
+            Card::new(Rank::Four, Suit::Spades),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Diamonds),
+            Card::new(Rank::Three, Suit::Spades),
+            Card::new(Rank::Three, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Diamonds),
         ];
         let deck = Deck::new_with_cards(deck_cards);
 
         let mut game = Game::new_with_deck(player_1, player_2, deck.clone());
 
-        let expected_player_1_controller = PredeterminedController::from(vec![32]);
-        let expected_player_1 = Player::new(expected_player_1_controller);
+        game.options.first_dealer = FirstDealer::CutWinner;
 
-        let expected_player_2_controller = PredeterminedController::from(vec![69]);
-        let expected_player_2 = Player::new(expected_player_2_controller);
+        let expected_dealer_points = 124;
+        let expected_pone_points = 116;
 
-        game.choose_dealer();
+        game.play(&Some(deck)).unwrap();
 
-        assert_eq!(game.deck, deck);
-        assert_eq!(game.player_1, expected_player_1);
-        assert_eq!(game.player_2, expected_player_2);
+        assert_eq!(game.player_1.points, expected_dealer_points);
+        assert_eq!(game.player_2.points, expected_pone_points);
     }
 
     #[test]
-    fn test_game_choose_dealer_player_2_wins_higher_value() {
-        // Chose Eight of Diamonds
-        let player_1_controller = PredeterminedController::from(vec![0, 32]);
-        let player_1 = Player::new(player_1_controller);
+    #[should_panic(expected = "Cards were lost or duplicated in the Deal phase!")]
+    fn test_census_checks_catch_a_short_deck() {
+        let mut deck_cards = Deck::new().as_vec().clone();
 
-        // Chose King of Diamonds
-        let player_2_controller = PredeterminedController::from(vec![0, 69]);
-        let player_2 = Player::new(player_2_controller);
+        deck_cards.pop();
 
-        let deck_cards = vec![
-            Card::new(Rank::Eight, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-            Card::new(Rank::Six, Suit::Clubs),
-            Card::new(Rank::Eight, Suit::Clubs),
-        ];
-        let deck = Deck::new_with_cards(deck_cards);
+        let player_1 = Player::new(PredeterminedController::from(vec![0]));
+        let player_2 = Player::new(PredeterminedController::from(vec![1]));
 
-        let mut game = Game::new_with_deck(player_1, player_2, deck.clone());
+        let mut game = Game::new_with_deck(player_1, player_2, Deck::new_with_cards(deck_cards))
+            .with_census_checks();
 
-        let expected_player_1_controller = PredeterminedController::from(vec![32]);
-        let expected_player_1 = Player::new(expected_player_1_controller);
+        let _ = game.step();
+    }
 
-        let expected_player_2_controller = PredeterminedController::from(vec![69]);
-        let expected_player_2 = Player::new(expected_player_2_controller);
+    #[test]
+    fn test_to_act_through_a_round() {
+        let player_1 = Player::new(RngController::new_with_seed(3));
+        let player_2 = Player::new(RngController::new_with_seed(4));
 
-        game.choose_dealer();
+        let mut game = Game::new(player_1, player_2).with_seed(5);
 
-        assert_eq!(game.deck, deck);
-        assert_eq!(game.player_1, expected_player_1);
-        assert_eq!(game.player_2, expected_player_2);
+        assert_eq!(game.to_act(), Some(PlayerId::One));
+
+        game.step().unwrap();
+
+        let pone = PlayerId::from_player_1(!game.round.player_1_is_dealer);
+
+        for expected in [None, Some(pone), Some(pone), None] {
+            assert_eq!(game.to_act(), expected, "in {:?}", game.phase);
+
+            game.step().unwrap();
+        }
+
+        assert_eq!(game.phase, Phase::Deal);
+        assert_eq!(game.to_act(), None);
     }
 
     #[test]
-    fn test_game_choose_dealer_player_2_wins_same_value_higher_suit() {
-        // Chose Eight of Diamonds
-        let player_1_controller = PredeterminedController::from(vec![0, 32]);
-        let player_1 = Player::new(player_1_controller);
+    fn test_step_driven_game_replays_like_it_was_played() {
+        let mut game = Game::new_step_driven().with_seed(11);
+
+        let mut decision = game.next_decision().unwrap();
+
+        while decision.player().is_some() {
+            assert!(game.card_census().is_complete(), "{:?}", decision);
+
+            let response = match decision {
+                Decision::Cut { deck_size, .. } => DecisionResponse::Cut(deck_size - 1),
+                Decision::Discard { .. } => DecisionResponse::Discard([3, 1]),
+                Decision::CutStarter { .. } => DecisionResponse::CutStarter(5),
+                Decision::Play {
+                    ref hand,
+                    stack_score,
+                    ..
+                } => {
+                    let playable = hand
+                        .iter()
+                        .rposition(|card| stack_score + card.score() <= 31)
+                        .unwrap();
+
+                    DecisionResponse::Play(playable)
+                }
+                Decision::Over { .. } => unreachable!(),
+            };
 
-        // Chose Eight of Clubs
-        let player_2_controller = PredeterminedController::from(vec![2, 69]);
-        let player_2 = Player::new(player_2_controller);
+            decision = game.apply(response).unwrap();
+        }
 
-        let deck_cards = vec![
-            Card::new(Rank::Eight, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-            Card::new(Rank::Six, Suit::Clubs),
-            Card::new(Rank::Eight, Suit::Clubs),
-        ];
-        let deck = Deck::new_with_cards(deck_cards);
+        let player_1_won = game.player_1.points >= game.options.target_score;
 
-        let mut game = Game::new_with_deck(player_1, player_2, deck.clone());
+        assert_eq!(game.phase, Phase::Over);
+        assert_eq!(
+            decision,
+            Decision::Over {
+                winner: PlayerId::from_player_1(player_1_won)
+            }
+        );
+        assert_eq!(game.next_decision(), Ok(decision));
 
-        let expected_player_1_controller = PredeterminedController::from(vec![32]);
-        let expected_player_1 = Player::new(expected_player_1_controller);
+        let mut replay = Game::replay(game.history(), NoOpDisplay::new());
+
+        let outcome = replay.play_quiet().unwrap();
+
+        assert_eq!(outcome.player_1_won, player_1_won);
+        assert_eq!(replay.history(), game.history());
+    }
+
+    #[test]
+    fn test_step_driven_game_keeps_waiting_after_a_bad_response() {
+        let options = GameOptions {
+            first_dealer: FirstDealer::Fixed { player_1: false },
+            ..GameOptions::default()
+        };
+
+        let mut game = Game::new_step_driven().with_options(options).with_seed(2);
+
+        let decision = game.next_decision().unwrap();
+        let history = game.history().to_vec();
+
+        assert_eq!(decision.player(), Some(PlayerId::One));
+        assert_eq!(
+            game.apply(DecisionResponse::Play(0)),
+            Err(GameError::UnexpectedResponse(DecisionResponse::Play(0)))
+        );
+        assert_eq!(
+            game.apply(DecisionResponse::Discard([0, 9])),
+            Err(GameError::InvalidIndex { index: 9, len: 6 })
+        );
+        assert_eq!(game.history(), history.as_slice());
+        assert_eq!(game.phase, Phase::Discard);
+        assert_eq!(game.next_decision(), Ok(decision.clone()));
+
+        let next = game.apply(DecisionResponse::Discard([0, 1])).unwrap();
+
+        assert_eq!(next.player(), Some(PlayerId::Two));
+        assert!(matches!(next, Decision::Discard { .. }));
+
+        let next = game.apply(DecisionResponse::Discard([2, 3])).unwrap();
+
+        assert_eq!(next.player(), Some(PlayerId::One));
+        assert!(matches!(next, Decision::CutStarter { .. }));
+
+        // The discards are only taken once the whole step can be.
+        assert_eq!(game.player_1.hand.len(), 6);
+
+        game.apply(DecisionResponse::CutStarter(20)).unwrap();
+
+        assert_eq!(game.phase, Phase::Play);
+        assert_eq!(game.player_1.hand.len(), 4);
+        assert_eq!(game.player_2.hand.len(), 4);
+    }
+
+    #[test]
+    fn test_step_driven_game_rejects_a_play_over_31() {
+        let mut game = Game::new_step_driven().with_seed(11);
+
+        let mut decision = game.next_decision().unwrap();
+        let mut rejected = 0;
+
+        while decision.player().is_some() {
+            let response = match decision {
+                Decision::Cut { .. } => DecisionResponse::Cut(0),
+                Decision::Discard { .. } => DecisionResponse::Discard([0, 1]),
+                Decision::CutStarter { .. } => DecisionResponse::CutStarter(0),
+                Decision::Play {
+                    ref hand,
+                    stack_score,
+                    ..
+                } => {
+                    if let Some(over) = hand.iter().position(|card| 31 < stack_score + card.score())
+                    {
+                        let history = game.history().to_vec();
+
+                        assert_eq!(
+                            game.apply(DecisionResponse::Play(over)),
+                            Err(GameError::InvalidPlay(hand[over].clone()))
+                        );
+                        assert_eq!(game.history(), history.as_slice());
+                        assert_eq!(game.next_decision(), Ok(decision.clone()));
+
+                        rejected += 1;
+                    }
+
+                    let playable = hand
+                        .iter()
+                        .position(|card| stack_score + card.score() <= 31)
+                        .unwrap();
+
+                    DecisionResponse::Play(playable)
+                }
+                Decision::Over { .. } => unreachable!(),
+            };
+
+            decision = game.apply(response).unwrap();
+        }
+
+        assert!(0 < rejected);
+        assert_eq!(game.phase, Phase::Over);
+    }
+
+    #[test]
+    fn test_game_play_checked_names_the_exhausted_controller() {
+        let player_1 = Player::new(PredeterminedController::from(vec![0, 0, 1]));
+        let player_2 = Player::new(PredeterminedController::from(vec![1]));
+
+        let mut game = Game::new_with_deck(player_1, player_2, Deck::new());
+
+        let expected = GameError::ControllerExhausted {
+            phase: Phase::Discard,
+            player_1: false,
+        };
+
+        assert_eq!(game.play_checked(), Err(expected.clone()));
+        assert_eq!(
+            expected.to_string(),
+            "Player 2 Controller ran out of moves in the Discard phase!"
+        );
+    }
+
+    #[test]
+    fn test_game_play_quiet_out_of_moves() {
+        let controller = PredeterminedController::from(vec![]);
+
+        let player_1 = Player::new(controller.clone());
+        let player_2 = Player::new(controller);
+
+        let mut game = Game::new(player_1, player_2);
+
+        let result = game.play_quiet();
+
+        assert_eq!(result, Err(GameError::OutOfMoves(String::from("Player 1"))));
+    }
+
+    #[test]
+    fn test_game_play_quiet_invalid_index() {
+        let controller = PredeterminedController::from(vec![60]);
+
+        let player_1 = Player::new(controller.clone());
+        let player_2 = Player::new(controller);
+
+        let mut game = Game::new(player_1, player_2);
+
+        let result = game.play_quiet();
+
+        assert_eq!(result, Err(GameError::InvalidIndex { index: 60, len: 52 }));
+    }
+
+    #[test]
+    fn test_game_count_round_before_play_round() {
+        let player_1 = Player::new(RngController::new());
+        let player_2 = Player::new(RngController::new());
+
+        let mut game = Game::new(player_1, player_2);
+
+        game.cut_round().unwrap();
+        game.deal_round().unwrap();
+        game.discard_round().unwrap();
 
-        let expected_player_2_controller = PredeterminedController::from(vec![69]);
-        let expected_player_2 = Player::new(expected_player_2_controller);
+        let points = (game.player_1.points, game.player_2.points);
 
-        game.choose_dealer();
+        let expected = Err(GameError::WrongPhase {
+            expected: Phase::Count,
+            actual: Phase::Play,
+        });
 
-        assert_eq!(game.deck, deck);
-        assert_eq!(game.player_1, expected_player_1);
-        assert_eq!(game.player_2, expected_player_2);
+        assert_eq!(game.count_round(), expected);
+        assert_eq!(game.phase(), Phase::Play);
+        assert_eq!((game.player_1.points, game.player_2.points), points);
     }
 
     #[test]
-    fn test_game_run_deal_and_discard_round() {
-        // Discard Six of Hearts and Eight of Clubs to crib
-        let player_1_controller = PredeterminedController::from(vec![0, 3, 32]);
+    fn test_game_run_discard_round_shows_crib_discards() {
+        // Discard Four of Clubs and Six of Clubs to crib
+        let player_1_controller = PredeterminedController::from(vec![0, 3]);
         let player_1 = Player::new(player_1_controller);
 
-        // Discard Five of Clubs and Six of Clubs to crib
-        let player_2_controller = PredeterminedController::from(vec![2, 3, 69]);
+        // Discard Seven of Clubs and Eight of Clubs to crib
+        let player_2_controller = PredeterminedController::from(vec![2, 3]);
         let player_2 = Player::new(player_2_controller);
 
         // Deck is dealt in reverse!
@@ -749,544 +4525,495 @@ mod tests {
         ];
         let deck = Deck::new_with_cards(deck_cards);
 
-        let mut game = Game::new_with_deck(player_1, player_2, deck);
+        let options = GameOptions {
+            show_crib_discards: true,
+            ..GameOptions::default()
+        };
 
-        let expected_player_1_cards = vec![
-            Card::new(Rank::Jack, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-        ];
-        let expected_player_1_crib = vec![
-            Card::new(Rank::Five, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Hearts),
-            Card::new(Rank::Six, Suit::Clubs),
-            Card::new(Rank::Eight, Suit::Clubs),
-        ];
-        let expected_player_1_controller = PredeterminedController::from(vec![32]);
-        let expected_player_1 = Player::new_with_cards_and_crib(
-            expected_player_1_controller,
-            expected_player_1_cards,
-            expected_player_1_crib,
-        );
+        let mut game =
+            Game::new_with_deck_default(player_1, player_2, deck, RecordingDisplay::default())
+                .with_options(options);
 
-        let expected_player_2_cards = vec![
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Eight, Suit::Diamonds),
+        // Player 1 is the dealer, and the pone (player 2) discards both first.
+        let expected = vec![
+            String::from("Opponent false [7♣] [8♣]"),
+            String::from("Player true [4♣] [6♣]"),
         ];
-        let expected_player_2_controller = PredeterminedController::from(vec![69]);
-        let expected_player_2 =
-            Player::new_with_cards(expected_player_2_controller, expected_player_2_cards);
 
-        game.run_deal_and_discard_round();
+        game.run_deal_round().unwrap();
+        game.run_discard_round().unwrap();
 
-        assert_eq!(game.deck, Deck::new_with_cards(Vec::new()));
-        assert_eq!(game.player_1, expected_player_1);
-        assert_eq!(game.player_2, expected_player_2);
+        assert_eq!(game.display.crib_discard_messages(), expected);
     }
 
     #[test]
-    fn test_game_get_starter_not_jack() {
-        // Discard Six of Hearts and Eight of Clubs to crib
-        let player_1_controller = PredeterminedController::from(vec![0, 3, 32]);
-        let player_1 = Player::new(player_1_controller);
-
-        // Discard Five of Clubs and Six of Clubs to crib
-        let player_2_controller = PredeterminedController::from(vec![2, 3, 69]);
-        let player_2 = Player::new(player_2_controller);
-
-        // Deck is dealt in reverse!
-        let deck_cards = vec![
-            Card::new(Rank::Eight, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-            Card::new(Rank::Six, Suit::Clubs),
-            Card::new(Rank::Eight, Suit::Clubs),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::Five, Suit::Clubs),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Jack, Suit::Diamonds),
+    fn test_game_run_discard_round_undo() {
+        // Discard Four of Clubs and Five of Clubs, take them back, then discard Five of Clubs and
+        // Six of Hearts.
+        let player_1_controller = UndoController {
+            predetermined: PredeterminedController::from(vec![0, 0, 1, 1]),
+            undos: 1,
+        };
+        let player_1_cards = vec![
             Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Clubs),
             Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Diamonds),
         ];
-        let deck = Deck::new_with_cards(deck_cards);
-
-        let mut game = Game::new_with_deck(player_1, player_2, deck);
+        let player_1 = Player::new_with_cards(player_1_controller, player_1_cards);
 
-        let expected_deck_cards = vec![
-            Card::new(Rank::Eight, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-            Card::new(Rank::Six, Suit::Clubs),
-            Card::new(Rank::Eight, Suit::Clubs),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::Five, Suit::Clubs),
+        // Discard Seven of Clubs and Eight of Clubs
+        let player_2_controller = PredeterminedController::from(vec![0, 0]);
+        let player_2_cards = vec![
             Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Jack, Suit::Diamonds),
-            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::King, Suit::Diamonds),
         ];
-        let expected_dealer_points = 0;
-        let expected_pone_points = 0;
-
-        let starter = game.get_starter();
+        let player_2 = Player::new_with_cards(player_2_controller, player_2_cards);
 
-        assert_eq!(starter, Card::new(Rank::Six, Suit::Hearts));
-        assert_eq!(game.deck.as_vec(), &expected_deck_cards);
-        assert_eq!(game.player_1.points, expected_dealer_points);
-        assert_eq!(game.player_2.points, expected_pone_points);
-    }
+        let options = GameOptions {
+            allow_undo: true,
+            ..GameOptions::default()
+        };
 
-    #[test]
-    fn test_game_get_starter_jack() {
-        // Discard Six of Hearts and Eight of Clubs to crib
-        let player_1_controller = PredeterminedController::from(vec![0, 3, 32]);
-        let player_1 = Player::new(player_1_controller);
+        let mut game = Game::new_default(player_1, player_2, RecordingDisplay::default())
+            .with_options(options);
 
-        // Discard Five of Clubs and Six of Clubs to crib
-        let player_2_controller = PredeterminedController::from(vec![2, 3, 69]);
-        let player_2 = Player::new(player_2_controller);
+        game.run_discard_round().unwrap();
 
-        // Deck is dealt in reverse!
-        let deck_cards = vec![
-            Card::new(Rank::Eight, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-            Card::new(Rank::Six, Suit::Clubs),
+        let expected_crib = Hand::from(vec![
+            Card::new(Rank::Seven, Suit::Clubs),
             Card::new(Rank::Eight, Suit::Clubs),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Queen, Suit::Diamonds),
             Card::new(Rank::Five, Suit::Clubs),
-            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Hearts),
+        ]);
+        let expected_player_1_hand = Hand::from(vec![
+            Card::new(Rank::Four, Suit::Clubs),
             Card::new(Rank::Six, Suit::Diamonds),
+        ]);
+
+        assert_eq!(game.round.crib, expected_crib);
+        assert_eq!(game.player_1.hand, expected_player_1_hand);
+        assert_eq!(
+            game.display.undo_messages(),
+            vec![String::from("undo Player 2")]
+        );
+        assert!(game.undo_stack.is_empty());
+    }
+
+    #[test]
+    fn test_game_run_play_round_undo() {
+        // Player 1 plays AC, takes it back, and plays QC instead, then the play is the same as
+        // `test_game_run_play_round_31_other_player_leads`.
+        let starter = Card::new(Rank::Eight, Suit::Hearts);
+
+        let player_1_controller = UndoController {
+            predetermined: PredeterminedController::from(vec![1, 0, 0, 0]),
+            undos: 1,
+        };
+        let player_1_cards = vec![
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Clubs),
             Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Hearts),
-            Card::new(Rank::Jack, Suit::Diamonds),
         ];
-        let deck = Deck::new_with_cards(deck_cards);
-
-        let mut game = Game::new_with_deck(player_1, player_2, deck);
+        let player_1 = Player::new_with_cards(player_1_controller, player_1_cards);
 
-        let expected_deck_cards = vec![
-            Card::new(Rank::Eight, Suit::Diamonds),
+        let player_2_controller = PredeterminedController::from(vec![0, 0, 0]);
+        let player_2_cards = vec![
             Card::new(Rank::King, Suit::Diamonds),
-            Card::new(Rank::Six, Suit::Clubs),
-            Card::new(Rank::Eight, Suit::Clubs),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::Five, Suit::Clubs),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Ten, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Diamonds),
         ];
-        let expected_dealer_points = 2;
-        let expected_pone_points = 0;
+        let player_2 = Player::new_with_cards(player_2_controller, player_2_cards);
 
-        let starter = game.get_starter();
+        let options = GameOptions {
+            allow_undo: true,
+            ..GameOptions::default()
+        };
 
-        assert_eq!(starter, Card::new(Rank::Jack, Suit::Diamonds));
-        assert_eq!(game.deck.as_vec(), &expected_deck_cards);
-        assert_eq!(game.player_1.points, expected_dealer_points);
-        assert_eq!(game.player_2.points, expected_pone_points);
+        let mut game = Game::new_default(player_1, player_2, RecordingDisplay::default())
+            .with_options(options);
+
+        game.run_play_round(&starter).unwrap();
+
+        assert_eq!(game.player_1.points, 3);
+        assert_eq!(game.player_2.points, 0);
+        assert_eq!(
+            game.history()[1],
+            GameEvent::PlayCard {
+                player_1: true,
+                card: Card::new(Rank::Queen, Suit::Clubs),
+            }
+        );
+        assert_eq!(
+            game.display.undo_messages(),
+            vec![String::from("undo Player 1")]
+        );
     }
 
     #[test]
-    fn test_game_run_play_round() {
-        // Play stack (start with p2)
-        //     * Stack 1 -> 7D(p2, 0pt, 7), 7C(p1, 2pt, 14), 8D(p2, 0pt, 22), 6D(p2, 3pt, 28),
-        //                  GO(p2, 1pt, 28)
-        //     * Stack 2 -> 4C(p2, 0pt, 4), JD(p1, 0pt, 14), QD(p1, 0pt, 24), GO(p1, 1pt, 24)
-        //     * Stack 3 -> KD(p1, 0pt, 10), GO (p1, 1pt, 10)
-        //
-        // Score at end: p1 = 4 (pair and 2 GOs), p2 = 4 (run of 3 and a GO)
-        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+    fn test_game_run_play_round_undo_not_allowed() {
+        let starter = Card::new(Rank::Eight, Suit::Hearts);
 
-        // Discard: 7C, JD, QD, KD
-        let player_1_controller = PredeterminedController::from(vec![1, 0, 0, 0, 32]);
+        let player_1_controller = UndoController {
+            predetermined: PredeterminedController::from(vec![1, 0, 0]),
+            undos: 1,
+        };
         let player_1_cards = vec![
-            Card::new(Rank::Jack, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Clubs),
         ];
         let player_1 = Player::new_with_cards(player_1_controller, player_1_cards);
 
-        // Discard: 7D, 8D, 6D, 4C
-        let player_2_controller = PredeterminedController::from(vec![2, 2, 1, 0, 69]);
+        let player_2_controller = PredeterminedController::from(vec![0, 0, 0]);
         let player_2_cards = vec![
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Ten, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Diamonds),
         ];
         let player_2 = Player::new_with_cards(player_2_controller, player_2_cards);
 
-        let mut game = Game::new(player_1, player_2);
+        let mut game = Game::new_default(player_1, player_2, RecordingDisplay::default());
 
-        let expected_dealer_points = 4;
-        let expected_pone_points = 4;
+        game.run_play_round(&starter).unwrap();
+
+        // The Ace of Clubs stays played.
+        assert_eq!(
+            game.history()[1],
+            GameEvent::PlayCard {
+                player_1: true,
+                card: Card::new(Rank::Ace, Suit::Clubs),
+            }
+        );
+        assert!(game.display.undo_messages().is_empty());
+    }
 
-        game.run_play_round(&starter);
+    #[test]
+    fn test_game_replay_matches_history() {
+        for seed in 0..50 {
+            let mut deck = Deck::new();
 
-        assert_eq!(game.player_1.points, expected_dealer_points);
-        assert_eq!(game.player_2.points, expected_pone_points);
+            deck.shuffle_with_seed(seed);
 
-        // assert that the [`Hand`]s were reset
-        assert_eq!(game.player_1.hand.as_vec().len(), 4);
-        assert_eq!(game.player_2.hand.as_vec().len(), 4);
-        assert!(game.player_1.discarded.is_empty());
-        assert!(game.player_2.discarded.is_empty());
+            let player_1 = Player::new(RngController::new_with_seed(seed));
+            let player_2 = Player::new(RngController::new_with_seed(seed + 1_000_000));
+
+            let mut game = Game::new_with_deck(player_1, player_2, deck);
+
+            let outcome = game.play_quiet().unwrap();
+
+            let mut replay = Game::replay(game.history(), NoOpDisplay::new());
+
+            assert_eq!(replay.play_quiet(), Ok(outcome), "seed {}", seed);
+            assert_eq!(replay.history(), game.history(), "seed {}", seed);
+        }
     }
 
-    #[test]
-    fn test_game_run_play_round_player_1_hit_121_before_first_reset() {
-        // Play stack (start with p2) p1.points = 118 && p2.points = 120
-        //     * Stack 1 -> 7D(p2, 0pt, 7), 7C(p1, 2pt, 14), 8D(p2, 0pt, 22), 6D(p2, 3pt, 28),
-        //                  GO(p2, 1pt, 28)
-        //     * p1 hit 121 break
-        //
-        // Score at end: p1 = 120 (pair), p2 = 124 (run of 3 and a GO)
-        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+    /// The most points a [`Player`] can sanely score in a single round: a 29 hand, a 29 crib, his
+    /// heels, and a generous bound for pegging.
+    const MAX_POINTS_PER_ROUND: u32 = 29 + 29 + 2 + 30;
 
-        // Discard: 7C, JD, QD, KD
-        let player_1_controller = PredeterminedController::from(vec![1, 0, 0, 0, 32]);
-        let player_1_cards = vec![
-            Card::new(Rank::Jack, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-        ];
-        let mut player_1 = Player::new_with_cards(player_1_controller, player_1_cards);
-        player_1.points = 118;
+    /// A stateful [`Controller`] that can't be cloned, and counts its decisions.
+    #[derive(Debug)]
+    struct CountingController {
+        rng: RngController,
+        decisions: Rc<Cell<usize>>,
+    }
 
-        // Discard: 7D, 8D, 6D, 4C
-        let player_2_controller = PredeterminedController::from(vec![2, 2, 1, 0, 69]);
-        let player_2_cards = vec![
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Eight, Suit::Diamonds),
-        ];
-        let mut player_2 = Player::new_with_cards(player_2_controller, player_2_cards);
-        player_2.points = 120;
+    impl Controller for CountingController {
+        fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+            self.decisions.set(self.decisions.get() + 1);
 
-        let mut game = Game::new(player_1, player_2);
+            self.rng.get_card_index(available_cards)
+        }
 
-        let expected_dealer_points = 120;
-        let expected_pone_points = 124;
+        /// A play over 31 is chosen again, so it isn't counted as a decision.
+        fn play_rejected(&mut self, _: &Card) {
+            self.decisions.set(self.decisions.get() - 1);
+        }
+    }
 
-        game.run_play_round(&starter);
+    /// Takes back its first `undos` choices.
+    #[derive(Debug)]
+    struct UndoController {
+        predetermined: PredeterminedController<NoOpDisplay>,
+        undos: usize,
+    }
 
-        assert_eq!(game.player_1.points, expected_dealer_points);
-        assert_eq!(game.player_2.points, expected_pone_points);
+    impl Controller for UndoController {
+        fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+            self.predetermined.get_card_index(available_cards)
+        }
 
-        // assert that the hands were reset
-        assert_eq!(game.player_1.hand.as_vec().len(), 4);
-        assert_eq!(game.player_2.hand.as_vec().len(), 4);
-        assert!(game.player_1.discarded.is_empty());
-        assert!(game.player_2.discarded.is_empty());
+        fn undo_choice(&mut self, _: &[Card]) -> bool {
+            let undo = 0 < self.undos;
+
+            self.undos = self.undos.saturating_sub(1);
+
+            undo
+        }
     }
 
-    #[test]
-    fn test_game_run_counting_round() {
-        let controller = PredeterminedController::from(Vec::new());
+    /// The [`Phase`], starter, and dealer flag of a [`GameContext`].
+    type SeenContext = (Phase, Option<Card>, bool);
 
-        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+    /// Records each [`GameContext`] it's given.
+    struct ContextController {
+        rng: RngController,
+        contexts: Rc<RefCell<Vec<SeenContext>>>,
+    }
 
-        // Hand Score 6pts: 15 2pts, 3-run 3pts, Nobs 1pt
-        // Crib Score 13pts: 15 4pts, 4-run 4pts, 5-flush 5pts
-        // Total Score 19pts
-        let player_1_cards = vec![
-            Card::new(Rank::Jack, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-        ];
-        let player_1_crib = vec![
-            Card::new(Rank::Ace, Suit::Diamonds),
-            Card::new(Rank::Two, Suit::Diamonds),
-            Card::new(Rank::Three, Suit::Diamonds),
-            Card::new(Rank::Four, Suit::Diamonds),
-        ];
-        let player_1 =
-            Player::new_with_cards_and_crib(controller.clone(), player_1_cards, player_1_crib);
+    impl Controller for ContextController {
+        fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+            self.rng.get_card_index(available_cards)
+        }
+
+        fn choose(&mut self, context: &GameContext, available_cards: &[Card]) -> Option<usize> {
+            self.contexts.borrow_mut().push((
+                context.phase,
+                context.starter.cloned(),
+                context.is_dealer,
+            ));
 
-        // Hand Score 12pts: 15 4pts, Pair 2pts, 2x 3-run 6pts
-        let player_2_cards = vec![
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Eight, Suit::Clubs),
-        ];
-        let player_2 = Player::new_with_cards(controller, player_2_cards);
+            self.get_card_index(available_cards)
+        }
+    }
 
-        let mut game = Game::new(player_1, player_2);
+    #[test]
+    fn test_game_run_short_game_with_fixed_dealer() {
+        let options = GameOptions {
+            target_score: 61,
+            first_dealer: FirstDealer::Fixed { player_1: false },
+            ..GameOptions::default()
+        };
 
-        let expected_dealer_points = 19;
-        let expected_pone_points = 12;
+        let player_1 = Player::new(RngController::new_with_seed(9));
+        let player_2 = Player::new(RngController::new_with_seed(10));
 
-        game.run_counting_round(&starter);
+        let mut game = Game::new(player_1, player_2)
+            .with_options(options)
+            .with_seed(9);
 
-        assert_eq!(game.player_1.points, expected_dealer_points);
-        assert_eq!(game.player_2.points, expected_pone_points);
+        let outcome = game.play(&None).unwrap();
+
+        let winner_points = if outcome.player_1_won {
+            outcome.player_1_points
+        } else {
+            outcome.player_2_points
+        };
+
+        assert!((61..121).contains(&winner_points));
+        assert!(outcome.player_1_points.min(outcome.player_2_points) < 61);
+        assert!(matches!(
+            game.history()[0],
+            GameEvent::Deal {
+                player_1_is_dealer: false,
+                ..
+            }
+        ));
     }
 
     #[test]
-    fn test_game_run_counting_round_player_2_hit_121_before_player_1_can_count() {
-        let controller = PredeterminedController::from(Vec::new());
+    fn test_game_run_five_card_game() {
+        let options = GameOptions {
+            first_dealer: FirstDealer::Fixed { player_1: true },
+            ..GameOptions::default()
+        }
+        .with_rules(GameRules::five_card());
 
-        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+        let player_1 = Player::new(RngController::new_with_seed(5));
+        let player_2 = Player::new(RngController::new_with_seed(6));
 
-        // Hand Score 6pts: 15 2pts, 3-run 3pts, Nobs 1pt
-        // Crib Score 13pts: 15 4pts, 4-run 4pts, 5-flush 5pts
-        // Total Score 19pts
-        let player_1_cards = vec![
-            Card::new(Rank::Jack, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-        ];
-        let player_1_crib = vec![
-            Card::new(Rank::Ace, Suit::Diamonds),
-            Card::new(Rank::Two, Suit::Diamonds),
-            Card::new(Rank::Three, Suit::Diamonds),
-            Card::new(Rank::Four, Suit::Diamonds),
-        ];
-        let player_1 =
-            Player::new_with_cards_and_crib(controller.clone(), player_1_cards, player_1_crib);
+        let mut game = Game::new(player_1, player_2)
+            .with_options(options)
+            .with_seed(5);
 
-        // Hand Score 12pts: 15 4pts, Pair 2pts, 2x 3-run 6pts
-        let player_2_cards = vec![
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Eight, Suit::Clubs),
-        ];
-        let mut player_2 = Player::new_with_cards(controller, player_2_cards);
-        player_2.points = 110;
+        let outcome = game.play(&None).unwrap();
 
-        let mut game = Game::new(player_1, player_2);
+        // The pone pegs three for last before the first deal.
+        assert_eq!(
+            game.history()[0],
+            GameEvent::ScorePoints {
+                player_1: false,
+                points: 3,
+                reason: ScoreReason::ThreeForLast,
+            }
+        );
+        assert!(matches!(
+            game.history()[1],
+            GameEvent::Deal {
+                ref player_1_hand,
+                ref player_2_hand,
+                ..
+            } if (player_1_hand.len() == 5) && (player_2_hand.len() == 5)
+        ));
+        assert!(game.history().iter().all(|event| match event {
+            GameEvent::HandCount { cards, is_crib, .. } =>
+                cards.len() == if *is_crib { 4 } else { 3 },
+            _ => true,
+        }));
+        assert!(61 <= outcome.player_1_points.max(outcome.player_2_points));
+    }
 
-        let expected_dealer_points = 0;
-        let expected_pone_points = 122;
+    #[test]
+    fn test_game_run_choose_sees_context() {
+        let contexts = Rc::new(RefCell::new(Vec::new()));
 
-        game.run_counting_round(&starter);
+        let controller_1 = ContextController {
+            rng: RngController::new_with_seed(5),
+            contexts: Rc::clone(&contexts),
+        };
 
-        assert_eq!(game.player_1.points, expected_dealer_points);
-        assert_eq!(game.player_2.points, expected_pone_points);
+        let player_1 = Player::new(controller_1);
+        let player_2 = Player::new(RngController::new_with_seed(6));
+
+        let mut game = Game::new(player_1, player_2).with_seed(5);
+
+        game.play(&None).unwrap();
+
+        let contexts = contexts.borrow();
+
+        assert_eq!(contexts[0], (Phase::Cut, None, false));
+        assert!(contexts[1..]
+            .iter()
+            .all(|(phase, starter, _)| (*phase == Phase::Play) && starter.is_some()));
+        assert!(contexts.iter().any(|(_, _, is_dealer)| *is_dealer));
+        assert!(contexts[1..].iter().any(|(_, _, is_dealer)| !*is_dealer));
     }
 
     #[test]
-    fn test_game_reset_deck() {
-        let controller = PredeterminedController::from(Vec::new());
+    fn test_game_run_boxed_controller_against_rng_controller() {
+        let mut deck = Deck::new();
 
-        let starter = Card::new(Rank::Eight, Suit::Diamonds);
+        deck.shuffle_with_seed(3);
 
-        let player_1_cards = vec![
-            Card::new(Rank::Jack, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-        ];
-        let player_1_crib = vec![
-            Card::new(Rank::Ace, Suit::Diamonds),
-            Card::new(Rank::Two, Suit::Diamonds),
-            Card::new(Rank::Three, Suit::Diamonds),
-            Card::new(Rank::Four, Suit::Diamonds),
-        ];
-        let player_1 =
-            Player::new_with_cards_and_crib(controller.clone(), player_1_cards, player_1_crib);
+        let controller_1: Box<dyn Controller> = Box::new(ExpectimaxController::new(3));
 
-        let player_2_cards = vec![
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Eight, Suit::Clubs),
-        ];
-        let player_2 = Player::new_with_cards(controller, player_2_cards);
+        let player_1 = Player::new(controller_1);
+        let player_2 = Player::new(RngController::new_with_seed(4));
 
-        let deck = Deck::new_with_cards(Vec::new());
         let mut game = Game::new_with_deck(player_1, player_2, deck);
 
-        let expected_deck_cards = vec![
-            Card::new(Rank::Jack, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-            Card::new(Rank::Ace, Suit::Diamonds),
-            Card::new(Rank::Two, Suit::Diamonds),
-            Card::new(Rank::Three, Suit::Diamonds),
-            Card::new(Rank::Four, Suit::Diamonds),
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Eight, Suit::Clubs),
-            Card::new(Rank::Eight, Suit::Diamonds),
-        ];
-        let expected_deck = Deck::new_with_cards(expected_deck_cards);
-
-        game.reset_deck(starter);
+        let outcome = game.play(&None).unwrap();
 
-        assert_eq!(game.deck, expected_deck);
+        assert_eq!(
+            outcome.player_1_won,
+            game.player_1.points > game.player_2.points
+        );
+        assert!(121 <= game.player_1.points || 121 <= game.player_2.points);
     }
 
     #[test]
-    fn test_game_reset_deck_with() {
-        let controller = PredeterminedController::from(Vec::new());
+    fn test_game_run_keeps_controller_state_across_deals() {
+        let decisions = Rc::new(Cell::new(0));
 
-        let player_1_cards = vec![
-            Card::new(Rank::Jack, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-        ];
-        let player_1_crib = vec![
-            Card::new(Rank::Ace, Suit::Diamonds),
-            Card::new(Rank::Two, Suit::Diamonds),
-            Card::new(Rank::Three, Suit::Diamonds),
-            Card::new(Rank::Four, Suit::Diamonds),
-        ];
-        let player_1 =
-            Player::new_with_cards_and_crib(controller.clone(), player_1_cards, player_1_crib);
+        let controller_1 = CountingController {
+            rng: RngController::new_with_seed(7),
+            decisions: Rc::clone(&decisions),
+        };
 
-        let player_2_cards = vec![
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Eight, Suit::Clubs),
-        ];
-        let player_2 = Player::new_with_cards(controller, player_2_cards);
+        let mut deck = Deck::new();
+
+        deck.shuffle_with_seed(9);
+
+        let player_1 = Player::new(controller_1);
+        let player_2 = Player::new(RngController::new_with_seed(8));
 
-        let deck = Deck::new_with_cards(Vec::new());
         let mut game = Game::new_with_deck(player_1, player_2, deck);
 
-        let expected_deck_cards = vec![
-            Card::new(Rank::Jack, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Clubs),
-            Card::new(Rank::Queen, Suit::Diamonds),
-            Card::new(Rank::King, Suit::Diamonds),
-            Card::new(Rank::Ace, Suit::Diamonds),
-            Card::new(Rank::Two, Suit::Diamonds),
-            Card::new(Rank::Three, Suit::Diamonds),
-            Card::new(Rank::Four, Suit::Diamonds),
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Six, Suit::Diamonds),
-            Card::new(Rank::Seven, Suit::Diamonds),
-            Card::new(Rank::Eight, Suit::Clubs),
-            Card::new(Rank::Eight, Suit::Diamonds),
-        ];
-        let expected_deck = Deck::new_with_cards(expected_deck_cards);
+        game.play(&None).unwrap();
 
-        game.reset_deck_with(expected_deck.clone());
+        let player_1_moves = game
+            .history()
+            .iter()
+            .filter(|event| match event {
+                GameEvent::Discard { player_1, .. } | GameEvent::PlayCard { player_1, .. } => {
+                    *player_1
+                }
+                _ => false,
+            })
+            .count();
 
-        assert_eq!(game.deck, expected_deck);
-        assert!(!game.player_1.has_cards());
-        assert!(!game.player_2.has_cards());
+        // The cut, then every discard and play, were all made by the same controller.
+        assert_eq!(decisions.get(), 1 + player_1_moves);
     }
 
     #[test]
-    fn test_game_play() {
-        // The maximum number of points that can be scored in a single round by the dealer is 78.
-        //     * Pegging: 29pts
-        //     * Hand: 20pts
-        //     * Crib: 29pts
-        //
-        // This is achieved by dealing the following
-        //     * Pone: 3H, 3S, 4H, 4S, 5H, JC
-        //     * Dealer: 3D, 3C, 4D, 4C, 5S, 5D
-        //
-        // So the state after dealing, discarding, and getting the starter:
-        //     * Pone Hand: 3H, 3S, 4H, 4S
-        //         * Discarded/Removed: JC, 5H
-        //     * Dealer Hand: 3D, 3C, 4D, 4C
-        //         * Discarded/Removed: 5D, 5S
-        //         * Indices: 5, 4
-        //     * Dealer Crib: JC, 5D, 5H, 5S
-        //     * Starter Card: 5C
-        //         * Note: The Pone's Jack matches suit of the starter 5 (Clubs).
-        //     * So deck has to atleast be (in exact order):
-        //         * 5C, JC, 5D, 5H, 5S, 4S, 4C, 4H, 4D, 3S, 3C, 3H, 3D
-        //     * Both players removed indices: 5, 4
-        //
-        // Then the Pegging (for the 29 dealer score) would be:
-        //     * 3H(P,0), 3D(D,2), 3S(P,6), 3C(D,12), 4H(P,0), 4D(D,2), 4S(P,6), 4C(D,12),GO(D,1)
-        //     * (P,110), (D,112), (P,116), (D,124*) if P and D start w/ 110pts
-        //         * D = Dealer played.
-        //         * P = Pone played.
-        //         * # = Points earned.
-        //     * Dealer Scores: 29pts
-        //     * Pone Scores: 12pts
-        //     * Both players play indices: 0, 0, 0, 0
-        //
-        // Counting scores:
-        //     * Pone Hand: 3H, 3S, 4H, 4S, 5C (Starter)
-        //         * Score w/ Starter: 20pts
-        //             * 2x15s (4pts) + 2xPairs (4pts) + 4xrun-of-3 (12pts)
-        //     * Dealer Hand: 3D, 3C, 4D, 4C, 5C (Starter)
-        //         * Score w/ Starter: 20pts
-        //             * 2x15s (4pts) + 2xPairs (4pts) + 4xrun-of-3 (12pts)
-        //     * Dealer Crib: JC, 5D, 5H, 5S, 5C (Starter)
-        //         * Score w/ Starter: 29pts
-        //             * 8x15s (16pts) + 6xPairs (12pts) + Nobs (1pt)
-        //
-        // Total Points for Players:
-        //     * Pone: 32pts
-        //         * Peggings (12pts) + Hand (20pts)
-        //     * Dealer: 78pts
-        //         * Peggings (29pts) + Hand (20pts) + Crib (29pts)
-        //
-        // If deck is doesn't change between rounds, but dealers alternate:
-        //     * P1 chooses JC for cut, P2 chooses 5D for cut
-        //         * P1 wins and is first dealer
-        //     * Round 1 (P1 = Dealer, P2 = Pone):
-        //         * P1: 78pts
-        //         * P2: 32pts
-        //         * Both players chose indices for discarding and pegging: 5,4,0,0,0,0
-        //     * Round 2 (P1 = Pone, P2 = Dealer):
-        //         * P1: 110pts
-        //         * P2: 110pts
-        //         * Both players chose indices for discarding and pegging: 5,4,0,0,0,0
-        //     * Round 3 (P1 = Dealer, P2 = Pone):
-        //         * P1: 124pts
-        //         * P2: 116pts
-        //         * Both players chose indices for discarding and pegging: 5,4,0,0
-        //         * Game ends when dealers plays their 3C during pegging.
-        //     * For all rounds both players chose the following indices for discarding and pegging:
-        //         * 5,4,0,0,0,0,5,4,0,0,0,0,5,4,0,0
-        let controller =
-            PredeterminedController::from(vec![2, 5, 4, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 5, 4, 0, 0]);
+    fn test_game_fuzz_invariants() {
+        for seed in 0..1_000 {
+            let mut deck = Deck::new();
 
-        let player_1 = Player::new(controller.clone());
-        let player_2 = Player::new(controller);
+            deck.shuffle_with_seed(seed);
 
-        let deck_cards = vec![
-            Card::new(Rank::Five, Suit::Clubs),
-            Card::new(Rank::Jack, Suit::Clubs),
-            Card::new(Rank::Five, Suit::Diamonds),
-            Card::new(Rank::Five, Suit::Hearts),
-            Card::new(Rank::Five, Suit::Spades),
-            Card::new(Rank::Four, Suit::Spades),
-            Card::new(Rank::Four, Suit::Clubs),
-            Card::new(Rank::Four, Suit::Hearts),
-            Card::new(Rank::Four, Suit::Diamonds),
-            Card::new(Rank::Three, Suit::Spades),
-            Card::new(Rank::Three, Suit::Clubs),
-            Card::new(Rank::Three, Suit::Hearts),
-            Card::new(Rank::Three, Suit::Diamonds),
-        ];
-        let deck = Deck::new_with_cards(deck_cards);
+            let player_1 = Player::new(RngController::new_with_seed(seed));
+            let player_2 = Player::new(RngController::new_with_seed(seed + 1_000_000));
 
-        let mut game = Game::new_with_deck(player_1, player_2, deck.clone());
+            let mut game = Game::new_with_deck(player_1, player_2, deck);
 
-        let expected_dealer_points = 124;
-        let expected_pone_points = 116;
+            let mut rounds = 0;
+            let mut round_start_points = (0, 0);
 
-        game.play(&Some(deck));
+            while game.phase != Phase::Over {
+                let previous_points = (game.player_1.points, game.player_2.points);
 
-        assert_eq!(game.player_1.points, expected_dealer_points);
-        assert_eq!(game.player_2.points, expected_pone_points);
+                if game.phase == Phase::Deal {
+                    rounds += 1;
+                    round_start_points = previous_points;
+                }
+
+                assert!(rounds < 1_000, "seed {}: game did not terminate", seed);
+
+                if let Err(err) = game.step() {
+                    panic!("seed {}: {}", seed, err);
+                }
+
+                assert!(
+                    game.card_census().is_complete(),
+                    "seed {}: cards not conserved",
+                    seed
+                );
+
+                assert!(
+                    previous_points.0 <= game.player_1.points
+                        && previous_points.1 <= game.player_2.points,
+                    "seed {}: points decreased",
+                    seed
+                );
+
+                assert!(
+                    game.player_1.points - round_start_points.0 <= MAX_POINTS_PER_ROUND
+                        && game.player_2.points - round_start_points.1 <= MAX_POINTS_PER_ROUND,
+                    "seed {}: too many points in round {}",
+                    seed,
+                    rounds
+                );
+            }
+
+            assert!(
+                121 <= game.player_1.points || 121 <= game.player_2.points,
+                "seed {}: game over without a winner",
+                seed
+            );
+
+            let scored_points = |is_player_1: bool| -> u32 {
+                game.history()
+                    .iter()
+                    .map(|event| match event {
+                        GameEvent::ScorePoints {
+                            player_1, points, ..
+                        } if *player_1 == is_player_1 => *points,
+                        _ => 0,
+                    })
+                    .sum()
+            };
+
+            assert_eq!(
+                (scored_points(true), scored_points(false)),
+                (game.player_1.points, game.player_2.points),
+                "seed {}: history doesn't add up",
+                seed
+            );
+        }
     }
 }
@@ -3,7 +3,9 @@
 //! This is mainly used for testing, but also used for the NPCs.
 
 use crate::cards::Card;
-use crate::game::{Controller, Display, PlayData, Player};
+use crate::game::{
+    Controller, Display, Pegs, Phase, PlayData, PlayScoreBreakdown, Player, Round, RoundSummary,
+};
 
 /// A struct for displaying (or not in this case) the [`Game`] that uses the [`Display`] trait.
 #[derive(Debug, PartialEq, Clone)]
@@ -28,10 +30,11 @@ impl Display for NoOpDisplay {
     fn println(&self, _message: &str) {}
 
     /// Returns an empty [`String`].
-    #[must_use]
     fn game_after_cut_message(
         &self,
+        _player_name: &str,
         _player_cut: &Card,
+        _opponent_name: &str,
         _opponent_cut: &Card,
         _player_won: bool,
     ) -> String {
@@ -39,10 +42,69 @@ impl Display for NoOpDisplay {
     }
 
     /// Returns an empty [`String`].
-    #[must_use]
+    fn game_cut_reveal_message(&self, _player_name: &str, _opponent_name: &str) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn game_recut_message(
+        &self,
+        _player_name: &str,
+        _player_cut: &Card,
+        _opponent_name: &str,
+        _opponent_cut: &Card,
+    ) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn cut_deck_message(&self, _number_of_cards: usize, _depth: Option<usize>) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn game_starter_cut_message(&self, _depth: usize, _cutter: &str) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn game_undo_message(&self, _chosen: &[Card], _undoer: &str) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn game_hint_message(&self, _phase: Phase, _hint: &[Card]) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn game_discard_message<C1, C2>(
+        &self,
+        _player: &Player<C1>,
+        _opponent: &Player<C2>,
+        _is_dealer: bool,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn game_crib_discards_message(
+        &self,
+        _cards: &[Card],
+        _discarder: &str,
+        _is_dealer: bool,
+    ) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
     fn game_before_play_message<C1, C2>(
         &self,
-        _starter: Option<&Card>,
+        _round: &Round,
         _player: &Player<C1>,
         _opponent: &Player<C2>,
     ) -> String
@@ -54,13 +116,11 @@ impl Display for NoOpDisplay {
     }
 
     /// Returns an empty [`String`].
-    #[must_use]
     fn game_during_play_message<C1, C2>(
         &self,
-        _starter: &Card,
+        _round: &Round,
         _player: &Player<C1>,
         _opponent: &Player<C2>,
-        _play_data: &PlayData,
     ) -> String
     where
         C1: Controller,
@@ -69,13 +129,10 @@ impl Display for NoOpDisplay {
         String::new()
     }
 
-    /// The [`String`] display for both [`Player`]s and the starter [`Card`] during counting.
-    ///
-    /// This will show the opponent's and player's points, [`Hand`]s and cribs.
-    #[must_use]
+    /// Returns an empty [`String`].
     fn game_during_counting_message<C1, C2>(
         &self,
-        _starter: &Card,
+        _round: &Round,
         _player: &Player<C1>,
         _opponent: &Player<C2>,
     ) -> String
@@ -86,9 +143,74 @@ impl Display for NoOpDisplay {
         String::new()
     }
 
+    /// Returns an empty [`String`].
+    fn board_message(
+        &self,
+        _player_name: &str,
+        _player: &Pegs,
+        _opponent_name: &str,
+        _opponent: &Pegs,
+    ) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn play_score_message(&self, _breakdown: &PlayScoreBreakdown, _scorer: &str) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn play_count_message(&self, _player: &str, _played: Option<&Card>, _count: u32) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn play_reset_message(&self, _thirty_one: bool) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn round_summary_message(
+        &self,
+        _summary: &RoundSummary,
+        _player_name: &str,
+        _opponent_name: &str,
+    ) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn prompt_cut(&self, _number_of_cards: usize) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn prompt_confirm_cut(&self, _depth: usize) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn prompt_starter_cut(&self, _number_of_cards: usize) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn prompt_undo(&self, _chosen: &[Card]) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn prompt_discard_to_crib(&self, _number_of_cards: usize, _is_own_crib: bool) -> String {
+        String::new()
+    }
+
+    /// Returns an empty [`String`].
+    fn prompt_play_card(&self, _number_of_cards: usize, _play_data: &PlayData) -> String {
+        String::new()
+    }
+
     /// The [`String`] display for game over.
-    #[must_use]
-    fn game_over_message(&self, _player_won: bool) -> String {
+    fn game_over_message(&self, _winner: &str) -> String {
         String::new()
     }
 }
@@ -0,0 +1,404 @@
+//! A portable text notation for a whole [`Game`], like PGN is for chess.
+//!
+//! Each [`GameEvent`] of [`Game::history`] is written on its own line, starting with a keyword,
+//! so a [`Game`] can be shared, pasted in an issue, and replayed with [`Game::replay`]. The
+//! [`Player`]s are `P1` and `P2`, and the [`Card`]s are written in plain ASCII, like `10H`, with
+//! a comma between the [`Card`]s of a hand, or `-` if there are none.
+//!
+//! | Keyword     | [`GameEvent`]                | Example                            |
+//! |-------------|------------------------------|------------------------------------|
+//! | `cut`       | [`GameEvent::Cut`]           | `cut P1 8D P2 5C dealer P1`        |
+//! | `deal`      | [`GameEvent::Deal`]          | `deal dealer P2 P1 5H,JS P2 4C,QD` |
+//! | `discard`   | [`GameEvent::Discard`]       | `discard P1 5H`                    |
+//! | `cut-depth` | [`GameEvent::CutForStarter`] | `cut-depth P2 12`                  |
+//! | `starter`   | [`GameEvent::StarterCut`]    | `starter JC`                       |
+//! | `play`      | [`GameEvent::PlayCard`]      | `play P1 5H`                       |
+//! | `go`        | [`GameEvent::Go`]            | `go P2`                            |
+//! | `score`     | [`GameEvent::ScorePoints`]   | `score P1 2 pegging`               |
+//! | `count`     | [`GameEvent::HandCount`]     | `count P1 crib 5H,5C,JS,QD 10`     |
+//! | `timeout`   | [`GameEvent::Timeout`]       | `timeout P1`                       |
+//! | `game-over` | [`GameEvent::GameOver`]      | `game-over P1`                     |
+//!
+//! The [`ScoreReason`]s are `his-heels`, `three-for-last`, `pegging`, `hand`, and `crib`. Each
+//! deal starts after a blank line, and anything after a `#` is a comment.
+
+#[cfg(doc)]
+use game::{Game, Player};
+
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use cards::Card;
+use game::{GameError, GameEvent, ScoreReason};
+
+/// Writes the [`GameEvent`]s in the notation, one per line.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{notation, GameEvent, ScoreReason};
+///
+/// let events = vec![
+///     GameEvent::PlayCard {
+///         player_1: true,
+///         card: Card::new(Rank::Ten, Suit::Hearts),
+///     },
+///     GameEvent::PlayCard {
+///         player_1: false,
+///         card: Card::new(Rank::Five, Suit::Clubs),
+///     },
+///     GameEvent::ScorePoints {
+///         player_1: false,
+///         points: 2,
+///         reason: ScoreReason::Pegging,
+///     },
+/// ];
+///
+/// assert_eq!(
+///     notation::to_string(&events),
+///     "play P1 10H\nplay P2 5C\nscore P2 2 pegging\n"
+/// );
+/// ```
+#[must_use]
+pub fn to_string(events: &[GameEvent]) -> String {
+    let mut notation = String::new();
+
+    for (index, event) in events.iter().enumerate() {
+        if (0 < index) && matches!(event, GameEvent::Deal { .. }) {
+            notation.push('\n');
+        }
+
+        // Writing to a `String` can't fail.
+        let _ = writeln!(notation, "{}", event_to_string(event));
+    }
+
+    notation
+}
+
+/// Reads the [`GameEvent`]s back from the notation, skipping blank lines and comments.
+///
+/// # Errors
+///
+/// [`GameError::InvalidNotation`] with the first line that isn't a [`GameEvent`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{notation, GameEvent};
+///
+/// let events = notation::parse("# The first play\nplay P1 10H\n\ngo P2 # Nothing fits\n");
+///
+/// let expected = vec![
+///     GameEvent::PlayCard {
+///         player_1: true,
+///         card: Card::new(Rank::Ten, Suit::Hearts),
+///     },
+///     GameEvent::Go { player_1: false },
+/// ];
+///
+/// assert_eq!(events, Ok(expected));
+/// ```
+pub fn parse(notation: &str) -> Result<Vec<GameEvent>, GameError> {
+    notation
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_event(line).ok_or_else(|| GameError::InvalidNotation(line.to_string())))
+        .collect()
+}
+
+/// Writes the [`GameEvent`] as a line, without the newline.
+fn event_to_string(event: &GameEvent) -> String {
+    match event {
+        GameEvent::Cut {
+            player_1_card,
+            player_2_card,
+            player_1_is_dealer,
+        } => format!(
+            "cut P1 {} P2 {} dealer {}",
+            player_1_card.to_ascii_string(),
+            player_2_card.to_ascii_string(),
+            player_to_str(*player_1_is_dealer)
+        ),
+        GameEvent::Deal {
+            player_1_is_dealer,
+            player_1_hand,
+            player_2_hand,
+        } => format!(
+            "deal dealer {} P1 {} P2 {}",
+            player_to_str(*player_1_is_dealer),
+            cards_to_string(player_1_hand),
+            cards_to_string(player_2_hand)
+        ),
+        GameEvent::Discard { player_1, card } => format!(
+            "discard {} {}",
+            player_to_str(*player_1),
+            card.to_ascii_string()
+        ),
+        GameEvent::CutForStarter { player_1, depth } => {
+            format!("cut-depth {} {depth}", player_to_str(*player_1))
+        }
+        GameEvent::StarterCut { card } => format!("starter {}", card.to_ascii_string()),
+        GameEvent::PlayCard { player_1, card } => format!(
+            "play {} {}",
+            player_to_str(*player_1),
+            card.to_ascii_string()
+        ),
+        GameEvent::Go { player_1 } => format!("go {}", player_to_str(*player_1)),
+        GameEvent::ScorePoints {
+            player_1,
+            points,
+            reason,
+        } => format!(
+            "score {} {points} {}",
+            player_to_str(*player_1),
+            reason_to_str(*reason)
+        ),
+        GameEvent::HandCount {
+            player_1,
+            cards,
+            is_crib,
+            points,
+        } => format!(
+            "count {} {} {} {points}",
+            player_to_str(*player_1),
+            if *is_crib { "crib" } else { "hand" },
+            cards_to_string(cards)
+        ),
+        GameEvent::Timeout { player_1 } => format!("timeout {}", player_to_str(*player_1)),
+        GameEvent::GameOver { player_1_won } => {
+            format!("game-over {}", player_to_str(*player_1_won))
+        }
+    }
+}
+
+/// Reads a [`GameEvent`] from a line, which has no comment, or [`None`] if it isn't one.
+fn parse_event(line: &str) -> Option<GameEvent> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+
+    let event = match tokens.as_slice() {
+        ["cut", "P1", player_1_card, "P2", player_2_card, "dealer", dealer] => GameEvent::Cut {
+            player_1_card: player_1_card.parse().ok()?,
+            player_2_card: player_2_card.parse().ok()?,
+            player_1_is_dealer: parse_player(dealer)?,
+        },
+        ["deal", "dealer", dealer, "P1", player_1_hand, "P2", player_2_hand] => GameEvent::Deal {
+            player_1_is_dealer: parse_player(dealer)?,
+            player_1_hand: parse_cards(player_1_hand)?,
+            player_2_hand: parse_cards(player_2_hand)?,
+        },
+        ["discard", player, card] => GameEvent::Discard {
+            player_1: parse_player(player)?,
+            card: card.parse().ok()?,
+        },
+        ["cut-depth", player, depth] => GameEvent::CutForStarter {
+            player_1: parse_player(player)?,
+            depth: depth.parse().ok()?,
+        },
+        ["starter", card] => GameEvent::StarterCut {
+            card: card.parse().ok()?,
+        },
+        ["play", player, card] => GameEvent::PlayCard {
+            player_1: parse_player(player)?,
+            card: card.parse().ok()?,
+        },
+        ["go", player] => GameEvent::Go {
+            player_1: parse_player(player)?,
+        },
+        ["score", player, points, reason] => GameEvent::ScorePoints {
+            player_1: parse_player(player)?,
+            points: points.parse().ok()?,
+            reason: parse_reason(reason)?,
+        },
+        ["count", player, kind, cards, points] => GameEvent::HandCount {
+            player_1: parse_player(player)?,
+            cards: parse_cards(cards)?,
+            is_crib: match *kind {
+                "hand" => false,
+                "crib" => true,
+                _ => return None,
+            },
+            points: points.parse().ok()?,
+        },
+        ["timeout", player] => GameEvent::Timeout {
+            player_1: parse_player(player)?,
+        },
+        ["game-over", winner] => GameEvent::GameOver {
+            player_1_won: parse_player(winner)?,
+        },
+        _ => return None,
+    };
+
+    Some(event)
+}
+
+/// Returns `P1` for player 1, or `P2` otherwise.
+fn player_to_str(player_1: bool) -> &'static str {
+    if player_1 {
+        "P1"
+    } else {
+        "P2"
+    }
+}
+
+/// Reads `P1` as `true`, and `P2` as `false`.
+fn parse_player(token: &str) -> Option<bool> {
+    match token {
+        "P1" => Some(true),
+        "P2" => Some(false),
+        _ => None,
+    }
+}
+
+/// The keyword of the [`ScoreReason`].
+fn reason_to_str(reason: ScoreReason) -> &'static str {
+    match reason {
+        ScoreReason::HisHeels => "his-heels",
+        ScoreReason::ThreeForLast => "three-for-last",
+        ScoreReason::Pegging => "pegging",
+        ScoreReason::Hand => "hand",
+        ScoreReason::Crib => "crib",
+    }
+}
+
+/// Reads a [`ScoreReason`] from its keyword.
+fn parse_reason(token: &str) -> Option<ScoreReason> {
+    match token {
+        "his-heels" => Some(ScoreReason::HisHeels),
+        "three-for-last" => Some(ScoreReason::ThreeForLast),
+        "pegging" => Some(ScoreReason::Pegging),
+        "hand" => Some(ScoreReason::Hand),
+        "crib" => Some(ScoreReason::Crib),
+        _ => None,
+    }
+}
+
+/// Writes the [`Card`]s separated by commas, or `-` if there are none.
+pub(crate) fn cards_to_string(cards: &[Card]) -> String {
+    if cards.is_empty() {
+        String::from("-")
+    } else {
+        cards.iter().map(Card::to_ascii_string).join(",")
+    }
+}
+
+/// Reads the [`Card`]s separated by commas, where `-` is none.
+pub(crate) fn parse_cards(token: &str) -> Option<Vec<Card>> {
+    if token == "-" {
+        return Some(Vec::new());
+    }
+
+    token.split(',').map(|card| card.parse().ok()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Rank, Suit};
+    use game::{Game, NoOpDisplay, Player, RngController};
+
+    #[test]
+    fn test_round_trip_whole_game() {
+        let player_1 = Player::new(RngController::new_with_seed(7));
+        let player_2 = Player::new(RngController::new_with_seed(8));
+
+        let mut game = Game::new(player_1, player_2);
+
+        let outcome = game.play_quiet().unwrap();
+
+        let events = parse(&to_string(game.history())).unwrap();
+
+        assert_eq!(events, game.history());
+
+        let mut replay = Game::replay(&events, NoOpDisplay::new());
+
+        assert_eq!(replay.play_quiet(), Ok(outcome));
+    }
+
+    #[test]
+    fn test_round_trip_every_event() {
+        let five = Card::new(Rank::Five, Suit::Hearts);
+        let ten = Card::new(Rank::Ten, Suit::Clubs);
+
+        let events = vec![
+            GameEvent::Cut {
+                player_1_card: five.clone(),
+                player_2_card: ten.clone(),
+                player_1_is_dealer: false,
+            },
+            GameEvent::ScorePoints {
+                player_1: true,
+                points: 3,
+                reason: ScoreReason::ThreeForLast,
+            },
+            GameEvent::Deal {
+                player_1_is_dealer: false,
+                player_1_hand: vec![five.clone(), ten.clone()],
+                player_2_hand: Vec::new(),
+            },
+            GameEvent::Discard {
+                player_1: true,
+                card: ten.clone(),
+            },
+            GameEvent::Timeout { player_1: true },
+            GameEvent::CutForStarter {
+                player_1: true,
+                depth: 12,
+            },
+            GameEvent::StarterCut { card: ten.clone() },
+            GameEvent::ScorePoints {
+                player_1: false,
+                points: 2,
+                reason: ScoreReason::HisHeels,
+            },
+            GameEvent::PlayCard {
+                player_1: true,
+                card: five.clone(),
+            },
+            GameEvent::Go { player_1: false },
+            GameEvent::HandCount {
+                player_1: false,
+                cards: vec![ten.clone()],
+                is_crib: true,
+                points: 0,
+            },
+            GameEvent::ScorePoints {
+                player_1: false,
+                points: 0,
+                reason: ScoreReason::Crib,
+            },
+            GameEvent::GameOver {
+                player_1_won: false,
+            },
+        ];
+
+        let notation = to_string(&events);
+
+        assert!(notation.contains("\n\ndeal dealer P2 P1 5H,10C P2 -\n"));
+        assert_eq!(parse(&notation), Ok(events));
+    }
+
+    #[test]
+    fn test_parse_invalid_line() {
+        let result = parse("play P1 5H\nplay P3 5C\n");
+
+        let expected = Err(GameError::InvalidNotation(String::from("play P3 5C")));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_invalid_card() {
+        let result = parse("count P1 hand 5H,1H 2");
+
+        let expected = Err(GameError::InvalidNotation(String::from(
+            "count P1 hand 5H,1H 2",
+        )));
+
+        assert_eq!(result, expected);
+    }
+}
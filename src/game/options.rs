@@ -0,0 +1,190 @@
+//! Optional behaviors for the [`Game`] that deviate from, or add to, the standard rules.
+
+use cards::Hand;
+use game::GameRules;
+
+#[cfg(doc)]
+use cards::{Card, Rank, Suit};
+#[cfg(doc)]
+use game::{Controller, Game, Player, PASS_INDEX};
+
+/// The options for a [`Game`].
+///
+/// The [`Default`] options play a standard game of cribbage.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GameOptions {
+    /// Allows a [`Controller`] to return [`PASS_INDEX`] during play, even if they could play.
+    ///
+    /// This is meant for exploring "what if I held this card" lines. A GO point is only awarded
+    /// when neither player can play, and the count only resets once both players stop.
+    pub allow_intentional_pass: bool,
+    /// Who is dealt the first [`Card`] of each round clockwise, or the last counter-clockwise.
+    pub first_dealt_to: FirstDealtTo,
+    /// Which way the deal goes around the table, see [`DealDirection`].
+    pub deal_direction: DealDirection,
+    /// Shows each discard to the crib, and whether the dealer or pone made it, as it happens.
+    ///
+    /// This is meant for teaching, so it's on for human games, but off by default for AI speed.
+    pub show_crib_discards: bool,
+    /// The points needed to win, which is 121 in a standard game.
+    pub target_score: u32,
+    /// Who deals first, which is a cut where the lower [`Card`] deals in a standard game.
+    pub first_dealer: FirstDealer,
+    /// How each [`Hand`] is sorted once it's dealt.
+    ///
+    /// The [`Controller`] indices refer to the sorted [`Hand`], so what's displayed is what's
+    /// chosen from.
+    pub hand_sort: HandSort,
+    /// The rules of the variant of cribbage, like the five-card game.
+    pub rules: GameRules,
+    /// Allows a [`Player`] to take back their crib discards or play, with
+    /// [`Controller::undo_choice`], before the opponent acts.
+    ///
+    /// This is meant for casual games against the AI, so it's off for competitive play.
+    pub allow_undo: bool,
+}
+
+impl Default for GameOptions {
+    fn default() -> GameOptions {
+        GameOptions {
+            allow_intentional_pass: false,
+            first_dealt_to: FirstDealtTo::default(),
+            deal_direction: DealDirection::default(),
+            show_crib_discards: false,
+            target_score: 121,
+            first_dealer: FirstDealer::default(),
+            hand_sort: HandSort::default(),
+            rules: GameRules::default(),
+            allow_undo: false,
+        }
+    }
+}
+
+impl GameOptions {
+    /// Returns the [`GameOptions`] with the [`GameRules`], and their target score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{GameOptions, GameRules};
+    ///
+    /// let options = GameOptions {
+    ///     show_crib_discards: true,
+    ///     ..GameOptions::default()
+    /// }
+    /// .with_rules(GameRules::five_card());
+    ///
+    /// assert!(options.show_crib_discards);
+    /// assert_eq!(options.rules.cards_dealt, 5);
+    /// assert_eq!(options.target_score, 61);
+    /// ```
+    #[must_use]
+    pub fn with_rules(self, rules: GameRules) -> GameOptions {
+        GameOptions {
+            target_score: rules.target_score,
+            rules,
+            ..self
+        }
+    }
+}
+
+/// Who deals the first round of a [`Game`].
+///
+/// The standard, from the American Cribbage Congress rules, is that both [`Player`]s cut the
+/// deck, and the lower [`Card`] deals. Some house rules give the deal to the higher [`Card`]
+/// instead.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum FirstDealer {
+    /// Both [`Player`]s cut, and the lower [`Card`] deals.
+    #[default]
+    CutLoser,
+    /// Both [`Player`]s cut, and the higher [`Card`] deals.
+    CutWinner,
+    /// Chosen at random by the [`Game`], without cutting.
+    Random,
+    /// Player 1 deals first if `player_1`, or player 2 otherwise, without cutting.
+    Fixed { player_1: bool },
+}
+
+impl FirstDealer {
+    /// Indicates if the [`Player`]s cut the deck to choose the dealer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::FirstDealer;
+    ///
+    /// assert!(FirstDealer::CutLoser.cuts());
+    /// assert!(!FirstDealer::Fixed { player_1: true }.cuts());
+    /// ```
+    #[must_use]
+    pub fn cuts(self) -> bool {
+        matches!(self, FirstDealer::CutLoser | FirstDealer::CutWinner)
+    }
+}
+
+/// Who is dealt the first [`Card`] of each round clockwise, or the last [`Card`]
+/// counter-clockwise, see [`DealDirection`].
+///
+/// The standard is the pone (the [`Player`] to the left of the dealer). The other variants are
+/// for testing if dealing order biases the outcome of a [`Game`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum FirstDealtTo {
+    #[default]
+    Pone,
+    Dealer,
+    PlayerOne,
+    PlayerTwo,
+}
+
+/// Which way the deal goes around the table.
+///
+/// The standard is clockwise, starting at [`GameOptions::first_dealt_to`]. Counter-clockwise
+/// deals the same seats in reverse, ending at [`GameOptions::first_dealt_to`], so with two
+/// [`Player`]s the other [`Hand`] gets the first [`Card`]. This makes counter-clockwise from the
+/// pone the same deal as clockwise from the dealer.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum DealDirection {
+    #[default]
+    Clockwise,
+    CounterClockwise,
+}
+
+/// How a [`Hand`] is sorted once it's dealt.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum HandSort {
+    /// Left in the order the [`Card`]s were dealt.
+    #[default]
+    Dealt,
+    /// Sorted with [`Hand::sort_by_rank`].
+    Rank,
+    /// Sorted with [`Hand::sort_by_suit_then_rank`].
+    SuitThenRank,
+}
+
+impl HandSort {
+    /// Sorts the [`Hand`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Hand, Rank, Suit};
+    /// use libterminal_cribbage::game::HandSort;
+    ///
+    /// let mut hand = Hand::from(vec![
+    ///     Card::new(Rank::King, Suit::Clubs),
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    /// ]);
+    ///
+    /// HandSort::Rank.sort(&mut hand);
+    ///
+    /// assert_eq!(hand.as_vec()[0], Card::new(Rank::Ace, Suit::Spades));
+    /// ```
+    pub fn sort(self, hand: &mut Hand) {
+        match self {
+            HandSort::Dealt => {}
+            HandSort::Rank => hand.sort_by_rank(),
+            HandSort::SuitThenRank => hand.sort_by_suit_then_rank(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! The result of a finished [`Game`].
+
+#[cfg(doc)]
+use game::Game;
+
+/// The outcome of a finished [`Game`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GameOutcome {
+    pub player_1_won: bool,
+    pub player_1_points: u32,
+    pub player_2_points: u32,
+    pub rounds: u32,
+}
@@ -0,0 +1,401 @@
+//! The turns of the play (pegging) part of a round, apart from the [`Game`].
+
+#[cfg(doc)]
+use game::{Game, GameEvent, PASS_INDEX};
+
+use cards::Card;
+use game::{Controller, GameError, PlayData, PlayScoreBreakdown, Player};
+
+/// The most turns a [`PeggingRound`] takes before it's considered stuck.
+const MAX_TURNS: usize = 100;
+
+/// Something that happened during a [`PeggingRound`].
+///
+/// Every event with a `player_1` field is attributed to player 1 if it's `true`, and player 2
+/// otherwise.
+#[derive(Debug, PartialEq, Clone)]
+pub enum PeggingEvent {
+    /// A [`Card`] was played on the stack, scoring the points in the breakdown.
+    ///
+    /// The last [`Card`] before the count starts over scores 1 for the GO, unless it makes the
+    /// count exactly 31, which scores 2 instead. Never both.
+    Play {
+        player_1: bool,
+        card: Card,
+        breakdown: PlayScoreBreakdown,
+    },
+    /// A [`Player`] with [`Card`]s in hand declared GO, since they couldn't play without going
+    /// over 31, or passed with [`PASS_INDEX`].
+    ///
+    /// A GO that couldn't be helped is only returned after [`Controller::acknowledge_go`].
+    Go { player_1: bool },
+    /// The count starts over at 0, after a 31, or when neither [`Player`] can play.
+    Reset { thirty_one: bool },
+}
+
+impl PeggingEvent {
+    /// Returns the [`Card`] played, if this is a [`PeggingEvent::Play`].
+    #[must_use]
+    pub fn card(&self) -> Option<&Card> {
+        match self {
+            PeggingEvent::Play { card, .. } => Some(card),
+            _ => None,
+        }
+    }
+
+    /// Returns the points scored, which are only ever scored by a [`PeggingEvent::Play`].
+    #[must_use]
+    pub fn points(&self) -> u32 {
+        match self {
+            PeggingEvent::Play { breakdown, .. } => breakdown.total(),
+            _ => 0,
+        }
+    }
+}
+
+/// Whose turn it is during play, and who leads after the count starts over.
+///
+/// Each turn is in two steps, so a [`Game`] can score, or take back, the turn in between:
+/// [`PeggingRound::take_turn`], where the [`Player`] plays or declares GO, and then
+/// [`PeggingRound::end_turn`], where the count starts over if needed and the turn passes. The
+/// stack itself is kept in the [`PlayData`].
+///
+/// The pone leads the first stack. After the count starts over, the other [`Player`] from the
+/// last to play a [`Card`] leads.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{
+///     PeggingEvent, PeggingRound, PlayData, Player, PredeterminedController,
+/// };
+///
+/// let controller = PredeterminedController::from(vec![0, 0]);
+///
+/// let mut player_1 = Player::new_with_cards(
+///     controller.clone(),
+///     vec![Card::new(Rank::Five, Suit::Clubs)],
+/// );
+/// let mut player_2 = Player::new_with_cards(
+///     controller.clone(),
+///     vec![Card::new(Rank::Ten, Suit::Hearts)],
+/// );
+///
+/// let mut play_data = PlayData::new();
+///
+/// // Player 2 deals, so player 1 leads.
+/// let mut pegging = PeggingRound::new(/*player_1_is_dealer=*/ false);
+///
+/// let events = pegging
+///     .play_all(&mut play_data, &mut player_1, &mut player_2)
+///     .unwrap();
+///
+/// assert_eq!(events.len(), 3);
+/// assert_eq!(events[2], PeggingEvent::Reset { thirty_one: false });
+///
+/// // Player 2 makes 15, and scores 1 more for the last card.
+/// assert_eq!(player_2.points, 3);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PeggingRound {
+    player_1_is_dealer: bool,
+    player_1_turn: bool,
+    turns: usize,
+}
+
+impl PeggingRound {
+    /// Creates a new [`PeggingRound`], where the pone leads.
+    #[must_use]
+    pub fn new(player_1_is_dealer: bool) -> PeggingRound {
+        PeggingRound {
+            player_1_is_dealer,
+            player_1_turn: !player_1_is_dealer,
+            turns: 0,
+        }
+    }
+
+    /// Indicates that it's player 1's turn, or player 2's otherwise.
+    #[must_use]
+    pub fn player_1_turn(&self) -> bool {
+        self.player_1_turn
+    }
+
+    /// Indicates that play is over, since neither [`Player`] has [`Card`]s in hand.
+    #[must_use]
+    pub fn is_over<C1, C2>(&self, player_1: &Player<C1>, player_2: &Player<C2>) -> bool
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        !player_1.has_cards_in_hand() && !player_2.has_cards_in_hand()
+    }
+
+    /// The [`Player`] whose turn it is plays a [`Card`], or declares GO, with
+    /// [`PlayData::play_turn`].
+    ///
+    /// [`None`] is returned if the [`Player`] has no [`Card`]s in hand, so the turn passes
+    /// silently. The points aren't added to the [`Player`], and the turn doesn't pass until
+    /// [`PeggingRound::end_turn`], so it can be taken again if the [`Card`] is taken back.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::TooManyTurns`] if more than 100 turns have been taken.
+    /// * [`GameError::OutOfMoves`], naming Player 1 or Player 2, if the [`Controller`] whose turn
+    ///   it is has no index to return.
+    /// * Otherwise, the same as [`PlayData::play_once`].
+    pub fn take_turn<C1, C2>(
+        &mut self,
+        play_data: &mut PlayData,
+        player_1: &mut Player<C1>,
+        player_2: &mut Player<C2>,
+    ) -> Result<Option<PeggingEvent>, GameError>
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        if MAX_TURNS < self.turns {
+            return Err(GameError::TooManyTurns);
+        }
+
+        self.turns += 1;
+
+        let player_1_turn = self.player_1_turn;
+        let is_dealer = self.player_1_is_dealer == player_1_turn;
+        let stack_size = play_data.stack.len();
+
+        let (had_cards_in_hand, result) = if player_1_turn {
+            (
+                player_1.has_cards_in_hand(),
+                play_data.play_turn(player_1, player_2, is_dealer),
+            )
+        } else {
+            (
+                player_2.has_cards_in_hand(),
+                play_data.play_turn(player_2, player_1, is_dealer),
+            )
+        };
+
+        let breakdown = result.map_err(|error| match error {
+            GameError::OutOfMoves(_) => GameError::out_of_moves(player_1_turn),
+            error => error,
+        })?;
+
+        let event = if stack_size < play_data.stack.len() {
+            play_data
+                .stack
+                .last()
+                .cloned()
+                .map(|card| PeggingEvent::Play {
+                    player_1: player_1_turn,
+                    card,
+                    breakdown,
+                })
+        } else if had_cards_in_hand {
+            Some(PeggingEvent::Go {
+                player_1: player_1_turn,
+            })
+        } else {
+            None
+        };
+
+        Ok(event)
+    }
+
+    /// Ends the turn, starting the count over if needed, with [`PlayData::reset_if_needed`], and
+    /// passing the turn.
+    ///
+    /// After the count starts over, the other [`Player`] from the last to play leads, from
+    /// [`PlayData::dealer_leads_next`], and a [`PeggingEvent::Reset`] is returned.
+    pub fn end_turn<C1, C2>(
+        &mut self,
+        play_data: &mut PlayData,
+        player_1: &Player<C1>,
+        player_2: &Player<C2>,
+    ) -> Option<PeggingEvent>
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let thirty_one = play_data.stack_score == 31;
+
+        let reset = play_data.reset_if_needed(player_1, player_2);
+
+        self.player_1_turn = if reset {
+            play_data.dealer_leads_next() == self.player_1_is_dealer
+        } else {
+            !self.player_1_turn
+        };
+
+        reset.then_some(PeggingEvent::Reset { thirty_one })
+    }
+
+    /// Plays every turn until play is over, adding the points to the [`Player`]s, and returns what
+    /// happened.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`PeggingRound::take_turn`].
+    pub fn play_all<C1, C2>(
+        &mut self,
+        play_data: &mut PlayData,
+        player_1: &mut Player<C1>,
+        player_2: &mut Player<C2>,
+    ) -> Result<Vec<PeggingEvent>, GameError>
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let mut events = Vec::new();
+
+        while !self.is_over(player_1, player_2) {
+            if let Some(event) = self.take_turn(play_data, player_1, player_2)? {
+                if self.player_1_turn {
+                    player_1.points += event.points();
+                } else {
+                    player_2.points += event.points();
+                }
+
+                events.push(event);
+            }
+
+            events.extend(self.end_turn(play_data, player_1, player_2));
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Rank, Suit};
+    use game::{NoOpDisplay, PredeterminedController};
+
+    /// Returns a [`Player`] who plays their [`Card`]s in order.
+    fn player(cards: &str) -> Player<PredeterminedController<NoOpDisplay>> {
+        let cards: Vec<Card> = cards
+            .split_whitespace()
+            .map(|card| card.parse().unwrap())
+            .collect();
+
+        Player::new_with_cards(PredeterminedController::from(vec![0; cards.len()]), cards)
+    }
+
+    #[test]
+    fn test_thirty_one_scores_2_without_a_go() {
+        let mut player_1 = player("10H 10S");
+        let mut player_2 = player("JH AC");
+
+        let mut play_data = PlayData::new();
+
+        let events = PeggingRound::new(/*player_1_is_dealer=*/ false)
+            .play_all(&mut play_data, &mut player_1, &mut player_2)
+            .unwrap();
+
+        let thirty_one = PeggingEvent::Play {
+            player_1: false,
+            card: Card::new(Rank::Ace, Suit::Clubs),
+            breakdown: PlayScoreBreakdown {
+                thirty_one: true,
+                ..PlayScoreBreakdown::default()
+            },
+        };
+
+        assert_eq!(events[3], thirty_one);
+        assert_eq!(events[4], PeggingEvent::Reset { thirty_one: true });
+        assert_eq!(events.len(), 5);
+        assert_eq!(player_1.points, 0);
+        assert_eq!(player_2.points, 2);
+    }
+
+    #[test]
+    fn test_the_other_player_leads_after_thirty_one() {
+        let mut player_1 = player("10H 10S 5C");
+        let mut player_2 = player("JH AC 6D");
+
+        let mut play_data = PlayData::new();
+
+        let events = PeggingRound::new(/*player_1_is_dealer=*/ false)
+            .play_all(&mut play_data, &mut player_1, &mut player_2)
+            .unwrap();
+
+        // The dealer, player 2, makes 31, so the pone leads the next stack.
+        assert_eq!(events[4], PeggingEvent::Reset { thirty_one: true });
+        assert_eq!(events[5].card(), Some(&Card::new(Rank::Five, Suit::Clubs)));
+        assert_eq!(
+            events[6].card(),
+            Some(&Card::new(Rank::Six, Suit::Diamonds))
+        );
+        assert_eq!(play_data.last_played_by_dealer, Some(true));
+    }
+
+    #[test]
+    fn test_last_card_scores_1() {
+        let mut player_1 = player("10H");
+        let mut player_2 = player("9S");
+
+        let mut play_data = PlayData::new();
+
+        let events = PeggingRound::new(/*player_1_is_dealer=*/ false)
+            .play_all(&mut play_data, &mut player_1, &mut player_2)
+            .unwrap();
+
+        assert_eq!(events[1].points(), 1);
+        assert_eq!(events[2], PeggingEvent::Reset { thirty_one: false });
+        assert_eq!(player_2.points, 1);
+    }
+
+    #[test]
+    fn test_go_is_declared_and_the_other_player_leads_after() {
+        // Player 2 is stuck at 30 with a Two, so declares GO, and player 1 plays on to 31.
+        let mut player_1 = player("10H KS AC");
+        let mut player_2 = player("QD 2C");
+
+        let mut play_data = PlayData::new();
+
+        let events = PeggingRound::new(/*player_1_is_dealer=*/ false)
+            .play_all(&mut play_data, &mut player_1, &mut player_2)
+            .unwrap();
+
+        let players_and_cards: Vec<(bool, Option<Card>)> = events
+            .iter()
+            .filter_map(|event| match event {
+                PeggingEvent::Play { player_1, card, .. } => Some((*player_1, Some(card.clone()))),
+                PeggingEvent::Go { player_1 } => Some((*player_1, None)),
+                PeggingEvent::Reset { .. } => None,
+            })
+            .collect();
+
+        let expected = vec![
+            (true, Some(Card::new(Rank::Ten, Suit::Hearts))),
+            (false, Some(Card::new(Rank::Queen, Suit::Diamonds))),
+            (true, Some(Card::new(Rank::King, Suit::Spades))),
+            (false, None),
+            (true, Some(Card::new(Rank::Ace, Suit::Clubs))),
+            // Player 1 played last before the count started over, so player 2 leads.
+            (false, Some(Card::new(Rank::Two, Suit::Clubs))),
+        ];
+
+        assert_eq!(players_and_cards, expected);
+        assert_eq!(player_1.points, 2);
+        assert_eq!(player_2.points, 1);
+    }
+
+    #[test]
+    fn test_take_turn_without_cards_is_silent() {
+        let mut player_1 = player("");
+        let mut player_2 = player("5H");
+
+        let mut play_data = PlayData::new();
+
+        let mut pegging = PeggingRound::new(/*player_1_is_dealer=*/ false);
+
+        let result = pegging.take_turn(&mut play_data, &mut player_1, &mut player_2);
+
+        assert_eq!(result, Ok(None));
+        assert_eq!(pegging.end_turn(&mut play_data, &player_1, &player_2), None);
+        assert!(!pegging.player_1_turn());
+    }
+}
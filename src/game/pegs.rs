@@ -0,0 +1,44 @@
+//! The pegs of a [`Player`] on the cribbage board.
+
+#[cfg(doc)]
+use game::Player;
+
+/// The two pegs of a [`Player`] on the cribbage board.
+///
+/// The front peg is at the [`Player::points`], and the back peg is where the front peg was before
+/// the last scoring event. A peg at `0` hasn't been put on the board yet.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Pegs {
+    pub front: u32,
+    pub back: u32,
+}
+
+impl Pegs {
+    /// Moves the back peg to the front, and the front peg to the points, if the points changed.
+    ///
+    /// Returns if the pegs were moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::Pegs;
+    ///
+    /// let mut pegs = Pegs::default();
+    ///
+    /// assert!(pegs.move_to(5));
+    /// assert!(pegs.move_to(7));
+    /// assert!(!pegs.move_to(7));
+    ///
+    /// assert_eq!(pegs, Pegs { front: 7, back: 5 });
+    /// ```
+    pub fn move_to(&mut self, points: u32) -> bool {
+        if self.front == points {
+            return false;
+        }
+
+        self.back = self.front;
+        self.front = points;
+
+        true
+    }
+}
@@ -0,0 +1,24 @@
+//! The phases of a [`Game`].
+
+#[cfg(doc)]
+use cards::{Deck, Hand};
+#[cfg(doc)]
+use game::Game;
+
+/// The phase of a [`Game`], which is the next step to be taken.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Phase {
+    /// Cutting the [`Deck`] to choose the dealer.
+    Cut,
+    /// Dealing the [`Hand`]s.
+    Deal,
+    /// Discarding to the crib and cutting for the starter.
+    Discard,
+    /// Playing (pegging).
+    Play,
+    /// Counting the [`Hand`]s and crib.
+    Count,
+    /// The [`Game`] is over.
+    Over,
+}
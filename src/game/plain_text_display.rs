@@ -0,0 +1,699 @@
+//! A display for the game in plain sentences, for screen readers.
+
+#[cfg(doc)]
+use crate::cards::{Deck, Hand, Suit};
+#[cfg(doc)]
+use crate::game::{Game, UiDisplay};
+
+use itertools::Itertools;
+
+use crate::cards::Card;
+use crate::game::{
+    Controller, Display, Pegs, Phase, PlayData, PlayScoreBreakdown, Player, Round, RoundSummary,
+    DEFAULT_OPPONENT_NAME, DEFAULT_PLAYER_NAME, DOUBLE_SKUNK_LINE, SKUNK_LINE,
+};
+
+/// A struct for displaying the [`Game`] in plain text that uses the [`Display`] trait.
+///
+/// It shows the same as a [`UiDisplay`], but without any [`Suit`] glyphs, box art, or spacers,
+/// so it reads well with a screen reader. Every [`Card`] is spelled out with
+/// [`Card::to_spoken_string`], like `"Five of Hearts"`, and every line is a short sentence or a
+/// labeled value. The [`Card`]s in the player's [`Hand`] are numbered, like the prompts.
+///
+/// Messages are printed as soon as they're ready, without any delay.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{Display, PlainTextDisplay};
+///
+/// let display = PlainTextDisplay::new();
+///
+/// let card = Card::new(Rank::Five, Suit::Hearts);
+///
+/// assert_eq!(
+///     display.play_count_message("Alice", Some(&card), 15),
+///     "Alice plays the Five of Hearts. The count is 15."
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PlainTextDisplay {}
+
+impl PlainTextDisplay {
+    /// Creates a new [`PlainTextDisplay`] struct.
+    #[must_use]
+    pub fn new() -> PlainTextDisplay {
+        PlainTextDisplay {}
+    }
+
+    /// The [`Card`]s spelled out, separated by commas, or `"none"` if there aren't any.
+    fn cards_string(cards: &[Card]) -> String {
+        if cards.is_empty() {
+            return String::from("none");
+        }
+
+        cards.iter().map(Card::to_spoken_string).join(", ")
+    }
+
+    /// The [`Card`]s spelled out and numbered from 1, like the prompts, separated by commas.
+    fn numbered_cards_string(cards: &[Card]) -> String {
+        if cards.is_empty() {
+            return String::from("none");
+        }
+
+        cards
+            .iter()
+            .enumerate()
+            .map(|(index, card)| format!("{}: {}", index + 1, card.to_spoken_string()))
+            .join(", ")
+    }
+
+    /// The line for the starter [`Card`], which is `"not cut yet"` if it's [`None`].
+    fn starter_line(starter: Option<&Card>) -> String {
+        let starter = starter.map_or(String::from("not cut yet"), Card::to_spoken_string);
+
+        format!("Starter: {starter}.")
+    }
+
+    /// The line for both named [`Player`]s' points.
+    fn points_line(
+        player_name: &str,
+        points: u32,
+        opponent_name: &str,
+        opponent_points: u32,
+    ) -> String {
+        format!("{player_name} has {points} points. {opponent_name} has {opponent_points} points.")
+    }
+
+    /// The names of the player and opponent, or their defaults if they haven't been named.
+    fn names<'a, C1, C2>(player: &'a Player<C1>, opponent: &'a Player<C2>) -> (&'a str, &'a str)
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        (
+            player.name_or(DEFAULT_PLAYER_NAME),
+            opponent.name_or(DEFAULT_OPPONENT_NAME),
+        )
+    }
+
+    /// The lines for the player's points, the starter, and the player's [`Hand`] and crib, if the
+    /// player is the dealer.
+    fn table_lines<C1, C2>(round: &Round, player: &Player<C1>, opponent: &Player<C2>) -> Vec<String>
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let (player_name, opponent_name) = Self::names(player, opponent);
+        let mut result = Vec::new();
+
+        result.push(Self::points_line(
+            player_name,
+            player.points,
+            opponent_name,
+            opponent.points,
+        ));
+        result.push(Self::starter_line(round.starter.as_ref()));
+        result.push(format!(
+            "{player_name}'s hand: {}.",
+            Self::numbered_cards_string(player.hand.as_vec())
+        ));
+
+        if let Some(crib) = round.crib_of(/*player_1=*/ true) {
+            result.push(format!(
+                "{player_name}'s crib: {}.",
+                Self::cards_string(crib.as_vec())
+            ));
+        }
+
+        result
+    }
+
+    /// The sentence for where the front peg of the [`Pegs`] is, compared to the skunk lines.
+    fn skunk_status(pegs: &Pegs) -> &'static str {
+        if pegs.front < DOUBLE_SKUNK_LINE {
+            "under the double skunk line"
+        } else if pegs.front < SKUNK_LINE {
+            "under the skunk line"
+        } else {
+            "past the skunk line"
+        }
+    }
+}
+
+impl Display for PlainTextDisplay {
+    /// Print message to `std::out` without a new line.
+    fn print_no_spacer_no_delay(&self, message: &str) {
+        print!("{message}");
+    }
+
+    /// Print message to `std::out`.
+    fn println_no_spacer_no_delay(&self, message: &str) {
+        println!("{message}");
+    }
+
+    /// Print message to `std::out`, after a blank line to set it apart, but without a spacer or
+    /// a delay.
+    fn println(&self, message: &str) {
+        println!("\n{message}");
+    }
+
+    /// The [`String`] display for both named [`Player`]s [`Card`]s cut from the [`Deck`].
+    fn game_after_cut_message(
+        &self,
+        player_name: &str,
+        player_cut: &Card,
+        opponent_name: &str,
+        opponent_cut: &Card,
+        player_won: bool,
+    ) -> String {
+        let winner = if player_won {
+            player_name
+        } else {
+            opponent_name
+        };
+
+        [
+            format!("{player_name} cut the {}.", player_cut.to_spoken_string()),
+            format!(
+                "{opponent_name} cut the {}.",
+                opponent_cut.to_spoken_string()
+            ),
+            format!("{winner} won the cut."),
+        ]
+        .join("\n")
+    }
+
+    /// The [`String`] display before both named [`Player`]s' cuts are revealed.
+    fn game_cut_reveal_message(&self, player_name: &str, opponent_name: &str) -> String {
+        format!("{player_name} and {opponent_name} turn over their cuts.")
+    }
+
+    /// The [`String`] display for both named [`Player`]s cutting the same rank, so they cut again.
+    fn game_recut_message(
+        &self,
+        player_name: &str,
+        player_cut: &Card,
+        opponent_name: &str,
+        opponent_cut: &Card,
+    ) -> String {
+        [
+            format!("{player_name} cut the {}.", player_cut.to_spoken_string()),
+            format!(
+                "{opponent_name} cut the {}.",
+                opponent_cut.to_spoken_string()
+            ),
+            String::from("It's a tie, so both cut again."),
+        ]
+        .join("\n")
+    }
+
+    /// The [`String`] display for the face down [`Deck`], and the `depth` if it's been chosen.
+    fn cut_deck_message(&self, number_of_cards: usize, depth: Option<usize>) -> String {
+        match depth {
+            Some(depth) => {
+                format!("The deck has {number_of_cards} cards, face down. You chose card {depth}.")
+            }
+            None => format!("The deck has {number_of_cards} cards, face down."),
+        }
+    }
+
+    /// The [`String`] display for the pone's cut for the starter.
+    ///
+    /// The `depth` is shown 1-based, like the [`Display::prompt_starter_cut`] answer.
+    fn game_starter_cut_message(&self, depth: usize, cutter: &str) -> String {
+        format!(
+            "{cutter} cuts the deck at card {} for the starter.",
+            depth + 1
+        )
+    }
+
+    /// The [`String`] display for both [`Player`]s while discarding to the crib.
+    fn game_discard_message<C1, C2>(
+        &self,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+        is_dealer: bool,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let (player_name, opponent_name) = Self::names(player, opponent);
+
+        let whose_crib = if is_dealer {
+            "You are the dealer. Discard to your crib."
+        } else {
+            "You are the pone. Discard to your opponent's crib."
+        };
+
+        [
+            Self::points_line(player_name, player.points, opponent_name, opponent.points),
+            format!(
+                "{player_name}'s hand: {}.",
+                Self::numbered_cards_string(player.hand.as_vec())
+            ),
+            whose_crib.to_string(),
+        ]
+        .join("\n")
+    }
+
+    /// The [`String`] display for both of a player's discards to the crib.
+    fn game_crib_discards_message(
+        &self,
+        cards: &[Card],
+        discarder: &str,
+        is_dealer: bool,
+    ) -> String {
+        let role = if is_dealer { "dealer" } else { "pone" };
+
+        format!(
+            "{discarder}, the {role}, discards {} to the crib.",
+            Self::cards_string(cards)
+        )
+    }
+
+    /// The [`String`] display for taking back the [`Card`]s just chosen.
+    fn game_undo_message(&self, chosen: &[Card], undoer: &str) -> String {
+        format!("{undoer} takes back {}.", Self::cards_string(chosen))
+    }
+
+    /// The [`String`] display for a hint of the [`Card`]s to discard or play.
+    fn game_hint_message(&self, phase: Phase, hint: &[Card]) -> String {
+        let cards = Self::cards_string(hint);
+
+        match phase {
+            Phase::Discard => format!("Hint: discard {cards} to the crib."),
+            Phase::Play if hint.is_empty() => "Hint: you can't play, so say go.".to_string(),
+            Phase::Play => format!("Hint: play the {cards}."),
+            _ => "Hint: there's nothing to choose.".to_string(),
+        }
+    }
+
+    /// The [`String`] display for both [`Player`]s and the [`Round`]'s starter [`Card`] before play.
+    fn game_before_play_message<C1, C2>(
+        &self,
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        Self::table_lines(round, player, opponent).join("\n")
+    }
+
+    /// The [`String`] display for both [`Player`]s, and the [`Round`]'s starter [`Card`] and
+    /// [`PlayData`] during play.
+    fn game_during_play_message<C1, C2>(
+        &self,
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let (_, opponent_name) = Self::names(player, opponent);
+        let mut result = Self::table_lines(round, player, opponent);
+
+        let cards_left = match opponent.hand.len() {
+            1 => String::from("1 card"),
+            number_of_cards => format!("{number_of_cards} cards"),
+        };
+
+        result.push(format!("{opponent_name} has {cards_left} left."));
+
+        if let Some(card) = opponent.last_discarded() {
+            result.push(format!(
+                "{opponent_name} last played the {}.",
+                card.to_spoken_string()
+            ));
+        }
+
+        result.push(format!(
+            "Play stack: {}.",
+            Self::cards_string(&round.play_data.stack)
+        ));
+        result.push(format!("The count is {}.", round.play_data.stack_score));
+
+        result.join("\n")
+    }
+
+    /// The [`String`] display for both [`Player`]s and the [`Round`]'s starter [`Card`] during
+    /// counting.
+    ///
+    /// Each [`Hand`] and crib is followed by its score, once the starter is cut.
+    fn game_during_counting_message<C1, C2>(
+        &self,
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let (player_name, opponent_name) = Self::names(player, opponent);
+        let mut result = Vec::new();
+
+        result.push(Self::points_line(
+            player_name,
+            player.points,
+            opponent_name,
+            opponent.points,
+        ));
+        result.push(Self::starter_line(round.starter.as_ref()));
+
+        let counted = [
+            (opponent_name, "hand", Some(&opponent.hand), false),
+            (
+                opponent_name,
+                "crib",
+                round.crib_of(/*player_1=*/ false),
+                true,
+            ),
+            (player_name, "hand", Some(&player.hand), false),
+            (player_name, "crib", round.crib_of(/*player_1=*/ true), true),
+        ];
+
+        for (name, kind, cards, is_crib) in counted {
+            let Some(cards) = cards else {
+                continue;
+            };
+
+            let score = round.starter.as_ref().map_or(String::new(), |starter| {
+                format!(" It scores {}.", cards.total(starter, is_crib))
+            });
+
+            result.push(format!(
+                "{name}'s {kind}: {}.{score}",
+                Self::cards_string(cards.as_vec())
+            ));
+        }
+
+        result.join("\n")
+    }
+
+    /// The [`String`] display for both named [`Player`]s' [`Pegs`], and which skunk lines
+    /// they're past.
+    fn board_message(
+        &self,
+        player_name: &str,
+        player: &Pegs,
+        opponent_name: &str,
+        opponent: &Pegs,
+    ) -> String {
+        [(player_name, player), (opponent_name, opponent)]
+            .iter()
+            .map(|(name, pegs)| {
+                format!(
+                    "{name}'s front peg is at {}, and back peg at {}, {}.",
+                    pegs.front,
+                    pegs.back,
+                    Self::skunk_status(pegs)
+                )
+            })
+            .join("\n")
+    }
+
+    /// The [`String`] display explaining the points scored by playing a [`Card`] (pegging).
+    fn play_score_message(&self, breakdown: &PlayScoreBreakdown, scorer: &str) -> String {
+        let mut result = Vec::new();
+
+        if breakdown.fifteen {
+            result.push("fifteen for 2".to_string());
+        }
+
+        if breakdown.thirty_one {
+            result.push("thirty-one for 2".to_string());
+        }
+
+        match breakdown.matching {
+            2 => result.push("a pair for 2".to_string()),
+            3 => result.push("three of a kind for 6".to_string()),
+            4 => result.push("four of a kind for 12".to_string()),
+            _ => {}
+        }
+
+        if breakdown.run != 0 {
+            result.push(format!("a run of {} for {}", breakdown.run, breakdown.run));
+        }
+
+        if breakdown.go {
+            result.push("go for 1".to_string());
+        }
+
+        format!("{scorer} scores {}.", result.join(", "))
+    }
+
+    /// The [`String`] display for a [`Card`] played onto the stack, or a GO, with the count.
+    fn play_count_message(&self, player: &str, played: Option<&Card>, count: u32) -> String {
+        match played {
+            Some(card) => format!(
+                "{player} plays the {}. The count is {count}.",
+                card.to_spoken_string()
+            ),
+            None => format!("{player} says go. The count is {count}."),
+        }
+    }
+
+    /// The [`String`] display for the stack starting over after a 31 or a GO.
+    fn play_reset_message(&self, thirty_one: bool) -> String {
+        let reason = if thirty_one { "Thirty-one" } else { "Go" };
+
+        format!("{reason}. The count starts over at 0.")
+    }
+
+    /// The [`String`] display for the [`RoundSummary`]: the points each [`Player`] pegged, each
+    /// hand and crib with the points it counted for, and both totals.
+    fn round_summary_message(
+        &self,
+        summary: &RoundSummary,
+        player_name: &str,
+        opponent_name: &str,
+    ) -> String {
+        let name = |player_1: bool| if player_1 { player_name } else { opponent_name };
+        let mut result = Vec::new();
+
+        result.push("Round summary.".to_string());
+        result.push(format!(
+            "{player_name} pegged {} points. {opponent_name} pegged {} points.",
+            summary.player_1_pegging, summary.player_2_pegging
+        ));
+
+        for counted in &summary.counts {
+            let kind = if counted.is_crib { "crib" } else { "hand" };
+
+            result.push(format!(
+                "{}'s {kind}: {}, for {} points.",
+                name(counted.player_1),
+                Self::cards_string(&counted.cards),
+                counted.points
+            ));
+        }
+
+        result.push(Self::points_line(
+            player_name,
+            summary.player_1_total,
+            opponent_name,
+            summary.player_2_total,
+        ));
+
+        result.join("\n")
+    }
+
+    /// The [`String`] prompt for the player to cut the [`Deck`], to choose the dealer.
+    fn prompt_cut(&self, number_of_cards: usize) -> String {
+        format!("Your turn to cut the deck for the deal. Enter 1 to {number_of_cards}: ")
+    }
+
+    /// The [`String`] prompt for the player to confirm their cut for the deal.
+    fn prompt_confirm_cut(&self, depth: usize) -> String {
+        format!(
+            "Cut at card {depth}? Press Enter or y to confirm, or anything else to choose again: "
+        )
+    }
+
+    /// The [`String`] prompt for the player, as the pone, to cut the [`Deck`] for the starter.
+    fn prompt_starter_cut(&self, number_of_cards: usize) -> String {
+        format!("Your turn to cut the deck for the starter. Enter 1 to {number_of_cards}: ")
+    }
+
+    /// The [`String`] prompt for the player to discard two [`Card`]s to the crib.
+    fn prompt_discard_to_crib(&self, number_of_cards: usize, is_own_crib: bool) -> String {
+        let crib_owner = if is_own_crib {
+            "your"
+        } else {
+            "your opponent's"
+        };
+
+        format!(
+            "Your turn to discard 2 cards to {crib_owner} crib. Enter 2 numbers from 1 to {number_of_cards}, like 1 2, or h for a hint: "
+        )
+    }
+
+    /// The [`String`] prompt for the player to play a [`Card`] onto the stack.
+    fn prompt_play_card(&self, number_of_cards: usize, play_data: &PlayData) -> String {
+        format!(
+            "Your turn to play a card. The count is {}. Enter 1 to {number_of_cards}, or h for a hint: ",
+            play_data.stack_score
+        )
+    }
+
+    /// The [`String`] prompt for the player to take back the [`Card`]s they just chose.
+    fn prompt_undo(&self, chosen: &[Card]) -> String {
+        format!(
+            "Enter u to take back {}, or anything else to keep it: ",
+            Self::cards_string(chosen)
+        )
+    }
+
+    /// The [`String`] display for game over.
+    fn game_over_message(&self, winner: &str) -> String {
+        format!("{winner} won the game!")
+    }
+}
+
+impl Default for PlainTextDisplay {
+    fn default() -> Self {
+        PlainTextDisplay::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::cards::{Hand, Rank, Suit};
+    use crate::game::PredeterminedController;
+
+    #[test]
+    fn test_game_during_play_message() {
+        let display = PlainTextDisplay::new();
+
+        let mut round = Round::new(/*player_1_is_dealer=*/ true);
+        round.starter = Some(Card::new(Rank::Four, Suit::Diamonds));
+        round.crib = Hand::from(vec![
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Clubs),
+        ]);
+
+        let controller = PredeterminedController::from(vec![0]);
+
+        let player_1 = Player::new_with_cards(
+            controller.clone(),
+            vec![
+                Card::new(Rank::Eight, Suit::Spades),
+                Card::new(Rank::King, Suit::Clubs),
+            ],
+        )
+        .with_name("Alice");
+
+        let mut player_2 = Player::new_with_cards(
+            controller,
+            vec![
+                Card::new(Rank::Ten, Suit::Hearts),
+                Card::new(Rank::Six, Suit::Clubs),
+            ],
+        )
+        .with_name("Bob");
+
+        round
+            .play_data
+            .play_once(&mut player_2, &player_1, /*is_dealer=*/ false)
+            .unwrap();
+
+        let expected = [
+            "Alice has 0 points. Bob has 0 points.",
+            "Starter: Four of Diamonds.",
+            "Alice's hand: 1: Eight of Spades, 2: King of Clubs.",
+            "Alice's crib: Ace of Clubs, Two of Clubs.",
+            "Bob has 1 card left.",
+            "Bob last played the Ten of Hearts.",
+            "Play stack: Ten of Hearts.",
+            "The count is 10.",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            display.game_during_play_message(&round, &player_1, &player_2),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_game_during_counting_message() {
+        let display = PlainTextDisplay::new();
+
+        let mut round = Round::new(/*player_1_is_dealer=*/ false);
+        round.starter = Some(Card::new(Rank::Five, Suit::Hearts));
+        round.crib = Hand::from(vec![Card::new(Rank::Jack, Suit::Hearts)]);
+
+        let controller = PredeterminedController::from(vec![]);
+
+        let player_1 =
+            Player::new_with_cards(controller.clone(), vec![Card::new(Rank::Ten, Suit::Spades)]);
+        let player_2 = Player::new_with_cards(controller, vec![Card::new(Rank::Two, Suit::Clubs)]);
+
+        let expected = [
+            "Player has 0 points. Opponent has 0 points.",
+            "Starter: Five of Hearts.",
+            "Opponent's hand: Two of Clubs. It scores 0.",
+            "Opponent's crib: Jack of Hearts. It scores 3.",
+            "Player's hand: Ten of Spades. It scores 2.",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            display.game_during_counting_message(&round, &player_1, &player_2),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_messages_are_plain_ascii() {
+        let display = PlainTextDisplay::new();
+
+        let cards = vec![
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Spades),
+        ];
+
+        let pegs = Pegs {
+            front: 95,
+            back: 90,
+        };
+
+        let breakdown = PlayScoreBreakdown {
+            fifteen: true,
+            matching: 2,
+            ..PlayScoreBreakdown::default()
+        };
+
+        let messages = [
+            display.game_after_cut_message("Alice", &cards[0], "Bob", &cards[1], true),
+            display.cut_deck_message(52, Some(7)),
+            display.game_crib_discards_message(&cards, "Alice", true),
+            display.game_hint_message(Phase::Discard, &cards),
+            display.board_message("Alice", &pegs, "Bob", &Pegs::default()),
+            display.play_score_message(&breakdown, "Bob"),
+            display.prompt_undo(&cards[..1]),
+        ];
+
+        assert!(messages.iter().all(|message| message.is_ascii()));
+        assert_eq!(
+            messages[3],
+            "Hint: discard Queen of Diamonds, Seven of Spades to the crib."
+        );
+        assert_eq!(
+            messages[4],
+            "Alice's front peg is at 95, and back peg at 90, past the skunk line.\n\
+             Bob's front peg is at 0, and back peg at 0, under the double skunk line."
+        );
+        assert_eq!(messages[5], "Bob scores fifteen for 2, a pair for 2.");
+    }
+}
@@ -4,18 +4,44 @@
 
 #[cfg(doc)]
 use cards::{Rank, Suit};
+#[cfg(doc)]
+use game::{Game, Scoreboard, PASS_INDEX};
 
 use cards::Card;
-use game::{Controller, Player};
+use game::{Controller, GameError, PlayContext, PlayScoreBreakdown, Player, Side, SubRound};
 
 /// Simple struct to keep track of the played stack of [`Card`]s and their running raw total score.
 ///
 /// The stack and stack score are public for display purposes. Having getters and setters is dumb
 /// when Rust natively handles mutablility.
-#[derive(Debug, PartialEq)]
+///
+/// The consecutive passes are the number of turns in a row where no [`Card`] was put on the stack,
+/// whether the [`Player`] couldn't play or intentionally passed (see
+/// [`PlayData::allow_intentional_pass`]).
+///
+/// The leader and follower points are the points each [`Side`] has scored on the current stack.
+/// Since [`PlayData::play_once`] alternates between [`Player`]s until the stack is reset, the
+/// [`Side`] of each turn is known from the number of turns taken on the stack. When the stack is
+/// reset, it's recorded as a [`SubRound`].
+///
+/// The last to lay a [`Card`] is kept across stacks, as whether it was the dealer, since the
+/// other [`Player`] leads after the count starts over. See [`PlayData::dealer_leads_next`].
+///
+/// The starter and the target score are only passed along to each [`Controller`], in the
+/// [`PlayContext`].
+#[derive(Debug, PartialEq, Clone)]
 pub struct PlayData {
     pub stack: Vec<Card>,
     pub stack_score: u32,
+    pub consecutive_passes: u32,
+    pub allow_intentional_pass: bool,
+    pub leader_points: u32,
+    pub follower_points: u32,
+    pub stack_turns: u32,
+    pub sub_rounds: Vec<SubRound>,
+    pub last_played_by_dealer: Option<bool>,
+    pub starter: Option<Card>,
+    pub target: u32,
 }
 
 impl PlayData {
@@ -33,6 +59,15 @@ impl PlayData {
         PlayData {
             stack: Vec::new(),
             stack_score: 0,
+            consecutive_passes: 0,
+            allow_intentional_pass: false,
+            leader_points: 0,
+            follower_points: 0,
+            stack_turns: 0,
+            sub_rounds: Vec::new(),
+            last_played_by_dealer: None,
+            starter: None,
+            target: 121,
         }
     }
 
@@ -50,6 +85,7 @@ impl PlayData {
     /// let expected = PlayData {
     ///     stack: vec![card1.clone(), card2.clone()],
     ///     stack_score: 11,
+    ///     ..PlayData::new()
     /// };
     ///
     /// let mut data = PlayData::new();
@@ -66,7 +102,9 @@ impl PlayData {
 
     /// Resets [`PlayData`] if necessary.
     ///
-    /// This is only needed if no [`Player`] can play **OR** the stack score is `31`.
+    /// This is only needed if no [`Player`] can play **OR** the stack score is `31`. When
+    /// intentional passes are allowed, this is also needed when both [`Player`]s have passed in a
+    /// row, since neither is going to put down another [`Card`].
     ///
     /// # Examples
     ///
@@ -110,9 +148,18 @@ impl PlayData {
     {
         let mut reset = false;
 
-        if !self.any_can_play(player_1, player_2) {
-            self.stack = Vec::new();
+        if !self.any_can_play(player_1, player_2) || (2 <= self.consecutive_passes) {
+            self.sub_rounds.push(SubRound {
+                stack: std::mem::take(&mut self.stack),
+                leader_points: self.leader_points,
+                follower_points: self.follower_points,
+            });
+
             self.stack_score = 0;
+            self.consecutive_passes = 0;
+            self.leader_points = 0;
+            self.follower_points = 0;
+            self.stack_turns = 0;
 
             reset = true;
         }
@@ -150,7 +197,7 @@ impl PlayData {
     /// // Can play either the 2 or the King.
     /// let could_play_before_discard = data.can_play(&player);
     ///
-    /// let card_from_player = player.discard().unwrap();
+    /// let card_from_player = player.discard().unwrap().unwrap();
     ///
     ///
     /// data.add_card(card_from_player.clone());
@@ -219,15 +266,28 @@ impl PlayData {
     ///     * Using [`Player::discard`].
     /// * The stack total is increased by the score of that [`Card`].
     /// * The stack's points are totalled and added to the [`Player`].
-    ///    * Using [`PlayData::current_points`].
+    ///    * Using [`PlayData::current_breakdown`].
+    ///
+    /// The returned [`PlayScoreBreakdown`] explains the points the [`Player`] scored, and is empty
+    /// if they passed. Whether the [`Player`] `is_dealer` is passed along in their [`PlayContext`].
+    ///
+    /// If the [`Player`] cannot play, they GO (pass their turn). If
+    /// [`PlayData::allow_intentional_pass`] is set, the [`Player`] may also pass their turn with
+    /// [`PASS_INDEX`] even though they could play. An intentional pass never earns a GO point.
     ///
-    /// If the [`Player`] cannot play, they GO (pass their turn).
+    /// # Errors
+    ///
+    /// * [`GameError::InvalidPlay`] if the played [`Card`] is already on the stack, or if the
+    ///   [`Player::hand`] didn't shrink by exactly one [`Card`]. Nothing is added to the stack.
+    ///   The same goes for a [`Card`] that would go over 31, see [`Player::play_or_pass`].
+    /// * [`GameError::OutOfMoves`] if the [`Player::controller`] has no index to return when the
+    ///   [`Player`] can play.
+    /// * [`GameError::InvalidIndex`] if the [`Player::controller`] returns an index that is out of
+    ///   bounds for the [`Player::hand`].
     ///
     /// # Panics
     ///
-    /// * If the index at the front of [`Player::controller`] returns an index that is out of bounds
-    /// for the [`Player::hand`].
-    /// * If there is a [`Rank`] variant who's enum value is greater than `12`.
+    /// If there is a [`Rank`] variant who's enum value is greater than `12`.
     ///
     /// # Examples
     ///
@@ -257,8 +317,11 @@ impl PlayData {
     /// ];
     /// let mut data = PlayData::from(stack);
     ///
-    /// data.play_once(&mut player_1, &player_2);
-    /// data.play_once(&mut player_2, &player_1);
+    /// let breakdown = data.play_once(&mut player_1, &player_2, /*is_dealer=*/ false).unwrap();
+    /// data.play_once(&mut player_2, &player_1, /*is_dealer=*/ false).unwrap();
+    ///
+    /// assert!(breakdown.go);
+    /// assert_eq!(breakdown.total(), 1);
     ///
     /// // Player 1 got 1 points for a GO and has 1 less card in their hand.
     /// assert_eq!(player_1.points, 1);
@@ -269,22 +332,304 @@ impl PlayData {
     /// assert!(player_2.has_cards());
     /// assert!(player_2.has_cards_in_hand());
     /// ```
-    pub fn play_once<C1, C2>(&mut self, player: &mut Player<C1>, opponent: &Player<C2>)
+    pub fn play_once<C1, C2>(
+        &mut self,
+        player: &mut Player<C1>,
+        opponent: &Player<C2>,
+        is_dealer: bool,
+    ) -> Result<PlayScoreBreakdown, GameError>
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let breakdown = self.play_turn(player, opponent, is_dealer)?;
+
+        player.points += breakdown.total();
+
+        Ok(breakdown)
+    }
+
+    /// Plays a single round of play for a [`Player`], like [`PlayData::play_once`], but without
+    /// adding the points to the [`Player`].
+    ///
+    /// This is for the [`Game`], which adds the points with a [`Scoreboard`] to catch a win as
+    /// soon as it's pegged.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`PlayData::play_once`].
+    ///
+    /// # Panics
+    ///
+    /// If there is a [`Rank`] variant who's enum value is greater than `12`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{PlayData, Player, PredeterminedController};
+    ///
+    /// let mut player_1 = Player::new_with_cards(
+    ///     PredeterminedController::from(vec![0]),
+    ///     vec![Card::new(Rank::Five, Suit::Clubs)],
+    /// );
+    /// let player_2 = Player::new_with_cards(
+    ///     PredeterminedController::from(vec![]),
+    ///     vec![Card::new(Rank::Ten, Suit::Clubs)],
+    /// );
+    ///
+    /// let mut data = PlayData::from(vec![Card::new(Rank::King, Suit::Hearts)]);
+    ///
+    /// let breakdown = data.play_turn(&mut player_1, &player_2, /*is_dealer=*/ false).unwrap();
+    ///
+    /// assert_eq!(breakdown.total(), 2);
+    /// assert_eq!(player_1.points, 0);
+    /// ```
+    pub fn play_turn<C1, C2>(
+        &mut self,
+        player: &mut Player<C1>,
+        opponent: &Player<C2>,
+        is_dealer: bool,
+    ) -> Result<PlayScoreBreakdown, GameError>
     where
         C1: Controller,
         C2: Controller,
     {
-        if self.can_play(player) {
-            let card_from_players_hand = player.discard().unwrap();
+        let context = PlayContext {
+            play_data: self,
+            points: player.points,
+            opponent_points: opponent.points,
+            target: self.target,
+            is_dealer,
+        };
+
+        let possible_card = if self.can_play(player) {
+            match player.choose_play_index(self.allow_intentional_pass, &context)? {
+                Some(index) => {
+                    let card = &player.hand[index];
+
+                    // Checked before the card leaves the hand, so nothing is lost on an error.
+                    if self.stack.contains(card) {
+                        return Err(GameError::InvalidPlay(card.clone()));
+                    }
+
+                    let hand_size_before_play = player.hand.len();
+
+                    let card = player.play_index(index)?;
+
+                    if player.hand.len() + 1 != hand_size_before_play {
+                        return Err(GameError::InvalidPlay(card));
+                    }
+
+                    Some(card)
+                }
+                None => None,
+            }
+        } else {
+            if player.has_cards_in_hand() {
+                player.say_go(&context);
+            }
+
+            None
+        };
+
+        let side = self.current_side();
+
+        let mut breakdown = PlayScoreBreakdown::default();
+
+        match possible_card {
+            Some(card_from_players_hand) => {
+                self.add_card(card_from_players_hand);
+
+                self.last_played_by_dealer = Some(is_dealer);
+
+                self.consecutive_passes = 0;
+
+                breakdown = self.current_breakdown();
+                breakdown.go = self.go_point(player, opponent) == 1;
+
+                let points = breakdown.total();
+
+                match side {
+                    Side::Leader => self.leader_points += points,
+                    Side::Follower => self.follower_points += points,
+                }
+            }
+            None => self.consecutive_passes += 1,
+        }
+
+        self.stack_turns += 1;
+
+        Ok(breakdown)
+    }
+
+    /// Returns the points for playing the [`Card`] on the current stack, not counting a GO point.
+    ///
+    /// # Panics
+    ///
+    /// If there is a [`Rank`] variant who's enum value is greater than `12`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::PlayData;
+    ///
+    /// let data = PlayData::from(vec![Card::new(Rank::Seven, Suit::Hearts)]);
+    ///
+    /// assert_eq!(data.points_for(&Card::new(Rank::Eight, Suit::Clubs)), 2);
+    /// assert_eq!(data.points_for(&Card::new(Rank::Seven, Suit::Clubs)), 2);
+    /// assert_eq!(data.points_for(&Card::new(Rank::Two, Suit::Clubs)), 0);
+    /// ```
+    #[must_use]
+    pub fn points_for(&self, card: &Card) -> u32 {
+        let mut data = PlayData::from(self.stack.clone());
 
-            self.add_card(card_from_players_hand);
+        data.add_card(card.clone());
+
+        data.current_points()
+    }
+
+    /// Returns every [`Card`] played this round, from the finished [`SubRound`]s and then the
+    /// current stack, in the order they were played.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{PlayData, SubRound};
+    ///
+    /// let mut data = PlayData::from(vec![Card::new(Rank::Two, Suit::Clubs)]);
+    ///
+    /// data.sub_rounds.push(SubRound {
+    ///     stack: vec![Card::new(Rank::King, Suit::Hearts)],
+    ///     leader_points: 0,
+    ///     follower_points: 0,
+    /// });
+    ///
+    /// let expected = vec![
+    ///     Card::new(Rank::King, Suit::Hearts),
+    ///     Card::new(Rank::Two, Suit::Clubs),
+    /// ];
+    ///
+    /// assert_eq!(data.played_cards(), expected);
+    /// ```
+    #[must_use]
+    pub fn played_cards(&self) -> Vec<Card> {
+        self.sub_rounds
+            .iter()
+            .flat_map(|sub_round| sub_round.stack.iter())
+            .chain(self.stack.iter())
+            .cloned()
+            .collect()
+    }
 
-            player.points += self.current_points();
+    /// Indicates that the dealer leads the next stack, after the count starts over.
+    ///
+    /// The other [`Player`] from the last to lay a [`Card`] leads, so this is when the pone laid
+    /// the last one. Before any [`Card`] is played, the pone leads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{PlayData, Player, PredeterminedController};
+    ///
+    /// let mut pone = Player::new_with_cards(
+    ///     PredeterminedController::from(vec![0]),
+    ///     vec![Card::new(Rank::Ace, Suit::Clubs)],
+    /// );
+    /// let dealer = Player::new_with_cards(
+    ///     PredeterminedController::from(vec![]),
+    ///     vec![Card::new(Rank::Two, Suit::Clubs)],
+    /// );
+    ///
+    /// let mut data = PlayData::from(vec![
+    ///     Card::new(Rank::King, Suit::Clubs),
+    ///     Card::new(Rank::King, Suit::Hearts),
+    ///     Card::new(Rank::Jack, Suit::Hearts),
+    /// ]);
+    ///
+    /// assert!(!data.dealer_leads_next());
+    ///
+    /// // The pone makes 31, so the dealer leads the next stack.
+    /// data.play_turn(&mut pone, &dealer, /*is_dealer=*/ false).unwrap();
+    ///
+    /// assert_eq!(data.last_played_by_dealer, Some(false));
+    /// assert!(data.reset_if_needed(&pone, &dealer));
+    /// assert!(data.dealer_leads_next());
+    /// ```
+    #[must_use]
+    pub fn dealer_leads_next(&self) -> bool {
+        self.last_played_by_dealer == Some(false)
+    }
 
-            player.points += self.go_point(player, opponent);
+    /// Returns the [`Side`] of the [`Player`] whose turn it is on the current stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{PlayData, Side};
+    ///
+    /// let data = PlayData::new();
+    ///
+    /// assert_eq!(data.current_side(), Side::Leader);
+    /// ```
+    #[must_use]
+    pub fn current_side(&self) -> Side {
+        if self.stack_turns.is_multiple_of(2) {
+            Side::Leader
+        } else {
+            Side::Follower
         }
     }
 
+    /// Returns the points scored by the given [`Side`] minus the points given to the other, on
+    /// the current stack.
+    ///
+    /// A finished stack has the same method in [`SubRound::net_tempo`].
+    ///
+    /// # Panics
+    ///
+    /// If either [`Side`]s points can't fit into an [`i32`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{PlayData, Player, PredeterminedController, Side};
+    ///
+    /// let controller = PredeterminedController::from(vec![0]);
+    ///
+    /// let mut player_1 = Player::new_with_cards(
+    ///     controller.clone(),
+    ///     vec![Card::new(Rank::Five, Suit::Clubs), Card::new(Rank::King, Suit::Clubs)],
+    /// );
+    /// let mut player_2 = Player::new_with_cards(
+    ///     controller.clone(),
+    ///     vec![Card::new(Rank::Five, Suit::Hearts), Card::new(Rank::King, Suit::Hearts)],
+    /// );
+    ///
+    /// let mut data = PlayData::new();
+    ///
+    /// // Player 1 leads a Five, and Player 2 pairs it.
+    /// data.play_once(&mut player_1, &player_2, /*is_dealer=*/ false).unwrap();
+    /// data.play_once(&mut player_2, &player_1, /*is_dealer=*/ false).unwrap();
+    ///
+    /// assert_eq!(data.net_tempo(Side::Leader), -2);
+    /// assert_eq!(data.net_tempo(Side::Follower), 2);
+    /// ```
+    #[must_use]
+    pub fn net_tempo(&self, side: Side) -> i32 {
+        let current = SubRound {
+            stack: Vec::new(),
+            leader_points: self.leader_points,
+            follower_points: self.follower_points,
+        };
+
+        current.net_tempo(side)
+    }
+
     /// Calculates the current points of the stack.
     ///
     /// Possible opimization is to calculate points in [`PlayData::add_card`].
@@ -311,10 +656,46 @@ impl PlayData {
     ///
     /// If there is a [`Rank`] variant who's enum value is greater than `12`.
     fn current_points(&self) -> u32 {
-        self.largest_run_points()
-            + self.pairs_points()
-            + self.fifteen_points()
-            + self.thirty_one_points()
+        self.current_breakdown().total()
+    }
+
+    /// Returns the [`PlayScoreBreakdown`] of the stack, not counting a GO point.
+    ///
+    /// See [`PlayData::play_once`] for how the points are scored.
+    ///
+    /// # Panics
+    ///
+    /// If there is a [`Rank`] variant who's enum value is greater than `12`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{PlayData, PlayScoreBreakdown};
+    ///
+    /// let data = PlayData::from(vec![
+    ///     Card::new(Rank::Five, Suit::Hearts),
+    ///     Card::new(Rank::Five, Suit::Clubs),
+    ///     Card::new(Rank::Five, Suit::Spades),
+    /// ]);
+    ///
+    /// let expected = PlayScoreBreakdown {
+    ///     fifteen: true,
+    ///     matching: 3,
+    ///     ..PlayScoreBreakdown::default()
+    /// };
+    ///
+    /// assert_eq!(data.current_breakdown(), expected);
+    /// ```
+    #[must_use]
+    pub fn current_breakdown(&self) -> PlayScoreBreakdown {
+        PlayScoreBreakdown {
+            fifteen: self.fifteen_points() != 0,
+            thirty_one: self.thirty_one_points() != 0,
+            matching: self.matching_ranks(),
+            run: self.largest_run_points(),
+            go: false,
+        }
     }
 
     /// Returns `0` or `1` if neither [`Player`] can play.
@@ -465,18 +846,14 @@ impl PlayData {
         }
     }
 
-    /// Returns `0`, `2`, `6`, or `12` depending on the [`Rank`] matching of the top 2-4 [`Card`]s.
-    ///
-    /// Pairs are counted as:
-    ///     * player 1 does a pair and gets 2pts
-    ///     * player 2 does a three-of-a-kind and gets 6pts
-    ///     * player 1 does a four-of-a-kind and gets 12pts
-    fn pairs_points(&self) -> u32 {
+    /// Returns the number of [`Card`]s at the top of the stack matching the top [`Card`]'s
+    /// [`Rank`], including the top [`Card`], or `0` if there are no matches.
+    fn matching_ranks(&self) -> u32 {
         if self.stack.len() < 2 {
             return 0;
         }
 
-        let mut matching_ranks = 0;
+        let mut matching_ranks = 1;
         let top_card_rank = self.stack.last().unwrap().rank;
 
         for card in self.stack.iter().rev().take(4).skip(1) {
@@ -487,11 +864,10 @@ impl PlayData {
             }
         }
 
-        match matching_ranks {
-            3 => 12,
-            2 => 6,
-            1 => 2,
-            _ => 0,
+        if matching_ranks < 2 {
+            0
+        } else {
+            matching_ranks
         }
     }
 }
@@ -518,6 +894,7 @@ impl From<Vec<Card>> for PlayData {
     /// let expected = PlayData {
     ///     stack: cards.clone(),
     ///     stack_score: 2,
+    ///     ..PlayData::new()
     /// };
     ///
     /// let result = PlayData::from(cards);
@@ -540,7 +917,7 @@ mod tests {
     use super::*;
     use crate::{
         cards::{Card, Rank, Suit},
-        game::PredeterminedController,
+        game::{PredeterminedController, PASS_INDEX},
     };
 
     #[test]
@@ -860,7 +1237,7 @@ mod tests {
 
         let data = PlayData::from(cards);
 
-        let result = data.pairs_points();
+        let result = data.current_breakdown().matching_points();
 
         assert_eq!(result, 0);
     }
@@ -876,7 +1253,7 @@ mod tests {
 
         let data = PlayData::from(cards);
 
-        let result = data.pairs_points();
+        let result = data.current_breakdown().matching_points();
 
         assert_eq!(result, 0);
     }
@@ -891,7 +1268,7 @@ mod tests {
 
         let data = PlayData::from(cards);
 
-        let result = data.pairs_points();
+        let result = data.current_breakdown().matching_points();
 
         assert_eq!(result, 2);
     }
@@ -907,7 +1284,7 @@ mod tests {
 
         let data = PlayData::from(cards);
 
-        let result = data.pairs_points();
+        let result = data.current_breakdown().matching_points();
 
         assert_eq!(result, 6);
     }
@@ -924,7 +1301,7 @@ mod tests {
 
         let data = PlayData::from(cards);
 
-        let result = data.pairs_points();
+        let result = data.current_breakdown().matching_points();
 
         assert_eq!(result, 12);
     }
@@ -1082,4 +1459,244 @@ mod tests {
 
         assert_eq!(result, 8);
     }
+
+    #[test]
+    fn test_play_once_intentional_pass_not_allowed_panics() {
+        let result = std::panic::catch_unwind(|| {
+            let controller = PredeterminedController::from(vec![PASS_INDEX]);
+
+            let player_1_cards = vec![Card::new(Rank::Five, Suit::Clubs)];
+            let mut player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+            let player_2_cards = vec![Card::new(Rank::Six, Suit::Clubs)];
+            let player_2 = Player::new_with_cards(controller, player_2_cards);
+
+            let mut data = PlayData::new();
+
+            data.play_once(&mut player_1, &player_2, /*is_dealer=*/ false)
+                .unwrap();
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_play_once_breakdown_run() {
+        let controller = PredeterminedController::from(vec![0]);
+
+        let player_1_cards = vec![Card::new(Rank::Four, Suit::Clubs)];
+        let mut player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        let player_2_cards = vec![Card::new(Rank::King, Suit::Clubs)];
+        let player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        let stack = vec![
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Spades),
+        ];
+        let mut data = PlayData::from(stack);
+
+        let breakdown = data
+            .play_once(&mut player_1, &player_2, /*is_dealer=*/ false)
+            .unwrap();
+
+        let expected = PlayScoreBreakdown {
+            run: 3,
+            ..PlayScoreBreakdown::default()
+        };
+
+        assert_eq!(breakdown, expected);
+        assert_eq!(player_1.points, 3);
+    }
+
+    #[test]
+    fn test_play_once_intentional_pass_allowed() {
+        let controller = PredeterminedController::from(vec![PASS_INDEX]);
+
+        let player_1_cards = vec![Card::new(Rank::Five, Suit::Clubs)];
+        let mut player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        let player_2_cards = vec![Card::new(Rank::Six, Suit::Clubs)];
+        let player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        let stack = vec![Card::new(Rank::King, Suit::Clubs)];
+        let mut data = PlayData::from(stack.clone());
+        data.allow_intentional_pass = true;
+
+        let breakdown = data
+            .play_once(&mut player_1, &player_2, /*is_dealer=*/ false)
+            .unwrap();
+
+        assert_eq!(breakdown, PlayScoreBreakdown::default());
+        assert_eq!(data.stack, stack);
+        assert_eq!(data.consecutive_passes, 1);
+        assert_eq!(player_1.points, 0);
+        assert!(player_1.has_cards_in_hand());
+        assert!(data.can_play(&player_1));
+
+        // Player 1 could still play, so the stack is not reset.
+        assert!(!data.reset_if_needed(&player_1, &player_2));
+        assert_eq!(data.stack, stack);
+    }
+
+    #[test]
+    fn test_reset_if_needed_both_players_passed() {
+        let controller = PredeterminedController::from(vec![PASS_INDEX, PASS_INDEX]);
+
+        let player_1_cards = vec![Card::new(Rank::Five, Suit::Clubs)];
+        let mut player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        let player_2_cards = vec![Card::new(Rank::Six, Suit::Clubs)];
+        let mut player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        let stack = vec![Card::new(Rank::King, Suit::Clubs)];
+        let mut data = PlayData::from(stack.clone());
+        data.allow_intentional_pass = true;
+
+        data.play_once(&mut player_1, &player_2, /*is_dealer=*/ false)
+            .unwrap();
+
+        assert!(!data.reset_if_needed(&player_1, &player_2));
+
+        data.play_once(&mut player_2, &player_1, /*is_dealer=*/ false)
+            .unwrap();
+
+        assert!(data.reset_if_needed(&player_1, &player_2));
+        assert!(data.stack.is_empty());
+        assert_eq!(data.stack_score, 0);
+        assert_eq!(data.consecutive_passes, 0);
+        // Neither player gets a GO point for passing.
+        assert_eq!(player_1.points, 0);
+        assert_eq!(player_2.points, 0);
+    }
+
+    #[test]
+    fn test_reset_if_needed_records_sub_round() {
+        let controller = PredeterminedController::from(vec![0, 0]);
+
+        // Player 1 leads the Five, Player 2 pairs it, and Player 1 makes three-of-a-kind for 15.
+        let player_1_cards = vec![
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Diamonds),
+        ];
+        let mut player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        let player_2_cards = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        let mut player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        let mut data = PlayData::from(vec![]);
+
+        data.play_once(&mut player_1, &player_2, /*is_dealer=*/ false)
+            .unwrap();
+        data.play_once(&mut player_2, &player_1, /*is_dealer=*/ false)
+            .unwrap();
+
+        assert_eq!(data.net_tempo(Side::Leader), -2);
+
+        data.play_once(&mut player_1, &player_2, /*is_dealer=*/ false)
+            .unwrap();
+
+        assert_eq!(data.net_tempo(Side::Leader), 6);
+        assert_eq!(data.current_side(), Side::Follower);
+        assert!(!data.reset_if_needed(&player_1, &player_2));
+
+        // Player 2 can no longer play their King.
+        data.stack_score = 30;
+
+        assert!(data.reset_if_needed(&player_1, &player_2));
+
+        let expected_sub_round = SubRound {
+            stack: vec![
+                Card::new(Rank::Five, Suit::Clubs),
+                Card::new(Rank::Five, Suit::Hearts),
+                Card::new(Rank::Five, Suit::Diamonds),
+            ],
+            leader_points: 8,
+            follower_points: 2,
+        };
+
+        assert_eq!(data.sub_rounds, vec![expected_sub_round]);
+        assert_eq!(data.current_side(), Side::Leader);
+        assert_eq!(data.net_tempo(Side::Leader), 0);
+    }
+
+    #[test]
+    fn test_play_once_card_already_on_stack_invalid_play() {
+        let controller = PredeterminedController::from(vec![0]);
+
+        let player_1_cards = vec![Card::new(Rank::Five, Suit::Clubs)];
+        let mut player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        let player_2_cards = vec![Card::new(Rank::Six, Suit::Clubs)];
+        let player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        // The Five of Clubs is somehow on the stack and in Player 1's hand.
+        let stack = vec![Card::new(Rank::Five, Suit::Clubs)];
+        let mut data = PlayData::from(stack.clone());
+
+        let result = data.play_once(&mut player_1, &player_2, /*is_dealer=*/ false);
+
+        assert_eq!(
+            result,
+            Err(GameError::InvalidPlay(Card::new(Rank::Five, Suit::Clubs)))
+        );
+        assert_eq!(data.stack, stack);
+        assert_eq!(data.stack_turns, 0);
+        assert_eq!(player_1.points, 0);
+    }
+
+    #[test]
+    fn test_play_turn_invalid_play_leaves_hand_unchanged() {
+        let controller = PredeterminedController::from(vec![1]);
+
+        let player_1_cards = vec![
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Clubs),
+        ];
+        let mut player_1 = Player::new_with_cards(controller.clone(), player_1_cards.clone());
+
+        let player_2_cards = vec![Card::new(Rank::Six, Suit::Clubs)];
+        let player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        // The Five of Clubs is somehow on the stack and in Player 1's hand.
+        let mut data = PlayData::from(vec![Card::new(Rank::Five, Suit::Clubs)]);
+
+        let result = data.play_turn(&mut player_1, &player_2, /*is_dealer=*/ false);
+
+        assert_eq!(
+            result,
+            Err(GameError::InvalidPlay(Card::new(Rank::Five, Suit::Clubs)))
+        );
+        assert_eq!(player_1.hand_cards(), player_1_cards);
+        assert!(player_1.discarded.is_empty());
+    }
+
+    #[test]
+    fn test_play_turn_fabricated_index_leaves_hand_and_stack_unchanged() {
+        // Index 5 is beyond Player 1's hand, so no Card can leave it.
+        let controller = PredeterminedController::from(vec![5]);
+
+        let player_1_cards = vec![
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Clubs),
+        ];
+        let mut player_1 = Player::new_with_cards(controller.clone(), player_1_cards.clone());
+
+        let player_2_cards = vec![Card::new(Rank::Six, Suit::Clubs)];
+        let player_2 = Player::new_with_cards(controller, player_2_cards);
+
+        let stack = vec![Card::new(Rank::King, Suit::Hearts)];
+        let mut data = PlayData::from(stack.clone());
+
+        let result = data.play_turn(&mut player_1, &player_2, /*is_dealer=*/ false);
+
+        assert_eq!(result, Err(GameError::InvalidIndex { index: 5, len: 2 }));
+        assert_eq!(player_1.hand_cards(), player_1_cards);
+        assert!(player_1.discarded.is_empty());
+        assert_eq!(data.stack, stack);
+        assert_eq!(data.stack_turns, 0);
+    }
 }
@@ -0,0 +1,72 @@
+//! The reasons for the points scored by playing (pegging) a [`Card`].
+
+#[cfg(doc)]
+use cards::{Card, Rank};
+#[cfg(doc)]
+use game::PlayData;
+
+/// The breakdown of the points scored by playing (pegging) a [`Card`] on the stack.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct PlayScoreBreakdown {
+    /// The stack score is `15`, for 2pts.
+    pub fifteen: bool,
+    /// The stack score is `31`, for 2pts.
+    pub thirty_one: bool,
+    /// The number of [`Card`]s of the same [`Rank`] on top of the stack: a pair (`2`) for 2pts,
+    /// three-of-a-kind (`3`) for 6pts, or four-of-a-kind (`4`) for 12pts. Otherwise, `0`.
+    pub matching: u32,
+    /// The length of the run on top of the stack, for 1pt per [`Card`]. Otherwise, `0`.
+    pub run: u32,
+    /// Neither player can play, and the stack score isn't `31`, for 1pt.
+    pub go: bool,
+}
+
+impl PlayScoreBreakdown {
+    /// Returns the points for a pair, three-of-a-kind, or four-of-a-kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::PlayScoreBreakdown;
+    ///
+    /// let breakdown = PlayScoreBreakdown {
+    ///     matching: 3,
+    ///     ..PlayScoreBreakdown::default()
+    /// };
+    ///
+    /// assert_eq!(breakdown.matching_points(), 6);
+    /// ```
+    #[must_use]
+    pub fn matching_points(&self) -> u32 {
+        match self.matching {
+            4 => 12,
+            3 => 6,
+            2 => 2,
+            _ => 0,
+        }
+    }
+
+    /// Returns the total points of the breakdown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::PlayScoreBreakdown;
+    ///
+    /// let breakdown = PlayScoreBreakdown {
+    ///     fifteen: true,
+    ///     matching: 2,
+    ///     ..PlayScoreBreakdown::default()
+    /// };
+    ///
+    /// assert_eq!(breakdown.total(), 4);
+    /// ```
+    #[must_use]
+    pub fn total(&self) -> u32 {
+        2 * u32::from(self.fifteen)
+            + 2 * u32::from(self.thirty_one)
+            + self.matching_points()
+            + self.run
+            + u32::from(self.go)
+    }
+}
@@ -2,7 +2,18 @@ use itertools::Itertools;
 use std::fmt;
 
 use cards::{Card, Deck, Hand};
-use game::Controller;
+use game::{Controller, GameError, PlayContext, DOUBLE_SKUNK_LINE, PASS_INDEX, SKUNK_LINE};
+
+/// How many times a [`Player::controller`] is asked again for a [`Card`] that would go over 31,
+/// before the play is rejected.
+pub(crate) const MAX_PLAY_ATTEMPTS: usize = 100;
+
+/// The name shown for player 1, the one at the keyboard, if they aren't given a
+/// [`Player::name`].
+pub const DEFAULT_PLAYER_NAME: &str = "Player";
+
+/// The name shown for player 2 if they aren't given a [`Player::name`].
+pub const DEFAULT_OPPONENT_NAME: &str = "Opponent";
 
 /// The representation of a player with a [`Hand`], a discarded pile, a [`Controller`], and points.
 ///
@@ -15,14 +26,18 @@ use game::Controller;
 /// during play.
 ///
 /// Points is self explainitory.
+///
+/// The name is shown in every message about the [`Player`], like `"Alice played [5♥]"`. It's
+/// empty unless set with [`Player::with_name`], in which case [`DEFAULT_PLAYER_NAME`] or
+/// [`DEFAULT_OPPONENT_NAME`] is shown instead.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Player<C>
 where
     C: Controller,
 {
     controller: C,
+    pub name: String,
     pub discarded: Vec<Card>,
-    pub crib: Hand,
     pub hand: Hand,
     pub points: u32,
 }
@@ -45,8 +60,8 @@ where
     pub fn new(controller: C) -> Player<C> {
         Player {
             controller,
+            name: String::new(),
             discarded: Vec::new(),
-            crib: Hand::new(),
             hand: Hand::new(),
             points: 0,
         }
@@ -69,61 +84,53 @@ where
     pub fn new_with_cards(controller: C, cards: Vec<Card>) -> Player<C> {
         Player {
             controller,
+            name: String::new(),
             discarded: Vec::new(),
-            crib: Hand::new(),
             hand: Hand::from(cards),
             points: 0,
         }
     }
 
-    /// Creates a new [`Player`] with a given [`Controller`], [`Player::hand`], and
-    /// [`Player::crib`] of given [`Card`]s.
+    /// Sets the [`Player::name`] shown in every message about the [`Player`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
-    /// use libterminal_cribbage::game::{Player, PredeterminedController};
+    /// use libterminal_cribbage::game::{Player, PredeterminedController, DEFAULT_PLAYER_NAME};
     ///
-    /// let cards = vec![Card::new(Rank::Ace, Suit::Hearts), Card::new(Rank::Ace, Suit::Clubs)];
-    ///
-    /// let controller = PredeterminedController::from(vec![0, 1, 2]);
+    /// let player = Player::new(PredeterminedController::from(vec![0])).with_name("Alice");
     ///
-    /// let player = Player::new_with_cards_and_crib(controller, cards.clone(), cards.clone());
+    /// assert_eq!(player.name, "Alice");
+    /// assert_eq!(player.name_or(DEFAULT_PLAYER_NAME), "Alice");
     /// ```
-    pub fn new_with_cards_and_crib(
-        controller: C,
-        hand_cards: Vec<Card>,
-        crib_cards: Vec<Card>,
-    ) -> Player<C> {
-        Player {
-            controller,
-            discarded: Vec::new(),
-            crib: Hand::from(crib_cards),
-            hand: Hand::from(hand_cards),
-            points: 0,
-        }
+    #[must_use]
+    pub fn with_name(mut self, name: &str) -> Player<C> {
+        self.name = name.to_string();
+
+        self
     }
 
-    /// Add a [`Card`] to [`Player::hand`].
+    /// Returns the [`Player::name`], or the default if the [`Player`] hasn't been given one.
     ///
     /// # Examples
     ///
     /// ```
-    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
-    /// use libterminal_cribbage::game::{Player, PredeterminedController};
+    /// use libterminal_cribbage::game::{Player, PredeterminedController, DEFAULT_OPPONENT_NAME};
     ///
-    /// let controller = PredeterminedController::from(vec![0, 1, 2]);
+    /// let player = Player::new(PredeterminedController::from(vec![0]));
     ///
-    /// let mut player = Player::new(controller);
-    ///
-    /// player.add_card(Card::new(Rank::Ace, Suit::Spades));
+    /// assert_eq!(player.name_or(DEFAULT_OPPONENT_NAME), "Opponent");
     /// ```
-    pub fn add_card(&mut self, card: Card) {
-        self.hand.add_card(card);
+    #[must_use]
+    pub fn name_or<'a>(&'a self, default: &'a str) -> &'a str {
+        if self.name.is_empty() {
+            default
+        } else {
+            &self.name
+        }
     }
 
-    /// Add a [`Card`] to [`Player::crib`].
+    /// Add a [`Card`] to [`Player::hand`].
     ///
     /// # Examples
     ///
@@ -135,10 +142,10 @@ where
     ///
     /// let mut player = Player::new(controller);
     ///
-    /// player.add_card_to_crib(Card::new(Rank::Ace, Suit::Spades));
+    /// player.add_card(Card::new(Rank::Ace, Suit::Spades));
     /// ```
-    pub fn add_card_to_crib(&mut self, card: Card) {
-        self.crib.add_card(card);
+    pub fn add_card(&mut self, card: Card) {
+        self.hand.add_card(card);
     }
 
     /// Indicates that the [`Player`] has [`Card`]s in [`Player::hand`].
@@ -163,8 +170,7 @@ where
         !self.hand.is_empty()
     }
 
-    /// Indicates that the [`Player`] has [`Card`]s in [`Player::hand`], [`Player::crib`], or
-    /// [`Player::discarded`].
+    /// Indicates that the [`Player`] has [`Card`]s in [`Player::hand`] or [`Player::discarded`].
     ///
     /// # Examples
     ///
@@ -183,18 +189,21 @@ where
     /// assert!(player.has_cards());
     /// ```
     pub fn has_cards(&self) -> bool {
-        !self.hand.is_empty() || !self.crib.is_empty() || !self.discarded.is_empty()
+        !self.hand.is_empty() || !self.discarded.is_empty()
     }
 
-    /// Chooses [`Card`] for the cut from given [`Deck`], which is removed from the [`Deck`].
+    /// Cuts the [`Deck`] to choose the dealer, returning the [`Card`] shown at the cut.
     ///
-    /// This [`Card`] is determined by the [`Player::controller`] and is
-    /// added to [`Player::discarded`].
+    /// The depth of the cut is one more than [`Controller::get_cut_index`] of the
+    /// [`Player::controller`], like [`Deck::cut`]. The bottom [`Card`] of the lifted packet is
+    /// shown, and taken out, and the rest of the packet is put back under the [`Deck`].
+    ///
+    /// [`None`] is returned if the [`Player::controller`] has no index to return.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// If the [`Player::controller`] returns an index that is out of bounds of the
-    /// [`Deck`].
+    /// [`GameError::InvalidIndex`] if the [`Player::controller`] returns an index that is out of
+    /// bounds of the [`Deck`].
     ///
     /// # Examples
     ///
@@ -204,37 +213,84 @@ where
     ///
     /// let mut deck = Deck::new();
     ///
-    /// // Cut the 13th card from the deck (12 is the index from 0).
+    /// // Cut 13 cards deep (12 is the index from 0).
     /// let controller = PredeterminedController::from(vec![12]);
     ///
     /// let mut player = Player::new(controller);
     ///
-    /// let result = player.choose_card_for_cut(&mut deck);
+    /// let (top, _) = deck.cut(13).unwrap();
+    /// let shown = top.as_vec()[0].clone();
     ///
-    /// assert_eq!(result, Some(Card::new(Rank::King, Suit::Hearts)));
+    /// assert_eq!(player.choose_card_for_cut(&mut deck), Ok(Some(shown.clone())));
     /// assert_eq!(deck.as_vec().len(), 51);
+    /// assert!(!deck.as_vec().contains(&shown));
     /// ```
-    #[must_use]
-    pub fn choose_card_for_cut(&mut self, deck: &mut Deck) -> Option<Card> {
-        // Handle stdin here. Only print input if not using stdin.
-        // Maybe Controller has a method `uses_stdin`?
-        // Also, put this into Player
-        // print!("Choose Card to Cut from Hand (0 to 51): ");
+    pub fn choose_card_for_cut(&mut self, deck: &mut Deck) -> Result<Option<Card>, GameError> {
+        let len = deck.as_vec().len();
+
+        let Some(index) = self.controller.get_cut_index(deck.as_vec()) else {
+            return Ok(None);
+        };
+
+        let (mut lifted, rest) = deck
+            .cut(index + 1)
+            .map_err(|_| GameError::InvalidIndex { index, len })?;
+
+        let card = lifted
+            .remove(0)
+            .map_err(|_| GameError::InvalidIndex { index, len })?;
+
+        let mut cards = lifted.as_vec().clone();
+
+        cards.extend(rest.as_vec().iter().cloned());
 
-        self.controller
-            .get_card_index(deck.as_vec())
-            .map(|index| deck.remove(index).unwrap())
+        *deck = Deck::new_with_cards(cards);
+
+        Ok(Some(card))
+    }
+
+    /// Chooses how many [`Card`]s deep to cut the [`Deck`] for the starter, as the pone.
+    ///
+    /// The depth is determined by [`Controller::get_starter_cut_depth`] of the
+    /// [`Player::controller`], and [`None`] is returned if it has no depth to return.
+    ///
+    /// # Errors
+    ///
+    /// [`GameError::InvalidIndex`] if the depth is out of bounds of the [`Deck`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::Deck;
+    /// use libterminal_cribbage::game::{Player, RngController};
+    ///
+    /// let deck = Deck::new();
+    ///
+    /// let mut player = Player::new(RngController::new());
+    ///
+    /// let depth = player.choose_starter_cut_depth(&deck).unwrap().unwrap();
+    ///
+    /// assert!(depth < 52);
+    /// ```
+    pub fn choose_starter_cut_depth(&mut self, deck: &Deck) -> Result<Option<usize>, GameError> {
+        let len = deck.as_vec().len();
+
+        match self.controller.get_starter_cut_depth(len) {
+            Some(depth) if len <= depth => Err(GameError::InvalidIndex { index: depth, len }),
+            possible_depth => Ok(possible_depth),
+        }
     }
 
     /// Discards, and returns, a [`Card`] from [`Player::hand`] if there are cards to remove.
     ///
     /// This [`Card`] is determined by the [`Player::controller`] and is
-    /// added to [`Player::discarded`].
+    /// added to [`Player::discarded`]. [`None`] is returned if the [`Player::controller`] has no
+    /// index to return.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// If the [`Player::controller`] returns an index that is out of bounds of the
-    /// [`Player::hand`].
+    /// [`GameError::InvalidIndex`] if the [`Player::controller`] returns an index that is out of
+    /// bounds of the [`Player::hand`].
     ///
     /// # Examples
     ///
@@ -259,22 +315,204 @@ where
     /// let result_2 = player.discard();
     /// let result_3 = player.discard();
     ///
-    /// assert_eq!(result_1, Some(cards[0].clone()));
-    /// assert_eq!(result_2, Some(cards[2].clone()));
-    /// assert_eq!(result_3, Some(cards[1].clone()));
+    /// assert_eq!(result_1, Ok(Some(cards[0].clone())));
+    /// assert_eq!(result_2, Ok(Some(cards[2].clone())));
+    /// assert_eq!(result_3, Ok(Some(cards[1].clone())));
     /// ```
-    #[must_use]
-    pub fn discard(&mut self) -> Option<Card> {
-        let possible_card = self
+    pub fn discard(&mut self) -> Result<Option<Card>, GameError> {
+        match self.controller.get_card_index(self.hand.as_vec()) {
+            Some(index) => self.discard_index_or_pass(index, false),
+            None => Ok(None),
+        }
+    }
+
+    /// Discards, and returns, a [`Card`] like [`Player::discard`], unless passing is allowed and
+    /// the [`Player::controller`] returns [`PASS_INDEX`].
+    ///
+    /// When the pass is honored, nothing is discarded and [`None`] is returned.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::OutOfMoves`] if the [`Player::controller`] has no index to return.
+    /// * [`GameError::InvalidIndex`] if the [`Player::controller`] returns an index that is out
+    ///   of bounds of the [`Player::hand`], including [`PASS_INDEX`] when passing is not allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Player, PredeterminedController, PASS_INDEX};
+    ///
+    /// let cards = vec![
+    ///     Card::new(Rank::Ace, Suit::Hearts),
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    /// ];
+    ///
+    /// let controller = PredeterminedController::from(vec![PASS_INDEX, 1]);
+    ///
+    /// let mut player = Player::new_with_cards(controller, cards.clone());
+    ///
+    /// let result_1 = player.discard_or_pass(/*allow_pass=*/ true);
+    /// let result_2 = player.discard_or_pass(/*allow_pass=*/ true);
+    ///
+    /// assert_eq!(result_1, Ok(None));
+    /// assert_eq!(result_2, Ok(Some(cards[1].clone())));
+    /// assert_eq!(player.discarded, vec![cards[1].clone()]);
+    /// ```
+    pub fn discard_or_pass(&mut self, allow_pass: bool) -> Result<Option<Card>, GameError> {
+        let index = self
             .controller
             .get_card_index(self.hand.as_vec())
-            .map(|index| self.hand.discard(index).unwrap());
+            .ok_or_else(|| GameError::OutOfMoves(String::from("Player")))?;
 
-        if let Some(card) = possible_card.clone() {
-            self.discarded.push(card);
+        self.discard_index_or_pass(index, allow_pass)
+    }
+
+    /// Plays, and returns, a [`Card`] like [`Player::discard_or_pass`], but the
+    /// [`Player::controller`] chooses with [`Controller::get_play_index`] and the [`PlayContext`].
+    ///
+    /// A [`Card`] that would take the stack over 31 is never played. Instead, the
+    /// [`Player::controller`] is told with [`Controller::play_rejected`], and asked again.
+    ///
+    /// # Errors
+    ///
+    /// * [`GameError::OutOfMoves`] if the [`Player::controller`] has no index to return.
+    /// * [`GameError::InvalidIndex`] if the [`Player::controller`] returns an index that is out
+    ///   of bounds of the [`Player::hand`], including [`PASS_INDEX`] when passing is not allowed.
+    /// * [`GameError::InvalidPlay`] if the [`Player::controller`] chooses a [`Card`] that would go
+    ///   over 31, and either no [`Card`] in the [`Player::hand`] can be played, or it keeps
+    ///   choosing one 100 times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{PlayContext, PlayData, Player, PredeterminedController};
+    ///
+    /// let cards = vec![
+    ///     Card::new(Rank::Ace, Suit::Hearts),
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    /// ];
+    ///
+    /// let controller = PredeterminedController::from(vec![1]);
+    ///
+    /// let mut player = Player::new_with_cards(controller, cards.clone());
+    ///
+    /// let play_data = PlayData::new();
+    /// let context = PlayContext {
+    ///     play_data: &play_data,
+    ///     points: 0,
+    ///     opponent_points: 0,
+    ///     target: 121,
+    ///     is_dealer: false,
+    /// };
+    ///
+    /// let result = player.play_or_pass(/*allow_pass=*/ false, &context);
+    ///
+    /// assert_eq!(result, Ok(Some(cards[1].clone())));
+    /// ```
+    pub fn play_or_pass(
+        &mut self,
+        allow_pass: bool,
+        context: &PlayContext,
+    ) -> Result<Option<Card>, GameError> {
+        match self.choose_play_index(allow_pass, context)? {
+            Some(index) => self.play_index(index).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the index of the [`Card`] the [`Player::controller`] chooses to play, like
+    /// [`Player::play_or_pass`], or [`None`] if it passes, without changing the [`Player::hand`].
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Player::play_or_pass`].
+    pub(crate) fn choose_play_index(
+        &mut self,
+        allow_pass: bool,
+        context: &PlayContext,
+    ) -> Result<Option<usize>, GameError> {
+        let highest_playable_score = 31_u32.saturating_sub(context.play_data.stack_score);
+
+        let mut attempts = 0;
+
+        loop {
+            let index = self
+                .controller
+                .get_play_index(self.hand.as_vec(), context)
+                .ok_or_else(|| GameError::OutOfMoves(String::from("Player")))?;
+
+            let Some(card) = self
+                .hand
+                .as_vec()
+                .get(index)
+                .filter(|card| highest_playable_score < card.score())
+                .cloned()
+            else {
+                return self.index_or_pass(index, allow_pass);
+            };
+
+            attempts += 1;
+
+            if (MAX_PLAY_ATTEMPTS <= attempts)
+                || !self.has_card_with_score_at_most(highest_playable_score)
+            {
+                return Err(GameError::InvalidPlay(card));
+            }
+
+            self.controller.play_rejected(&card);
+        }
+    }
+
+    /// Discards the [`Card`] at the index, unless passing is allowed and the index is
+    /// [`PASS_INDEX`].
+    ///
+    /// # Errors
+    ///
+    /// [`GameError::InvalidIndex`] if the index is out of bounds of the [`Player::hand`].
+    fn discard_index_or_pass(
+        &mut self,
+        index: usize,
+        allow_pass: bool,
+    ) -> Result<Option<Card>, GameError> {
+        match self.index_or_pass(index, allow_pass)? {
+            Some(index) => self.play_index(index).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the index, or [`None`] if passing is allowed and the index is [`PASS_INDEX`].
+    ///
+    /// # Errors
+    ///
+    /// [`GameError::InvalidIndex`] if the index is out of bounds of the [`Player::hand`].
+    fn index_or_pass(&self, index: usize, allow_pass: bool) -> Result<Option<usize>, GameError> {
+        if allow_pass && (index == PASS_INDEX) {
+            return Ok(None);
+        }
+
+        let len = self.hand.len();
+
+        if len <= index {
+            return Err(GameError::InvalidIndex { index, len });
         }
 
-        possible_card
+        Ok(Some(index))
+    }
+
+    /// Removes, and returns, the [`Card`] at the index of [`Player::hand`], adding it to
+    /// [`Player::discarded`].
+    ///
+    /// # Errors
+    ///
+    /// [`GameError::InvalidIndex`] if the index is out of bounds of the [`Player::hand`].
+    pub(crate) fn play_index(&mut self, index: usize) -> Result<Card, GameError> {
+        let card = self.remove_index(index)?;
+
+        self.discarded.push(card.clone());
+
+        Ok(card)
     }
 
     /// Returns the last [`Card`] discarded.
@@ -302,8 +540,8 @@ where
     /// let result_2 = player.discard();
     /// let result_3 = player.last_discarded();
     ///
-    /// assert_eq!(result_1, Some(Card::new(Rank::Ace, Suit::Hearts)));
-    /// assert_eq!(result_2, Some(Card::new(Rank::Ace, Suit::Clubs)));
+    /// assert_eq!(result_1, Ok(Some(Card::new(Rank::Ace, Suit::Hearts))));
+    /// assert_eq!(result_2, Ok(Some(Card::new(Rank::Ace, Suit::Clubs))));
     /// assert_eq!(result_3, Some(&Card::new(Rank::Ace, Suit::Clubs)));
     /// ```
     #[must_use]
@@ -311,16 +549,148 @@ where
         self.discarded.last()
     }
 
+    /// Indicates that the [`Player::controller`] wants to take back the [`Card`]s just chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Player, PredeterminedController};
+    ///
+    /// let mut player = Player::new(PredeterminedController::from(vec![0]));
+    ///
+    /// assert!(!player.wants_undo(&[Card::new(Rank::Ace, Suit::Spades)]));
+    /// ```
+    pub fn wants_undo(&mut self, chosen: &[Card]) -> bool {
+        self.controller.undo_choice(chosen)
+    }
+
+    /// Says GO, because none of the [`Card`]s in hand can be played, waiting until the
+    /// [`Player::controller`] acknowledges it with [`Controller::acknowledge_go`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{PlayContext, PlayData, Player, PredeterminedController};
+    ///
+    /// let mut player = Player::new_with_cards(
+    ///     PredeterminedController::from(vec![]),
+    ///     vec![Card::new(Rank::King, Suit::Spades)],
+    /// );
+    ///
+    /// let play_data = PlayData::from(vec![
+    ///     Card::new(Rank::King, Suit::Clubs),
+    ///     Card::new(Rank::King, Suit::Hearts),
+    ///     Card::new(Rank::Five, Suit::Diamonds),
+    /// ]);
+    /// let context = PlayContext {
+    ///     play_data: &play_data,
+    ///     points: 0,
+    ///     opponent_points: 0,
+    ///     target: 121,
+    ///     is_dealer: false,
+    /// };
+    ///
+    /// player.say_go(&context);
+    ///
+    /// assert!(player.has_cards_in_hand());
+    /// ```
+    pub fn say_go(&mut self, context: &PlayContext) {
+        self.controller.acknowledge_go(self.hand.as_vec(), context);
+    }
+
+    /// Indicates that the [`Player::controller`] ran out of time on its last choice, which was
+    /// made for it, and forgets it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Player, PredeterminedController};
+    ///
+    /// let mut player = Player::new(PredeterminedController::from(vec![0]));
+    ///
+    /// assert!(!player.take_timed_out());
+    /// ```
+    pub fn take_timed_out(&mut self) -> bool {
+        self.controller.take_timed_out()
+    }
+
+    /// Indicates that the [`Player`] would be skunked if they lost now, being under [`SKUNK_LINE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Player, PredeterminedController};
+    ///
+    /// let mut player = Player::new(PredeterminedController::from(vec![]));
+    /// player.points = 90;
+    ///
+    /// assert!(player.is_skunked_if_loses());
+    ///
+    /// player.points = 91;
+    ///
+    /// assert!(!player.is_skunked_if_loses());
+    /// ```
+    #[must_use]
+    pub fn is_skunked_if_loses(&self) -> bool {
+        self.points < SKUNK_LINE
+    }
+
+    /// Indicates that the [`Player`] would be double skunked if they lost now, being under
+    /// [`DOUBLE_SKUNK_LINE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Player, PredeterminedController};
+    ///
+    /// let mut player = Player::new(PredeterminedController::from(vec![]));
+    /// player.points = 60;
+    ///
+    /// assert!(player.is_double_skunked_if_loses());
+    ///
+    /// player.points = 61;
+    ///
+    /// assert!(!player.is_double_skunked_if_loses());
+    /// assert!(player.is_skunked_if_loses());
+    /// ```
+    #[must_use]
+    pub fn is_double_skunked_if_loses(&self) -> bool {
+        self.points < DOUBLE_SKUNK_LINE
+    }
+
+    /// Returns a clone of the [`Card`]s in [`Player::hand`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Player, PredeterminedController};
+    ///
+    /// let cards = vec![Card::new(Rank::Ace, Suit::Hearts), Card::new(Rank::Ace, Suit::Clubs)];
+    ///
+    /// let controller = PredeterminedController::from(vec![0, 1, 2]);
+    ///
+    /// let player = Player::new_with_cards(controller, cards.clone());
+    ///
+    /// assert_eq!(player.hand_cards(), cards);
+    /// ```
+    #[must_use]
+    pub fn hand_cards(&self) -> Vec<Card> {
+        self.hand.as_vec().clone()
+    }
+
     /// Removes, and returns, a [`Card`] from [`Player::hand`] if there are cards to remove.
     ///
     /// This [`Card`] is determined by the [`Player::controller`].
     ///
     /// Unlike [`Player::discard`], this method does not add to [`Player::discarded`].
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// If the [`Player::controller`] returns an index that is out of bounds of the
-    /// [`Player::hand`].
+    /// [`GameError::InvalidIndex`] if the [`Player::controller`] returns an index that is out of
+    /// bounds of the [`Player::hand`].
     ///
     /// # Examples
     ///
@@ -338,17 +708,78 @@ where
     ///
     /// let result = player.remove_card();
     ///
-    /// assert_eq!(result, Some(card));
+    /// assert_eq!(result, Ok(Some(card)));
     /// ```
-    #[must_use]
-    pub fn remove_card(&mut self) -> Option<Card> {
-        self.controller
-            .get_card_index(self.hand.as_vec())
-            .map(|index| {
-                self.hand.discard(index).unwrap_or_else(|_| {
-                    panic!("Cannot grab index {} from hand {}", index, self.hand)
-                })
-            })
+    pub fn remove_card(&mut self) -> Result<Option<Card>, GameError> {
+        let index = self.controller.get_card_index(self.hand.as_vec());
+
+        index.map(|index| self.remove_index(index)).transpose()
+    }
+
+    /// Removes, and returns, both [`Card`]s from [`Player::hand`] to discard to a crib.
+    ///
+    /// Like [`Player::remove_card`], but the [`Player::controller`] chooses both [`Card`]s at
+    /// once with [`Controller::get_crib_discards`], and is told if the crib is theirs. The
+    /// [`Card`]s are returned in the order they were chosen. [`None`] is returned if the
+    /// [`Player::controller`] has no indices to return.
+    ///
+    /// # Errors
+    ///
+    /// [`GameError::InvalidIndex`] if the [`Player::controller`] returns an index that is out of
+    /// bounds of the [`Player::hand`], or the same index twice. Nothing is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Player, PredeterminedController};
+    ///
+    /// let cards = vec![
+    ///     Card::new(Rank::Ace, Suit::Spades),
+    ///     Card::new(Rank::Two, Suit::Spades),
+    ///     Card::new(Rank::Three, Suit::Spades),
+    /// ];
+    ///
+    /// let controller = PredeterminedController::from(vec![2, 0]);
+    ///
+    /// let mut player = Player::new_with_cards(controller, cards.clone());
+    ///
+    /// let result = player.remove_cards_for_crib(/*is_my_crib=*/ true);
+    ///
+    /// assert_eq!(result, Ok(Some([cards[2].clone(), cards[0].clone()])));
+    /// assert_eq!(player.hand.as_vec(), &vec![cards[1].clone()]);
+    /// ```
+    pub fn remove_cards_for_crib(
+        &mut self,
+        is_my_crib: bool,
+    ) -> Result<Option<[Card; 2]>, GameError> {
+        let len = self.hand.len();
+
+        let Some([first, second]) = self
+            .controller
+            .get_crib_discards(self.hand.as_vec(), is_my_crib)
+        else {
+            return Ok(None);
+        };
+
+        for index in [first, second] {
+            if len <= index || first == second {
+                return Err(GameError::InvalidIndex { index, len });
+            }
+        }
+
+        // Remove the larger index first, so the smaller index still points at the same card.
+        let (first_card, second_card) = if first < second {
+            let second_card = self.remove_index(second)?;
+
+            (self.remove_index(first)?, second_card)
+        } else {
+            let first_card = self.remove_index(first)?;
+
+            (first_card, self.remove_index(second)?)
+        };
+
+        Ok(Some([first_card, second_card]))
     }
 
     /// Adds all the [`Card`]s in [`Player::discarded`] to the [`Player::hand`].
@@ -407,30 +838,12 @@ where
     /// ```
     #[must_use]
     pub fn has_card_with_score_at_most(&self, value: u32) -> bool {
-        self.hand.as_vec().iter().any(|card| card.score() <= value)
+        self.hand.iter().any(|card| card.score() <= value)
     }
 
-    /// Indicats if [`Player::crib`] is not empty.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use libterminal_cribbage::game::{Player, PredeterminedController};
-    ///
-    /// let controller = PredeterminedController::from(vec![0]);
+    /// Removes all cards from [`Player::discarded`] and [`Player::hand`].
     ///
-    /// let mut player = Player::new(controller);
-    ///
-    /// assert!(!player.has_crib());
-    /// ```
-    #[must_use]
-    pub fn has_crib(&self) -> bool {
-        !self.crib.as_vec().is_empty()
-    }
-
-    /// Removes all cards from [`Player::discarded`], [`Player::crib`], and [`Player::hand`].
-    ///
-    /// The order is [`Player::hand`], [`Player::crib`], and [`Player::discarded`].
+    /// The order is [`Player::hand`], and then [`Player::discarded`].
     ///
     /// # Examples
     ///
@@ -443,23 +856,15 @@ where
     ///     Card::new(Rank::Ace, Suit::Spades),
     ///     Card::new(Rank::Ace, Suit::Clubs),
     /// ];
-    /// let crib = vec![
-    ///     Card::new(Rank::Two, Suit::Spades),
-    ///     Card::new(Rank::Two, Suit::Clubs),
-    /// ];
     ///
     /// let controller = PredeterminedController::from(vec![0, 1, 0]);
     ///
-    /// let mut player = Player::new_with_cards_and_crib(controller, cards, crib);
+    /// let mut player = Player::new_with_cards(controller, cards);
     ///
     /// let expected_hand = vec![
     ///     Card::new(Rank::Ace, Suit::Spades),
     ///     Card::new(Rank::Ace, Suit::Clubs),
     /// ];
-    /// let expected_crib = vec![
-    ///     Card::new(Rank::Two, Suit::Spades),
-    ///     Card::new(Rank::Two, Suit::Clubs),
-    /// ];
     /// let expected_discarded = vec![
     ///     Card::new(Rank::Ace, Suit::Hearts),
     /// ];
@@ -467,26 +872,21 @@ where
     /// let _ = player.discard();
     ///
     /// assert_eq!(player.hand.as_vec(), &expected_hand);
-    /// assert_eq!(player.crib.as_vec(), &expected_crib);
     /// assert_eq!(player.discarded, expected_discarded);
     ///
     /// let expected_removed = vec![
     ///     Card::new(Rank::Ace, Suit::Spades),
     ///     Card::new(Rank::Ace, Suit::Clubs),
-    ///     Card::new(Rank::Two, Suit::Spades),
-    ///     Card::new(Rank::Two, Suit::Clubs),
     ///     Card::new(Rank::Ace, Suit::Hearts),
     /// ];
     ///
     /// assert_eq!(player.remove_all(), expected_removed);
     /// assert_eq!(player.hand.len(), 0);
-    /// assert_eq!(player.crib.len(), 0);
     /// assert!(player.discarded.is_empty());
     /// ```
     pub fn remove_all(&mut self) -> Vec<Card> {
         let mut result = self.hand.as_vec().clone();
 
-        result.append(&mut self.crib.as_vec().clone());
         result.append(&mut self.discarded);
 
         self.reset();
@@ -494,7 +894,7 @@ where
         result
     }
 
-    /// Resets all cards from [`Player::discarded`], [`Player::crib`], and [`Player::hand`].
+    /// Resets all cards from [`Player::discarded`] and [`Player::hand`].
     ///
     /// # Examples
     ///
@@ -507,23 +907,15 @@ where
     ///     Card::new(Rank::Ace, Suit::Spades),
     ///     Card::new(Rank::Ace, Suit::Clubs),
     /// ];
-    /// let crib = vec![
-    ///     Card::new(Rank::Two, Suit::Spades),
-    ///     Card::new(Rank::Two, Suit::Clubs),
-    /// ];
     ///
     /// let controller = PredeterminedController::from(vec![0, 1, 0]);
     ///
-    /// let mut player = Player::new_with_cards_and_crib(controller, cards, crib);
+    /// let mut player = Player::new_with_cards(controller, cards);
     ///
     /// let expected_hand = vec![
     ///     Card::new(Rank::Ace, Suit::Spades),
     ///     Card::new(Rank::Ace, Suit::Clubs),
     /// ];
-    /// let expected_crib = vec![
-    ///     Card::new(Rank::Two, Suit::Spades),
-    ///     Card::new(Rank::Two, Suit::Clubs),
-    /// ];
     /// let expected_discarded = vec![
     ///     Card::new(Rank::Ace, Suit::Hearts),
     /// ];
@@ -531,20 +923,35 @@ where
     /// let _ = player.discard();
     ///
     /// assert_eq!(player.hand.as_vec(), &expected_hand);
-    /// assert_eq!(player.crib.as_vec(), &expected_crib);
     /// assert_eq!(player.discarded, expected_discarded);
     ///
     /// player.reset();
     ///
     /// assert_eq!(player.hand.len(), 0);
-    /// assert_eq!(player.crib.len(), 0);
     /// assert!(player.discarded.is_empty());
     /// ```
     pub fn reset(&mut self) {
         self.hand = Hand::new();
-        self.crib = Hand::new();
         self.discarded = Vec::new();
     }
+
+    /// Returns the [`Player::controller`], so a step-driven game can answer it from outside.
+    pub(crate) fn controller_mut(&mut self) -> &mut C {
+        &mut self.controller
+    }
+
+    /// Removes, and returns, the [`Card`] at the index of [`Player::hand`].
+    ///
+    /// # Errors
+    ///
+    /// [`GameError::InvalidIndex`] if the index is out of bounds of the [`Player::hand`].
+    fn remove_index(&mut self, index: usize) -> Result<Card, GameError> {
+        let len = self.hand.len();
+
+        self.hand
+            .discard(index)
+            .map_err(|_| GameError::InvalidIndex { index, len })
+    }
 }
 
 impl<C> fmt::Display for Player<C>
@@ -560,8 +967,8 @@ where
 
         write!(
             f,
-            "Player: {{ Hand: {0}, Crib: {1}, Points: {2}, Discarded: [ {3} ] }}",
-            self.hand, self.crib, self.points, discarded_str_joined
+            "Player: {{ Hand: {0}, Points: {1}, Discarded: [ {2} ] }}",
+            self.hand, self.points, discarded_str_joined
         )
     }
 }
@@ -572,14 +979,14 @@ mod tests {
 
     use super::*;
     use cards::{Card, Hand, Rank, Suit};
-    use game::PredeterminedController;
+    use game::{PlayData, PredeterminedController};
 
     #[test]
     fn test_new() {
         let expected = Player {
             controller: PredeterminedController::from(vec![0, 1, 2]),
+            name: String::new(),
             discarded: Vec::new(),
-            crib: Hand::new(),
             hand: Hand::new(),
             points: 0,
         };
@@ -592,29 +999,70 @@ mod tests {
     }
 
     #[test]
-    fn test_new_with_cards() {
+    fn test_play_or_pass_over_31_asks_again() {
         let cards = vec![
-            Card::new(Rank::Ace, Suit::Hearts),
-            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Clubs),
         ];
 
-        let expected = Player {
-            controller: PredeterminedController::from(vec![0, 1, 2]),
-            discarded: Vec::new(),
-            crib: Hand::new(),
-            hand: Hand::from(cards.clone()),
+        let controller = PredeterminedController::from(vec![0, 1]);
+
+        let mut player = Player::new_with_cards(controller, cards);
+
+        let play_data = PlayData::from(vec![
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Clubs),
+        ]);
+        let context = PlayContext {
+            play_data: &play_data,
             points: 0,
+            opponent_points: 0,
+            target: 121,
+            is_dealer: false,
         };
 
-        let controller = PredeterminedController::from(vec![0, 1, 2]);
+        let result = player.play_or_pass(/*allow_pass=*/ false, &context);
 
-        let result = Player::new_with_cards(controller, cards);
+        assert_eq!(result, Ok(Some(Card::new(Rank::Three, Suit::Clubs))));
+        assert_eq!(
+            player.hand_cards(),
+            vec![Card::new(Rank::King, Suit::Hearts)]
+        );
+    }
 
-        assert_eq!(result, expected);
+    #[test]
+    fn test_play_or_pass_over_31_none_playable() {
+        let cards = vec![Card::new(Rank::King, Suit::Hearts)];
+
+        let controller = PredeterminedController::from(vec![0]);
+
+        let mut player = Player::new_with_cards(controller, cards);
+
+        let play_data = PlayData::from(vec![
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Clubs),
+        ]);
+        let context = PlayContext {
+            play_data: &play_data,
+            points: 0,
+            opponent_points: 0,
+            target: 121,
+            is_dealer: false,
+        };
+
+        let result = player.play_or_pass(/*allow_pass=*/ false, &context);
+
+        assert_eq!(
+            result,
+            Err(GameError::InvalidPlay(Card::new(Rank::King, Suit::Hearts)))
+        );
+        assert!(player.has_cards_in_hand());
     }
 
     #[test]
-    fn test_new_with_cards_and_crib() {
+    fn test_new_with_cards() {
         let cards = vec![
             Card::new(Rank::Ace, Suit::Hearts),
             Card::new(Rank::Ace, Suit::Clubs),
@@ -622,15 +1070,15 @@ mod tests {
 
         let expected = Player {
             controller: PredeterminedController::from(vec![0, 1, 2]),
+            name: String::new(),
             discarded: Vec::new(),
-            crib: Hand::from(cards.clone()),
             hand: Hand::from(cards.clone()),
             points: 0,
         };
 
         let controller = PredeterminedController::from(vec![0, 1, 2]);
 
-        let result = Player::new_with_cards_and_crib(controller, cards.clone(), cards);
+        let result = Player::new_with_cards(controller, cards);
 
         assert_eq!(result, expected);
     }
@@ -641,8 +1089,8 @@ mod tests {
 
         let expected = Player {
             controller: PredeterminedController::from(vec![0, 1, 2]),
+            name: String::new(),
             discarded: Vec::new(),
-            crib: Hand::new(),
             hand: Hand::from(vec![card.clone()]),
             points: 0,
         };
@@ -656,27 +1104,6 @@ mod tests {
         assert_eq!(player, expected);
     }
 
-    #[test]
-    fn test_add_card_to_crib() {
-        let card = Card::new(Rank::Ace, Suit::Spades);
-
-        let expected = Player {
-            controller: PredeterminedController::from(vec![0, 1, 2]),
-            discarded: Vec::new(),
-            crib: Hand::from(vec![card.clone()]),
-            hand: Hand::new(),
-            points: 0,
-        };
-
-        let controller = PredeterminedController::from(vec![0, 1, 2]);
-
-        let mut player = Player::new(controller);
-
-        player.add_card_to_crib(card);
-
-        assert_eq!(player, expected);
-    }
-
     #[test]
     fn test_has_cards_in_hand_false() {
         let controller = PredeterminedController::from(vec![0, 1, 2]);
@@ -700,8 +1127,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn test_discard_controller_index_oob_panics() {
+    fn test_discard_controller_index_oob_invalid_index() {
         let card = Card::new(Rank::Ace, Suit::Spades);
 
         let controller = PredeterminedController::from(vec![1, 0, 2]);
@@ -710,7 +1136,10 @@ mod tests {
 
         player.add_card(card);
 
-        let _ = player.discard();
+        let result = player.discard();
+
+        assert_eq!(result, Err(GameError::InvalidIndex { index: 1, len: 1 }));
+        assert!(player.has_cards_in_hand());
     }
 
     #[test]
@@ -731,23 +1160,24 @@ mod tests {
 
         let expected_player = Player {
             controller: PredeterminedController::from(Vec::new()),
+            name: String::new(),
             discarded: expected_discarded.clone(),
-            crib: Hand::new(),
             hand: Hand::new(),
             points: 0,
         };
 
         let mut player = Player::new_with_cards(controller, cards);
 
-        let result: Vec<Card> = (0..=2).map(|_| player.discard().unwrap()).collect();
+        let result: Vec<Card> = (0..=2)
+            .map(|_| player.discard().unwrap().unwrap())
+            .collect();
 
         assert_eq!(result, expected_discarded);
         assert_eq!(player, expected_player);
     }
 
     #[test]
-    #[should_panic]
-    fn test_remove_card_controller_index_oob_panics() {
+    fn test_remove_card_controller_index_oob_invalid_index() {
         let cards = vec![
             Card::new(Rank::Ace, Suit::Hearts),
             Card::new(Rank::Ace, Suit::Spades),
@@ -758,7 +1188,36 @@ mod tests {
 
         let mut player = Player::new_with_cards(controller, cards);
 
-        let _result: Vec<_> = (0..=2).map(|_| player.remove_card()).collect();
+        let result: Vec<_> = (0..=2).map(|_| player.remove_card()).collect();
+
+        let expected = vec![
+            Ok(Some(Card::new(Rank::Ace, Suit::Hearts))),
+            Err(GameError::InvalidIndex { index: 3, len: 2 }),
+            Ok(Some(Card::new(Rank::Ace, Suit::Clubs))),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_remove_cards_for_crib_then_invalid_index() {
+        let cards = vec![
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Ace, Suit::Clubs),
+        ];
+
+        // The default `get_crib_discards` maps the second index 0 back to 1.
+        let controller = PredeterminedController::from(vec![1, 0, 2, 2]);
+
+        let mut player = Player::new_with_cards(controller, cards.clone());
+
+        let expected_first = Ok(Some([cards[1].clone(), cards[0].clone()]));
+        let expected_second = Err(GameError::InvalidIndex { index: 2, len: 1 });
+
+        assert_eq!(player.remove_cards_for_crib(false), expected_first);
+        assert_eq!(player.remove_cards_for_crib(false), expected_second);
+        assert_eq!(player.hand.as_vec(), &vec![cards[2].clone()]);
     }
 
     #[test]
@@ -779,15 +1238,17 @@ mod tests {
 
         let expected_player = Player {
             controller: PredeterminedController::from(Vec::new()),
+            name: String::new(),
             discarded: Vec::new(),
-            crib: Hand::new(),
             hand: Hand::new(),
             points: 0,
         };
 
         let mut player = Player::new_with_cards(controller, cards);
 
-        let result: Vec<Card> = (0..=2).map(|_| player.remove_card().unwrap()).collect();
+        let result: Vec<Card> = (0..=2)
+            .map(|_| player.remove_card().unwrap().unwrap())
+            .collect();
 
         assert_eq!(result, expected_removed);
         assert_eq!(player, expected_player);
@@ -805,15 +1266,17 @@ mod tests {
 
         let expected = Player {
             controller: PredeterminedController::from(Vec::new()),
+            name: String::new(),
             discarded: Vec::new(),
-            crib: Hand::new(),
             hand: Hand::from(cards.clone()),
             points: 0,
         };
 
         let mut player = Player::new_with_cards(controller, cards);
 
-        let _discards: Vec<Card> = (0..=2).map(|_| player.discard().unwrap()).collect();
+        let _discards: Vec<Card> = (0..=2)
+            .map(|_| player.discard().unwrap().unwrap())
+            .collect();
 
         assert!(player.has_cards());
         assert!(!player.has_cards_in_hand());
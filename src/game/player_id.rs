@@ -0,0 +1,49 @@
+//! Which of the two [`Player`]s of a [`Game`] is meant.
+
+#[cfg(doc)]
+use game::{Game, Player};
+
+/// Player 1 or player 2 of a [`Game`], like for [`Game::to_act`].
+///
+/// Inside the [`Game`], this is usually a `player_1` [`bool`], which converts with
+/// [`PlayerId::from_player_1`] and [`PlayerId::is_player_1`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::PlayerId;
+///
+/// assert_eq!(PlayerId::from_player_1(false), PlayerId::Two);
+/// assert_eq!(PlayerId::Two.other(), PlayerId::One);
+/// assert!(PlayerId::One.is_player_1());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayerId {
+    One,
+    Two,
+}
+
+impl PlayerId {
+    /// Returns [`PlayerId::One`] if `player_1`, or [`PlayerId::Two`] otherwise.
+    #[must_use]
+    pub fn from_player_1(player_1: bool) -> PlayerId {
+        if player_1 {
+            PlayerId::One
+        } else {
+            PlayerId::Two
+        }
+    }
+
+    /// Indicates that this is player 1.
+    #[must_use]
+    pub fn is_player_1(self) -> bool {
+        self == PlayerId::One
+    }
+
+    /// Returns the opponent.
+    #[must_use]
+    pub fn other(self) -> PlayerId {
+        PlayerId::from_player_1(!self.is_player_1())
+    }
+}
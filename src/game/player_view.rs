@@ -0,0 +1,19 @@
+//! A borrow-free snapshot of a [`Player`] for rendering.
+
+#[cfg(doc)]
+use game::{Game, Player};
+
+use cards::Card;
+
+/// An owned snapshot of a [`Player`]'s name, points, and [`Card`]s.
+///
+/// Since everything is cloned, a [`PlayerView`] can be rendered without holding a borrow of the
+/// [`Game`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct PlayerView {
+    pub name: String,
+    pub points: u32,
+    pub hand: Vec<Card>,
+    pub crib: Vec<Card>,
+    pub is_dealer: bool,
+}
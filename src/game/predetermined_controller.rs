@@ -75,7 +75,7 @@ impl<D: Display> Controller for PredeterminedController<D> {
 
         let message = format!(
             "Choose Card to Discard (1 to {number_of_cards}): {:?}",
-            result.map(|index| index + 1)
+            result.map(|index| index.saturating_add(1))
         );
 
         self.display.println_no_spacer_no_delay(&message);
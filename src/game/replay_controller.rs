@@ -0,0 +1,149 @@
+//! A [`Controller`] that replays the choices of a recorded [`Game`].
+
+#[cfg(doc)]
+use game::{Game, Player};
+
+use std::collections::VecDeque;
+
+use cards::Card;
+use game::{Controller, GameEvent};
+
+/// A controller that chooses the same [`Card`]s as a player did in a recorded [`Game`].
+///
+/// Instead of indices, like a [`PredeterminedController`](game::PredeterminedController), the
+/// [`Card`]s themselves are kept, so the choices are replayed no matter the order of the
+/// `available_cards`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReplayController {
+    cards: VecDeque<Card>,
+    cut_depths: VecDeque<usize>,
+}
+
+impl ReplayController {
+    /// Creates a new [`ReplayController`] that chooses the given [`Card`]s in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, ReplayController};
+    ///
+    /// let available_cards = vec![
+    ///     Card::new(Rank::Queen, Suit::Hearts),
+    ///     Card::new(Rank::King, Suit::Clubs),
+    /// ];
+    ///
+    /// let mut controller = ReplayController::new(vec![Card::new(Rank::King, Suit::Clubs)]);
+    ///
+    /// assert_eq!(controller.get_card_index(&available_cards), Some(1));
+    /// assert_eq!(controller.get_card_index(&available_cards), None);
+    /// ```
+    #[must_use]
+    pub fn new(cards: Vec<Card>) -> ReplayController {
+        ReplayController {
+            cards: VecDeque::from(cards),
+            cut_depths: VecDeque::new(),
+        }
+    }
+
+    /// Creates a new [`ReplayController`] with the cuts, discards, and plays of a player from the
+    /// [`GameEvent`]s.
+    ///
+    /// Passes are not replayed, since a player can only choose to pass when
+    /// [`GameOptions::allow_intentional_pass`](game::GameOptions::allow_intentional_pass) is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{GameEvent, ReplayController};
+    ///
+    /// let events = vec![
+    ///     GameEvent::Discard {
+    ///         player_1: true,
+    ///         card: Card::new(Rank::Ace, Suit::Hearts),
+    ///     },
+    ///     GameEvent::Discard {
+    ///         player_1: false,
+    ///         card: Card::new(Rank::Two, Suit::Hearts),
+    ///     },
+    /// ];
+    ///
+    /// let controller = ReplayController::from_events(&events, /*player_1=*/ true);
+    ///
+    /// assert_eq!(controller, ReplayController::new(vec![Card::new(Rank::Ace, Suit::Hearts)]));
+    /// ```
+    #[must_use]
+    pub fn from_events(events: &[GameEvent], player_1: bool) -> ReplayController {
+        let cards = events
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::Cut {
+                    player_1_card,
+                    player_2_card,
+                    ..
+                } => Some(if player_1 {
+                    player_1_card
+                } else {
+                    player_2_card
+                }),
+                GameEvent::Discard {
+                    player_1: by_player_1,
+                    card,
+                }
+                | GameEvent::PlayCard {
+                    player_1: by_player_1,
+                    card,
+                } if *by_player_1 == player_1 => Some(card),
+                _ => None,
+            })
+            .cloned()
+            .collect();
+
+        let cut_depths = events
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::CutForStarter {
+                    player_1: by_player_1,
+                    depth,
+                } if *by_player_1 == player_1 => Some(*depth),
+                _ => None,
+            })
+            .collect();
+
+        ReplayController {
+            cut_depths,
+            ..ReplayController::new(cards)
+        }
+    }
+}
+
+impl Controller for ReplayController {
+    /// Returns the index of the next recorded [`Card`] in the `available_cards`.
+    ///
+    /// If there are no more recorded [`Card`]s, or the next one isn't available, then [`None`].
+    fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+        let card = self.cards.pop_front()?;
+
+        available_cards
+            .iter()
+            .position(|available| *available == card)
+    }
+
+    /// Returns the index that cuts to the next recorded [`Card`] in the `deck_cards`, so it's the
+    /// bottom of the lifted packet, like [`Player::choose_card_for_cut`].
+    ///
+    /// If there are no more recorded [`Card`]s, or the next one isn't in the `deck_cards`, then
+    /// [`None`].
+    fn get_cut_index(&mut self, deck_cards: &[Card]) -> Option<usize> {
+        self.get_card_index(deck_cards)
+            .map(|position| deck_cards.len() - 1 - position)
+    }
+
+    /// Returns the next recorded depth of a cut for the starter.
+    ///
+    /// If there are no more recorded depths, then [`None`].
+    fn get_starter_cut_depth(&mut self, _deck_size: usize) -> Option<usize> {
+        self.cut_depths.pop_front()
+    }
+}
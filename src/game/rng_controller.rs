@@ -1,4 +1,4 @@
-use rand::{rngs::ThreadRng, Rng};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
 
 use cards::Card;
 use game::Controller;
@@ -8,7 +8,7 @@ use game::Controller;
 /// This is a very dumb AI, but it's a good first start.
 #[derive(Debug, Clone)]
 pub struct RngController {
-    rng: ThreadRng,
+    rng: SmallRng,
 }
 
 impl RngController {
@@ -24,7 +24,36 @@ impl RngController {
     #[must_use]
     pub fn new() -> RngController {
         RngController {
-            rng: rand::thread_rng(),
+            rng: SmallRng::from_entropy(),
+        }
+    }
+
+    /// Creates a new [`RngController`] with a seeded RNG, so the moves are reproducible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Controller, RngController};
+    ///
+    /// let available_cards = vec![
+    ///     Card::new(Rank::Queen, Suit::Hearts),
+    ///     Card::new(Rank::King, Suit::Clubs),
+    ///     Card::new(Rank::Ace, Suit::Clubs),
+    /// ];
+    ///
+    /// let mut controller_1 = RngController::new_with_seed(7);
+    /// let mut controller_2 = RngController::new_with_seed(7);
+    ///
+    /// assert_eq!(
+    ///     controller_1.get_card_index(&available_cards),
+    ///     controller_2.get_card_index(&available_cards)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new_with_seed(seed: u64) -> RngController {
+        RngController {
+            rng: SmallRng::seed_from_u64(seed),
         }
     }
 }
@@ -55,7 +84,6 @@ impl Controller for RngController {
     /// assert!(controller.get_card_index(&no_cards).is_none());
     /// assert!(controller.get_card_index(&available_cards).is_some());
     /// ```
-    #[must_use]
     fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
         if available_cards.is_empty() {
             None
@@ -63,6 +91,23 @@ impl Controller for RngController {
             Some(self.rng.gen_range(0..available_cards.len()))
         }
     }
+
+    /// Returns a random depth to cut the [`Deck`](cards::Deck) for the starter, less than the
+    /// `deck_size`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Controller, RngController};
+    ///
+    /// let mut controller = RngController::new();
+    ///
+    /// assert!(controller.get_starter_cut_depth(0).is_none());
+    /// assert!(controller.get_starter_cut_depth(40).is_some_and(|depth| depth < 40));
+    /// ```
+    fn get_starter_cut_depth(&mut self, deck_size: usize) -> Option<usize> {
+        (0 < deck_size).then(|| self.rng.gen_range(0..deck_size))
+    }
 }
 
 impl Default for RngController {
@@ -0,0 +1,96 @@
+//! The state of a single round of a [`Game`]: who deals, the crib, the starter, and the play.
+
+#[cfg(doc)]
+use game::{Game, Player};
+
+use cards::{Card, Hand};
+use game::PlayData;
+
+/// A single round (deal) of a [`Game`].
+///
+/// The [`Round`] owns the crib, so it can only ever belong to the dealer, and the starter and
+/// [`PlayData`] are kept together with who dealt them.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::Round;
+///
+/// let mut round = Round::new(/*player_1_is_dealer=*/ false);
+///
+/// round.crib.add_card(Card::new(Rank::Five, Suit::Hearts));
+///
+/// assert!(round.is_dealer(/*player_1=*/ false));
+/// assert!(round.crib_of(/*player_1=*/ true).is_none());
+/// assert_eq!(round.crib_of(/*player_1=*/ false).map(|crib| crib.len()), Some(1));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Round {
+    /// Whether player 1 is the dealer, and so owns the crib.
+    pub player_1_is_dealer: bool,
+    /// The crib, which always belongs to the dealer.
+    pub crib: Hand,
+    /// The starter [`Card`], or [`None`] until it's cut.
+    pub starter: Option<Card>,
+    /// The stack of [`Card`]s during play.
+    pub play_data: PlayData,
+}
+
+impl Round {
+    /// Creates a new [`Round`] with an empty crib, no starter, and no [`Card`]s played.
+    #[must_use]
+    pub fn new(player_1_is_dealer: bool) -> Round {
+        Round {
+            player_1_is_dealer,
+            crib: Hand::new(),
+            starter: None,
+            play_data: PlayData::new(),
+        }
+    }
+
+    /// Indicates that player 1 is the dealer if `player_1`, or that player 2 is otherwise.
+    #[must_use]
+    pub fn is_dealer(&self, player_1: bool) -> bool {
+        self.player_1_is_dealer == player_1
+    }
+
+    /// Returns the crib if player 1 (if `player_1`, or player 2 otherwise) is the dealer, and it
+    /// has any [`Card`]s.
+    #[must_use]
+    pub fn crib_of(&self, player_1: bool) -> Option<&Hand> {
+        (self.is_dealer(player_1) && !self.crib.is_empty()).then_some(&self.crib)
+    }
+}
+
+impl Default for Round {
+    /// A [`Round`] where player 1 deals.
+    fn default() -> Round {
+        Round::new(/*player_1_is_dealer=*/ true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Rank, Suit};
+
+    #[test]
+    fn test_crib_of_empty_crib_is_none() {
+        let round = Round::default();
+
+        assert!(round.is_dealer(/*player_1=*/ true));
+        assert!(round.crib_of(/*player_1=*/ true).is_none());
+    }
+
+    #[test]
+    fn test_crib_of_only_the_dealer() {
+        let mut round = Round::new(/*player_1_is_dealer=*/ true);
+
+        round.crib.add_card(Card::new(Rank::Five, Suit::Hearts));
+
+        assert_eq!(round.crib_of(/*player_1=*/ true), Some(&round.crib));
+        assert!(round.crib_of(/*player_1=*/ false).is_none());
+    }
+}
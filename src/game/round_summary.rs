@@ -0,0 +1,210 @@
+//! A summary of the points scored in a round, read back from the history of a [`Game`].
+
+#[cfg(doc)]
+use game::{Display, Game, Player};
+
+use cards::Card;
+use game::{GameEvent, ScoreReason};
+
+/// A hand, or crib, counted in a round, from a [`GameEvent::HandCount`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CountedHand {
+    /// If the [`Card`]s belong to player 1, or player 2 otherwise.
+    pub player_1: bool,
+    pub cards: Vec<Card>,
+    pub is_crib: bool,
+    pub points: u32,
+}
+
+/// The points each [`Player`] scored in the last round of a [`Game`], and the totals after it.
+///
+/// Everything is read from the [`GameEvent`]s, so nothing is scored twice. This is what
+/// [`Display::round_summary_message`] shows after the counting.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{GameEvent, RoundSummary, ScoreReason};
+///
+/// let history = vec![
+///     GameEvent::Deal {
+///         player_1_is_dealer: true,
+///         player_1_hand: Vec::new(),
+///         player_2_hand: Vec::new(),
+///     },
+///     GameEvent::StarterCut {
+///         card: Card::new(Rank::Five, Suit::Hearts),
+///     },
+///     GameEvent::ScorePoints {
+///         player_1: false,
+///         points: 2,
+///         reason: ScoreReason::Pegging,
+///     },
+///     GameEvent::ScorePoints {
+///         player_1: true,
+///         points: 6,
+///         reason: ScoreReason::Hand,
+///     },
+/// ];
+///
+/// // Resumed from a save at 40 to 50.
+/// let summary = RoundSummary::from_history(&history, 46, 52).unwrap();
+///
+/// assert_eq!(summary.player_1_pegging, 0);
+/// assert_eq!(summary.player_2_pegging, 2);
+/// assert_eq!(summary.player_1_total, 46);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RoundSummary {
+    pub player_1_is_dealer: bool,
+    pub starter: Option<Card>,
+    /// The points player 1 pegged during play, including his heels.
+    pub player_1_pegging: u32,
+    /// The points player 2 pegged during play, including his heels.
+    pub player_2_pegging: u32,
+    /// The hands, and crib, in the order they were counted.
+    pub counts: Vec<CountedHand>,
+    /// Player 1's points after the round.
+    pub player_1_total: u32,
+    /// Player 2's points after the round.
+    pub player_2_total: u32,
+}
+
+impl RoundSummary {
+    /// Summarizes the last round in the history, which starts at the last [`GameEvent::Deal`].
+    ///
+    /// The totals are the [`Player::points`] after the round, since a resumed [`Game`] has no
+    /// history of the points scored before it was saved. Returns [`None`] if nothing has been
+    /// dealt yet.
+    #[must_use]
+    pub fn from_history(
+        history: &[GameEvent],
+        player_1_points: u32,
+        player_2_points: u32,
+    ) -> Option<RoundSummary> {
+        let round_start = history
+            .iter()
+            .rposition(|event| matches!(event, GameEvent::Deal { .. }))?;
+
+        let mut summary = RoundSummary {
+            player_1_total: player_1_points,
+            player_2_total: player_2_points,
+            ..RoundSummary::default()
+        };
+
+        for event in &history[round_start..] {
+            match event {
+                GameEvent::Deal {
+                    player_1_is_dealer, ..
+                } => summary.player_1_is_dealer = *player_1_is_dealer,
+                GameEvent::StarterCut { card } => summary.starter = Some(card.clone()),
+                GameEvent::ScorePoints {
+                    player_1: true,
+                    points,
+                    reason: ScoreReason::Pegging | ScoreReason::HisHeels,
+                } => summary.player_1_pegging += points,
+                GameEvent::ScorePoints {
+                    player_1: false,
+                    points,
+                    reason: ScoreReason::Pegging | ScoreReason::HisHeels,
+                } => summary.player_2_pegging += points,
+                GameEvent::HandCount {
+                    player_1,
+                    cards,
+                    is_crib,
+                    points,
+                } => summary.counts.push(CountedHand {
+                    player_1: *player_1,
+                    cards: cards.clone(),
+                    is_crib: *is_crib,
+                    points: *points,
+                }),
+                _ => {}
+            }
+        }
+
+        Some(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Rank, Suit};
+
+    fn deal(player_1_is_dealer: bool) -> GameEvent {
+        GameEvent::Deal {
+            player_1_is_dealer,
+            player_1_hand: Vec::new(),
+            player_2_hand: Vec::new(),
+        }
+    }
+
+    fn score(player_1: bool, points: u32, reason: ScoreReason) -> GameEvent {
+        GameEvent::ScorePoints {
+            player_1,
+            points,
+            reason,
+        }
+    }
+
+    #[test]
+    fn test_from_history_nothing_dealt() {
+        assert_eq!(RoundSummary::from_history(&[], 0, 0), None);
+    }
+
+    #[test]
+    fn test_from_history_only_last_round() {
+        let hand = vec![
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Hearts),
+        ];
+
+        let history = vec![
+            deal(/*player_1_is_dealer=*/ true),
+            GameEvent::StarterCut {
+                card: Card::new(Rank::Two, Suit::Spades),
+            },
+            score(true, 4, ScoreReason::Pegging),
+            GameEvent::HandCount {
+                player_1: false,
+                cards: hand.clone(),
+                is_crib: false,
+                points: 3,
+            },
+            score(false, 3, ScoreReason::Hand),
+            deal(/*player_1_is_dealer=*/ false),
+            GameEvent::StarterCut {
+                card: Card::new(Rank::Jack, Suit::Clubs),
+            },
+            score(false, 2, ScoreReason::HisHeels),
+            score(true, 1, ScoreReason::Pegging),
+            GameEvent::HandCount {
+                player_1: true,
+                cards: hand.clone(),
+                is_crib: false,
+                points: 2,
+            },
+            score(true, 2, ScoreReason::Hand),
+        ];
+
+        let expected = RoundSummary {
+            player_1_is_dealer: false,
+            starter: Some(Card::new(Rank::Jack, Suit::Clubs)),
+            player_1_pegging: 1,
+            player_2_pegging: 2,
+            counts: vec![CountedHand {
+                player_1: true,
+                cards: hand,
+                is_crib: false,
+                points: 2,
+            }],
+            player_1_total: 7,
+            player_2_total: 5,
+        };
+
+        assert_eq!(RoundSummary::from_history(&history, 7, 5), Some(expected));
+    }
+}
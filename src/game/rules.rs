@@ -0,0 +1,94 @@
+//! The rules that differ between the six-card and five-card games of cribbage.
+
+#[cfg(doc)]
+use cards::{Card, Hand, Rank, Suit};
+#[cfg(doc)]
+use game::{Game, GameOptions, Player};
+
+/// The number of [`Card`]s each [`Player`] discards to the crib, in either game.
+pub const CRIB_DISCARDS: usize = 2;
+
+/// The rules of the variant of cribbage a [`Game`] plays.
+///
+/// The [`Default`] is the standard six-card game. In both games each [`Player`] discards
+/// [`CRIB_DISCARDS`] [`Card`]s, so the crib always has 4 [`Card`]s.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{GameOptions, GameRules};
+///
+/// let rules = GameRules::five_card();
+///
+/// assert_eq!(rules.cards_kept(), 3);
+///
+/// let options = GameOptions::default().with_rules(rules);
+///
+/// assert_eq!(options.target_score, 61);
+///
+/// // The six-card game, with the suit breaking tied cuts for the deal.
+/// let house_rules = GameRules {
+///     suit_breaks_cut_ties: true,
+///     ..GameRules::six_card()
+/// };
+///
+/// assert!(!GameRules::six_card().suit_breaks_cut_ties);
+/// assert!(house_rules.suit_breaks_cut_ties);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct GameRules {
+    /// The [`Card`]s dealt to each [`Player`].
+    pub cards_dealt: usize,
+    /// The points needed to win, which [`GameOptions::with_rules`] uses for
+    /// [`GameOptions::target_score`].
+    pub target_score: u32,
+    /// The points the pone pegs before the first deal, to make up for the dealer's crib.
+    ///
+    /// This is "three for last" in the five-card game.
+    pub pone_head_start: u32,
+    /// A house rule where the [`Suit`] breaks a tie when both [`Player`]s cut the same [`Rank`]
+    /// for the deal, instead of cutting again.
+    ///
+    /// The [`Suit`] order, from lowest to highest, is [`Suit::Hearts`], [`Suit::Spades`],
+    /// [`Suit::Diamonds`], [`Suit::Clubs`]. The official rules re-cut, so this is off in both
+    /// games.
+    pub suit_breaks_cut_ties: bool,
+}
+
+impl GameRules {
+    /// The standard six-card game: 6 [`Card`]s dealt, a 4 [`Card`] [`Hand`], and 121 points to win.
+    #[must_use]
+    pub fn six_card() -> GameRules {
+        GameRules {
+            cards_dealt: 6,
+            target_score: 121,
+            pone_head_start: 0,
+            suit_breaks_cut_ties: false,
+        }
+    }
+
+    /// The historical five-card game: 5 [`Card`]s dealt, a 3 [`Card`] [`Hand`], 61 points to win,
+    /// and three for last.
+    #[must_use]
+    pub fn five_card() -> GameRules {
+        GameRules {
+            cards_dealt: 5,
+            target_score: 61,
+            pone_head_start: 3,
+            suit_breaks_cut_ties: false,
+        }
+    }
+
+    /// Returns the number of [`Card`]s in each [`Hand`] after discarding to the crib.
+    #[must_use]
+    pub fn cards_kept(&self) -> usize {
+        self.cards_dealt.saturating_sub(CRIB_DISCARDS)
+    }
+}
+
+impl Default for GameRules {
+    /// The standard six-card game.
+    fn default() -> GameRules {
+        GameRules::six_card()
+    }
+}
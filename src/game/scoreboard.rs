@@ -0,0 +1,164 @@
+//! Adds points to both [`Player`]s in one place, so the winner is known the moment they score.
+
+#[cfg(doc)]
+use game::{Game, GameOptions, Player};
+
+use game::{GameEvent, ScoreReason};
+
+/// The [`Player`] who reached the target score.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Winner {
+    PlayerOne,
+    PlayerTwo,
+}
+
+/// Adds points to both [`Player`]s, recording a [`GameEvent::ScorePoints`] for each score.
+///
+/// Every point in a [`Game`] goes through [`Scoreboard::add_points`], so a win is detected as soon
+/// as it's pegged, instead of after the rest of the play or count.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{GameEvent, ScoreReason, Scoreboard, Winner};
+///
+/// let mut player_1_points = 115;
+/// let mut player_2_points = 119;
+/// let mut history = Vec::new();
+///
+/// let mut scoreboard = Scoreboard::new(
+///     &mut player_1_points,
+///     &mut player_2_points,
+///     &mut history,
+///     /*target=*/ 121,
+/// );
+///
+/// assert_eq!(scoreboard.add_points(true, 4, ScoreReason::Pegging), None);
+/// assert_eq!(
+///     scoreboard.add_points(false, 2, ScoreReason::Pegging),
+///     Some(Winner::PlayerTwo)
+/// );
+///
+/// assert_eq!(player_2_points, 121);
+/// assert_eq!(history.len(), 2);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Scoreboard<'a> {
+    player_1_points: &'a mut u32,
+    player_2_points: &'a mut u32,
+    history: &'a mut Vec<GameEvent>,
+    target: u32,
+}
+
+impl<'a> Scoreboard<'a> {
+    /// Creates a new [`Scoreboard`] for both [`Player`]s' points, recording to the history.
+    ///
+    /// The target is the points needed to win, like [`GameOptions::target_score`].
+    #[must_use]
+    pub fn new(
+        player_1_points: &'a mut u32,
+        player_2_points: &'a mut u32,
+        history: &'a mut Vec<GameEvent>,
+        target: u32,
+    ) -> Scoreboard<'a> {
+        Scoreboard {
+            player_1_points,
+            player_2_points,
+            history,
+            target,
+        }
+    }
+
+    /// Adds the points to player 1 if `player_1`, or player 2 otherwise, and returns the
+    /// [`Winner`] if either [`Player`] has reached the target.
+    ///
+    /// No [`GameEvent::ScorePoints`] is recorded for `0` points.
+    pub fn add_points(
+        &mut self,
+        player_1: bool,
+        points: u32,
+        reason: ScoreReason,
+    ) -> Option<Winner> {
+        if 0 < points {
+            if player_1 {
+                *self.player_1_points += points;
+            } else {
+                *self.player_2_points += points;
+            }
+
+            self.history.push(GameEvent::ScorePoints {
+                player_1,
+                points,
+                reason,
+            });
+        }
+
+        self.winner()
+    }
+
+    /// Returns the [`Winner`] if either [`Player`] has reached the target.
+    #[must_use]
+    pub fn winner(&self) -> Option<Winner> {
+        if self.target <= *self.player_1_points {
+            Some(Winner::PlayerOne)
+        } else if self.target <= *self.player_2_points {
+            Some(Winner::PlayerTwo)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_points_zero_records_nothing() {
+        let mut player_1_points = 0;
+        let mut player_2_points = 0;
+        let mut history = Vec::new();
+
+        let mut scoreboard = Scoreboard::new(
+            &mut player_1_points,
+            &mut player_2_points,
+            &mut history,
+            121,
+        );
+
+        assert_eq!(scoreboard.add_points(true, 0, ScoreReason::Hand), None);
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn test_add_points_wins_at_exactly_the_target() {
+        let mut player_1_points = 119;
+        let mut player_2_points = 0;
+        let mut history = Vec::new();
+
+        let mut scoreboard = Scoreboard::new(
+            &mut player_1_points,
+            &mut player_2_points,
+            &mut history,
+            121,
+        );
+
+        assert_eq!(scoreboard.add_points(true, 1, ScoreReason::Pegging), None);
+        assert_eq!(
+            scoreboard.add_points(true, 1, ScoreReason::Pegging),
+            Some(Winner::PlayerOne)
+        );
+        assert_eq!(player_1_points, 121);
+        assert_eq!(
+            history,
+            vec![
+                GameEvent::ScorePoints {
+                    player_1: true,
+                    points: 1,
+                    reason: ScoreReason::Pegging,
+                };
+                2
+            ]
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! A saved [`Game`], so that it can be resumed later.
+
+#[cfg(doc)]
+use game::Game;
+
+use std::fmt;
+use std::str::FromStr;
+
+use cards::Card;
+use game::notation::{cards_to_string, parse_cards};
+use game::{GameError, Phase};
+
+/// The points, dealer, and [`Card`]s in play of a [`Game`], which is enough to resume it.
+///
+/// A [`Game`] is only saved between steps, so the [`Phase`] is the next step to take, and the
+/// hands, crib, and starter are as that step will find them.
+///
+/// A [`GameSnapshot`] can be written with [`fmt::Display`], and read back with [`FromStr`].
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{GameSnapshot, Phase};
+///
+/// let snapshot = GameSnapshot {
+///     phase: Phase::Count,
+///     player_1_points: 42,
+///     player_2_points: 67,
+///     player_1_is_dealer: false,
+///     player_1_hand: vec![Card::new(Rank::Five, Suit::Hearts)],
+///     player_2_hand: vec![Card::new(Rank::Jack, Suit::Clubs)],
+///     crib: Vec::new(),
+///     starter: Some(Card::new(Rank::Ten, Suit::Spades)),
+/// };
+///
+/// let saved = snapshot.to_string();
+///
+/// assert_eq!(saved.parse(), Ok(snapshot));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GameSnapshot {
+    pub phase: Phase,
+    pub player_1_points: u32,
+    pub player_2_points: u32,
+    pub player_1_is_dealer: bool,
+    pub player_1_hand: Vec<Card>,
+    pub player_2_hand: Vec<Card>,
+    pub crib: Vec<Card>,
+    pub starter: Option<Card>,
+}
+
+impl fmt::Display for GameSnapshot {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let starter = self.starter.as_slice();
+
+        writeln!(formatter, "phase={}", phase_to_str(self.phase))?;
+        writeln!(formatter, "player_1_points={}", self.player_1_points)?;
+        writeln!(formatter, "player_2_points={}", self.player_2_points)?;
+        writeln!(formatter, "player_1_is_dealer={}", self.player_1_is_dealer)?;
+        writeln!(
+            formatter,
+            "player_1_hand={}",
+            cards_to_string(&self.player_1_hand)
+        )?;
+        writeln!(
+            formatter,
+            "player_2_hand={}",
+            cards_to_string(&self.player_2_hand)
+        )?;
+        writeln!(formatter, "crib={}", cards_to_string(&self.crib))?;
+        writeln!(formatter, "starter={}", cards_to_string(starter))
+    }
+}
+
+impl FromStr for GameSnapshot {
+    type Err = GameError;
+
+    fn from_str(saved: &str) -> Result<Self, Self::Err> {
+        let mut phase = None;
+        let mut player_1_points = None;
+        let mut player_2_points = None;
+        let mut player_1_is_dealer = None;
+        let mut player_1_hand = None;
+        let mut player_2_hand = None;
+        let mut crib = None;
+        let mut starter = None;
+
+        for line in saved.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let invalid = || GameError::InvalidSnapshot(line.to_string());
+
+            let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+
+            match key {
+                "phase" => phase = Some(parse_phase(value).ok_or_else(invalid)?),
+                "player_1_points" => player_1_points = Some(value.parse().map_err(|_| invalid())?),
+                "player_2_points" => player_2_points = Some(value.parse().map_err(|_| invalid())?),
+                "player_1_is_dealer" => {
+                    player_1_is_dealer = Some(value.parse().map_err(|_| invalid())?);
+                }
+                "player_1_hand" => player_1_hand = Some(parse_cards(value).ok_or_else(invalid)?),
+                "player_2_hand" => player_2_hand = Some(parse_cards(value).ok_or_else(invalid)?),
+                "crib" => crib = Some(parse_cards(value).ok_or_else(invalid)?),
+                "starter" => {
+                    let mut cards = parse_cards(value).ok_or_else(invalid)?;
+
+                    if 1 < cards.len() {
+                        return Err(invalid());
+                    }
+
+                    starter = Some(cards.pop());
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        let missing = |key: &str| GameError::InvalidSnapshot(format!("missing {key}"));
+
+        Ok(GameSnapshot {
+            phase: phase.ok_or_else(|| missing("phase"))?,
+            player_1_points: player_1_points.ok_or_else(|| missing("player_1_points"))?,
+            player_2_points: player_2_points.ok_or_else(|| missing("player_2_points"))?,
+            player_1_is_dealer: player_1_is_dealer.ok_or_else(|| missing("player_1_is_dealer"))?,
+            player_1_hand: player_1_hand.ok_or_else(|| missing("player_1_hand"))?,
+            player_2_hand: player_2_hand.ok_or_else(|| missing("player_2_hand"))?,
+            crib: crib.ok_or_else(|| missing("crib"))?,
+            starter: starter.ok_or_else(|| missing("starter"))?,
+        })
+    }
+}
+
+/// The keyword of the [`Phase`].
+fn phase_to_str(phase: Phase) -> &'static str {
+    match phase {
+        Phase::Cut => "cut",
+        Phase::Deal => "deal",
+        Phase::Discard => "discard",
+        Phase::Play => "play",
+        Phase::Count => "count",
+        Phase::Over => "over",
+    }
+}
+
+/// Reads a [`Phase`] from its keyword.
+fn parse_phase(token: &str) -> Option<Phase> {
+    match token {
+        "cut" => Some(Phase::Cut),
+        "deal" => Some(Phase::Deal),
+        "discard" => Some(Phase::Discard),
+        "play" => Some(Phase::Play),
+        "count" => Some(Phase::Count),
+        "over" => Some(Phase::Over),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_invalid() {
+        let result = "player_1_points=lots".parse::<GameSnapshot>();
+
+        let expected = Err(GameError::InvalidSnapshot(String::from(
+            "player_1_points=lots",
+        )));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_str_unknown_key() {
+        let result = "stack=5C".parse::<GameSnapshot>();
+
+        let expected = Err(GameError::InvalidSnapshot(String::from("stack=5C")));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_str_missing_key() {
+        let saved = "phase=deal\nplayer_1_points=3\nplayer_2_points=5\nplayer_1_is_dealer=true\n";
+
+        let expected = Err(GameError::InvalidSnapshot(String::from(
+            "missing player_1_hand",
+        )));
+
+        assert_eq!(saved.parse::<GameSnapshot>(), expected);
+    }
+
+    #[test]
+    fn test_from_str_two_starters_invalid() {
+        let result = "starter=5C,6C".parse::<GameSnapshot>();
+
+        let expected = Err(GameError::InvalidSnapshot(String::from("starter=5C,6C")));
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,545 @@
+//! A display for watching a [`Game`] between two AIs, with every [`Card`] face up.
+
+#[cfg(doc)]
+use crate::cards::Hand;
+#[cfg(doc)]
+use crate::game::Game;
+
+use std::cell::Cell;
+use std::io::{self, BufRead};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::{thread, time};
+
+use itertools::Itertools;
+
+use crate::cards::Card;
+use crate::game::{
+    Controller, Display, Pegs, Phase, PlayData, PlayScoreBreakdown, Player, Round, RoundSummary,
+    UiDisplay, DEFAULT_OPPONENT_NAME, DEFAULT_PLAYER_NAME,
+};
+
+/// A struct for spectating the [`Game`] that uses the [`Display`] trait.
+///
+/// Unlike a [`UiDisplay`], which only shows the player's side of the table, both [`Player`]s'
+/// [`Hand`]s and the crib are always shown, and every message is attributed to a [`Player`] by
+/// name. Each message is followed by the [`SpectatorDisplay::delay`].
+///
+/// With commands, like from [`SpectatorDisplay::with_keyboard`], the [`Game`] can be paused and
+/// stepped. Any line pauses after the current message. While paused, an empty line steps to the
+/// next message, and `c` continues.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libterminal_cribbage::game::{Game, Player, RngController, SpectatorDisplay};
+///
+/// let display = SpectatorDisplay::new(Duration::from_millis(1));
+///
+/// let player_1 = Player::new(RngController::new_with_seed(1)).with_name("Alice");
+/// let player_2 = Player::new(RngController::new_with_seed(2)).with_name("Bob");
+///
+/// let mut game = Game::new_default(player_1, player_2, display);
+///
+/// game.cut_round().unwrap();
+/// game.deal_round().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct SpectatorDisplay {
+    /// How long to wait after each message, so the [`Game`] can be followed.
+    pub delay: time::Duration,
+    ui: UiDisplay,
+    commands: Option<Receiver<String>>,
+    paused: Cell<bool>,
+}
+
+impl SpectatorDisplay {
+    /// Creates a new [`SpectatorDisplay`], waiting the delay after each message, without any
+    /// commands.
+    #[must_use]
+    pub fn new(delay: time::Duration) -> SpectatorDisplay {
+        SpectatorDisplay {
+            delay,
+            ui: UiDisplay::new(),
+            commands: None,
+            paused: Cell::new(false),
+        }
+    }
+
+    /// Reads the commands to pause, step, and continue from the lines sent to the [`Receiver`].
+    #[must_use]
+    pub fn with_commands(mut self, commands: Receiver<String>) -> SpectatorDisplay {
+        self.commands = Some(commands);
+
+        self
+    }
+
+    /// Reads the commands to pause, step, and continue from the lines typed on stdin.
+    ///
+    /// The lines are read on another thread, so the [`Game`] keeps going until one is entered.
+    #[must_use]
+    pub fn with_keyboard(self) -> SpectatorDisplay {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else {
+                    return;
+                };
+
+                if sender.send(line.trim().to_string()).is_err() {
+                    return;
+                }
+            }
+        });
+
+        self.with_commands(receiver)
+    }
+
+    /// Indicates that the [`Game`] is paused, and only moves on one message at a time.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    /// Waits after a message for the [`SpectatorDisplay::delay`], or for a command when paused.
+    ///
+    /// If the commands run out, the [`Game`] continues without them.
+    fn wait(&self) {
+        let Some(commands) = &self.commands else {
+            thread::sleep(self.delay);
+
+            return;
+        };
+
+        if !self.paused.get() {
+            match commands.recv_timeout(self.delay) {
+                Ok(_) => self.paused.set(true),
+                Err(RecvTimeoutError::Timeout) => return,
+                Err(RecvTimeoutError::Disconnected) => {
+                    thread::sleep(self.delay);
+
+                    return;
+                }
+            }
+        }
+
+        match commands.recv() {
+            Ok(command) if command.eq_ignore_ascii_case("c") => self.paused.set(false),
+            Ok(_) => {}
+            Err(_) => self.paused.set(false),
+        }
+    }
+
+    /// The display [`String`] of the [`Card`]s, like a [`Hand`].
+    fn cards_string(cards: &[Card]) -> String {
+        format!("[ {} ]", cards.iter().join(","))
+    }
+
+    /// The lines for both named [`Player`]s' points and [`Hand`]s.
+    fn both_hands<C1, C2>(player: &Player<C1>, opponent: &Player<C2>) -> Vec<String>
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let player_name = player.name_or(DEFAULT_PLAYER_NAME);
+        let opponent_name = opponent.name_or(DEFAULT_OPPONENT_NAME);
+
+        vec![
+            format!(
+                "{player_name} Points: {} | {opponent_name} Points: {}",
+                player.points, opponent.points
+            ),
+            format!("{player_name} Hand: {}", player.hand),
+            format!("{opponent_name} Hand: {}", opponent.hand),
+        ]
+    }
+
+    /// The line for the crib, named for the dealer, if it has any [`Card`]s.
+    fn crib_line<C1, C2>(
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+    ) -> Option<String>
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        if round.crib.is_empty() {
+            return None;
+        }
+
+        let dealer_name = if round.player_1_is_dealer {
+            player.name_or(DEFAULT_PLAYER_NAME)
+        } else {
+            opponent.name_or(DEFAULT_OPPONENT_NAME)
+        };
+
+        Some(format!("{dealer_name} Crib: {}", round.crib))
+    }
+
+    /// The display [`String`] spacer before and after every display.
+    fn spacer() -> String {
+        String::from("******************************************")
+    }
+}
+
+impl Display for SpectatorDisplay {
+    /// Print message to `std::out` without a new line, a spacer, or a delay.
+    fn print_no_spacer_no_delay(&self, message: &str) {
+        print!("{message}");
+    }
+
+    /// Print message to `std::out` without a spacer or a delay.
+    fn println_no_spacer_no_delay(&self, message: &str) {
+        println!("{message}");
+    }
+
+    /// Print message with spacer to `std::out`, and then wait for the delay, or a command if
+    /// paused.
+    fn println(&self, message: &str) {
+        println!("\n{}", Self::spacer());
+        println!("{message}");
+
+        self.wait();
+    }
+
+    /// The same as [`UiDisplay`].
+    fn game_after_cut_message(
+        &self,
+        player_name: &str,
+        player_cut: &Card,
+        opponent_name: &str,
+        opponent_cut: &Card,
+        player_won: bool,
+    ) -> String {
+        self.ui.game_after_cut_message(
+            player_name,
+            player_cut,
+            opponent_name,
+            opponent_cut,
+            player_won,
+        )
+    }
+
+    /// The same as [`UiDisplay`].
+    fn game_cut_reveal_message(&self, player_name: &str, opponent_name: &str) -> String {
+        self.ui.game_cut_reveal_message(player_name, opponent_name)
+    }
+
+    /// The same as [`UiDisplay`].
+    fn game_recut_message(
+        &self,
+        player_name: &str,
+        player_cut: &Card,
+        opponent_name: &str,
+        opponent_cut: &Card,
+    ) -> String {
+        self.ui
+            .game_recut_message(player_name, player_cut, opponent_name, opponent_cut)
+    }
+
+    /// The same as [`UiDisplay`], though a spectator shouldn't be prompted.
+    fn cut_deck_message(&self, number_of_cards: usize, depth: Option<usize>) -> String {
+        self.ui.cut_deck_message(number_of_cards, depth)
+    }
+
+    /// The same as [`UiDisplay`].
+    fn game_starter_cut_message(&self, depth: usize, cutter: &str) -> String {
+        self.ui.game_starter_cut_message(depth, cutter)
+    }
+
+    /// The [`String`] display for both [`Player`]s' points and [`Hand`]s while discarding to the
+    /// crib, and whose crib it is.
+    fn game_discard_message<C1, C2>(
+        &self,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+        is_dealer: bool,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let mut result = Self::both_hands(player, opponent);
+
+        let dealer_name = if is_dealer {
+            player.name_or(DEFAULT_PLAYER_NAME)
+        } else {
+            opponent.name_or(DEFAULT_OPPONENT_NAME)
+        };
+
+        result.push(format!("{dealer_name} is the dealer, and owns the crib."));
+
+        result.join("\n")
+    }
+
+    /// The same as [`UiDisplay`].
+    fn game_crib_discards_message(
+        &self,
+        cards: &[Card],
+        discarder: &str,
+        is_dealer: bool,
+    ) -> String {
+        self.ui
+            .game_crib_discards_message(cards, discarder, is_dealer)
+    }
+
+    /// The same as [`UiDisplay`].
+    fn game_undo_message(&self, chosen: &[Card], undoer: &str) -> String {
+        self.ui.game_undo_message(chosen, undoer)
+    }
+
+    /// The same as [`UiDisplay`].
+    fn game_hint_message(&self, phase: Phase, hint: &[Card]) -> String {
+        self.ui.game_hint_message(phase, hint)
+    }
+
+    /// The [`String`] display for both [`Player`]s, the starter, and the crib before play.
+    fn game_before_play_message<C1, C2>(
+        &self,
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let mut result = Self::both_hands(player, opponent);
+
+        let starter = round
+            .starter
+            .as_ref()
+            .map_or(String::from("[?]"), ToString::to_string);
+
+        result.insert(1, format!("Starter: {starter}"));
+        result.extend(Self::crib_line(round, player, opponent));
+
+        result.join("\n")
+    }
+
+    /// The [`String`] display for both [`Player`]s, what each last played, and the stack during
+    /// play.
+    fn game_during_play_message<C1, C2>(
+        &self,
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let mut result = Self::both_hands(player, opponent);
+
+        for (name, last_played) in [
+            (player.name_or(DEFAULT_PLAYER_NAME), player.last_discarded()),
+            (
+                opponent.name_or(DEFAULT_OPPONENT_NAME),
+                opponent.last_discarded(),
+            ),
+        ] {
+            let last_played = last_played.map_or(String::new(), ToString::to_string);
+
+            result.push(format!("{name} Last Played: {last_played}"));
+        }
+
+        result.push(format!(
+            "Play Stack: {}",
+            Self::cards_string(&round.play_data.stack)
+        ));
+        result.push(format!("Count: {}", round.play_data.stack_score));
+
+        result.join("\n")
+    }
+
+    /// The same as [`UiDisplay`], which already shows both [`Player`]s' [`Hand`]s.
+    fn game_during_counting_message<C1, C2>(
+        &self,
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        self.ui
+            .game_during_counting_message(round, player, opponent)
+    }
+
+    /// The same as [`UiDisplay`].
+    fn board_message(
+        &self,
+        player_name: &str,
+        player: &Pegs,
+        opponent_name: &str,
+        opponent: &Pegs,
+    ) -> String {
+        self.ui
+            .board_message(player_name, player, opponent_name, opponent)
+    }
+
+    /// The same as [`UiDisplay`].
+    fn play_score_message(&self, breakdown: &PlayScoreBreakdown, scorer: &str) -> String {
+        self.ui.play_score_message(breakdown, scorer)
+    }
+
+    /// The same as [`UiDisplay`].
+    fn play_count_message(&self, player: &str, played: Option<&Card>, count: u32) -> String {
+        self.ui.play_count_message(player, played, count)
+    }
+
+    /// The same as [`UiDisplay`].
+    fn play_reset_message(&self, thirty_one: bool) -> String {
+        self.ui.play_reset_message(thirty_one)
+    }
+
+    /// The same as [`UiDisplay`].
+    fn round_summary_message(
+        &self,
+        summary: &RoundSummary,
+        player_name: &str,
+        opponent_name: &str,
+    ) -> String {
+        self.ui
+            .round_summary_message(summary, player_name, opponent_name)
+    }
+
+    /// The same as [`UiDisplay`], though a spectator shouldn't be prompted.
+    fn prompt_cut(&self, number_of_cards: usize) -> String {
+        self.ui.prompt_cut(number_of_cards)
+    }
+
+    /// The same as [`UiDisplay`], though a spectator shouldn't be prompted.
+    fn prompt_confirm_cut(&self, depth: usize) -> String {
+        self.ui.prompt_confirm_cut(depth)
+    }
+
+    /// The same as [`UiDisplay`], though a spectator shouldn't be prompted.
+    fn prompt_starter_cut(&self, number_of_cards: usize) -> String {
+        self.ui.prompt_starter_cut(number_of_cards)
+    }
+
+    /// The same as [`UiDisplay`], though a spectator shouldn't be prompted.
+    fn prompt_discard_to_crib(&self, number_of_cards: usize, is_own_crib: bool) -> String {
+        self.ui.prompt_discard_to_crib(number_of_cards, is_own_crib)
+    }
+
+    /// The same as [`UiDisplay`], though a spectator shouldn't be prompted.
+    fn prompt_play_card(&self, number_of_cards: usize, play_data: &PlayData) -> String {
+        self.ui.prompt_play_card(number_of_cards, play_data)
+    }
+
+    /// The same as [`UiDisplay`], though a spectator shouldn't be prompted.
+    fn prompt_undo(&self, chosen: &[Card]) -> String {
+        self.ui.prompt_undo(chosen)
+    }
+
+    /// The same as [`UiDisplay`].
+    fn game_over_message(&self, winner: &str) -> String {
+        self.ui.game_over_message(winner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::cards::{Rank, Suit};
+    use crate::game::PredeterminedController;
+
+    #[test]
+    fn test_game_discard_message_shows_both_hands() {
+        let display = SpectatorDisplay::new(time::Duration::ZERO);
+
+        let controller = PredeterminedController::from(vec![]);
+
+        let player_1 = Player::new_with_cards(
+            controller.clone(),
+            vec![
+                Card::new(Rank::Eight, Suit::Spades),
+                Card::new(Rank::King, Suit::Clubs),
+            ],
+        )
+        .with_name("Alice");
+        let player_2 = Player::new_with_cards(controller, vec![Card::new(Rank::Two, Suit::Hearts)]);
+
+        let expected = String::new()
+            + "Alice Points: 0 | Opponent Points: 0\n"
+            + "Alice Hand: [ [8♠],[K♣] ]\n"
+            + "Opponent Hand: [ [2♥] ]\n"
+            + "Opponent is the dealer, and owns the crib.";
+
+        let result = display.game_discard_message(&player_1, &player_2, /*is_dealer=*/ false);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_game_during_play_message_shows_both_hands_and_stack() {
+        let display = SpectatorDisplay::new(time::Duration::ZERO);
+
+        let controller = PredeterminedController::from(vec![0]);
+
+        let mut player_1 = Player::new_with_cards(
+            controller.clone(),
+            vec![
+                Card::new(Rank::Eight, Suit::Spades),
+                Card::new(Rank::King, Suit::Clubs),
+            ],
+        )
+        .with_name("Alice");
+        let player_2 = Player::new_with_cards(controller, vec![Card::new(Rank::Two, Suit::Hearts)])
+            .with_name("Bob");
+
+        let played = player_1.discard().unwrap().unwrap();
+
+        let mut round = Round::new(/*player_1_is_dealer=*/ true);
+
+        round.crib.add_card(Card::new(Rank::Ace, Suit::Clubs));
+        round.play_data = PlayData::from(vec![played]);
+
+        let expected = String::new()
+            + "Alice Points: 0 | Bob Points: 0\n"
+            + "Alice Hand: [ [K♣] ]\n"
+            + "Bob Hand: [ [2♥] ]\n"
+            + "Alice Last Played: [8♠]\n"
+            + "Bob Last Played: \n"
+            + "Play Stack: [ [8♠] ]\n"
+            + "Count: 8";
+
+        let result = display.game_during_play_message(&round, &player_1, &player_2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wait_pauses_steps_and_continues() {
+        let (commands, receiver) = mpsc::channel();
+
+        let display =
+            SpectatorDisplay::new(time::Duration::from_millis(10)).with_commands(receiver);
+
+        for command in ["", "", "c"] {
+            commands.send(command.to_string()).unwrap();
+        }
+
+        // Pauses, and waits to step.
+        display.wait();
+
+        assert!(display.is_paused());
+
+        // Continues.
+        display.wait();
+
+        assert!(!display.is_paused());
+
+        // No command, so only the delay.
+        display.wait();
+
+        assert!(!display.is_paused());
+    }
+}
@@ -0,0 +1,192 @@
+//! A [`Controller`] that answers with [`DecisionResponse`]s given from outside the [`Game`].
+
+#[cfg(doc)]
+use game::Game;
+
+use cards::Card;
+use game::{Controller, Decision, DecisionResponse, PlayContext, PlayerId};
+
+/// A controller for a step-driven [`Game`], see [`Game::next_decision`] and [`Game::apply`].
+///
+/// Instead of blocking for a choice, it answers with the [`DecisionResponse`]s given so far in
+/// the current step, in order. Once those run out, it keeps the [`Decision`] it was asked for and
+/// has no move, so the [`Game`] can stop, wait for the response, and take the step again.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::Deck;
+/// use libterminal_cribbage::game::{Controller, Decision, PlayerId, StepController};
+///
+/// let deck = Deck::new();
+///
+/// let mut controller = StepController::new(PlayerId::Two);
+///
+/// assert_eq!(controller.get_cut_index(deck.as_vec()), None);
+///
+/// let expected = Decision::Cut {
+///     player: PlayerId::Two,
+///     deck_size: 52,
+/// };
+///
+/// assert_eq!(controller.pending(), Some(&expected));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct StepController {
+    player: PlayerId,
+    responses: Vec<DecisionResponse>,
+    next_response: usize,
+    pending: Option<Decision>,
+}
+
+impl StepController {
+    /// Creates a new [`StepController`] for the [`PlayerId`], with no responses.
+    #[must_use]
+    pub fn new(player: PlayerId) -> StepController {
+        StepController {
+            player,
+            responses: Vec::new(),
+            next_response: 0,
+            pending: None,
+        }
+    }
+
+    /// Returns the [`Decision`] that couldn't be answered, if any.
+    #[must_use]
+    pub fn pending(&self) -> Option<&Decision> {
+        self.pending.as_ref()
+    }
+
+    /// Answers the pending [`Decision`] with the [`DecisionResponse`].
+    pub(crate) fn respond(&mut self, response: DecisionResponse) {
+        self.pending = None;
+        self.responses.push(response);
+    }
+
+    /// Takes back the last [`DecisionResponse`], so the [`Decision`] is pending again.
+    pub(crate) fn take_back(&mut self, decision: Decision) {
+        self.responses.pop();
+        self.pending = Some(decision);
+    }
+
+    /// Waits on the [`Decision`] again, after the step it was asked in is undone.
+    pub(crate) fn wait_on(&mut self, decision: Decision) {
+        self.pending = Some(decision);
+    }
+
+    /// Takes the pending [`Decision`], leaving [`None`].
+    pub(crate) fn take_pending(&mut self) -> Option<Decision> {
+        self.pending.take()
+    }
+
+    /// Forgets every [`DecisionResponse`], once the step they were given for is taken.
+    pub(crate) fn clear_responses(&mut self) {
+        self.responses.clear();
+        self.next_response = 0;
+    }
+
+    /// Returns the next [`DecisionResponse`], or keeps the [`Decision`] as pending if there is
+    /// none.
+    fn respond_to(&mut self, decision: Decision) -> Option<DecisionResponse> {
+        let response = self.responses.get(self.next_response).cloned();
+
+        match response {
+            Some(_) => self.next_response += 1,
+            None => self.pending = Some(decision),
+        }
+
+        response
+    }
+}
+
+impl Controller for StepController {
+    // The Game only ever asks for the choices below, so there is never a bare index to give.
+    fn get_card_index(&mut self, _available_cards: &[Card]) -> Option<usize> {
+        None
+    }
+
+    fn get_cut_index(&mut self, deck_cards: &[Card]) -> Option<usize> {
+        let decision = Decision::Cut {
+            player: self.player,
+            deck_size: deck_cards.len(),
+        };
+
+        match self.respond_to(decision)? {
+            DecisionResponse::Cut(index) => Some(index),
+            _ => None,
+        }
+    }
+
+    fn get_starter_cut_depth(&mut self, deck_size: usize) -> Option<usize> {
+        let decision = Decision::CutStarter {
+            player: self.player,
+            deck_size,
+        };
+
+        match self.respond_to(decision)? {
+            DecisionResponse::CutStarter(depth) => Some(depth),
+            _ => None,
+        }
+    }
+
+    fn get_crib_discards(&mut self, hand: &[Card], is_own_crib: bool) -> Option<[usize; 2]> {
+        let decision = Decision::Discard {
+            player: self.player,
+            hand: hand.to_vec(),
+            is_own_crib,
+        };
+
+        match self.respond_to(decision)? {
+            DecisionResponse::Discard(indices) => Some(indices),
+            _ => None,
+        }
+    }
+
+    fn get_play_index(&mut self, available_cards: &[Card], context: &PlayContext) -> Option<usize> {
+        let decision = Decision::Play {
+            player: self.player,
+            hand: available_cards.to_vec(),
+            stack: context.play_data.stack.clone(),
+            stack_score: context.play_data.stack_score,
+            played_cards: context.play_data.played_cards(),
+        };
+
+        match self.respond_to(decision)? {
+            DecisionResponse::Play(index) => Some(index),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Rank, Suit};
+
+    #[test]
+    fn test_responses_are_given_in_order_then_pending() {
+        let hand = vec![
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Hearts),
+        ];
+
+        let mut controller = StepController::new(PlayerId::One);
+
+        controller.respond(DecisionResponse::Discard([2, 0]));
+
+        assert_eq!(controller.get_crib_discards(&hand, true), Some([2, 0]));
+        assert_eq!(controller.pending(), None);
+        assert_eq!(controller.get_crib_discards(&hand, true), None);
+
+        let expected = Decision::Discard {
+            player: PlayerId::One,
+            hand,
+            is_own_crib: true,
+        };
+
+        assert_eq!(controller.take_pending(), Some(expected));
+        assert_eq!(controller.pending(), None);
+    }
+}
@@ -0,0 +1,87 @@
+//! The history of a single stack (sub-round) during play.
+
+#[cfg(doc)]
+use game::{PlayData, Player};
+
+use std::convert::TryFrom;
+
+use cards::Card;
+
+/// Which side of a sub-round a [`Player`] is on.
+///
+/// The leader put down (or tried to put down) the first [`Card`] of the stack, and the follower
+/// is the other [`Player`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Side {
+    Leader,
+    Follower,
+}
+
+impl Side {
+    /// Returns the other [`Side`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::Side;
+    ///
+    /// assert_eq!(Side::Leader.other(), Side::Follower);
+    /// assert_eq!(Side::Follower.other(), Side::Leader);
+    /// ```
+    #[must_use]
+    pub fn other(self) -> Side {
+        match self {
+            Side::Leader => Side::Follower,
+            Side::Follower => Side::Leader,
+        }
+    }
+}
+
+/// A finished stack from [`PlayData`], with the points each [`Side`] scored on it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SubRound {
+    pub stack: Vec<Card>,
+    pub leader_points: u32,
+    pub follower_points: u32,
+}
+
+impl SubRound {
+    /// Returns the points scored by the given [`Side`].
+    #[must_use]
+    pub fn points_for(&self, side: Side) -> u32 {
+        match side {
+            Side::Leader => self.leader_points,
+            Side::Follower => self.follower_points,
+        }
+    }
+
+    /// Returns the points scored by the given [`Side`] minus the points given to the other.
+    ///
+    /// # Panics
+    ///
+    /// If either [`Side`]s points can't fit into an [`i32`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{Card, Rank, Suit};
+    /// use libterminal_cribbage::game::{Side, SubRound};
+    ///
+    /// // The follower paired the leader's Five.
+    /// let sub_round = SubRound {
+    ///     stack: vec![Card::new(Rank::Five, Suit::Clubs), Card::new(Rank::Five, Suit::Hearts)],
+    ///     leader_points: 0,
+    ///     follower_points: 2,
+    /// };
+    ///
+    /// assert_eq!(sub_round.net_tempo(Side::Leader), -2);
+    /// assert_eq!(sub_round.net_tempo(Side::Follower), 2);
+    /// ```
+    #[must_use]
+    pub fn net_tempo(&self, side: Side) -> i32 {
+        let gained = i32::try_from(self.points_for(side)).unwrap();
+        let given = i32::try_from(self.points_for(side.other())).unwrap();
+
+        gained - given
+    }
+}
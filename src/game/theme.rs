@@ -0,0 +1,399 @@
+//! How a [`UiDisplay`] draws [`Card`]s: the [`Suit`] symbols, colors, and card frames.
+
+#[cfg(doc)]
+use game::{DisplayConfig, UiDisplay};
+
+use std::str::FromStr;
+
+use toml::{Table, Value};
+
+use cards::{Card, Suit};
+
+/// A color for text in the terminal, from the 8 standard ANSI colors.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Color {
+    /// The terminal's own text color, so nothing is colored.
+    #[default]
+    Default,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// Every [`Color`], in the order they're declared.
+    const ALL: [Color; 9] = [
+        Color::Default,
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+    ];
+
+    /// Wraps the text in the ANSI escape codes for the [`Color`], unless it's
+    /// [`Color::Default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::Color;
+    ///
+    /// assert_eq!(Color::Red.paint("[5♥]"), "\x1b[31m[5♥]\x1b[0m");
+    /// assert_eq!(Color::Default.paint("[5♠]"), "[5♠]");
+    /// ```
+    #[must_use]
+    pub fn paint(self, text: &str) -> String {
+        match self.ansi_code() {
+            Some(code) => format!("\x1b[{code}m{text}\x1b[0m"),
+            None => text.to_string(),
+        }
+    }
+
+    /// The ANSI code for the foreground [`Color`], or [`None`] for [`Color::Default`].
+    fn ansi_code(self) -> Option<u8> {
+        match self {
+            Color::Default => None,
+            Color::Black => Some(30),
+            Color::Red => Some(31),
+            Color::Green => Some(32),
+            Color::Yellow => Some(33),
+            Color::Blue => Some(34),
+            Color::Magenta => Some(35),
+            Color::Cyan => Some(36),
+            Color::White => Some(37),
+        }
+    }
+
+    /// The name of the [`Color`] in a theme file.
+    fn name(self) -> &'static str {
+        match self {
+            Color::Default => "default",
+            Color::Black => "black",
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Yellow => "yellow",
+            Color::Blue => "blue",
+            Color::Magenta => "magenta",
+            Color::Cyan => "cyan",
+            Color::White => "white",
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    /// Reads a [`Color`] from its name, like `"red"`, ignoring case.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Color::ALL
+            .iter()
+            .find(|color| color.name().eq_ignore_ascii_case(input))
+            .copied()
+            .ok_or_else(|| format!("{input} is not a color!"))
+    }
+}
+
+/// The characters of the box drawn around each [`Card`] in card art.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CardFrame {
+    pub top_left: char,
+    pub top_right: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+    pub horizontal: char,
+    pub vertical: char,
+}
+
+impl Default for CardFrame {
+    /// The unicode box drawing lines, like `┌─┐`.
+    fn default() -> CardFrame {
+        CardFrame {
+            top_left: '\u{250c}',
+            top_right: '\u{2510}',
+            bottom_left: '\u{2514}',
+            bottom_right: '\u{2518}',
+            horizontal: '\u{2500}',
+            vertical: '\u{2502}',
+        }
+    }
+}
+
+/// The look of the [`Card`]s shown by a [`UiDisplay`], set in its [`DisplayConfig`].
+///
+/// A [`Theme`] can be read from TOML, where every key is optional and defaults to the
+/// [`Theme::default`] look:
+///
+/// ```toml
+/// card_back = "#"
+///
+/// [suits]
+/// hearts = "H"
+/// spades = "S"
+/// diamonds = "D"
+/// clubs = "C"
+///
+/// [colors]
+/// red = "magenta"
+/// black = "default"
+///
+/// [frame]
+/// top_left = "+"
+/// top_right = "+"
+/// bottom_left = "+"
+/// bottom_right = "+"
+/// horizontal = "-"
+/// vertical = "|"
+/// ```
+///
+/// The colors are only used if [`DisplayConfig::color`] is on, and the frame if
+/// [`DisplayConfig::card_art`] is on.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::cards::{Card, Rank, Suit};
+/// use libterminal_cribbage::game::{Color, Theme};
+///
+/// let theme: Theme = "[suits]\nhearts = \"H\"\n[colors]\nred = \"magenta\"\n"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(theme.suit_symbol(Suit::Hearts), 'H');
+/// assert_eq!(theme.suit_symbol(Suit::Spades), '♠');
+/// assert_eq!(theme.red, Color::Magenta);
+/// assert_eq!(theme.card_string(&Card::new(Rank::Ten, Suit::Hearts)), "[10H]");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Theme {
+    pub hearts: char,
+    pub spades: char,
+    pub diamonds: char,
+    pub clubs: char,
+    /// The [`Color`] of [`Suit::Hearts`] and [`Suit::Diamonds`].
+    pub red: Color,
+    /// The [`Color`] of [`Suit::Spades`] and [`Suit::Clubs`].
+    pub black: Color,
+    /// The character for the back of a face down [`Card`].
+    pub card_back: char,
+    pub frame: CardFrame,
+}
+
+impl Theme {
+    /// Returns the symbol of the [`Suit`].
+    #[must_use]
+    pub fn suit_symbol(&self, suit: Suit) -> char {
+        match suit {
+            Suit::Hearts => self.hearts,
+            Suit::Spades => self.spades,
+            Suit::Diamonds => self.diamonds,
+            Suit::Clubs => self.clubs,
+        }
+    }
+
+    /// Returns the [`Color`] of the [`Card`], by whether its [`Suit`] is red.
+    #[must_use]
+    pub fn color_of(&self, card: &Card) -> Color {
+        if card.suit.is_red() {
+            self.red
+        } else {
+            self.black
+        }
+    }
+
+    /// Returns the [`Card`] in brackets, with the [`Theme::suit_symbol`], like `"[10♥]"`.
+    #[must_use]
+    pub fn card_string(&self, card: &Card) -> String {
+        format!("[{}{}]", card.rank, self.suit_symbol(card.suit))
+    }
+
+    /// Returns the [`Card`] as lines of box art in the [`CardFrame`], all of the same width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::cards::{render_card_art, Card, Rank, Suit};
+    /// use libterminal_cribbage::game::Theme;
+    ///
+    /// let card = Card::new(Rank::Ten, Suit::Hearts);
+    ///
+    /// assert_eq!(Theme::default().render_card_art(&card), render_card_art(&card));
+    /// ```
+    #[must_use]
+    pub fn render_card_art(&self, card: &Card) -> Vec<String> {
+        let frame = &self.frame;
+        let rank = card.rank.to_string();
+        let edge = frame.horizontal.to_string().repeat(5);
+
+        vec![
+            format!("{}{edge}{}", frame.top_left, frame.top_right),
+            format!("{}{rank:<2}   {}", frame.vertical, frame.vertical),
+            format!(
+                "{}  {}  {}",
+                frame.vertical,
+                self.suit_symbol(card.suit),
+                frame.vertical
+            ),
+            format!("{}   {rank:>2}{}", frame.vertical, frame.vertical),
+            format!("{}{edge}{}", frame.bottom_left, frame.bottom_right),
+        ]
+    }
+}
+
+impl Default for Theme {
+    /// The unicode [`Suit`] glyphs, red hearts and diamonds, shaded card backs, and box drawing
+    /// frames.
+    fn default() -> Theme {
+        Theme {
+            hearts: '\u{2665}',
+            spades: '\u{2660}',
+            diamonds: '\u{2666}',
+            clubs: '\u{2663}',
+            red: Color::Red,
+            black: Color::Default,
+            card_back: '\u{2592}',
+            frame: CardFrame::default(),
+        }
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    /// Reads a [`Theme`] from TOML, starting from the [`Theme::default`].
+    ///
+    /// Every symbol must be a single character, and unknown keys are errors, to catch typos.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let table: Table = input
+            .parse()
+            .map_err(|err| format!("Invalid theme: {err}"))?;
+        let mut theme = Theme::default();
+
+        for (key, value) in &table {
+            match (key.as_str(), value) {
+                ("card_back", value) => theme.card_back = parse_char(key, value)?,
+                ("suits", Value::Table(suits)) => {
+                    for (key, value) in suits {
+                        let symbol = match key.as_str() {
+                            "hearts" => &mut theme.hearts,
+                            "spades" => &mut theme.spades,
+                            "diamonds" => &mut theme.diamonds,
+                            "clubs" => &mut theme.clubs,
+                            _ => return Err(format!("Invalid theme: unknown key suits.{key}")),
+                        };
+
+                        *symbol = parse_char(key, value)?;
+                    }
+                }
+                ("colors", Value::Table(colors)) => {
+                    for (key, value) in colors {
+                        let color = match key.as_str() {
+                            "red" => &mut theme.red,
+                            "black" => &mut theme.black,
+                            _ => return Err(format!("Invalid theme: unknown key colors.{key}")),
+                        };
+
+                        *color = value
+                            .as_str()
+                            .ok_or_else(|| format!("Invalid theme: {key} is not a string"))?
+                            .parse()
+                            .map_err(|err| format!("Invalid theme: {err}"))?;
+                    }
+                }
+                ("frame", Value::Table(frame)) => {
+                    for (key, value) in frame {
+                        let character = match key.as_str() {
+                            "top_left" => &mut theme.frame.top_left,
+                            "top_right" => &mut theme.frame.top_right,
+                            "bottom_left" => &mut theme.frame.bottom_left,
+                            "bottom_right" => &mut theme.frame.bottom_right,
+                            "horizontal" => &mut theme.frame.horizontal,
+                            "vertical" => &mut theme.frame.vertical,
+                            _ => return Err(format!("Invalid theme: unknown key frame.{key}")),
+                        };
+
+                        *character = parse_char(key, value)?;
+                    }
+                }
+                _ => return Err(format!("Invalid theme: unknown key {key}")),
+            }
+        }
+
+        Ok(theme)
+    }
+}
+
+/// Reads the single character of the string value for the key.
+fn parse_char(key: &str, value: &Value) -> Result<char, String> {
+    let mut chars = value.as_str().map(str::chars);
+
+    match chars.as_mut().map(|chars| (chars.next(), chars.next())) {
+        Some((Some(character), None)) => Ok(character),
+        _ => Err(format!("Invalid theme: {key} is not a single character")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::Rank;
+
+    #[test]
+    fn test_from_str_empty_is_default() {
+        assert_eq!("".parse(), Ok(Theme::default()));
+    }
+
+    #[test]
+    fn test_from_str_ascii_frame() {
+        let theme: Theme = [
+            "card_back = \"#\"",
+            "[frame]",
+            "top_left = \"+\"",
+            "top_right = \"+\"",
+            "bottom_left = \"+\"",
+            "bottom_right = \"+\"",
+            "horizontal = \"-\"",
+            "vertical = \"|\"",
+            "[suits]",
+            "clubs = \"C\"",
+        ]
+        .join("\n")
+        .parse()
+        .unwrap();
+
+        let expected = vec!["+-----+", "|A    |", "|  C  |", "|    A|", "+-----+"];
+
+        assert_eq!(theme.card_back, '#');
+        assert_eq!(
+            theme.render_card_art(&Card::new(Rank::Ace, Suit::Clubs)),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert_eq!(
+            "[suits]\nhearts = \"<3\"".parse::<Theme>(),
+            Err(String::from(
+                "Invalid theme: hearts is not a single character"
+            ))
+        );
+        assert_eq!(
+            "[colors]\nred = \"pink\"".parse::<Theme>(),
+            Err(String::from("Invalid theme: pink is not a color!"))
+        );
+        assert_eq!(
+            "[frame]\ncorner = \"+\"".parse::<Theme>(),
+            Err(String::from("Invalid theme: unknown key frame.corner"))
+        );
+    }
+}
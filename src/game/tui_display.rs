@@ -0,0 +1,736 @@
+//! A full-screen display for the game, which redraws the table instead of scrolling.
+
+#[cfg(doc)]
+use crate::cards::{Deck, Hand};
+#[cfg(doc)]
+use crate::game::Game;
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::thread;
+
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, queue};
+use itertools::Itertools;
+
+use crate::cards::Card;
+use crate::game::{
+    Controller, Display, DisplayConfig, Language, Message, Pegs, Phase, PlayData,
+    PlayScoreBreakdown, Player, Round, RoundSummary, UiDisplay, DEFAULT_OPPONENT_NAME,
+    DEFAULT_PLAYER_NAME,
+};
+
+/// The size of the screen, in columns and rows, if the terminal's size can't be read.
+const DEFAULT_SCREEN_SIZE: (u16, u16) = (80, 24);
+
+/// The widest the layout is drawn, even on a wider terminal.
+const MAX_SCREEN_WIDTH: usize = 80;
+
+/// The narrowest a track of the board is drawn, even on a narrower terminal.
+const MIN_TRACK_WIDTH: usize = 10;
+
+/// The most lines kept in the message log, which is more than fits on any screen.
+const MAX_LOG_LINES: usize = 200;
+
+/// The holes on the board, to the end of the track.
+const BOARD_HOLES: u32 = 121;
+
+/// What's on the table, as the player sees it, as of the last table message.
+#[derive(Debug, Default, PartialEq, Clone)]
+struct Table {
+    hand: Vec<Card>,
+    opponent_hand_size: usize,
+    /// Whether the crib is the player's, rather than the opponent's.
+    is_dealer: bool,
+    crib_size: usize,
+    starter: Option<Card>,
+    stack: Vec<Card>,
+    stack_score: u32,
+}
+
+/// Everything on the screen of a [`TuiDisplay`], as of the last message.
+#[derive(Debug, PartialEq, Clone)]
+struct Screen {
+    /// The last [`Table`], or [`None`] before the first deal.
+    table: Option<Table>,
+    player_name: String,
+    opponent_name: String,
+    player_pegs: Pegs,
+    opponent_pegs: Pegs,
+    /// The lines of every message so far, oldest first.
+    log: VecDeque<String>,
+}
+
+impl Screen {
+    /// An empty screen, with the default names, before anything has happened.
+    fn new() -> Screen {
+        Screen {
+            table: None,
+            player_name: String::from(DEFAULT_PLAYER_NAME),
+            opponent_name: String::from(DEFAULT_OPPONENT_NAME),
+            player_pegs: Pegs::default(),
+            opponent_pegs: Pegs::default(),
+            log: VecDeque::new(),
+        }
+    }
+}
+
+/// A struct for displaying the [`Game`] full-screen that uses the [`Display`] trait.
+///
+/// Instead of scrolling each message by, like a [`UiDisplay`], the whole screen is cleared and
+/// redrawn with the board and both [`Player`]s' [`Pegs`], the opponent's face down [`Hand`] and
+/// the crib, the starter, the play stack, the player's [`Hand`], and a log of the latest
+/// messages. The table is kept from the table messages (e.g. [`Display::board_message`]), which
+/// aren't logged since they're already on the screen.
+///
+/// The other messages and prompts are the same as a [`UiDisplay`] with the [`DisplayConfig`], but
+/// without any spacers or card art, which don't fit the layout. Prompts are printed under the
+/// layout, where the cursor is left.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{Display, DisplayConfig, TuiDisplay};
+///
+/// let display = TuiDisplay::new_with_config(DisplayConfig::fast());
+///
+/// assert_eq!(
+///     display.prompt_cut(40),
+///     "[Cut] Your turn to cut the deck for the deal (1 to 40): "
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TuiDisplay {
+    ui: UiDisplay,
+    screen: RefCell<Screen>,
+}
+
+impl TuiDisplay {
+    /// Creates a new [`TuiDisplay`] struct, with the [`DisplayConfig::default`].
+    #[must_use]
+    pub fn new() -> TuiDisplay {
+        TuiDisplay::new_with_config(DisplayConfig::default())
+    }
+
+    /// Creates a new [`TuiDisplay`] struct with the [`DisplayConfig`], without its spacers or
+    /// card art.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{DisplayConfig, TuiDisplay};
+    ///
+    /// let display = TuiDisplay::new_with_config(DisplayConfig::fast());
+    /// ```
+    #[must_use]
+    pub fn new_with_config(config: DisplayConfig) -> TuiDisplay {
+        let config = DisplayConfig {
+            use_spacers: false,
+            card_art: false,
+            ..config
+        };
+
+        TuiDisplay {
+            ui: UiDisplay::new_with_config(config),
+            screen: RefCell::new(Screen::new()),
+        }
+    }
+
+    /// Keeps the [`Table`] of the [`Round`] as the player sees it, with the stack of its
+    /// [`PlayData`] only if `during_play`.
+    fn remember_table<C1, C2>(
+        &self,
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+        during_play: bool,
+    ) where
+        C1: Controller,
+        C2: Controller,
+    {
+        let play_data = &round.play_data;
+        let mut screen = self.screen.borrow_mut();
+
+        screen.player_name = player.name_or(DEFAULT_PLAYER_NAME).to_string();
+        screen.opponent_name = opponent.name_or(DEFAULT_OPPONENT_NAME).to_string();
+        screen.table = Some(Table {
+            hand: player.hand.as_vec().clone(),
+            opponent_hand_size: opponent.hand.len(),
+            is_dealer: round.is_dealer(/*player_1=*/ true),
+            crib_size: round.crib.len(),
+            starter: round.starter.clone(),
+            stack: if during_play {
+                play_data.stack.clone()
+            } else {
+                Vec::new()
+            },
+            stack_score: if during_play {
+                play_data.stack_score
+            } else {
+                0
+            },
+        });
+    }
+
+    /// Adds each line of the message to the log, dropping the oldest past [`MAX_LOG_LINES`].
+    fn log(&self, message: &str) {
+        let mut screen = self.screen.borrow_mut();
+
+        screen.log.extend(message.lines().map(String::from));
+
+        while screen.log.len() > MAX_LOG_LINES {
+            screen.log.pop_front();
+        }
+    }
+
+    /// Clears the terminal, and draws the [`TuiDisplay::screen_lines`] to fit it, leaving the
+    /// cursor on the line below for a prompt.
+    ///
+    /// # Panics
+    ///
+    /// If the screen can't be written to stdout.
+    fn draw(&self) {
+        let (columns, rows) = terminal::size().unwrap_or(DEFAULT_SCREEN_SIZE);
+        let lines = self.screen_lines(usize::from(columns), usize::from(rows));
+
+        let mut stdout = io::stdout();
+
+        queue!(
+            stdout,
+            terminal::Clear(ClearType::All),
+            cursor::MoveTo(0, 0)
+        )
+        .and_then(|()| writeln!(stdout, "{}", lines.join("\n")))
+        .and_then(|()| stdout.flush())
+        .expect("Could not draw the screen!");
+    }
+
+    /// The lines of the layout for a screen of `width` columns and `height` rows, leaving the
+    /// last row for a prompt.
+    ///
+    /// The board is always drawn, the table only once there's a [`Table`], and the latest lines
+    /// of the log fill the rest of the rows.
+    fn screen_lines(&self, width: usize, height: usize) -> Vec<String> {
+        let screen = self.screen.borrow();
+        let width = width.min(MAX_SCREEN_WIDTH);
+
+        let name_width = screen
+            .player_name
+            .chars()
+            .count()
+            .max(screen.opponent_name.chars().count());
+        let track_width = width.saturating_sub(name_width + 14).max(MIN_TRACK_WIDTH);
+
+        let mut result = vec![String::from("Terminal Cribbage"), "=".repeat(width)];
+
+        for (name, pegs) in [
+            (&screen.player_name, &screen.player_pegs),
+            (&screen.opponent_name, &screen.opponent_pegs),
+        ] {
+            result.push(format!(
+                "{name:<name_width$} |{}| {:>3} (+{})",
+                Self::peg_track(pegs, BOARD_HOLES, track_width),
+                pegs.front,
+                pegs.front.saturating_sub(pegs.back)
+            ));
+        }
+
+        result.push("-".repeat(width));
+
+        if let Some(table) = &screen.table {
+            result.extend(self.table_lines(table, &screen.player_name, &screen.opponent_name));
+            result.push("-".repeat(width));
+        }
+
+        let log_rows = height.saturating_sub(result.len() + 1);
+
+        result.extend(
+            screen
+                .log
+                .iter()
+                .skip(screen.log.len().saturating_sub(log_rows))
+                .cloned(),
+        );
+
+        result
+    }
+
+    /// The lines of the [`Table`]: the opponent's face down [`Hand`], the crib, the starter, the
+    /// play stack and its count, and the player's [`Hand`], numbered like the prompts.
+    fn table_lines(&self, table: &Table, player_name: &str, opponent_name: &str) -> Vec<String> {
+        let back = if self.ui.ascii_suits {
+            '#'
+        } else {
+            self.ui.config.theme.card_back
+        };
+        let dealer_name = if table.is_dealer {
+            player_name
+        } else {
+            opponent_name
+        };
+
+        let hand = table
+            .hand
+            .iter()
+            .enumerate()
+            .map(|(index, card)| format!("{}:{}", index + 1, self.ui.card_string(Some(card))))
+            .join(" ");
+
+        vec![
+            format!(
+                "{}: {}",
+                self.ui.text(Message::HandLabel, &[&opponent_name]),
+                back.to_string().repeat(table.opponent_hand_size)
+            ),
+            format!(
+                "{}: {}",
+                self.ui.text(Message::CribLabel, &[&dealer_name]),
+                back.to_string().repeat(table.crib_size)
+            ),
+            self.ui.text(
+                Message::Starter,
+                &[&self.ui.card_string(table.starter.as_ref())],
+            ),
+            format!(
+                "{}: {} | {}",
+                self.ui.text(Message::PlayStack, &[]),
+                self.ui.cards_string(&table.stack),
+                self.ui.text(Message::Count, &[&table.stack_score])
+            ),
+            format!(
+                "{}: {hand}",
+                self.ui.text(Message::HandLabel, &[&player_name])
+            ),
+        ]
+    }
+
+    /// The track of the [`Pegs`] on the board, `width` characters wide for the `target` holes.
+    ///
+    /// Each character stands for the holes up to its share of the `target`: `=` up to the back
+    /// peg, `+` from there up to the front peg, and `.` for the rest.
+    fn peg_track(pegs: &Pegs, target: u32, width: usize) -> String {
+        let target = target as usize;
+
+        (1..=width)
+            .map(|slot| {
+                let hole = (slot * target).div_ceil(width);
+
+                if hole <= pegs.back as usize {
+                    '='
+                } else if hole <= pegs.front as usize {
+                    '+'
+                } else {
+                    '.'
+                }
+            })
+            .collect()
+    }
+}
+
+impl Display for TuiDisplay {
+    /// The [`DisplayConfig::language`].
+    fn language(&self) -> Language {
+        self.ui.language()
+    }
+
+    /// Print message to `std::out` without a new line, a spacer, or a delay.
+    fn print_no_spacer_no_delay(&self, message: &str) {
+        print!("{message}");
+    }
+
+    /// Print message to `std::out` without a spacer or a delay.
+    fn println_no_spacer_no_delay(&self, message: &str) {
+        println!("{message}");
+    }
+
+    /// Adds the message to the log and redraws the screen, waiting for the delay of the
+    /// [`DisplayConfig`] after.
+    ///
+    /// An empty message, like for the table or the board, which are already on the screen, only
+    /// redraws the screen.
+    fn println(&self, message: &str) {
+        if message.is_empty() {
+            self.draw();
+
+            return;
+        }
+
+        self.log(message);
+        self.draw();
+
+        if !self.ui.config.delay.is_zero() {
+            thread::sleep(self.ui.config.delay);
+        }
+    }
+
+    /// The [`String`] display for both named [`Player`]s [`Card`]s cut from the [`Deck`].
+    fn game_after_cut_message(
+        &self,
+        player_name: &str,
+        player_cut: &Card,
+        opponent_name: &str,
+        opponent_cut: &Card,
+        player_won: bool,
+    ) -> String {
+        self.ui.game_after_cut_message(
+            player_name,
+            player_cut,
+            opponent_name,
+            opponent_cut,
+            player_won,
+        )
+    }
+
+    /// The [`String`] display before both named [`Player`]s' cuts are revealed.
+    fn game_cut_reveal_message(&self, player_name: &str, opponent_name: &str) -> String {
+        self.ui.game_cut_reveal_message(player_name, opponent_name)
+    }
+
+    /// The [`String`] display for both named [`Player`]s cutting the same rank, so they cut again.
+    fn game_recut_message(
+        &self,
+        player_name: &str,
+        player_cut: &Card,
+        opponent_name: &str,
+        opponent_cut: &Card,
+    ) -> String {
+        self.ui
+            .game_recut_message(player_name, player_cut, opponent_name, opponent_cut)
+    }
+
+    /// The [`String`] display for the face down [`Deck`], like a [`UiDisplay`].
+    fn cut_deck_message(&self, number_of_cards: usize, depth: Option<usize>) -> String {
+        self.ui.cut_deck_message(number_of_cards, depth)
+    }
+
+    /// The [`String`] display for the pone's cut for the starter.
+    fn game_starter_cut_message(&self, depth: usize, cutter: &str) -> String {
+        self.ui.game_starter_cut_message(depth, cutter)
+    }
+
+    /// Keeps the table, and returns only whose crib the discards are going to, since the
+    /// [`Hand`]s and points are on the screen.
+    fn game_discard_message<C1, C2>(
+        &self,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+        is_dealer: bool,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        self.remember_table(&Round::new(is_dealer), player, opponent, false);
+
+        if is_dealer {
+            self.ui.text(Message::DiscardAsDealer, &[])
+        } else {
+            self.ui.text(Message::DiscardAsPone, &[])
+        }
+    }
+
+    /// The [`String`] display for both of a player's discards to the crib.
+    fn game_crib_discards_message(
+        &self,
+        cards: &[Card],
+        discarder: &str,
+        is_dealer: bool,
+    ) -> String {
+        self.ui
+            .game_crib_discards_message(cards, discarder, is_dealer)
+    }
+
+    /// The [`String`] display for taking back the [`Card`]s just chosen.
+    fn game_undo_message(&self, chosen: &[Card], undoer: &str) -> String {
+        self.ui.game_undo_message(chosen, undoer)
+    }
+
+    /// The [`String`] display for a hint of the [`Card`]s to discard or play.
+    fn game_hint_message(&self, phase: Phase, hint: &[Card]) -> String {
+        self.ui.game_hint_message(phase, hint)
+    }
+
+    /// Keeps the table, and returns an empty [`String`], since the table is on the screen.
+    fn game_before_play_message<C1, C2>(
+        &self,
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        self.remember_table(round, player, opponent, false);
+
+        String::new()
+    }
+
+    /// Keeps the table, and returns an empty [`String`], since the table is on the screen.
+    fn game_during_play_message<C1, C2>(
+        &self,
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        self.remember_table(round, player, opponent, true);
+
+        String::new()
+    }
+
+    /// The [`String`] display for both [`Player`]s' [`Hand`]s and the crib during counting, like
+    /// a [`UiDisplay`], since the opponent's [`Hand`] isn't face up on the table.
+    fn game_during_counting_message<C1, C2>(
+        &self,
+        round: &Round,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        self.remember_table(round, player, opponent, false);
+
+        self.ui
+            .game_during_counting_message(round, player, opponent)
+    }
+
+    /// Keeps both named [`Player`]s' [`Pegs`] for the board, returning an empty [`String`], since
+    /// the board is on the screen.
+    fn board_message(
+        &self,
+        player_name: &str,
+        player: &Pegs,
+        opponent_name: &str,
+        opponent: &Pegs,
+    ) -> String {
+        let mut screen = self.screen.borrow_mut();
+
+        screen.player_name = player_name.to_string();
+        screen.opponent_name = opponent_name.to_string();
+        screen.player_pegs = *player;
+        screen.opponent_pegs = *opponent;
+
+        String::new()
+    }
+
+    /// The [`String`] display explaining the points scored by playing a [`Card`] (pegging).
+    fn play_score_message(&self, breakdown: &PlayScoreBreakdown, scorer: &str) -> String {
+        self.ui.play_score_message(breakdown, scorer)
+    }
+
+    /// The [`String`] display for a [`Card`] played onto the stack, or a GO, with the count.
+    fn play_count_message(&self, player: &str, played: Option<&Card>, count: u32) -> String {
+        self.ui.play_count_message(player, played, count)
+    }
+
+    /// The [`String`] display for the stack starting over after a 31 or a GO.
+    fn play_reset_message(&self, thirty_one: bool) -> String {
+        self.ui.play_reset_message(thirty_one)
+    }
+
+    /// The [`String`] display for the [`RoundSummary`], like a [`UiDisplay`].
+    fn round_summary_message(
+        &self,
+        summary: &RoundSummary,
+        player_name: &str,
+        opponent_name: &str,
+    ) -> String {
+        self.ui
+            .round_summary_message(summary, player_name, opponent_name)
+    }
+
+    /// The [`String`] prompt for the player to cut the [`Deck`], to choose the dealer.
+    fn prompt_cut(&self, number_of_cards: usize) -> String {
+        self.ui.prompt_cut(number_of_cards)
+    }
+
+    /// The [`String`] prompt for the player to confirm their cut for the deal.
+    fn prompt_confirm_cut(&self, depth: usize) -> String {
+        self.ui.prompt_confirm_cut(depth)
+    }
+
+    /// The [`String`] prompt for the player, as the pone, to cut the [`Deck`] for the starter.
+    fn prompt_starter_cut(&self, number_of_cards: usize) -> String {
+        self.ui.prompt_starter_cut(number_of_cards)
+    }
+
+    /// The [`String`] prompt for the player to discard two [`Card`]s to the crib.
+    fn prompt_discard_to_crib(&self, number_of_cards: usize, is_own_crib: bool) -> String {
+        self.ui.prompt_discard_to_crib(number_of_cards, is_own_crib)
+    }
+
+    /// The [`String`] prompt for the player to play a [`Card`] onto the stack.
+    fn prompt_play_card(&self, number_of_cards: usize, play_data: &PlayData) -> String {
+        self.ui.prompt_play_card(number_of_cards, play_data)
+    }
+
+    /// The [`String`] prompt for the player to take back the [`Card`]s they just chose.
+    fn prompt_undo(&self, chosen: &[Card]) -> String {
+        self.ui.prompt_undo(chosen)
+    }
+
+    /// The [`String`] display for game over.
+    fn game_over_message(&self, winner: &str) -> String {
+        self.ui.game_over_message(winner)
+    }
+}
+
+impl Default for TuiDisplay {
+    fn default() -> Self {
+        TuiDisplay::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::cards::{Rank, Suit};
+    use crate::game::PredeterminedController;
+
+    fn table() -> Table {
+        Table {
+            hand: vec![
+                Card::new(Rank::Five, Suit::Hearts),
+                Card::new(Rank::Jack, Suit::Clubs),
+            ],
+            opponent_hand_size: 3,
+            is_dealer: false,
+            crib_size: 4,
+            starter: Some(Card::new(Rank::Four, Suit::Hearts)),
+            stack: vec![
+                Card::new(Rank::Five, Suit::Spades),
+                Card::new(Rank::Ten, Suit::Diamonds),
+            ],
+            stack_score: 15,
+        }
+    }
+
+    #[test]
+    fn test_peg_track() {
+        let pegs = Pegs {
+            front: 60,
+            back: 30,
+        };
+
+        assert_eq!(TuiDisplay::peg_track(&pegs, 120, 12), "===+++......");
+        assert_eq!(
+            TuiDisplay::peg_track(&Pegs::default(), 121, 10),
+            ".........."
+        );
+    }
+
+    #[test]
+    fn test_screen_lines_before_the_first_deal() {
+        let display = TuiDisplay::new_with_config(DisplayConfig::fast());
+
+        display.log("Time to cut the deck!");
+
+        let expected = vec![
+            String::from("Terminal Cribbage"),
+            "=".repeat(40),
+            format!("Player   |{}|   0 (+0)", ".".repeat(18)),
+            format!("Opponent |{}|   0 (+0)", ".".repeat(18)),
+            "-".repeat(40),
+            String::from("Time to cut the deck!"),
+        ];
+
+        assert_eq!(display.screen_lines(40, 24), expected);
+    }
+
+    #[test]
+    fn test_screen_lines_with_table() {
+        let mut display = TuiDisplay::new_with_config(DisplayConfig::fast());
+
+        display.ui.ascii_suits = true;
+
+        let mut screen = display.screen.borrow_mut();
+
+        screen.player_name = String::from("Alice");
+        screen.opponent_name = String::from("Bob");
+        screen.table = Some(table());
+
+        drop(screen);
+
+        let lines = display.screen_lines(80, 24);
+
+        let expected = vec![
+            String::from("Bob Hand: ###"),
+            String::from("Bob Crib: ####"),
+            String::from("Starter: [4H]"),
+            String::from("Play Stack: [ [5S],[10D] ] | Count: 15"),
+            String::from("Alice Hand: 1:[5H] 2:[JC]"),
+            "-".repeat(80),
+        ];
+
+        assert_eq!(lines[5..], expected);
+    }
+
+    #[test]
+    fn test_screen_lines_only_shows_the_latest_log_lines() {
+        let display = TuiDisplay::new_with_config(DisplayConfig::fast());
+
+        for line in 1..=30 {
+            display.log(&format!("Line {line}"));
+        }
+
+        display.log("Two\nlines");
+
+        let lines = display.screen_lines(40, 10);
+
+        // The board takes 5 rows, and the prompt 1, so only 4 are left for the log.
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[5..], ["Line 29", "Line 30", "Two", "lines"]);
+    }
+
+    #[test]
+    fn test_table_messages_keep_the_table() {
+        let display = TuiDisplay::new_with_config(DisplayConfig::fast());
+
+        let player = Player::new_with_cards(
+            PredeterminedController::from(vec![]),
+            vec![Card::new(Rank::Five, Suit::Hearts)],
+        );
+        let opponent = Player::new_with_cards(
+            PredeterminedController::from(vec![]),
+            vec![
+                Card::new(Rank::Two, Suit::Clubs),
+                Card::new(Rank::Three, Suit::Clubs),
+            ],
+        );
+
+        let mut round = Round::new(/*player_1_is_dealer=*/ false);
+
+        round.crib.add_card(Card::new(Rank::Ace, Suit::Spades));
+        round.starter = Some(Card::new(Rank::Four, Suit::Hearts));
+        round.play_data.add_card(Card::new(Rank::Ten, Suit::Spades));
+
+        assert_eq!(
+            display.game_discard_message(&player, &opponent, /*is_dealer=*/ false),
+            "You are the pone. Discard to your opponent's crib."
+        );
+        assert_eq!(
+            display.game_during_play_message(&round, &player, &opponent),
+            ""
+        );
+
+        let expected = Table {
+            hand: vec![Card::new(Rank::Five, Suit::Hearts)],
+            opponent_hand_size: 2,
+            is_dealer: false,
+            crib_size: 1,
+            starter: Some(Card::new(Rank::Four, Suit::Hearts)),
+            stack: vec![Card::new(Rank::Ten, Suit::Spades)],
+            stack_score: 10,
+        };
+
+        assert_eq!(display.screen.borrow().table, Some(expected));
+    }
+}
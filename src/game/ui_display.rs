@@ -1,43 +1,268 @@
 //! Handles the display of the game.
 
 #[cfg(doc)]
-use crate::cards::Hand;
-
+use crate::cards::Suit;
 #[cfg(doc)]
 use crate::game::Game;
 
-use std::{thread, time};
+use std::{fmt, thread, time};
 
 use itertools::Itertools;
 
-use crate::cards::Card;
-use crate::game::{Controller, Display, PlayData, Player};
+use crate::cards::{breakdown, Card, Hand};
+use crate::game::{
+    Controller, Display, Language, Message, Pegs, Phase, PlayData, PlayScoreBreakdown, Player,
+    Round, RoundSummary, Theme, DEFAULT_OPPONENT_NAME, DEFAULT_PLAYER_NAME, DOUBLE_SKUNK_LINE,
+    SKUNK_LINE,
+};
+
+/// The number of holes in each street (row) of the board, not counting the final hole.
+const BOARD_STREET_HOLES: u32 = 30;
+
+/// The final hole of the board.
+const BOARD_FINAL_HOLE: u32 = 121;
+
+/// How a [`UiDisplay`] paces its messages, renders [`Card`]s, and in which [`Language`].
+///
+/// By default, every message is followed by a half second delay and wrapped in spacers, the
+/// rendering extras are off, the messages are in English, and the [`Card`]s have the
+/// [`Theme::default`] look.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DisplayConfig {
+    /// How long to wait after every [`Display::println`].
+    pub delay: time::Duration,
+    /// Prints a spacer line before every [`Display::println`].
+    pub use_spacers: bool,
+    /// Colors the [`Card`]s with ANSI escape codes, in the [`Theme`]'s colors.
+    pub color: bool,
+    /// Renders [`Hand`]s, cribs, and the play stack as rows of [`Theme::render_card_art`].
+    pub card_art: bool,
+    /// The [`Language`] of every message.
+    pub language: Language,
+    /// The [`Suit`] symbols, colors, card backs, and card frames.
+    pub theme: Theme,
+}
+
+impl DisplayConfig {
+    /// The [`DisplayConfig::default`], but without any delay, for impatient players and tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libterminal_cribbage::game::DisplayConfig;
+    ///
+    /// let config = DisplayConfig::fast();
+    ///
+    /// assert_eq!(config.delay, Duration::ZERO);
+    /// assert!(config.use_spacers);
+    /// ```
+    #[must_use]
+    pub fn fast() -> DisplayConfig {
+        DisplayConfig {
+            delay: time::Duration::ZERO,
+            ..DisplayConfig::default()
+        }
+    }
+}
+
+impl Default for DisplayConfig {
+    fn default() -> DisplayConfig {
+        DisplayConfig {
+            delay: time::Duration::from_millis(500),
+            use_spacers: true,
+            color: false,
+            card_art: false,
+            language: Language::default(),
+            theme: Theme::default(),
+        }
+    }
+}
 
 /// A struct for displaying the [`Game`] that uses the [`Display`] trait.
 #[derive(Debug, PartialEq, Clone)]
 pub struct UiDisplay {
     pub joiner: String,
-    post_print_delay_millis: time::Duration,
+    /// Renders [`Card`]s with [`Card::to_ascii_string`] instead of the [`Suit`] glyphs.
+    pub ascii_suits: bool,
+    pub config: DisplayConfig,
 }
 
 impl UiDisplay {
-    /// Creates a new [`UiDisplay`] struct.
+    /// Creates a new [`UiDisplay`] struct, with the [`DisplayConfig::default`].
     #[must_use]
     pub fn new() -> UiDisplay {
+        UiDisplay::new_with_config(DisplayConfig::default())
+    }
+
+    /// Creates a new [`UiDisplay`] struct with the [`DisplayConfig`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{DisplayConfig, UiDisplay};
+    ///
+    /// let display = UiDisplay::new_with_config(DisplayConfig::fast());
+    ///
+    /// assert_eq!(display.config, DisplayConfig::fast());
+    /// ```
+    #[must_use]
+    pub fn new_with_config(config: DisplayConfig) -> UiDisplay {
         UiDisplay {
             joiner: String::from("\n"),
-            post_print_delay_millis: time::Duration::from_millis(500),
+            ascii_suits: false,
+            config,
+        }
+    }
+
+    /// Sets the [`DisplayConfig`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{DisplayConfig, UiDisplay};
+    ///
+    /// let config = DisplayConfig {
+    ///     color: true,
+    ///     card_art: true,
+    ///     ..DisplayConfig::default()
+    /// };
+    ///
+    /// let display = UiDisplay::new().with_config(config);
+    ///
+    /// assert_eq!(display.config, config);
+    /// ```
+    #[must_use]
+    pub fn with_config(mut self, config: DisplayConfig) -> UiDisplay {
+        self.config = config;
+
+        self
+    }
+
+    /// The [`Message`] in the [`DisplayConfig::language`], filled in with the `args`.
+    pub(crate) fn text(&self, message: Message, args: &[&dyn fmt::Display]) -> String {
+        self.config.language.format(message, args)
+    }
+
+    /// Colors the text in the [`Theme`]'s color of the [`Card`], if [`DisplayConfig::color`] is
+    /// on.
+    fn paint(&self, card: &Card, text: String) -> String {
+        if self.config.color {
+            self.config.theme.color_of(card).paint(&text)
+        } else {
+            text
         }
     }
 
     /// The display [`String`] representation of a [`Option<&Card>`].
-    fn card_string(possible_card: Option<&Card>) -> String {
+    pub(crate) fn card_string(&self, possible_card: Option<&Card>) -> String {
         match possible_card {
-            Some(card) => card.to_string(),
+            Some(card) if self.ascii_suits => {
+                self.paint(card, format!("[{}]", card.to_ascii_string()))
+            }
+            Some(card) => self.paint(card, self.config.theme.card_string(card)),
             None => "[?]".to_string(),
         }
     }
 
+    /// The rows of [`Theme::render_card_art`] for the [`Card`]s side by side, joined by the
+    /// joiner.
+    fn cards_art(&self, cards: &[Card]) -> String {
+        let arts: Vec<Vec<String>> = cards
+            .iter()
+            .map(|card| self.config.theme.render_card_art(card))
+            .collect();
+        let height = arts.first().map_or(0, Vec::len);
+
+        (0..height)
+            .map(|row| {
+                cards
+                    .iter()
+                    .zip(&arts)
+                    .map(|(card, art)| self.paint(card, art[row].clone()))
+                    .join(" ")
+            })
+            .join(&self.joiner)
+    }
+
+    /// The labeled display [`String`] of the [`Card`]s, as box art if [`DisplayConfig::card_art`]
+    /// is on and there are any [`Card`]s.
+    fn labeled_cards(&self, label: &str, cards: &[Card]) -> String {
+        if self.config.card_art && !cards.is_empty() {
+            format!("{label}:{}{}", self.joiner, self.cards_art(cards))
+        } else {
+            format!("{label}: {}", self.cards_string(cards))
+        }
+    }
+
+    /// The display [`String`] representation of a list of [`Card`]s.
+    pub(crate) fn cards_string(&self, cards: &[Card]) -> String {
+        let cards_str = cards
+            .iter()
+            .map(|card| self.card_string(Some(card)))
+            .join(",");
+
+        format!("[ {cards_str} ]")
+    }
+
+    /// The display [`char`] of a hole on the board: `'#'` for the front peg, `'o'` for the back
+    /// peg, and `'.'` for an empty hole.
+    fn hole_char(pegs: &Pegs, hole: u32) -> char {
+        if pegs.front.min(BOARD_FINAL_HOLE) == hole {
+            '#'
+        } else if pegs.back == hole {
+            'o'
+        } else {
+            '.'
+        }
+    }
+
+    /// The display [`String`] of the [`Pegs`] in the street of the board starting at the hole.
+    ///
+    /// The holes are grouped by fives, and the final hole is added to the last street.
+    fn board_street(pegs: &Pegs, first_hole: u32) -> String {
+        let last_hole = first_hole + BOARD_STREET_HOLES - 1;
+
+        let mut street = (first_hole..=last_hole)
+            .map(|hole| Self::hole_char(pegs, hole))
+            .chunks(5)
+            .into_iter()
+            .map(|holes| holes.collect::<String>())
+            .join(" ");
+
+        if last_hole + 1 == BOARD_FINAL_HOLE {
+            street.push_str(&format!(" | {}", Self::hole_char(pegs, BOARD_FINAL_HOLE)));
+        }
+
+        street
+    }
+
+    /// The display [`String`] of which skunk lines the front peg of the [`Pegs`] is past.
+    fn skunk_status(&self, pegs: &Pegs) -> String {
+        let message = if pegs.front < DOUBLE_SKUNK_LINE {
+            Message::UnderDoubleSkunkLine
+        } else if pegs.front < SKUNK_LINE {
+            Message::UnderSkunkLine
+        } else {
+            Message::PastSkunkLine
+        };
+
+        self.text(message, &[])
+    }
+
+    /// The names of the player and opponent, or their defaults if they haven't been named.
+    fn names<'a, C1, C2>(player: &'a Player<C1>, opponent: &'a Player<C2>) -> (&'a str, &'a str)
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        (
+            player.name_or(DEFAULT_PLAYER_NAME),
+            opponent.name_or(DEFAULT_OPPONENT_NAME),
+        )
+    }
+
     /// The display [`String`] spacer before and after every display.
     fn spacer() -> String {
         String::from("******************************************")
@@ -45,6 +270,11 @@ impl UiDisplay {
 }
 
 impl Display for UiDisplay {
+    /// The [`DisplayConfig::language`].
+    fn language(&self) -> Language {
+        self.config.language
+    }
+
     /// Print message to `std::out` without a new line, a spacer, or a delay.
     fn print_no_spacer_no_delay(&self, message: &str) {
         print!("{message}");
@@ -56,50 +286,213 @@ impl Display for UiDisplay {
     }
 
     /// Print message with spacer to `std::out` using [`thread::sleep`] with a delay after printing.
+    ///
+    /// The spacer and delay come from the [`DisplayConfig`].
     fn println(&self, message: &str) {
-        println!("\n{}", Self::spacer());
+        if self.config.use_spacers {
+            println!("\n{}", Self::spacer());
+        }
+
         println!("{message}");
 
-        thread::sleep(self.post_print_delay_millis);
+        if !self.config.delay.is_zero() {
+            thread::sleep(self.config.delay);
+        }
     }
 
-    /// The [`String`] display for both [`Player`]s [`Card`]s cut from the [`Deck`].
-    #[must_use]
+    /// The [`String`] display for both named [`Player`]s [`Card`]s cut from the [`Deck`].
     fn game_after_cut_message(
         &self,
+        player_name: &str,
         player_cut: &Card,
+        opponent_name: &str,
         opponent_cut: &Card,
         player_won: bool,
     ) -> String {
         let mut result = Vec::new();
 
-        result.push(format!(
-            "Player Cut: {}",
-            Self::card_string(Some(player_cut))
+        result.push(self.text(
+            Message::CutCard,
+            &[&player_name, &self.card_string(Some(player_cut))],
         ));
-        result.push(format!(
-            "Opponent Cut: {}",
-            Self::card_string(Some(opponent_cut))
+        result.push(self.text(
+            Message::CutCard,
+            &[&opponent_name, &self.card_string(Some(opponent_cut))],
+        ));
+
+        let winner = if player_won {
+            player_name
+        } else {
+            opponent_name
+        };
+
+        result.push(self.text(Message::WonCut, &[&winner]));
+
+        result.join(&self.joiner)
+    }
+
+    /// The [`String`] display before both named [`Player`]s' cuts are revealed.
+    fn game_cut_reveal_message(&self, player_name: &str, opponent_name: &str) -> String {
+        self.text(Message::CutReveal, &[&player_name, &opponent_name])
+    }
+
+    /// The [`String`] display for both named [`Player`]s cutting the same rank, so they cut again.
+    fn game_recut_message(
+        &self,
+        player_name: &str,
+        player_cut: &Card,
+        opponent_name: &str,
+        opponent_cut: &Card,
+    ) -> String {
+        [
+            self.text(
+                Message::CutCard,
+                &[&player_name, &self.card_string(Some(player_cut))],
+            ),
+            self.text(
+                Message::CutCard,
+                &[&opponent_name, &self.card_string(Some(opponent_cut))],
+            ),
+            self.text(Message::Recut, &[]),
+        ]
+        .join(&self.joiner)
+    }
+
+    /// The [`String`] display for the face down [`Deck`], with a ruler of the positions below it.
+    ///
+    /// The backs are the [`Theme::card_back`], or `#` with [`UiDisplay::ascii_suits`]. If the
+    /// `depth` has been chosen, it's marked with a `^` below the ruler.
+    fn cut_deck_message(&self, number_of_cards: usize, depth: Option<usize>) -> String {
+        let back = if self.ascii_suits {
+            '#'
+        } else {
+            self.config.theme.card_back
+        };
+        let mut ruler = String::from("1");
+
+        for position in (10..=number_of_cards).step_by(10) {
+            let width = position - ruler.len();
+
+            ruler.push_str(&format!("{position:>width$}"));
+        }
+
+        let mut result = vec![
+            self.text(Message::DeckFaceDown, &[&number_of_cards]),
+            back.to_string().repeat(number_of_cards),
+            ruler,
+        ];
+
+        if let Some(depth) = depth {
+            result.push(format!("{}^", " ".repeat(depth.saturating_sub(1))));
+        }
+
+        result.join(&self.joiner)
+    }
+
+    /// The [`String`] display for the pone's cut for the starter.
+    ///
+    /// The `depth` is shown 1-based, like the [`Display::prompt_starter_cut`] answer.
+    fn game_starter_cut_message(&self, depth: usize, cutter: &str) -> String {
+        self.text(Message::StarterCut, &[&cutter, &(depth + 1)])
+    }
+
+    /// The [`String`] display for both [`Player`]s while discarding to the crib.
+    ///
+    /// This will show the opponent's and player's points, but only show the player's [`Hand`].
+    /// It also hints at whose crib the discards are going to, based on if the player is the dealer.
+    fn game_discard_message<C1, C2>(
+        &self,
+        player: &Player<C1>,
+        opponent: &Player<C2>,
+        is_dealer: bool,
+    ) -> String
+    where
+        C1: Controller,
+        C2: Controller,
+    {
+        let (player_name, opponent_name) = Self::names(player, opponent);
+        let mut result = Vec::new();
+
+        result.push(self.text(
+            Message::Points,
+            &[
+                &player_name,
+                &player.points,
+                &opponent_name,
+                &opponent.points,
+            ],
+        ));
+        result.push(self.labeled_cards(
+            &self.text(Message::HandLabel, &[&player_name]),
+            player.hand.as_vec(),
         ));
 
-        if player_won {
-            result.push("Player Won Cut".to_string());
+        if is_dealer {
+            result.push(self.text(Message::DiscardAsDealer, &[]));
         } else {
-            result.push("Opponent Won Cut".to_string());
+            result.push(self.text(Message::DiscardAsPone, &[]));
         }
 
         result.join(&self.joiner)
     }
 
-    /// The [`String`] display for both [`Player`]s and the starter [`Card`] before play.
+    /// The [`String`] display for both of a player's discards to the crib.
+    ///
+    /// The discards are attributed to the dealer or pone, as well as the `discarder`, since this
+    /// is meant for teaching how the crib is built.
+    fn game_crib_discards_message(
+        &self,
+        cards: &[Card],
+        discarder: &str,
+        is_dealer: bool,
+    ) -> String {
+        let message = if is_dealer {
+            Message::DealerCribDiscards
+        } else {
+            Message::PoneCribDiscards
+        };
+
+        let cards = cards
+            .iter()
+            .map(|card| self.card_string(Some(card)))
+            .join(" ");
+
+        self.text(message, &[&discarder, &cards])
+    }
+
+    /// The [`String`] display for taking back the [`Card`]s just chosen.
+    fn game_undo_message(&self, chosen: &[Card], undoer: &str) -> String {
+        let chosen = chosen
+            .iter()
+            .map(|card| self.card_string(Some(card)))
+            .join(" ");
+
+        self.text(Message::Undo, &[&undoer, &chosen])
+    }
+
+    /// The [`String`] display for a hint of the [`Card`]s to discard or play.
+    fn game_hint_message(&self, phase: Phase, hint: &[Card]) -> String {
+        let cards = hint
+            .iter()
+            .map(|card| self.card_string(Some(card)))
+            .join(" ");
+
+        match phase {
+            Phase::Discard => self.text(Message::HintDiscard, &[&cards]),
+            Phase::Play if hint.is_empty() => self.text(Message::HintGo, &[]),
+            Phase::Play => self.text(Message::HintPlay, &[&cards]),
+            _ => self.text(Message::HintNothing, &[]),
+        }
+    }
+
+    /// The [`String`] display for both [`Player`]s and the [`Round`]'s starter [`Card`] before play.
     ///
     /// This will show the opponent's and player's points, but only show the player's [`Hand`] and
-    /// crib. If starter is [`None`], then `"[?]"`. The player's crib will only be displayed if they
-    /// have one.
-    #[must_use]
+    /// crib. If starter is [`None`], then `"[?]"`. The crib will only be displayed if the player is
+    /// the dealer.
     fn game_before_play_message<C1, C2>(
         &self,
-        starter: Option<&Card>,
+        round: &Round,
         player: &Player<C1>,
         opponent: &Player<C2>,
     ) -> String
@@ -107,77 +500,106 @@ impl Display for UiDisplay {
         C1: Controller,
         C2: Controller,
     {
+        let (player_name, opponent_name) = Self::names(player, opponent);
         let mut result = Vec::new();
 
-        result.push(format!(
-            "Player Points: {} | Opponent Points: {}",
-            player.points, opponent.points
+        result.push(self.text(
+            Message::Points,
+            &[
+                &player_name,
+                &player.points,
+                &opponent_name,
+                &opponent.points,
+            ],
+        ));
+        result.push(self.text(
+            Message::Starter,
+            &[&self.card_string(round.starter.as_ref())],
+        ));
+        result.push(self.labeled_cards(
+            &self.text(Message::HandLabel, &[&player_name]),
+            player.hand.as_vec(),
         ));
-        result.push(format!("Starter: {}", Self::card_string(starter)));
-        result.push(format!("Player Hand: {}", player.hand));
 
-        if player.has_crib() {
-            result.push(format!("Player Crib: {}", player.crib));
+        if let Some(crib) = round.crib_of(/*player_1=*/ true) {
+            result.push(self.labeled_cards(
+                &self.text(Message::CribLabel, &[&player_name]),
+                crib.as_vec(),
+            ));
         }
 
         result.join(&self.joiner)
     }
 
-    /// The [`String`] display for both [`Player`]s, the starter [`Card`], and [`PlayData`] during play.
+    /// The [`String`] display for both [`Player`]s, and the [`Round`]'s starter [`Card`] and
+    /// [`PlayData`] during play.
     ///
     /// This will show the opponent's and player's points, but only show the player's [`Hand`] and
-    /// crib. The player's crib will only be displayed if they have one.
-    #[must_use]
+    /// crib. The crib will only be displayed if the player is the dealer.
     fn game_during_play_message<C1, C2>(
         &self,
-        starter: &Card,
+        round: &Round,
         player: &Player<C1>,
         opponent: &Player<C2>,
-        play_data: &PlayData,
     ) -> String
     where
         C1: Controller,
         C2: Controller,
     {
+        let (player_name, opponent_name) = Self::names(player, opponent);
         let mut result = Vec::new();
 
-        result.push(format!(
-            "Player Points: {} | Opponent Points: {}",
-            player.points, opponent.points
+        result.push(self.text(
+            Message::Points,
+            &[
+                &player_name,
+                &player.points,
+                &opponent_name,
+                &opponent.points,
+            ],
+        ));
+        result.push(self.text(
+            Message::Starter,
+            &[&self.card_string(round.starter.as_ref())],
+        ));
+        result.push(self.labeled_cards(
+            &self.text(Message::HandLabel, &[&player_name]),
+            player.hand.as_vec(),
         ));
-        result.push(format!("Starter: {starter}"));
-        result.push(format!("Player Hand: {}", player.hand));
 
-        if player.has_crib() {
-            result.push(format!("Player Crib: {}", player.crib));
+        if let Some(crib) = round.crib_of(/*player_1=*/ true) {
+            result.push(self.labeled_cards(
+                &self.text(Message::CribLabel, &[&player_name]),
+                crib.as_vec(),
+            ));
         }
 
-        result.push(format!("Opponent Hand Size: {}", opponent.hand.len()));
+        result.push(self.text(Message::HandSize, &[&opponent_name, &opponent.hand.len()]));
 
         let opponent_last_played = opponent
             .last_discarded()
-            .map_or(String::new(), std::string::ToString::to_string);
-
-        result.push(format!("Opponent Last Played: {opponent_last_played}"));
+            .map_or(String::new(), |card| self.card_string(Some(card)));
 
-        let play_stack_str = play_data
-            .stack
-            .iter()
-            .map(std::string::ToString::to_string)
-            .join(",");
+        result.push(self.text(
+            Message::LastPlayed,
+            &[&opponent_name, &opponent_last_played],
+        ));
 
-        result.push(format!("Play Stack: [ {play_stack_str} ]"));
+        result
+            .push(self.labeled_cards(&self.text(Message::PlayStack, &[]), &round.play_data.stack));
+        result.push(self.text(Message::Count, &[&round.play_data.stack_score]));
 
         result.join(&self.joiner)
     }
 
-    /// The [`String`] display for both [`Player`]s and the starter [`Card`] during counting.
+    /// The [`String`] display for both [`Player`]s and the [`Round`]'s starter [`Card`] during
+    /// counting.
     ///
-    /// This will show the opponent's and player's points, [`Hand`]s and cribs.
-    #[must_use]
+    /// This will show the opponent's and player's points, [`Hand`]s and the dealer's crib. The
+    /// scores are only shown once the starter is cut.
     fn game_during_counting_message<C1, C2>(
         &self,
-        starter: &Card,
+        round: &Round,
         player: &Player<C1>,
         opponent: &Player<C2>,
     ) -> String
@@ -185,66 +607,312 @@ impl Display for UiDisplay {
         C1: Controller,
         C2: Controller,
     {
+        let (player_name, opponent_name) = Self::names(player, opponent);
         let mut result = Vec::new();
 
-        result.push(format!(
-            "Player Points: {} | Opponent Points: {}",
-            player.points, opponent.points
+        result.push(self.text(
+            Message::Points,
+            &[
+                &player_name,
+                &player.points,
+                &opponent_name,
+                &opponent.points,
+            ],
+        ));
+        let player_crib = round.crib_of(/*player_1=*/ true);
+        let opponent_crib = round.crib_of(/*player_1=*/ false);
+
+        result.push(self.text(
+            Message::Starter,
+            &[&self.card_string(round.starter.as_ref())],
         ));
-        result.push(format!("Starter: {starter}"));
 
-        result.push(format!("Player Hand: {}", player.hand));
+        result.push(self.labeled_cards(
+            &self.text(Message::HandLabel, &[&player_name]),
+            player.hand.as_vec(),
+        ));
 
-        if player.has_crib() {
-            result.push(format!("Player Crib: {}", player.crib));
+        if let Some(crib) = player_crib {
+            result.push(self.labeled_cards(
+                &self.text(Message::CribLabel, &[&player_name]),
+                crib.as_vec(),
+            ));
         }
 
-        result.push(format!("Opponent Hand: {}", opponent.hand));
+        result.push(self.labeled_cards(
+            &self.text(Message::HandLabel, &[&opponent_name]),
+            opponent.hand.as_vec(),
+        ));
 
-        if opponent.has_crib() {
-            result.push(format!("Opponent Crib: {}", opponent.crib));
+        if let Some(crib) = opponent_crib {
+            result.push(self.labeled_cards(
+                &self.text(Message::CribLabel, &[&opponent_name]),
+                crib.as_vec(),
+            ));
         }
 
-        result.push(format!(
-            "Opponent Hand Score: {}",
-            opponent.hand.total(starter, /*is_crib=*/ false)
+        let Some(starter) = round.starter.as_ref() else {
+            return result.join(&self.joiner);
+        };
+
+        result.push(self.text(
+            Message::HandScore,
+            &[
+                &opponent_name,
+                &opponent.hand.total(starter, /*is_crib=*/ false),
+            ],
         ));
 
-        if opponent.has_crib() {
-            result.push(format!(
-                "Opponent Crib Score: {}",
-                opponent.crib.total(starter, /*is_crib=*/ true)
+        if let Some(crib) = opponent_crib {
+            result.push(self.text(
+                Message::CribScore,
+                &[&opponent_name, &crib.total(starter, /*is_crib=*/ true)],
             ));
         }
 
-        result.push(format!(
-            "Hand Score: {}",
-            player.hand.total(starter, /*is_crib=*/ false)
+        result.push(self.text(
+            Message::HandScore,
+            &[
+                &player_name,
+                &player.hand.total(starter, /*is_crib=*/ false),
+            ],
         ));
 
-        if player.has_crib() {
+        if let Some(crib) = player_crib {
+            result.push(self.text(
+                Message::CribScore,
+                &[&player_name, &crib.total(starter, /*is_crib=*/ true)],
+            ));
+        }
+
+        result.join(&self.joiner)
+    }
+
+    /// The [`String`] display for the cribbage board, with both [`Player`]s' [`Pegs`].
+    ///
+    /// Each street of 30 holes has a row for the player (`P`) and opponent (`O`), where `#` is the
+    /// front peg and `o` is the back peg. The final hole, 121, is at the end of the last street.
+    ///
+    /// The [`DOUBLE_SKUNK_LINE`] and [`SKUNK_LINE`] are marked between the streets, and the last
+    /// line says which of them each named [`Player`] is past.
+    fn board_message(
+        &self,
+        player_name: &str,
+        player: &Pegs,
+        opponent_name: &str,
+        opponent: &Pegs,
+    ) -> String {
+        let mut result = Vec::new();
+
+        result.push(self.text(Message::Board, &[]));
+
+        for first_hole in (1..BOARD_FINAL_HOLE).step_by(BOARD_STREET_HOLES as usize) {
+            if first_hole == DOUBLE_SKUNK_LINE {
+                result.push(format!(
+                    "{:>6} {}",
+                    "",
+                    self.text(Message::DoubleSkunkLine, &[])
+                ));
+            } else if first_hole == SKUNK_LINE {
+                result.push(format!("{:>6} {}", "", self.text(Message::SkunkLine, &[])));
+            }
+
+            let holes = format!("{}-{}", first_hole, first_hole + BOARD_STREET_HOLES - 1);
+
+            result.push(format!(
+                "{:>6} P {}",
+                holes,
+                Self::board_street(player, first_hole)
+            ));
             result.push(format!(
-                "Crib Score: {}",
-                player.crib.total(starter, /*is_crib=*/ true)
+                "{:>6} O {}",
+                "",
+                Self::board_street(opponent, first_hole)
             ));
         }
 
+        result.push(format!(
+            "{player_name}: {} | {opponent_name}: {}",
+            self.skunk_status(player),
+            self.skunk_status(opponent)
+        ));
+
         result.join(&self.joiner)
     }
 
-    /// The [`String`] display for game over.
-    #[must_use]
-    fn game_over_message(&self, player_won: bool) -> String {
+    /// The [`String`] display explaining the points scored by playing a [`Card`] (pegging).
+    ///
+    /// Each scoring combination is called out, like `"Alice: Fifteen for 2! Pair for 2!"`.
+    fn play_score_message(&self, breakdown: &PlayScoreBreakdown, scorer: &str) -> String {
         let mut result = Vec::new();
 
-        if player_won {
-            result.push("You Won!".to_string());
+        result.push(format!("{scorer}:"));
+
+        if breakdown.fifteen {
+            result.push(self.text(Message::Fifteen, &[]));
+        }
+
+        if breakdown.thirty_one {
+            result.push(self.text(Message::ThirtyOne, &[]));
+        }
+
+        match breakdown.matching {
+            2 => result.push(self.text(Message::Pair, &[])),
+            3 => result.push(self.text(Message::ThreeOfAKind, &[])),
+            4 => result.push(self.text(Message::FourOfAKind, &[])),
+            _ => {}
+        }
+
+        if breakdown.run != 0 {
+            result.push(self.text(Message::Run, &[&breakdown.run]));
+        }
+
+        if breakdown.go {
+            result.push(self.text(Message::Go, &[]));
+        }
+
+        result.join(" ")
+    }
+
+    /// The [`String`] display for a [`Card`] played onto the stack, or a GO, with the count.
+    fn play_count_message(&self, player: &str, played: Option<&Card>, count: u32) -> String {
+        match played {
+            Some(card) => self.text(
+                Message::PlayCount,
+                &[&player, &self.card_string(Some(card)), &count],
+            ),
+            None => self.text(Message::SaysGo, &[&player, &count]),
+        }
+    }
+
+    /// The [`String`] display for the stack starting over after a 31 or a GO.
+    fn play_reset_message(&self, thirty_one: bool) -> String {
+        if thirty_one {
+            self.text(Message::ResetAfterThirtyOne, &[])
         } else {
-            result.push("You Lost!".to_string());
+            self.text(Message::ResetAfterGo, &[])
+        }
+    }
+
+    /// The [`String`] display for the [`RoundSummary`]: the points each [`Player`] pegged, each
+    /// hand and crib with the points it counted for and how, and both totals.
+    fn round_summary_message(
+        &self,
+        summary: &RoundSummary,
+        player_name: &str,
+        opponent_name: &str,
+    ) -> String {
+        let name = |player_1: bool| if player_1 { player_name } else { opponent_name };
+        let mut result = Vec::new();
+
+        result.push(self.text(Message::RoundSummary, &[]));
+        result.push(self.text(
+            Message::Pegged,
+            &[
+                &player_name,
+                &summary.player_1_pegging,
+                &opponent_name,
+                &summary.player_2_pegging,
+            ],
+        ));
+
+        for counted in &summary.counts {
+            let message = if counted.is_crib {
+                Message::CribCounted
+            } else {
+                Message::HandCounted
+            };
+
+            result.push(self.text(
+                message,
+                &[
+                    &name(counted.player_1),
+                    &self.cards_string(&counted.cards),
+                    &counted.points,
+                ],
+            ));
+
+            let Some(starter) = summary.starter.as_ref() else {
+                continue;
+            };
+
+            let how = breakdown(&Hand::from(counted.cards.clone()), starter, counted.is_crib);
+
+            // The last line is the total, which is already shown.
+            let how = how.to_string();
+
+            if let Some((_, lines)) = how.lines().collect::<Vec<_>>().split_last() {
+                result.extend(lines.iter().map(|line| format!("  {line}")));
+            }
         }
 
+        result.push(self.text(
+            Message::Points,
+            &[
+                &player_name,
+                &summary.player_1_total,
+                &opponent_name,
+                &summary.player_2_total,
+            ],
+        ));
+
         result.join(&self.joiner)
     }
+
+    /// The [`String`] prompt for the player to cut the [`Deck`], to choose the dealer.
+    fn prompt_cut(&self, number_of_cards: usize) -> String {
+        self.text(Message::PromptCut, &[&number_of_cards])
+    }
+
+    /// The [`String`] prompt for the player to confirm their cut for the deal.
+    ///
+    /// Only an empty line, or `y`, confirms it.
+    fn prompt_confirm_cut(&self, depth: usize) -> String {
+        self.text(Message::PromptConfirmCut, &[&depth])
+    }
+
+    /// The [`String`] prompt for the player, as the pone, to cut the [`Deck`] for the starter.
+    fn prompt_starter_cut(&self, number_of_cards: usize) -> String {
+        self.text(Message::PromptStarterCut, &[&number_of_cards])
+    }
+
+    /// The [`String`] prompt for the player to discard two [`Card`]s to the crib.
+    ///
+    /// The [`Card`]s are chosen at once, like `"1 2"`.
+    fn prompt_discard_to_crib(&self, number_of_cards: usize, is_own_crib: bool) -> String {
+        let message = if is_own_crib {
+            Message::PromptDiscardToOwnCrib
+        } else {
+            Message::PromptDiscardToOpponentCrib
+        };
+
+        self.text(message, &[&number_of_cards])
+    }
+
+    /// The [`String`] prompt for the player to play a [`Card`] onto the stack.
+    fn prompt_play_card(&self, number_of_cards: usize, play_data: &PlayData) -> String {
+        self.text(
+            Message::PromptPlayCard,
+            &[&play_data.stack_score, &number_of_cards],
+        )
+    }
+
+    /// The [`String`] prompt for the player to take back the [`Card`]s they just chose.
+    ///
+    /// Anything but `u` keeps them.
+    fn prompt_undo(&self, chosen: &[Card]) -> String {
+        let chosen = chosen
+            .iter()
+            .map(|card| self.card_string(Some(card)))
+            .join(" ");
+
+        self.text(Message::PromptUndo, &[&chosen])
+    }
+
+    /// The [`String`] display for game over.
+    fn game_over_message(&self, winner: &str) -> String {
+        self.text(Message::GameOver, &[&winner])
+    }
 }
 
 impl Default for UiDisplay {
@@ -257,8 +925,8 @@ impl Default for UiDisplay {
 mod tests {
     use super::*;
 
-    use crate::cards::{Card, Rank, Suit};
-    use crate::game::{PlayData, Player, PredeterminedController};
+    use crate::cards::{Rank, Suit};
+    use crate::game::{CountedHand, PlayData, Player, PredeterminedController};
 
     #[test]
     fn test_game_after_cut_message_player_won() {
@@ -269,8 +937,13 @@ mod tests {
 
         let expected = "Player Cut: [K♣]\nOpponent Cut: [8♠]\nPlayer Won Cut";
 
-        let result =
-            display.game_after_cut_message(&player_cut, &opponent_cut, /*player_won=*/ true);
+        let result = display.game_after_cut_message(
+            "Player",
+            &player_cut,
+            "Opponent",
+            &opponent_cut,
+            /*player_won=*/ true,
+        );
 
         assert_eq!(result, expected);
     }
@@ -282,10 +955,412 @@ mod tests {
         let player_cut = Card::new(Rank::Eight, Suit::Spades);
         let opponent_cut = Card::new(Rank::King, Suit::Clubs);
 
-        let expected = "Player Cut: [8♠]\nOpponent Cut: [K♣]\nOpponent Won Cut";
+        let expected = "Alice Cut: [8♠]\nBob Cut: [K♣]\nBob Won Cut";
+
+        let result = display.game_after_cut_message(
+            "Alice",
+            &player_cut,
+            "Bob",
+            &opponent_cut,
+            /*player_won=*/ false,
+        );
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_board_message() {
+        let display = UiDisplay::new();
+
+        let player = Pegs {
+            front: 37,
+            back: 33,
+        };
+        let opponent = Pegs {
+            front: 125,
+            back: 118,
+        };
+
+        let expected = String::new()
+            + "Board (# front peg, o back peg):\n"
+            + "  1-30 P ..... ..... ..... ..... ..... .....\n"
+            + "       O ..... ..... ..... ..... ..... .....\n"
+            + " 31-60 P ..o.. .#... ..... ..... ..... .....\n"
+            + "       O ..... ..... ..... ..... ..... .....\n"
+            + "       - double skunk line -\n"
+            + " 61-90 P ..... ..... ..... ..... ..... .....\n"
+            + "       O ..... ..... ..... ..... ..... .....\n"
+            + "       - skunk line -\n"
+            + "91-120 P ..... ..... ..... ..... ..... ..... | .\n"
+            + "       O ..... ..... ..... ..... ..... ..o.. | #\n"
+            + "Player: under the double skunk line | Opponent: past the skunk line";
+
+        assert_eq!(
+            display.board_message("Player", &player, "Opponent", &opponent),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_play_score_message() {
+        let display = UiDisplay::new();
+
+        let breakdown = PlayScoreBreakdown {
+            fifteen: true,
+            matching: 3,
+            ..PlayScoreBreakdown::default()
+        };
+
+        assert_eq!(
+            display.play_score_message(&breakdown, "Player"),
+            "Player: Fifteen for 2! Three of a kind for 6!"
+        );
+    }
+
+    #[test]
+    fn test_play_score_message_run_and_go() {
+        let display = UiDisplay::new();
+
+        let breakdown = PlayScoreBreakdown {
+            run: 4,
+            go: true,
+            ..PlayScoreBreakdown::default()
+        };
+
+        assert_eq!(
+            display.play_score_message(&breakdown, "Bob"),
+            "Bob: Run of 4 for 4! Go for 1!"
+        );
+    }
+
+    #[test]
+    fn test_play_count_message() {
+        let display = UiDisplay::new();
+
+        let card = Card::new(Rank::Seven, Suit::Clubs);
+
+        assert_eq!(
+            display.play_count_message("Alice", Some(&card), 24),
+            "Alice plays [7♣] | Count: 24"
+        );
+        assert_eq!(
+            display.play_count_message("Bob", None, 24),
+            "Bob says GO | Count: 24"
+        );
+    }
+
+    #[test]
+    fn test_play_count_message_spanish() {
+        let display = UiDisplay::new().with_config(DisplayConfig {
+            language: Language::Spanish,
+            ..DisplayConfig::default()
+        });
+
+        let card = Card::new(Rank::Seven, Suit::Clubs);
+
+        assert_eq!(
+            display.play_count_message("Alice", Some(&card), 24),
+            "Alice juega [7♣] | Cuenta: 24"
+        );
+        assert_eq!(display.game_over_message("Bob"), "¡Bob ganó!");
+    }
+
+    #[test]
+    fn test_play_reset_message() {
+        let display = UiDisplay::new();
+
+        assert_eq!(
+            display.play_reset_message(/*thirty_one=*/ true),
+            "31! The count starts over at 0"
+        );
+        assert_eq!(
+            display.play_reset_message(/*thirty_one=*/ false),
+            "GO! The count starts over at 0"
+        );
+    }
+
+    #[test]
+    fn test_round_summary_message() {
+        let display = UiDisplay::new();
+
+        let summary = RoundSummary {
+            player_1_is_dealer: true,
+            starter: Some(Card::new(Rank::Five, Suit::Hearts)),
+            player_1_pegging: 4,
+            player_2_pegging: 1,
+            counts: vec![CountedHand {
+                player_1: false,
+                cards: vec![
+                    Card::new(Rank::Jack, Suit::Clubs),
+                    Card::new(Rank::Two, Suit::Spades),
+                ],
+                is_crib: false,
+                points: 2,
+            }],
+            player_1_total: 30,
+            player_2_total: 25,
+        };
+
+        let expected = [
+            "Round Summary",
+            "Alice Pegged: 4 | Bob Pegged: 1",
+            "Bob Hand: [ [J♣],[2♠] ] for 2",
+            "  Fifteen for 2: [J♣] [5♥]",
+            "Alice Points: 30 | Bob Points: 25",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            display.round_summary_message(&summary, "Alice", "Bob"),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_game_recut_message() {
+        let display = UiDisplay::new();
+
+        let expected = "Alice Cut: [8♣]\nBob Cut: [8♦]\nIt's a tie, so both cut again";
+
+        assert_eq!(
+            display.game_recut_message(
+                "Alice",
+                &Card::new(Rank::Eight, Suit::Clubs),
+                "Bob",
+                &Card::new(Rank::Eight, Suit::Diamonds),
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_cut_deck_message() {
+        let mut display = UiDisplay::new();
+
+        display.ascii_suits = true;
+
+        let expected = [
+            "Deck (23 cards, face down):",
+            "#######################",
+            "1       10        20",
+            "            ^",
+        ]
+        .join("\n");
+
+        assert_eq!(display.cut_deck_message(23, Some(13)), expected);
+        assert_eq!(
+            display.cut_deck_message(23, None),
+            expected.rsplit_once('\n').unwrap().0
+        );
+    }
+
+    #[test]
+    fn test_prompt_cut() {
+        let display = UiDisplay::new();
+
+        assert_eq!(
+            display.prompt_cut(52),
+            "[Cut] Your turn to cut the deck for the deal (1 to 52): "
+        );
+    }
+
+    #[test]
+    fn test_prompt_starter_cut() {
+        let display = UiDisplay::new();
+
+        assert_eq!(
+            display.prompt_starter_cut(40),
+            "[Cut] Your turn to cut the deck for the starter (1 to 40): "
+        );
+    }
+
+    #[test]
+    fn test_game_starter_cut_message() {
+        let display = UiDisplay::new();
+
+        assert_eq!(
+            display.game_starter_cut_message(11, "Opponent"),
+            "Opponent cuts the deck at card 12 for the starter"
+        );
+    }
+
+    #[test]
+    fn test_game_hint_message() {
+        let display = UiDisplay::new();
+
+        let hint = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+
+        assert_eq!(
+            display.game_hint_message(Phase::Discard, &hint),
+            "[Hint] Discard [5♥] [J♠] to the crib"
+        );
+        assert_eq!(
+            display.game_hint_message(Phase::Play, &[]),
+            "[Hint] You can't play, so it's a GO"
+        );
+    }
+
+    #[test]
+    fn test_game_undo_message() {
+        let display = UiDisplay::new();
+
+        let chosen = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+
+        assert_eq!(
+            display.game_undo_message(&chosen, "Alice"),
+            "Alice takes back [5♥] [J♠]"
+        );
+    }
+
+    #[test]
+    fn test_prompt_discard_to_crib() {
+        let display = UiDisplay::new();
+
+        assert_eq!(
+            display.prompt_discard_to_crib(6, false),
+            "[Discard] Your turn to discard 2 cards to your opponent's crib (1 to 6, like `1 2`, or `h` for a hint): "
+        );
+    }
+
+    #[test]
+    fn test_prompt_play_card() {
+        let display = UiDisplay::new();
+
+        let play_data = PlayData::from(vec![
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Hearts),
+        ]);
+
+        assert_eq!(
+            display.prompt_play_card(3, &play_data),
+            "[Play] Your turn to play a card onto the stack at 15 (1 to 3, or `h` for a hint): "
+        );
+    }
+
+    #[test]
+    fn test_game_discard_message_dealer() {
+        let display = UiDisplay::new();
+
+        let controller = PredeterminedController::from(vec![]);
+
+        let player_1_cards = vec![
+            Card::new(Rank::Eight, Suit::Spades),
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Spades),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Clubs),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        let player_2 = Player::new(controller);
+
+        let expected = String::new()
+            + "Player Points: 0 | Opponent Points: 0\n"
+            + "Player Hand: [ [8♠],[K♣],[2♠],[6♦],[5♦],[5♣] ]\n"
+            + "You are the dealer. Discard to your crib.";
+
+        let result = display.game_discard_message(&player_1, &player_2, /*is_dealer=*/ true);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_game_discard_message_named() {
+        let display = UiDisplay::new();
+
+        let controller = PredeterminedController::from(vec![]);
+
+        let player_1_cards = vec![
+            Card::new(Rank::Eight, Suit::Spades),
+            Card::new(Rank::King, Suit::Clubs),
+        ];
+        let player_1 =
+            Player::new_with_cards(controller.clone(), player_1_cards).with_name("Alice");
+
+        let player_2 = Player::new(controller).with_name("Bob");
+
+        let expected = String::new()
+            + "Alice Points: 0 | Bob Points: 0\n"
+            + "Alice Hand: [ [8♠],[K♣] ]\n"
+            + "You are the pone. Discard to your opponent's crib.";
+
+        let result = display.game_discard_message(&player_1, &player_2, /*is_dealer=*/ false);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_game_after_cut_message_ascii_suits() {
+        let mut display = UiDisplay::new();
+        display.ascii_suits = true;
+
+        let player_cut = Card::new(Rank::King, Suit::Spades);
+        let opponent_cut = Card::new(Rank::Ten, Suit::Hearts);
+
+        let expected =
+            String::new() + "Player Cut: [KS]\n" + "Opponent Cut: [10H]\n" + "Player Won Cut";
+
+        let result = display.game_after_cut_message(
+            "Player",
+            &player_cut,
+            "Opponent",
+            &opponent_cut,
+            /*player_won=*/ true,
+        );
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_game_crib_discards_message() {
+        let display = UiDisplay::new();
+
+        let cards = vec![
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Hearts),
+        ];
+
+        assert_eq!(
+            display.game_crib_discards_message(&cards, "Alice", /*is_dealer=*/ true),
+            "Alice, the dealer, discards [5♣] [J♥] to crib"
+        );
+        assert_eq!(
+            display.game_crib_discards_message(&cards, "Bob", /*is_dealer=*/ false),
+            "Bob, the pone, discards [5♣] [J♥] to crib"
+        );
+    }
+
+    #[test]
+    fn test_game_discard_message_pone() {
+        let display = UiDisplay::new();
+
+        let controller = PredeterminedController::from(vec![]);
+
+        let player_1_cards = vec![
+            Card::new(Rank::Eight, Suit::Spades),
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Spades),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Diamonds),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        let mut player_2 = Player::new(controller);
+        player_2.points = 12;
+
+        let expected = String::new()
+            + "Player Points: 0 | Opponent Points: 12\n"
+            + "Player Hand: [ [8♠],[K♣],[2♠],[6♦],[5♦] ]\n"
+            + "You are the pone. Discard to your opponent's crib.";
 
-        let result =
-            display.game_after_cut_message(&player_cut, &opponent_cut, /*player_won=*/ false);
+        let result = display.game_discard_message(&player_1, &player_2, /*is_dealer=*/ false);
 
         assert_eq!(result, expected);
     }
@@ -294,7 +1369,7 @@ mod tests {
     fn test_game_before_play_message_discard_to_crib_no_starter() {
         let display = UiDisplay::new();
 
-        let starter = None;
+        let round = Round::new(/*player_1_is_dealer=*/ false);
         let controller = PredeterminedController::from(vec![]);
 
         let player_1_cards = vec![
@@ -314,7 +1389,7 @@ mod tests {
             + "Starter: [?]\n"
             + "Player Hand: [ [8♠],[K♣],[2♠],[6♦],[5♦],[5♣] ]";
 
-        let result = display.game_before_play_message(starter, &player_1, &player_2);
+        let result = display.game_before_play_message(&round, &player_1, &player_2);
 
         assert_eq!(result, expected);
     }
@@ -323,7 +1398,9 @@ mod tests {
     fn test_game_before_play_message_discard_to_crib_with_starter() {
         let display = UiDisplay::new();
 
-        let starter = Card::new(Rank::Four, Suit::Diamonds);
+        let mut round = Round::new(/*player_1_is_dealer=*/ false);
+        round.starter = Some(Card::new(Rank::Four, Suit::Diamonds));
+
         let controller = PredeterminedController::from(vec![]);
 
         let player_1_cards = vec![
@@ -343,7 +1420,7 @@ mod tests {
             + "Starter: [4♦]\n"
             + "Player Hand: [ [8♠],[K♣],[2♠],[6♦],[5♦],[5♣] ]";
 
-        let result = display.game_before_play_message(Some(&starter), &player_1, &player_2);
+        let result = display.game_before_play_message(&round, &player_1, &player_2);
 
         assert_eq!(result, expected);
     }
@@ -352,7 +1429,9 @@ mod tests {
     fn test_game_before_play_message_with_crib_with_starter() {
         let display = UiDisplay::new();
 
-        let starter = Card::new(Rank::Four, Suit::Diamonds);
+        let mut round = Round::new(/*player_1_is_dealer=*/ true);
+        round.starter = Some(Card::new(Rank::Four, Suit::Diamonds));
+
         let controller = PredeterminedController::from(vec![]);
 
         let hand = vec![
@@ -367,7 +1446,9 @@ mod tests {
             Card::new(Rank::Five, Suit::Diamonds),
             Card::new(Rank::Five, Suit::Clubs),
         ];
-        let player_1 = Player::new_with_cards_and_crib(controller.clone(), hand, crib);
+        let player_1 = Player::new_with_cards(controller.clone(), hand);
+
+        round.crib = Hand::from(crib);
 
         let player_2 = Player::new(controller);
 
@@ -377,7 +1458,7 @@ mod tests {
             + "Player Hand: [ [8♠],[K♣],[2♠],[6♦] ]\n"
             + "Player Crib: [ [A♣],[2♣],[5♦],[5♣] ]";
 
-        let result = display.game_before_play_message(Some(&starter), &player_1, &player_2);
+        let result = display.game_before_play_message(&round, &player_1, &player_2);
 
         assert_eq!(result, expected);
     }
@@ -386,7 +1467,9 @@ mod tests {
     fn test_game_during_play_message_with_crib() {
         let display = UiDisplay::new();
 
-        let starter = Card::new(Rank::Four, Suit::Diamonds);
+        let mut round = Round::new(/*player_1_is_dealer=*/ true);
+        round.starter = Some(Card::new(Rank::Four, Suit::Diamonds));
+
         let controller = PredeterminedController::from(vec![3]);
 
         let player_1_hand = vec![
@@ -400,7 +1483,9 @@ mod tests {
             Card::new(Rank::Five, Suit::Diamonds),
             Card::new(Rank::Five, Suit::Clubs),
         ];
-        let player_1 = Player::new_with_cards_and_crib(controller.clone(), player_1_hand, crib);
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_hand);
+
+        round.crib = Hand::from(crib);
 
         let player_2_hand = vec![
             Card::new(Rank::Eight, Suit::Diamonds),
@@ -411,9 +1496,12 @@ mod tests {
         let mut player_2 = Player::new_with_cards(controller, player_2_hand);
 
         let stack = vec![Card::new(Rank::Ace, Suit::Diamonds)];
-        let mut play_data = PlayData::from(stack);
+        round.play_data = PlayData::from(stack);
 
-        play_data.play_once(&mut player_2, &player_1);
+        round
+            .play_data
+            .play_once(&mut player_2, &player_1, /*is_dealer=*/ false)
+            .unwrap();
 
         let expected = String::new()
             + "Player Points: 0 | Opponent Points: 0\n"
@@ -422,9 +1510,10 @@ mod tests {
             + "Player Crib: [ [A♣],[2♣],[5♦],[5♣] ]\n"
             + "Opponent Hand Size: 3\n"
             + "Opponent Last Played: [8♣]\n"
-            + "Play Stack: [ [A♦],[8♣] ]";
+            + "Play Stack: [ [A♦],[8♣] ]\n"
+            + "Count: 9";
 
-        let result = display.game_during_play_message(&starter, &player_1, &player_2, &play_data);
+        let result = display.game_during_play_message(&round, &player_1, &player_2);
 
         assert_eq!(result, expected);
     }
@@ -433,7 +1522,9 @@ mod tests {
     fn test_game_during_play_message_without_crib() {
         let display = UiDisplay::new();
 
-        let starter = Card::new(Rank::Four, Suit::Diamonds);
+        let mut round = Round::new(/*player_1_is_dealer=*/ false);
+        round.starter = Some(Card::new(Rank::Four, Suit::Diamonds));
+
         let controller = PredeterminedController::from(vec![3]);
 
         let player_1_hand = vec![
@@ -452,9 +1543,12 @@ mod tests {
         let mut player_2 = Player::new_with_cards(controller, player_2_hand);
 
         let stack = vec![Card::new(Rank::Ace, Suit::Diamonds)];
-        let mut play_data = PlayData::from(stack);
+        round.play_data = PlayData::from(stack);
 
-        play_data.play_once(&mut player_2, &player_1);
+        round
+            .play_data
+            .play_once(&mut player_2, &player_1, /*is_dealer=*/ false)
+            .unwrap();
 
         let expected = String::new()
             + "Player Points: 0 | Opponent Points: 0\n"
@@ -462,9 +1556,10 @@ mod tests {
             + "Player Hand: [ [8♠],[K♣],[6♦] ]\n"
             + "Opponent Hand Size: 3\n"
             + "Opponent Last Played: [8♣]\n"
-            + "Play Stack: [ [A♦],[8♣] ]";
+            + "Play Stack: [ [A♦],[8♣] ]\n"
+            + "Count: 9";
 
-        let result = display.game_during_play_message(&starter, &player_1, &player_2, &play_data);
+        let result = display.game_during_play_message(&round, &player_1, &player_2);
 
         assert_eq!(result, expected);
     }
@@ -473,7 +1568,9 @@ mod tests {
     fn test_game_during_counting_message_with_crib() {
         let display = UiDisplay::new();
 
-        let starter = Card::new(Rank::Four, Suit::Diamonds);
+        let mut round = Round::new(/*player_1_is_dealer=*/ true);
+        round.starter = Some(Card::new(Rank::Four, Suit::Diamonds));
+
         let controller = PredeterminedController::from(vec![3]);
 
         let player_1_hand = vec![
@@ -488,7 +1585,9 @@ mod tests {
             Card::new(Rank::Five, Suit::Diamonds),
             Card::new(Rank::Five, Suit::Clubs),
         ];
-        let player_1 = Player::new_with_cards_and_crib(controller.clone(), player_1_hand, crib);
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_hand);
+
+        round.crib = Hand::from(crib);
 
         let player_2_hand = vec![
             Card::new(Rank::Eight, Suit::Diamonds),
@@ -505,10 +1604,10 @@ mod tests {
             + "Player Crib: [ [A♣],[2♣],[5♦],[5♣] ]\n"
             + "Opponent Hand: [ [8♦],[K♦],[6♣],[8♣] ]\n"
             + "Opponent Hand Score: 2\n"
-            + "Hand Score: 4\n"
-            + "Crib Score: 4";
+            + "Player Hand Score: 4\n"
+            + "Player Crib Score: 4";
 
-        let result = display.game_during_counting_message(&starter, &player_1, &player_2);
+        let result = display.game_during_counting_message(&round, &player_1, &player_2);
 
         assert_eq!(result, expected);
     }
@@ -517,7 +1616,9 @@ mod tests {
     fn test_game_during_counting_message_opponent_crib() {
         let display = UiDisplay::new();
 
-        let starter = Card::new(Rank::Four, Suit::Diamonds);
+        let mut round = Round::new(/*player_1_is_dealer=*/ false);
+        round.starter = Some(Card::new(Rank::Four, Suit::Diamonds));
+
         let controller = PredeterminedController::from(vec![3]);
 
         let player_1_hand = vec![
@@ -540,7 +1641,9 @@ mod tests {
             Card::new(Rank::Five, Suit::Diamonds),
             Card::new(Rank::Five, Suit::Clubs),
         ];
-        let mut player_2 = Player::new_with_cards_and_crib(controller, player_2_hand, crib);
+        let mut player_2 = Player::new_with_cards(controller, player_2_hand);
+
+        round.crib = Hand::from(crib);
 
         player_1.points += 8;
         player_2.points += 2;
@@ -553,9 +1656,71 @@ mod tests {
             + "Opponent Crib: [ [A♣],[2♣],[5♦],[5♣] ]\n"
             + "Opponent Hand Score: 2\n"
             + "Opponent Crib Score: 4\n"
-            + "Hand Score: 4";
+            + "Player Hand Score: 4";
+
+        let result = display.game_during_counting_message(&round, &player_1, &player_2);
 
-        let result = display.game_during_counting_message(&starter, &player_1, &player_2);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_game_before_play_message_color() {
+        let display = UiDisplay::new().with_config(DisplayConfig {
+            color: true,
+            ..DisplayConfig::default()
+        });
+
+        let mut round = Round::new(/*player_1_is_dealer=*/ false);
+        round.starter = Some(Card::new(Rank::Four, Suit::Diamonds));
+
+        let controller = PredeterminedController::from(vec![]);
+
+        let player_1_cards = vec![
+            Card::new(Rank::Eight, Suit::Spades),
+            Card::new(Rank::Six, Suit::Hearts),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        let player_2 = Player::new(controller);
+
+        let expected = String::new()
+            + "Player Points: 0 | Opponent Points: 0\n"
+            + "Starter: \x1b[31m[4♦]\x1b[0m\n"
+            + "Player Hand: [ [8♠],\x1b[31m[6♥]\x1b[0m ]";
+
+        let result = display.game_before_play_message(&round, &player_1, &player_2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_game_before_play_message_card_art() {
+        let display = UiDisplay::new().with_config(DisplayConfig {
+            card_art: true,
+            ..DisplayConfig::default()
+        });
+
+        let controller = PredeterminedController::from(vec![]);
+
+        let player_1_cards = vec![
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Ten, Suit::Hearts),
+        ];
+        let player_1 = Player::new_with_cards(controller.clone(), player_1_cards);
+
+        let player_2 = Player::new(controller);
+
+        let expected = String::new()
+            + "Player Points: 0 | Opponent Points: 0\n"
+            + "Starter: [?]\n"
+            + "Player Hand:\n"
+            + "┌─────┐ ┌─────┐\n"
+            + "│A    │ │10   │\n"
+            + "│  ♠  │ │  ♥  │\n"
+            + "│    A│ │   10│\n"
+            + "└─────┘ └─────┘";
+
+        let result = display.game_before_play_message(&Round::default(), &player_1, &player_2);
 
         assert_eq!(result, expected);
     }
@@ -11,18 +11,67 @@
 //!
 //! ## Known Issues/Future Work
 //!
-//! * The AI is not robust and merely choose random cards to discard. This will eventually be
-//! replaced by a more functional AI algrothim (e.g. Monte Carlo Search Tree).
-//! * There is no board displayed. Only the score and cards are shown. Eventually I would like to
-//! add a board where the user can see the pegs moving.
+//! * The AI discards by expected value, with the [`game::ExpectimaxController`] and the
+//!   [`game::HeuristicController`], but it doesn't search ahead when pegging. A search of the
+//!   whole play (e.g. Monte Carlo Tree Search) would peg better.
 //! * For this project, I made my own cards module, complete with Decks, Hands, and Cards. This was
-//! merely as an excercise for myself, and maybe it would be best to use an external crate.
+//!   merely as an excercise for myself, and maybe it would be best to use an external crate.
+//!
+//! ## Features
+//!
+//! * `engine` (default): the [`cards`], the scoring, and the [`game`] rules, with the built-in AI.
+//!   This is all that's needed to embed the rules in something else, like a server.
+//! * `terminal` (default): everything that reads or writes the terminal, like the
+//!   [`game::UiDisplay`], the [`game::IoController`] reading stdin, the [`menu`], and the [`cli`].
+//!   The `terminal_cribbage` binary needs it.
+//! * `serde`: serializes the [`cards`], and the views of the [`game`].
+//! * `server`: serves a [`game::Game`] to two WebSocket players.
+//! * `tui`: the `terminal` game full-screen with a [`game::TuiDisplay`], with `--tui`, redrawing
+//!   the board, the hands, and the play stack instead of scrolling.
+//! * `wasm`: the `engine` on `wasm32-unknown-unknown`, seeding its random numbers from the
+//!   browser's `crypto.getRandomValues`. See the `wasm_step` example, which drives a
+//!   [`game::Game`] one [`game::Decision`] at a time.
+//! * `exhaustive`: runs the tests that score every hand with every starter, checking
+//!   [`cards::all_hands_score_distribution`] against the known counts, and that regenerate the
+//!   crib expectations of the AI's discards. They're slow in debug.
+//!
+//! The `engine` still needs `std`, for its threads in the [`simulation`], and the timeouts of the
+//! [`game::Controller`]s. Neither is used by a step-driven [`game::Game`], so it's safe on
+//! `wasm32-unknown-unknown`, where there are no threads, and no stdin.
 //!
 //! [here are the rules]: https://en.wikipedia.org/wiki/Rules_of_cribbage
 //! [GitHub Repository]: https://github.com/DirtGrubDylan/terminal_cribbage
 
+#[cfg(feature = "tui")]
+extern crate crossterm;
+#[cfg(feature = "engine")]
 extern crate itertools;
+#[cfg(feature = "engine")]
 extern crate rand;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "server")]
+extern crate serde_json;
+#[cfg(feature = "terminal")]
+extern crate toml;
+#[cfg(feature = "server")]
+extern crate tungstenite;
 
+#[cfg(feature = "engine")]
 pub mod cards;
+#[cfg(feature = "terminal")]
+pub mod cli;
+#[cfg(feature = "engine")]
+pub mod elo;
+#[cfg(feature = "engine")]
 pub mod game;
+#[cfg(feature = "terminal")]
+pub mod menu;
+#[cfg(feature = "engine")]
+pub mod profile;
+#[cfg(feature = "server")]
+pub mod server;
+#[cfg(feature = "engine")]
+pub mod simulation;
+#[cfg(feature = "engine")]
+pub mod tournament;
@@ -1,8 +1,58 @@
+extern crate ctrlc;
 extern crate libterminal_cribbage;
 
-use libterminal_cribbage::game::{Game, IoController, Player, RngController, UiDisplay};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use libterminal_cribbage::cli::{CliArgs, USAGE};
+use libterminal_cribbage::game::export::{round_records, write_csv, write_json};
+#[cfg(feature = "tui")]
+use libterminal_cribbage::game::TuiDisplay;
+use libterminal_cribbage::game::{
+    notation, AiDifficulty, Controller, Display, DisplayConfig, Game, GameError, GameEvent,
+    GameOptions, GameSnapshot, Phase, PlainTextDisplay, Player, SpectatorDisplay, UiDisplay,
+    DEFAULT_PLAYER_NAME,
+};
+use libterminal_cribbage::menu::{GameSetup, Menu, Opponent};
+use libterminal_cribbage::profile::Profiles;
+#[cfg(feature = "server")]
+use libterminal_cribbage::server;
+use libterminal_cribbage::simulation::SimulationStats;
+
+/// Where an interrupted game is saved, and resumed from.
+const SAVE_FILE_PATH: &str = "terminal_cribbage.save";
+
+/// Where the career statistics of every player are kept.
+const PROFILES_FILE_PATH: &str = "terminal_cribbage.profiles";
+
+/// How long to wait after each message while spectating, unless given with `--delay <MILLIS>`,
+/// or `--no-delay`.
+const DEFAULT_SPECTATOR_DELAY_MILLIS: u64 = 1000;
 
 fn main() {
+    let args = CliArgs::parse(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{err}\n\n{USAGE}");
+
+        process::exit(2);
+    });
+
+    if args.help {
+        println!("{USAGE}");
+
+        return;
+    }
+
+    if args.ai_vs_ai {
+        play_ai_vs_ai(&args);
+
+        return;
+    }
+
     let title_text = String::new()
         + "================================================================================\n"
         + "   ______  _______     _____  ______   ______        _        ______  ________  \n"
@@ -14,12 +64,430 @@ fn main() {
         + "================================================================================\n";
 
     println!("{title_text}");
-    println!("Time to cut the deck!");
 
-    let player_1 = Player::new(IoController::new());
-    let player_2 = Player::new(RngController::new());
+    #[cfg(feature = "server")]
+    if let Some(ref address) = args.server {
+        serve(address);
+
+        return;
+    }
+
+    let setup = setup_from_menu();
+
+    if let Opponent::Spectate(difficulty) = setup.opponent {
+        spectate(&args, &setup, difficulty);
+
+        return;
+    }
+
+    #[cfg(feature = "tui")]
+    if args.tui && !setup.plain_text {
+        let display = TuiDisplay::new_with_config(setup.display_config(display_config(&args)));
+
+        play(&args, &setup, display);
+
+        return;
+    }
+
+    if setup.plain_text {
+        play(&args, &setup, PlainTextDisplay::new());
+    } else {
+        let display = UiDisplay::new_with_config(setup.display_config(display_config(&args)));
+
+        play(&args, &setup, display);
+    }
+}
+
+/// Plays `--games` headless [`Game`]s between two AIs of the `--difficulty`, without the menu,
+/// printing the result of each, and then the win rate of each AI.
+///
+/// With a `--seed`, each [`Game`] is seeded with the next seed, so the results are the same every
+/// time.
+fn play_ai_vs_ai(args: &CliArgs) {
+    let setup = GameSetup {
+        opponent: Opponent::Spectate(args.difficulty),
+        ..GameSetup::default()
+    };
+
+    let mut stats = SimulationStats::default();
+    let mut histories = Vec::new();
+
+    for game_number in 1..=args.games {
+        let seed = args
+            .seed
+            .map(|seed| seed.wrapping_add(u64::from(game_number - 1)));
+
+        let player_1 = Player::new(setup.player_controller(seed));
+        let player_2 = Player::new(setup.opponent_controller(seed));
+
+        let mut game = Game::new(player_1, player_2).with_options(setup.options.clone());
+
+        if let Some(seed) = seed {
+            game = game.with_seed(seed);
+        }
+
+        let outcome = game
+            .play_quiet()
+            .unwrap_or_else(|err| exit_with_error(&err));
+
+        println!(
+            "Game {game_number}: player {} won, {} to {}, in {} rounds.",
+            if outcome.player_1_won { 1 } else { 2 },
+            outcome.player_1_points,
+            outcome.player_2_points,
+            outcome.rounds
+        );
+
+        stats.record(&outcome, game.history());
+        histories.push(game.history().to_vec());
+    }
+
+    println!(
+        "Player 1 won {:.1}%, and player 2 won {:.1}%, of {} games.",
+        stats.win_rate(/*player_1=*/ true) * 100.0,
+        stats.win_rate(/*player_1=*/ false) * 100.0,
+        stats.games
+    );
+
+    if let Some(ref path) = args.export_history {
+        export_history(&histories, path);
+    }
+}
+
+/// Plays the [`Game`] set up in the menu, shown with the display, saving it on Ctrl-C and
+/// offering a replay once it's over.
+///
+/// If it's the `--daily` deal of the date, the seed is the date, and the final score is shown
+/// with it to compare.
+fn play<D>(args: &CliArgs, setup: &GameSetup, display: D)
+where
+    D: Display + Clone,
+{
+    let daily = args.daily;
+    let seed = daily.map(|date| date.seed()).or(args.seed);
+
+    let player_1 = Player::new(setup.player_controller(seed));
+    let player_2 = Player::new(setup.opponent_controller(seed));
+
+    let options = setup.options.clone();
+
+    let mut game = match daily {
+        Some(date) => {
+            println!("Playing the daily deal for {date}!");
+
+            Game::new_daily_default(date, player_1, player_2, display.clone())
+        }
+        None => Game::new_default(player_1, player_2, display.clone()),
+    }
+    .with_options(options.clone());
+
+    if let (Some(seed), None) = (seed, daily) {
+        println!("Using seed {seed}!");
+
+        game = game.with_seed(seed);
+    }
+
+    match load_saved_game() {
+        Some(snapshot) => {
+            println!("Resuming saved game!");
+
+            game = game.resume(&snapshot);
+        }
+        None if options.first_dealer.cuts() => println!("Time to cut the deck!"),
+        None => println!("Time to deal!"),
+    }
+
+    let latest_snapshot = Arc::new(Mutex::new(game.snapshot()));
+
+    let handler_snapshot = Arc::clone(&latest_snapshot);
+
+    ctrlc::set_handler(move || {
+        let snapshot = handler_snapshot.lock().map_or_else(
+            |poisoned| poisoned.into_inner().clone(),
+            |guard| guard.clone(),
+        );
+
+        match fs::write(SAVE_FILE_PATH, snapshot.to_string()) {
+            Ok(()) => println!("\nGame saved to {SAVE_FILE_PATH}!"),
+            Err(err) => eprintln!("\nCould not save game to {SAVE_FILE_PATH}: {err}"),
+        }
+
+        process::exit(0);
+    })
+    .expect("Could not set the Ctrl-C handler!");
+
+    while game.phase() != Phase::Over {
+        if let Err(err) = game.step() {
+            exit_with_error(&err);
+        }
+
+        if let Ok(mut snapshot) = latest_snapshot.lock() {
+            *snapshot = game.snapshot();
+        }
+    }
+
+    record_profile(game.history(), args.profile.as_deref());
+
+    let snapshot = game.snapshot();
+
+    if let Some(date) = daily {
+        println!(
+            "Daily deal for {date} (seed {}): {} to {}.",
+            date.seed(),
+            snapshot.player_1_points,
+            snapshot.player_2_points
+        );
+    }
+
+    if let Some(ref path) = args.export {
+        export(
+            game.history(),
+            snapshot.player_1_points,
+            snapshot.player_2_points,
+            path,
+        );
+    }
+
+    if let Some(ref path) = args.export_history {
+        export_history(&[game.history().to_vec()], path);
+    }
+
+    if prompt("Replay the game? [y/N]: ").eq_ignore_ascii_case("y") {
+        replay(game.history(), options, display);
+    }
+}
+
+/// Watches two AIs of the difficulty play, with every card face up.
+///
+/// Entering a line pauses, then Enter steps and `c` continues.
+fn spectate(args: &CliArgs, setup: &GameSetup, difficulty: AiDifficulty) {
+    let seed = args.seed;
+
+    let player_1 =
+        Player::new(setup.player_controller(seed)).with_name(&format!("{difficulty} AI 1"));
+    let player_2 =
+        Player::new(setup.opponent_controller(seed)).with_name(&format!("{difficulty} AI 2"));
+
+    let options = GameOptions {
+        show_crib_discards: true,
+        ..setup.options.clone()
+    };
+
+    let display = SpectatorDisplay::new(delay(args)).with_keyboard();
+
+    let mut game = Game::new_default(player_1, player_2, display).with_options(options);
+
+    if let Some(seed) = seed {
+        game = game.with_seed(seed);
+    }
+
+    println!("Press Enter to pause, then Enter to step or `c` to continue.");
+
+    step_until_over(&mut game);
+}
+
+/// Steps through the [`Game`] until it's over, exiting on any [`GameError`].
+fn step_until_over<C1, C2, D>(game: &mut Game<C1, C2, D>)
+where
+    C1: Controller,
+    C2: Controller,
+    D: Display,
+{
+    while game.phase() != Phase::Over {
+        if let Err(err) = game.step() {
+            exit_with_error(&err);
+        }
+    }
+}
+
+/// Replays the recorded [`GameEvent`]s with the display, stepping through each phase with the
+/// Enter key.
+fn replay<D>(history: &[GameEvent], options: GameOptions, display: D)
+where
+    D: Display,
+{
+    let mut replay = Game::replay(history, display).with_options(options.clone());
+
+    while replay.phase() != Phase::Over {
+        prompt(&format!(
+            "[{:?}] Press Enter to continue...",
+            replay.phase()
+        ));
+
+        if let Err(err) = replay.step() {
+            exit_with_error(&err);
+        }
+    }
+}
+
+/// Writes every round of the recorded [`GameEvent`]s to the path, as JSON if it ends with `.json`,
+/// or as CSV otherwise.
+fn export(history: &[GameEvent], player_1_points: u32, player_2_points: u32, path: &str) {
+    let records = round_records(history, player_1_points, player_2_points);
+
+    let is_json = Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+
+    let result = fs::File::create(path).and_then(|mut file| {
+        if is_json {
+            write_json(&mut file, &records)
+        } else {
+            write_csv(&mut file, &records)
+        }
+    });
+
+    match result {
+        Ok(()) => println!("Exported the game to {path}!"),
+        Err(err) => eprintln!("Could not export the game to {path}: {err}"),
+    }
+}
+
+/// Adds the finished game to player 1's profile, named with `--profile <NAME>`, and shows their
+/// career statistics.
+fn record_profile(history: &[GameEvent], name: Option<&str>) {
+    let name = name.unwrap_or(DEFAULT_PLAYER_NAME);
+
+    let mut profiles = match fs::read_to_string(PROFILES_FILE_PATH) {
+        Ok(saved) => match saved.parse() {
+            Ok(profiles) => profiles,
+            Err(err) => {
+                eprintln!("{err}");
+
+                return;
+            }
+        },
+        Err(_) => Profiles::default(),
+    };
+
+    let profile = profiles.get_or_insert(name);
+
+    profile.record_game(history, /*player_1=*/ true);
+
+    println!("Career stats for {profile}");
+
+    if let Err(err) = fs::write(PROFILES_FILE_PATH, profiles.to_string()) {
+        eprintln!("Could not save the profiles to {PROFILES_FILE_PATH}: {err}");
+    }
+}
+
+/// Writes the history of every game to the path in the [`notation`], each after a `# Game N`
+/// comment.
+fn export_history(histories: &[Vec<GameEvent>], path: &str) {
+    let text: String = histories
+        .iter()
+        .enumerate()
+        .map(|(index, history)| format!("# Game {}\n{}\n", index + 1, notation::to_string(history)))
+        .collect();
+
+    match fs::write(path, text) {
+        Ok(()) => println!("Exported the history to {path}!"),
+        Err(err) => eprintln!("Could not export the history to {path}: {err}"),
+    }
+}
+
+/// Explains the [`GameError`] to the player, and exits instead of crashing.
+fn exit_with_error(err: &GameError) -> ! {
+    eprintln!("\nSorry, the game can't continue: {err}");
+
+    process::exit(1);
+}
+
+/// Runs the startup [`Menu`] on stdin, or uses the default [`GameSetup`] if stdin fails.
+fn setup_from_menu() -> GameSetup {
+    let stdin = io::stdin();
+
+    Menu::new(stdin.lock(), io::stdout())
+        .run()
+        .unwrap_or_else(|err| {
+            eprintln!("Could not read the menu answers, so using the defaults: {err}");
+
+            GameSetup::default()
+        })
+}
+
+/// Returns the [`DisplayConfig::fast`] if `--no-delay` is given, so there's no delay after each
+/// message, or the [`DisplayConfig::default`] otherwise.
+///
+/// The theme is read from the TOML file given with `--theme <PATH>`, or is the
+/// default if there isn't one, or it can't be read.
+fn display_config(args: &CliArgs) -> DisplayConfig {
+    let config = if args.no_delay {
+        DisplayConfig::fast()
+    } else {
+        DisplayConfig::default()
+    };
+
+    let Some(ref path) = args.theme else {
+        return config;
+    };
+
+    match fs::read_to_string(path)
+        .map_err(|err| err.to_string())
+        .and_then(|theme| theme.parse())
+    {
+        Ok(theme) => DisplayConfig { theme, ..config },
+        Err(err) => {
+            eprintln!("Could not load the theme from {path}, so using the default: {err}");
+
+            config
+        }
+    }
+}
+
+/// Returns the delay given with `--delay <MILLIS>`, to wait after each message while spectating,
+/// or none with `--no-delay`.
+fn delay(args: &CliArgs) -> Duration {
+    if args.no_delay {
+        return Duration::ZERO;
+    }
+
+    Duration::from_millis(args.delay_millis.unwrap_or(DEFAULT_SPECTATOR_DELAY_MILLIS))
+}
+
+/// Waits for two WebSocket players on the address, and serves them a game.
+#[cfg(feature = "server")]
+fn serve(address: &str) {
+    println!("Waiting for two players to connect to ws://{address}...");
+
+    match server::serve(address) {
+        Ok(outcome) => println!(
+            "Game over! {} to {}.",
+            outcome.player_1_points, outcome.player_2_points
+        ),
+        Err(err) => {
+            eprintln!("\nSorry, the game can't continue: {err}");
+
+            process::exit(1);
+        }
+    }
+}
+
+/// Prints the message, and returns the trimmed line read from stdin.
+fn prompt(message: &str) -> String {
+    println!("{message}");
+
+    let mut line = String::new();
+
+    let _ = io::stdin().read_line(&mut line);
+
+    line.trim().to_string()
+}
+
+/// Loads, and then removes, the saved game at [`SAVE_FILE_PATH`] if there is one.
+fn load_saved_game() -> Option<GameSnapshot> {
+    let saved = fs::read_to_string(SAVE_FILE_PATH).ok()?;
+
+    match saved.parse() {
+        Ok(snapshot) => {
+            let _ = fs::remove_file(SAVE_FILE_PATH);
 
-    let mut game = Game::new_default(player_1, player_2, UiDisplay::new());
+            Some(snapshot)
+        }
+        Err(err) => {
+            eprintln!("{err}, keeping {SAVE_FILE_PATH}");
 
-    game.play_default();
+            None
+        }
+    }
 }
@@ -0,0 +1,455 @@
+//! The startup menu for setting up a [`Game`]: the opponent, who deals, the rules, and how it's
+//! shown, in which [`Language`].
+//!
+//! The [`Menu`] reads answers from any [`BufRead`] and writes questions to any [`Write`], so it
+//! can be driven by stdin, or by scripted answers in tests.
+
+#[cfg(doc)]
+use game::{Game, SpectatorDisplay};
+
+use std::io::{self, BufRead, Write};
+
+use game::{
+    make_ai_controller, make_ai_controller_with_seed, AiDifficulty, Controller, DisplayConfig,
+    FirstDealer, GameOptions, HandSort, IoController, Language, PlainTextDisplay, UiDisplay,
+};
+
+/// Who player 1 plays against.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Opponent {
+    /// A built-in AI of the [`AiDifficulty`].
+    Ai(AiDifficulty),
+    /// Another human, taking turns at the same terminal.
+    Human,
+    /// Nobody, since player 1 is an AI of the [`AiDifficulty`] too, and the [`Game`] is only
+    /// watched with a [`SpectatorDisplay`].
+    Spectate(AiDifficulty),
+}
+
+/// Everything chosen in the [`Menu`] to set up a [`Game`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct GameSetup {
+    pub opponent: Opponent,
+    pub options: GameOptions,
+    /// Shows the [`Game`] with a [`PlainTextDisplay`], for screen readers, instead of a
+    /// [`UiDisplay`].
+    pub plain_text: bool,
+    /// The [`Language`] of the [`UiDisplay`], and the prompts.
+    pub language: Language,
+}
+
+impl GameSetup {
+    /// Returns the [`Controller`] for the [`Opponent`], seeded if it's an AI and there's a seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, Player};
+    /// use libterminal_cribbage::menu::GameSetup;
+    ///
+    /// let setup = GameSetup::default();
+    ///
+    /// let player_1 = Player::new(setup.opponent_controller(Some(1)));
+    /// let player_2 = Player::new(setup.opponent_controller(Some(2)));
+    ///
+    /// let mut game = Game::new(player_1, player_2).with_options(setup.options.clone());
+    ///
+    /// assert!(game.play_quiet().is_ok());
+    /// ```
+    #[must_use]
+    pub fn opponent_controller(&self, seed: Option<u64>) -> Box<dyn Controller> {
+        match (self.opponent, seed) {
+            (Opponent::Ai(difficulty) | Opponent::Spectate(difficulty), Some(seed)) => {
+                make_ai_controller_with_seed(difficulty, seed)
+            }
+            (Opponent::Ai(difficulty) | Opponent::Spectate(difficulty), None) => {
+                make_ai_controller(difficulty)
+            }
+            (Opponent::Human, _) => self.io_controller(),
+        }
+    }
+
+    /// Returns the [`Controller`] for player 1, which is an [`IoController`] unless they're only
+    /// spectating.
+    ///
+    /// When spectating, player 1 is an AI of the same [`AiDifficulty`] as the [`Opponent`], but
+    /// seeded differently if there's a seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{AiDifficulty, Game, Player};
+    /// use libterminal_cribbage::menu::{GameSetup, Opponent};
+    ///
+    /// let setup = GameSetup {
+    ///     opponent: Opponent::Spectate(AiDifficulty::Easy),
+    ///     ..GameSetup::default()
+    /// };
+    ///
+    /// let player_1 = Player::new(setup.player_controller(Some(1)));
+    /// let player_2 = Player::new(setup.opponent_controller(Some(1)));
+    ///
+    /// let mut game = Game::new(player_1, player_2).with_options(setup.options.clone());
+    ///
+    /// assert!(game.play_quiet().is_ok());
+    /// ```
+    #[must_use]
+    pub fn player_controller(&self, seed: Option<u64>) -> Box<dyn Controller> {
+        match self.opponent {
+            Opponent::Spectate(_) => {
+                self.opponent_controller(seed.map(|seed| seed.wrapping_add(1)))
+            }
+            Opponent::Ai(_) | Opponent::Human => self.io_controller(),
+        }
+    }
+
+    /// Returns the [`DisplayConfig`] with the [`GameSetup::language`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{DisplayConfig, Language};
+    /// use libterminal_cribbage::menu::GameSetup;
+    ///
+    /// let setup = GameSetup {
+    ///     language: Language::Spanish,
+    ///     ..GameSetup::default()
+    /// };
+    ///
+    /// let config = setup.display_config(DisplayConfig::fast());
+    ///
+    /// assert_eq!(config.language, Language::Spanish);
+    /// assert!(config.delay.is_zero());
+    /// ```
+    #[must_use]
+    pub fn display_config(&self, config: DisplayConfig) -> DisplayConfig {
+        DisplayConfig {
+            language: self.language,
+            ..config
+        }
+    }
+
+    /// Returns an [`IoController`] for a human, prompting in plain text if
+    /// [`GameSetup::plain_text`], or in the [`GameSetup::language`] otherwise.
+    fn io_controller(&self) -> Box<dyn Controller> {
+        if self.plain_text {
+            Box::new(IoController::new().with_display(PlainTextDisplay::new()))
+        } else {
+            let display = UiDisplay::new_with_config(self.display_config(DisplayConfig::default()));
+
+            Box::new(IoController::new().with_display(display))
+        }
+    }
+}
+
+impl Default for GameSetup {
+    /// A casual game against the default [`AiDifficulty`], showing the crib discards, with each
+    /// hand sorted by rank, and allowing undo, shown with card symbols in English.
+    fn default() -> GameSetup {
+        GameSetup {
+            opponent: Opponent::Ai(AiDifficulty::default()),
+            options: GameOptions {
+                show_crib_discards: true,
+                hand_sort: HandSort::Rank,
+                allow_undo: true,
+                ..GameOptions::default()
+            },
+            plain_text: false,
+            language: Language::default(),
+        }
+    }
+}
+
+/// Asks the questions for a [`GameSetup`].
+///
+/// Every question has a default, which is used for an empty answer. An invalid answer is asked
+/// again, and once the input runs out the rest of the defaults are used.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::AiDifficulty;
+/// use libterminal_cribbage::menu::{Menu, Opponent};
+///
+/// // An AI opponent, on Hard, and the defaults for the rest.
+/// let answers = "1\n3\n";
+/// let mut output = Vec::new();
+///
+/// let setup = Menu::new(answers.as_bytes(), &mut output).run().unwrap();
+///
+/// assert_eq!(setup.opponent, Opponent::Ai(AiDifficulty::Hard));
+/// assert_eq!(setup.options.target_score, 121);
+/// assert!(String::from_utf8(output).unwrap().contains("Points to win"));
+/// ```
+#[derive(Debug)]
+pub struct Menu<R, W> {
+    input: R,
+    output: W,
+}
+
+impl<R, W> Menu<R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    /// Creates a new [`Menu`] reading answers from the input, and writing to the output.
+    #[must_use]
+    pub fn new(input: R, output: W) -> Menu<R, W> {
+        Menu { input, output }
+    }
+
+    /// Asks every question, and returns the [`GameSetup`].
+    ///
+    /// # Errors
+    ///
+    /// Any [`io::Error`] from reading the input or writing the output.
+    pub fn run(&mut self) -> io::Result<GameSetup> {
+        let mut setup = GameSetup::default();
+
+        let opponent = self.choose(
+            "Who are you playing against?",
+            &["An AI", "A human", "Nobody, just watch two AIs"],
+            0,
+        )?;
+
+        if opponent != 1 {
+            let difficulties: Vec<String> =
+                AiDifficulty::ALL.iter().map(ToString::to_string).collect();
+            let default_difficulty = AiDifficulty::ALL
+                .iter()
+                .position(|difficulty| *difficulty == AiDifficulty::default())
+                .unwrap_or_default();
+
+            let difficulty =
+                self.choose("How hard is the AI?", &difficulties, default_difficulty)?;
+
+            setup.opponent = if opponent == 0 {
+                Opponent::Ai(AiDifficulty::ALL[difficulty])
+            } else {
+                Opponent::Spectate(AiDifficulty::ALL[difficulty])
+            };
+        } else {
+            setup.opponent = Opponent::Human;
+        }
+
+        setup.options.first_dealer = match self.choose(
+            "Who deals first?",
+            &[
+                "Cut for the deal, low card deals",
+                "You",
+                "Your opponent",
+                "Cut for the deal, high card deals",
+                "Random",
+            ],
+            0,
+        )? {
+            1 => FirstDealer::Fixed { player_1: true },
+            2 => FirstDealer::Fixed { player_1: false },
+            3 => FirstDealer::CutWinner,
+            4 => FirstDealer::Random,
+            _ => FirstDealer::CutLoser,
+        };
+
+        setup.options.allow_intentional_pass = self.confirm(
+            "Allow passing during play with a card you could play?",
+            false,
+        )?;
+        setup.options.show_crib_discards = self.confirm("Show each discard to the crib?", true)?;
+        setup.options.target_score = self.ask_target_score(setup.options.target_score)?;
+        setup.options.hand_sort = match self.choose(
+            "How should your hand be sorted?",
+            &["By rank", "By suit", "As dealt"],
+            0,
+        )? {
+            1 => HandSort::SuitThenRank,
+            2 => HandSort::Dealt,
+            _ => HandSort::Rank,
+        };
+        setup.options.allow_undo = self.confirm(
+            "Allow taking back your discards and plays?",
+            setup.options.allow_undo,
+        )?;
+        setup.plain_text = self.confirm(
+            "Use plain text for screen readers, with the cards spelled out?",
+            setup.plain_text,
+        )?;
+
+        if !setup.plain_text {
+            let languages: Vec<String> = Language::ALL.iter().map(ToString::to_string).collect();
+
+            setup.language = Language::ALL[self.choose("Language?", &languages, 0)?];
+        }
+
+        Ok(setup)
+    }
+
+    /// Asks for one of the numbered choices, and returns its index.
+    fn choose<S>(&mut self, question: &str, choices: &[S], default: usize) -> io::Result<usize>
+    where
+        S: AsRef<str>,
+    {
+        writeln!(self.output, "{question}")?;
+
+        for (index, choice) in choices.iter().enumerate() {
+            writeln!(self.output, "  {}) {}", index + 1, choice.as_ref())?;
+        }
+
+        loop {
+            let Some(answer) = self.ask(&format!(
+                "Choose 1 to {} [{}]: ",
+                choices.len(),
+                default + 1
+            ))?
+            else {
+                return Ok(default);
+            };
+
+            match answer.parse::<usize>() {
+                Ok(number) if (1..=choices.len()).contains(&number) => return Ok(number - 1),
+                _ if answer.is_empty() => return Ok(default),
+                _ => writeln!(self.output, "{answer} is not one of the choices!")?,
+            }
+        }
+    }
+
+    /// Asks a yes or no question.
+    fn confirm(&mut self, question: &str, default: bool) -> io::Result<bool> {
+        let hint = if default { "[Y/n]" } else { "[y/N]" };
+
+        loop {
+            let Some(answer) = self.ask(&format!("{question} {hint}: "))? else {
+                return Ok(default);
+            };
+
+            match answer.to_ascii_lowercase().as_str() {
+                "" => return Ok(default),
+                "y" | "yes" => return Ok(true),
+                "n" | "no" => return Ok(false),
+                _ => writeln!(self.output, "Please answer y or n!")?,
+            }
+        }
+    }
+
+    /// Asks for the points needed to win, which must be more than 0.
+    fn ask_target_score(&mut self, default: u32) -> io::Result<u32> {
+        loop {
+            let Some(answer) = self.ask(&format!("Points to win [{default}]: "))? else {
+                return Ok(default);
+            };
+
+            match answer.parse::<u32>() {
+                Ok(points) if 0 < points => return Ok(points),
+                _ if answer.is_empty() => return Ok(default),
+                _ => writeln!(self.output, "{answer} is not a number of points!")?,
+            }
+        }
+    }
+
+    /// Writes the prompt, and returns the trimmed answer, or [`None`] if the input ran out.
+    fn ask(&mut self, prompt: &str) -> io::Result<Option<String>> {
+        write!(self.output, "{prompt}")?;
+
+        self.output.flush()?;
+
+        let mut line = String::new();
+
+        if self.input.read_line(&mut line)? == 0 {
+            writeln!(self.output)?;
+
+            return Ok(None);
+        }
+
+        Ok(Some(line.trim().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(answers: &str) -> (GameSetup, String) {
+        let mut output = Vec::new();
+
+        let setup = Menu::new(answers.as_bytes(), &mut output).run().unwrap();
+
+        (setup, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_run_no_answers_is_default() {
+        let (setup, _) = run("");
+
+        assert_eq!(setup, GameSetup::default());
+    }
+
+    #[test]
+    fn test_run_empty_answers_are_default() {
+        let (setup, _) = run("\n\n\n\n\n\n\n\n\n\n");
+
+        assert_eq!(setup, GameSetup::default());
+    }
+
+    #[test]
+    fn test_run_every_answer() {
+        let (setup, _) = run("1\n1\n3\ny\nn\n61\n2\nn\ny\n");
+
+        let expected = GameSetup {
+            opponent: Opponent::Ai(AiDifficulty::Easy),
+            options: GameOptions {
+                allow_intentional_pass: true,
+                show_crib_discards: false,
+                target_score: 61,
+                first_dealer: FirstDealer::Fixed { player_1: false },
+                hand_sort: HandSort::SuitThenRank,
+                allow_undo: false,
+                ..GameOptions::default()
+            },
+            plain_text: true,
+            language: Language::English,
+        };
+
+        assert_eq!(setup, expected);
+    }
+
+    #[test]
+    fn test_run_language() {
+        let (setup, output) = run("2\n\n\n\n\n\n\n\n2\n");
+
+        assert_eq!(setup.language, Language::Spanish);
+        assert!(output.contains("2) Español"));
+    }
+
+    #[test]
+    fn test_run_human_skips_difficulty() {
+        let (setup, output) = run("2\n2\n");
+
+        assert_eq!(setup.opponent, Opponent::Human);
+        assert_eq!(
+            setup.options.first_dealer,
+            FirstDealer::Fixed { player_1: true }
+        );
+        assert!(!output.contains("How hard is the AI?"));
+    }
+
+    #[test]
+    fn test_run_spectate_asks_difficulty() {
+        let (setup, output) = run("3\n3\n");
+
+        assert_eq!(setup.opponent, Opponent::Spectate(AiDifficulty::Hard));
+        assert!(output.contains("How hard is the AI?"));
+    }
+
+    #[test]
+    fn test_run_invalid_answers_are_asked_again() {
+        let (setup, output) = run("4\nai\n1\n1\n\nmaybe\ny\n\n0\n-5\n31\n");
+
+        assert_eq!(setup.opponent, Opponent::Ai(AiDifficulty::Easy));
+        assert!(setup.options.allow_intentional_pass);
+        assert_eq!(setup.options.target_score, 31);
+        assert!(output.contains("4 is not one of the choices!"));
+        assert!(output.contains("ai is not one of the choices!"));
+        assert!(output.contains("Please answer y or n!"));
+        assert!(output.contains("0 is not a number of points!"));
+        assert!(output.contains("-5 is not a number of points!"));
+    }
+}
@@ -0,0 +1,365 @@
+//! Career statistics of named players, kept across [`Game`]s on disk.
+//!
+//! Every [`Profile`] is updated from the [`Game::history`] once a [`Game`] is over, and the
+//! [`Profiles`] of every player are written with [`fmt::Display`], and read back with [`FromStr`].
+
+#[cfg(doc)]
+use game::Game;
+
+use std::fmt;
+use std::str::FromStr;
+
+use game::{GameEvent, ScoreReason};
+
+/// The career statistics of a named player.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{Game, Player, RngController};
+/// use libterminal_cribbage::profile::Profile;
+///
+/// let player_1 = Player::new(RngController::new_with_seed(1));
+/// let player_2 = Player::new(RngController::new_with_seed(2));
+///
+/// let mut game = Game::new(player_1, player_2);
+///
+/// let outcome = game.play_quiet().unwrap();
+///
+/// let mut profile = Profile::new("Alice");
+///
+/// profile.record_game(game.history(), /*player_1=*/ true);
+///
+/// assert_eq!(profile.games_played, 1);
+/// assert_eq!(profile.games_won, u32::from(outcome.player_1_won));
+/// assert!(profile.highest_hand <= 29);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Profile {
+    pub name: String,
+    pub games_played: u32,
+    pub games_won: u32,
+    /// The number of rounds dealt, across every [`Game`].
+    pub rounds_played: u32,
+    /// The number of hands counted, not including the crib.
+    pub hands_counted: u32,
+    /// The total points of every hand counted, not including the crib.
+    pub hand_points: u32,
+    /// The most points of any single hand counted.
+    pub highest_hand: u32,
+    /// The total points pegged during play, including his heels.
+    pub pegging_points: u32,
+}
+
+impl Profile {
+    /// Creates a new [`Profile`] with the name, that hasn't played any [`Game`]s.
+    #[must_use]
+    pub fn new(name: &str) -> Profile {
+        Profile {
+            name: name.to_string(),
+            ..Profile::default()
+        }
+    }
+
+    /// Adds the finished [`Game`], read from its history, as player 1 if `player_1`, or player 2
+    /// otherwise.
+    pub fn record_game(&mut self, history: &[GameEvent], player_1: bool) {
+        self.games_played += 1;
+
+        for event in history {
+            match *event {
+                GameEvent::Deal { .. } => self.rounds_played += 1,
+                GameEvent::HandCount {
+                    player_1: counted_player_1,
+                    is_crib: false,
+                    points,
+                    ..
+                } if counted_player_1 == player_1 => {
+                    self.hands_counted += 1;
+                    self.hand_points += points;
+                    self.highest_hand = self.highest_hand.max(points);
+                }
+                GameEvent::ScorePoints {
+                    player_1: scored_player_1,
+                    points,
+                    reason: ScoreReason::Pegging | ScoreReason::HisHeels,
+                } if scored_player_1 == player_1 => self.pegging_points += points,
+                GameEvent::GameOver { player_1_won } if player_1_won == player_1 => {
+                    self.games_won += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the fraction of [`Game`]s won, or 0 if there are none.
+    #[must_use]
+    pub fn win_rate(&self) -> f64 {
+        average(self.games_won, self.games_played)
+    }
+
+    /// Returns the average points of a hand, not including the crib, or 0 if there are none.
+    #[must_use]
+    pub fn average_hand(&self) -> f64 {
+        average(self.hand_points, self.hands_counted)
+    }
+
+    /// Returns the average points pegged per round, or 0 if there are none.
+    #[must_use]
+    pub fn pegging_average(&self) -> f64 {
+        average(self.pegging_points, self.rounds_played)
+    }
+}
+
+impl fmt::Display for Profile {
+    /// The career statistics on one line, to show once a [`Game`] is over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::profile::Profile;
+    ///
+    /// let profile = Profile {
+    ///     games_played: 4,
+    ///     games_won: 3,
+    ///     rounds_played: 36,
+    ///     hands_counted: 35,
+    ///     hand_points: 280,
+    ///     highest_hand: 24,
+    ///     pegging_points: 144,
+    ///     ..Profile::new("Alice")
+    /// };
+    ///
+    /// assert_eq!(
+    ///     profile.to_string(),
+    ///     "Alice: 4 games played, 75.0% won, 8.0 average hand, 24 highest hand, \
+    ///      4.0 pegging average"
+    /// );
+    /// ```
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{}: {} games played, {:.1}% won, {:.1} average hand, {} highest hand, {:.1} pegging \
+             average",
+            self.name,
+            self.games_played,
+            self.win_rate() * 100.0,
+            self.average_hand(),
+            self.highest_hand,
+            self.pegging_average()
+        )
+    }
+}
+
+/// Returns the total divided by the count, or 0 if the count is 0.
+fn average(total: u32, count: u32) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        f64::from(total) / f64::from(count)
+    }
+}
+
+/// Every [`Profile`], by name, as stored on disk.
+///
+/// Each [`Profile`] is written as a `[name]` header, followed by a `key=value` line for each
+/// statistic.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::profile::Profiles;
+///
+/// let mut profiles = Profiles::default();
+///
+/// profiles.get_or_insert("Alice").games_played = 3;
+/// profiles.get_or_insert("Bob").games_won = 1;
+///
+/// let saved = profiles.to_string();
+///
+/// assert_eq!(saved.parse(), Ok(profiles.clone()));
+/// assert_eq!(profiles.get("Alice").unwrap().games_played, 3);
+/// assert_eq!(profiles.get("Carol"), None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Profiles {
+    profiles: Vec<Profile>,
+}
+
+impl Profiles {
+    /// Returns the [`Profile`] with the name, if there is one.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    /// Returns the [`Profile`] with the name, adding a new one if there isn't one yet.
+    pub fn get_or_insert(&mut self, name: &str) -> &mut Profile {
+        let index = match self
+            .profiles
+            .iter()
+            .position(|profile| profile.name == name)
+        {
+            Some(index) => index,
+            None => {
+                self.profiles.push(Profile::new(name));
+
+                self.profiles.len() - 1
+            }
+        };
+
+        &mut self.profiles[index]
+    }
+}
+
+impl fmt::Display for Profiles {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        for profile in &self.profiles {
+            writeln!(formatter, "[{}]", profile.name)?;
+            writeln!(formatter, "games_played={}", profile.games_played)?;
+            writeln!(formatter, "games_won={}", profile.games_won)?;
+            writeln!(formatter, "rounds_played={}", profile.rounds_played)?;
+            writeln!(formatter, "hands_counted={}", profile.hands_counted)?;
+            writeln!(formatter, "hand_points={}", profile.hand_points)?;
+            writeln!(formatter, "highest_hand={}", profile.highest_hand)?;
+            writeln!(formatter, "pegging_points={}", profile.pegging_points)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Profiles {
+    type Err = String;
+
+    /// Reads the [`Profiles`], failing on the first line that isn't a header or a known statistic.
+    fn from_str(saved: &str) -> Result<Self, Self::Err> {
+        let mut profiles: Vec<Profile> = Vec::new();
+
+        for line in saved.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let invalid = || format!("Invalid profiles: {line}");
+
+            if let Some(name) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                profiles.push(Profile::new(name));
+
+                continue;
+            }
+
+            let profile = profiles.last_mut().ok_or_else(invalid)?;
+            let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+
+            let statistic = match key {
+                "games_played" => &mut profile.games_played,
+                "games_won" => &mut profile.games_won,
+                "rounds_played" => &mut profile.rounds_played,
+                "hands_counted" => &mut profile.hands_counted,
+                "hand_points" => &mut profile.hand_points,
+                "highest_hand" => &mut profile.highest_hand,
+                "pegging_points" => &mut profile.pegging_points,
+                _ => return Err(invalid()),
+            };
+
+            *statistic = value.parse().map_err(|_| invalid())?;
+        }
+
+        Ok(Profiles { profiles })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cards::{Card, Rank, Suit};
+
+    #[test]
+    fn test_record_game() {
+        let history = vec![
+            GameEvent::Deal {
+                player_1_is_dealer: true,
+                player_1_hand: vec![],
+                player_2_hand: vec![],
+            },
+            GameEvent::StarterCut {
+                card: Card::new(Rank::Jack, Suit::Hearts),
+            },
+            GameEvent::ScorePoints {
+                player_1: true,
+                points: 2,
+                reason: ScoreReason::HisHeels,
+            },
+            GameEvent::ScorePoints {
+                player_1: false,
+                points: 2,
+                reason: ScoreReason::Pegging,
+            },
+            GameEvent::ScorePoints {
+                player_1: true,
+                points: 3,
+                reason: ScoreReason::Pegging,
+            },
+            GameEvent::HandCount {
+                player_1: false,
+                cards: vec![],
+                is_crib: false,
+                points: 12,
+            },
+            GameEvent::HandCount {
+                player_1: true,
+                cards: vec![],
+                is_crib: false,
+                points: 8,
+            },
+            GameEvent::HandCount {
+                player_1: true,
+                cards: vec![],
+                is_crib: true,
+                points: 20,
+            },
+            GameEvent::GameOver {
+                player_1_won: false,
+            },
+        ];
+
+        let mut profile = Profile::new("Alice");
+
+        profile.record_game(&history, /*player_1=*/ true);
+
+        let expected = Profile {
+            games_played: 1,
+            games_won: 0,
+            rounds_played: 1,
+            hands_counted: 1,
+            hand_points: 8,
+            highest_hand: 8,
+            pegging_points: 5,
+            ..Profile::new("Alice")
+        };
+
+        assert_eq!(profile, expected);
+    }
+
+    #[test]
+    fn test_averages_no_games() {
+        let profile = Profile::new("Bob");
+
+        assert_eq!(profile.win_rate(), 0.0);
+        assert_eq!(profile.average_hand(), 0.0);
+        assert_eq!(profile.pegging_average(), 0.0);
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert_eq!(
+            "games_played=1".parse::<Profiles>(),
+            Err(String::from("Invalid profiles: games_played=1"))
+        );
+        assert_eq!(
+            "[Alice]\nskunks=1".parse::<Profiles>(),
+            Err(String::from("Invalid profiles: skunks=1"))
+        );
+    }
+}
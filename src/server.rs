@@ -0,0 +1,524 @@
+//! Serves a [`Game`] over a WebSocket per player, so a browser (or any other client) can be the
+//! UI.
+//!
+//! Each player connects a WebSocket, and gets a [`RemoteController`]. The server sends every
+//! [`ServerMessage`] as JSON text, and reads each answer as a JSON [`ClientMessage`]. After every
+//! step of the [`Game`], both players are sent their [`GameView`], and every play is asked for
+//! with the stack it goes on.
+//!
+//! This is only built with the `server` feature.
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::rc::Rc;
+
+use tungstenite::handshake::HandshakeError;
+use tungstenite::{Message, WebSocket};
+
+use cards::Card;
+use game::{
+    Controller, Game, GameContext, GameError, GameOutcome, GameView, Phase, PlayContext, Player,
+};
+
+/// A message from the server to a client.
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// What the player can see, after each step of the [`Game`].
+    State { view: GameView },
+    /// Asks for the index of one of the [`Card`]s, with a [`ClientMessage::Index`].
+    ///
+    /// The [`Phase`] is [`None`] if the [`Card`] isn't chosen for a particular [`Phase`].
+    ChooseCard {
+        phase: Option<Phase>,
+        cards: Vec<Card>,
+    },
+    /// Asks for the index of one of the [`Card`]s to play on the `stack`, which scores
+    /// `stack_score`, with a [`ClientMessage::Index`].
+    ChoosePlay {
+        cards: Vec<Card>,
+        stack: Vec<Card>,
+        stack_score: u32,
+    },
+    /// Asks for the indices of two [`Card`]s to discard to the crib, with a
+    /// [`ClientMessage::Indices`].
+    ChooseDiscards { cards: Vec<Card>, is_own_crib: bool },
+    /// Asks how deep to cut the deck of `deck_size` [`Card`]s for the starter, with a
+    /// [`ClientMessage::Index`].
+    CutForStarter { deck_size: usize },
+    /// The [`Card`] chosen to play would go over 31, so another is asked for.
+    PlayRejected { card: Card },
+    /// The `answer` doesn't fit what was asked, like an index past the [`Card`]s, so it's asked
+    /// again.
+    Invalid { answer: ClientMessage },
+}
+
+/// A message from a client to the server, answering a [`ServerMessage`].
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// A single 0-based index, or depth.
+    Index { index: usize },
+    /// Several 0-based indices, chosen at once.
+    Indices { indices: Vec<usize> },
+}
+
+/// The errors that can happen while serving a [`Game`].
+#[derive(Debug)]
+pub enum ServerError {
+    /// Listening for, or accepting, a connection failed.
+    Io(io::Error),
+    /// The WebSocket handshake, or a message, failed.
+    WebSocket(Box<tungstenite::Error>),
+    /// A message couldn't be written as JSON.
+    Json(serde_json::Error),
+    /// The [`Game`] itself failed, like when a player disconnects and runs out of moves.
+    Game(GameError),
+}
+
+impl fmt::Display for ServerError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ServerError::Io(err) => write!(formatter, "Connection failed: {err}"),
+            ServerError::WebSocket(err) => write!(formatter, "WebSocket failed: {err}"),
+            ServerError::Json(err) => write!(formatter, "Invalid JSON: {err}"),
+            ServerError::Game(err) => write!(formatter, "{err}"),
+        }
+    }
+}
+
+impl Error for ServerError {}
+
+impl From<io::Error> for ServerError {
+    fn from(err: io::Error) -> ServerError {
+        ServerError::Io(err)
+    }
+}
+
+impl From<tungstenite::Error> for ServerError {
+    fn from(err: tungstenite::Error) -> ServerError {
+        ServerError::WebSocket(Box::new(err))
+    }
+}
+
+impl From<serde_json::Error> for ServerError {
+    fn from(err: serde_json::Error) -> ServerError {
+        ServerError::Json(err)
+    }
+}
+
+impl From<GameError> for ServerError {
+    fn from(err: GameError) -> ServerError {
+        ServerError::Game(err)
+    }
+}
+
+/// A WebSocket shared between a [`RemoteController`] and the server sending [`GameView`]s.
+pub type SharedSocket<S> = Rc<RefCell<WebSocket<S>>>;
+
+/// A [`Controller`] that asks a client over a WebSocket for every choice.
+///
+/// If the client disconnects, or answers with anything but the expected [`ClientMessage`], the
+/// [`Controller`] has no move, so the [`Game`] ends with [`GameError::OutOfMoves`]. Any other
+/// JSON text is ignored, and the answer is read again. An answer that doesn't fit, like an index
+/// past the [`Card`]s, is sent back with [`ServerMessage::Invalid`], and asked for again.
+#[derive(Debug)]
+pub struct RemoteController<S> {
+    socket: SharedSocket<S>,
+}
+
+impl<S> RemoteController<S>
+where
+    S: Read + Write,
+{
+    /// Creates a new [`RemoteController`] for the WebSocket.
+    #[must_use]
+    pub fn new(socket: SharedSocket<S>) -> RemoteController<S> {
+        RemoteController { socket }
+    }
+
+    /// Sends the [`ServerMessage`], and returns the client's answer, or [`None`] if the socket
+    /// closed or failed.
+    fn ask(&mut self, message: &ServerMessage) -> Option<ClientMessage> {
+        send(&self.socket, message).ok()?;
+
+        loop {
+            match self.socket.borrow_mut().read().ok()? {
+                Message::Text(text) => {
+                    if let Ok(answer) = serde_json::from_str(&text) {
+                        return Some(answer);
+                    }
+                }
+                Message::Close(_) => return None,
+                _ => {}
+            }
+        }
+    }
+
+    /// Asks for a single index, or depth, below `len`, until the answer fits.
+    fn ask_index(&mut self, message: &ServerMessage, len: usize) -> Option<usize> {
+        loop {
+            match self.ask(message)? {
+                ClientMessage::Index { index } if index < len => return Some(index),
+                answer @ ClientMessage::Index { .. } => self.reject(answer)?,
+                ClientMessage::Indices { .. } => return None,
+            }
+        }
+    }
+
+    /// Tells the client the answer doesn't fit, or returns [`None`] if the socket failed.
+    fn reject(&mut self, answer: ClientMessage) -> Option<()> {
+        send(&self.socket, &ServerMessage::Invalid { answer }).ok()
+    }
+}
+
+impl<S> Controller for RemoteController<S>
+where
+    S: Read + Write,
+{
+    /// Asks the client for the index of one of the [`Card`]s, without a [`Phase`].
+    fn get_card_index(&mut self, available_cards: &[Card]) -> Option<usize> {
+        let message = ServerMessage::ChooseCard {
+            phase: None,
+            cards: available_cards.to_vec(),
+        };
+
+        self.ask_index(&message, available_cards.len())
+    }
+
+    /// Asks the client for the index of one of the [`Card`]s, in the [`Phase`] of the
+    /// [`GameContext`].
+    fn choose(&mut self, context: &GameContext, available_cards: &[Card]) -> Option<usize> {
+        let message = ServerMessage::ChooseCard {
+            phase: Some(context.phase),
+            cards: available_cards.to_vec(),
+        };
+
+        self.ask_index(&message, available_cards.len())
+    }
+
+    /// Asks the client how deep to cut the deck for the starter.
+    fn get_starter_cut_depth(&mut self, deck_size: usize) -> Option<usize> {
+        self.ask_index(&ServerMessage::CutForStarter { deck_size }, deck_size)
+    }
+
+    /// Asks the client for both discards to the crib at once, until they're two different
+    /// indices of the [`Card`]s.
+    fn get_crib_discards(&mut self, hand: &[Card], is_own_crib: bool) -> Option<[usize; 2]> {
+        let message = ServerMessage::ChooseDiscards {
+            cards: hand.to_vec(),
+            is_own_crib,
+        };
+
+        loop {
+            match self.ask(&message)? {
+                ClientMessage::Indices { indices } => match *indices.as_slice() {
+                    [first, second]
+                        if first < hand.len() && second < hand.len() && first != second =>
+                    {
+                        return Some([first, second]);
+                    }
+                    _ => self.reject(ClientMessage::Indices { indices })?,
+                },
+                ClientMessage::Index { .. } => return None,
+            }
+        }
+    }
+
+    /// Asks the client for the index of one of the [`Card`]s to play, with the stack and its
+    /// score from the [`PlayContext`].
+    fn get_play_index(&mut self, available_cards: &[Card], context: &PlayContext) -> Option<usize> {
+        let message = ServerMessage::ChoosePlay {
+            cards: available_cards.to_vec(),
+            stack: context.play_data.stack.clone(),
+            stack_score: context.play_data.stack_score,
+        };
+
+        self.ask_index(&message, available_cards.len())
+    }
+
+    /// Tells the client the [`Card`] would go over 31.
+    fn play_rejected(&mut self, card: &Card) {
+        // If this fails, the next ask fails too, and ends the game.
+        let _ = send(
+            &self.socket,
+            &ServerMessage::PlayRejected { card: card.clone() },
+        );
+    }
+}
+
+/// Sends the [`ServerMessage`] as JSON text.
+///
+/// # Errors
+///
+/// * [`ServerError::Json`] if the message couldn't be written as JSON.
+/// * [`ServerError::WebSocket`] if the message couldn't be sent.
+pub fn send<S>(socket: &SharedSocket<S>, message: &ServerMessage) -> Result<(), ServerError>
+where
+    S: Read + Write,
+{
+    let text = serde_json::to_string(message)?;
+
+    socket.borrow_mut().send(Message::Text(text))?;
+
+    Ok(())
+}
+
+/// Accepts a WebSocket connection on the listener.
+///
+/// # Errors
+///
+/// * [`ServerError::Io`] if the connection couldn't be accepted.
+/// * [`ServerError::WebSocket`] if the WebSocket handshake failed.
+pub fn accept(listener: &TcpListener) -> Result<SharedSocket<TcpStream>, ServerError> {
+    let (stream, _) = listener.accept()?;
+
+    let socket = tungstenite::accept(stream).map_err(|err| match err {
+        HandshakeError::Failure(err) => ServerError::from(err),
+        HandshakeError::Interrupted(_) => ServerError::Io(io::ErrorKind::WouldBlock.into()),
+    })?;
+
+    Ok(Rc::new(RefCell::new(socket)))
+}
+
+/// Plays a [`Game`] between both WebSocket connections, sending each their [`GameView`] after
+/// every step.
+///
+/// # Errors
+///
+/// * [`ServerError::Game`] if the [`Game`] fails, like when a player disconnects.
+/// * [`ServerError::Json`] or [`ServerError::WebSocket`] if a [`GameView`] couldn't be sent.
+pub fn play<S>(
+    player_1_socket: &SharedSocket<S>,
+    player_2_socket: &SharedSocket<S>,
+) -> Result<GameOutcome, ServerError>
+where
+    S: Read + Write,
+{
+    let player_1 = Player::new(RemoteController::new(Rc::clone(player_1_socket)));
+    let player_2 = Player::new(RemoteController::new(Rc::clone(player_2_socket)));
+
+    let mut game = Game::new(player_1, player_2);
+    let mut rounds = 0;
+
+    loop {
+        for (player_1, socket) in [(true, player_1_socket), (false, player_2_socket)] {
+            let view = game.view(player_1);
+
+            send(socket, &ServerMessage::State { view })?;
+        }
+
+        match game.phase() {
+            Phase::Over => break,
+            Phase::Deal => rounds += 1,
+            _ => {}
+        }
+
+        game.step()?;
+    }
+
+    Ok(game.outcome(rounds))
+}
+
+/// Listens on the address for two WebSocket connections, then plays a [`Game`] between them.
+///
+/// The first to connect is player 1.
+///
+/// # Errors
+///
+/// Any [`ServerError`] from [`accept`] or [`play`].
+pub fn serve<A>(address: A) -> Result<GameOutcome, ServerError>
+where
+    A: ToSocketAddrs,
+{
+    let listener = TcpListener::bind(address)?;
+
+    let player_1_socket = accept(&listener)?;
+    let player_2_socket = accept(&listener)?;
+
+    play(&player_1_socket, &player_2_socket)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::thread;
+
+    use cards::{Rank, Suit};
+    use game::PlayData;
+
+    #[test]
+    fn test_remote_controller_asks_the_client() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let client = thread::spawn(move || {
+            let (mut socket, _) = tungstenite::connect(format!("ws://{address}")).unwrap();
+
+            let question = socket.read().unwrap().into_text().unwrap();
+
+            socket
+                .send(Message::Text(String::from(r#"{"type":"index","index":1}"#)))
+                .unwrap();
+
+            question
+        });
+
+        let mut controller = RemoteController::new(accept(&listener).unwrap());
+
+        let cards = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+
+        assert_eq!(controller.get_card_index(&cards), Some(1));
+
+        let question: ServerMessage = serde_json::from_str(&client.join().unwrap()).unwrap();
+
+        assert_eq!(question, ServerMessage::ChooseCard { phase: None, cards });
+    }
+
+    #[test]
+    fn test_remote_controller_asks_again_for_an_index_past_the_cards() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let client = thread::spawn(move || {
+            let (mut socket, _) = tungstenite::connect(format!("ws://{address}")).unwrap();
+
+            let index_past_the_cards = r#"{"type":"index","index":99}"#;
+
+            socket.read().unwrap();
+            socket
+                .send(Message::Text(String::from(index_past_the_cards)))
+                .unwrap();
+
+            let rejection = socket.read().unwrap().into_text().unwrap();
+            let question = socket.read().unwrap().into_text().unwrap();
+
+            socket
+                .send(Message::Text(String::from(r#"{"type":"index","index":1}"#)))
+                .unwrap();
+
+            (rejection, question)
+        });
+
+        let mut controller = RemoteController::new(accept(&listener).unwrap());
+
+        let cards = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+
+        assert_eq!(controller.get_card_index(&cards), Some(1));
+
+        let (rejection, question) = client.join().unwrap();
+
+        let rejection: ServerMessage = serde_json::from_str(&rejection).unwrap();
+        let question: ServerMessage = serde_json::from_str(&question).unwrap();
+
+        let expected_rejection = ServerMessage::Invalid {
+            answer: ClientMessage::Index { index: 99 },
+        };
+
+        assert_eq!(rejection, expected_rejection);
+        assert_eq!(question, ServerMessage::ChooseCard { phase: None, cards });
+    }
+
+    #[test]
+    fn test_remote_controller_asks_again_for_the_same_discard_twice() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let client = thread::spawn(move || {
+            let (mut socket, _) = tungstenite::connect(format!("ws://{address}")).unwrap();
+
+            let same_discard_twice = r#"{"type":"indices","indices":[0,0]}"#;
+
+            socket.read().unwrap();
+            socket
+                .send(Message::Text(String::from(same_discard_twice)))
+                .unwrap();
+
+            let rejection = socket.read().unwrap().into_text().unwrap();
+
+            let discards = r#"{"type":"indices","indices":[2,0]}"#;
+
+            socket.read().unwrap();
+            socket.send(Message::Text(String::from(discards))).unwrap();
+
+            rejection
+        });
+
+        let mut controller = RemoteController::new(accept(&listener).unwrap());
+
+        let hand = vec![
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Spades),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+
+        assert_eq!(
+            controller.get_crib_discards(&hand, /*is_own_crib=*/ true),
+            Some([2, 0])
+        );
+
+        let rejection: ServerMessage = serde_json::from_str(&client.join().unwrap()).unwrap();
+
+        let expected = ServerMessage::Invalid {
+            answer: ClientMessage::Indices {
+                indices: vec![0, 0],
+            },
+        };
+
+        assert_eq!(rejection, expected);
+    }
+
+    #[test]
+    fn test_remote_controller_plays_knowing_the_stack() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let client = thread::spawn(move || {
+            let (mut socket, _) = tungstenite::connect(format!("ws://{address}")).unwrap();
+
+            let question = socket.read().unwrap().into_text().unwrap();
+
+            socket
+                .send(Message::Text(String::from(r#"{"type":"index","index":0}"#)))
+                .unwrap();
+
+            question
+        });
+
+        let mut controller = RemoteController::new(accept(&listener).unwrap());
+
+        let cards = vec![Card::new(Rank::Five, Suit::Hearts)];
+        let stack = vec![Card::new(Rank::Ten, Suit::Clubs)];
+
+        let play_data = PlayData::from(stack.clone());
+        let context = PlayContext {
+            play_data: &play_data,
+            points: 0,
+            opponent_points: 0,
+            target: 121,
+            is_dealer: false,
+        };
+
+        assert_eq!(controller.get_play_index(&cards, &context), Some(0));
+
+        let question: ServerMessage = serde_json::from_str(&client.join().unwrap()).unwrap();
+
+        let expected = ServerMessage::ChoosePlay {
+            cards,
+            stack,
+            stack_score: 10,
+        };
+
+        assert_eq!(question, expected);
+    }
+}
@@ -0,0 +1,447 @@
+//! Headless [`Game`]s between two [`Controller`]s, and the statistics across all of them.
+//!
+//! This is meant for evaluating AIs, e.g. checking that a change to a [`Controller`] actually
+//! wins more often, or pegs more points.
+
+use std::panic;
+use std::thread;
+
+use game::{
+    Controller, Game, GameError, GameEvent, GameOutcome, NoOpDisplay, ScoreReason, SKUNK_LINE,
+};
+
+/// The totals of one player across every [`Game`] of a [`Simulator`].
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PlayerStats {
+    pub wins: u32,
+    pub skunks: u32,
+    pub points: u64,
+    pub hand_points: u64,
+    pub crib_points: u64,
+    pub pegging_points: u64,
+}
+
+impl PlayerStats {
+    /// Adds the totals of the other [`PlayerStats`].
+    fn merge(&mut self, other: &PlayerStats) {
+        self.wins += other.wins;
+        self.skunks += other.skunks;
+        self.points += other.points;
+        self.hand_points += other.hand_points;
+        self.crib_points += other.crib_points;
+        self.pegging_points += other.pegging_points;
+    }
+
+    /// Adds the points scored for the [`ScoreReason`].
+    ///
+    /// Points for his heels, and three for last, only count towards [`PlayerStats::points`].
+    fn add_points(&mut self, points: u32, reason: ScoreReason) {
+        let points = u64::from(points);
+
+        match reason {
+            ScoreReason::Hand => self.hand_points += points,
+            ScoreReason::Crib => self.crib_points += points,
+            ScoreReason::Pegging => self.pegging_points += points,
+            ScoreReason::HisHeels | ScoreReason::ThreeForLast => {}
+        }
+    }
+}
+
+/// The statistics of player 1 and player 2 across every [`Game`] of a [`Simulator`].
+///
+/// The final scores come from each [`GameOutcome`], and the points of each [`ScoreReason`] come from
+/// the [`Game::history`].
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SimulationStats {
+    pub games: u32,
+    pub player_1: PlayerStats,
+    pub player_2: PlayerStats,
+}
+
+impl SimulationStats {
+    /// Records a finished [`Game`], from its [`GameOutcome`] and [`Game::history`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{GameEvent, GameOutcome, ScoreReason};
+    /// use libterminal_cribbage::simulation::SimulationStats;
+    ///
+    /// let outcome = GameOutcome {
+    ///     player_1_won: true,
+    ///     player_1_points: 121,
+    ///     player_2_points: 80,
+    ///     rounds: 9,
+    /// };
+    /// let history = vec![
+    ///     GameEvent::ScorePoints {
+    ///         player_1: false,
+    ///         points: 2,
+    ///         reason: ScoreReason::Pegging,
+    ///     },
+    ///     GameEvent::ScorePoints {
+    ///         player_1: true,
+    ///         points: 12,
+    ///         reason: ScoreReason::Hand,
+    ///     },
+    /// ];
+    ///
+    /// let mut stats = SimulationStats::default();
+    ///
+    /// stats.record(&outcome, &history);
+    ///
+    /// assert_eq!(stats.games, 1);
+    /// assert_eq!(stats.player_1.wins, 1);
+    /// assert_eq!(stats.player_1.skunks, 1);
+    /// assert_eq!(stats.player_1.hand_points, 12);
+    /// assert_eq!(stats.player_2.pegging_points, 2);
+    /// ```
+    pub fn record(&mut self, outcome: &GameOutcome, history: &[GameEvent]) {
+        self.games += 1;
+
+        self.player_1.points += u64::from(outcome.player_1_points);
+        self.player_2.points += u64::from(outcome.player_2_points);
+
+        let (winner, loser_points) = if outcome.player_1_won {
+            (&mut self.player_1, outcome.player_2_points)
+        } else {
+            (&mut self.player_2, outcome.player_1_points)
+        };
+
+        winner.wins += 1;
+
+        if loser_points < SKUNK_LINE {
+            winner.skunks += 1;
+        }
+
+        for event in history {
+            if let GameEvent::ScorePoints {
+                player_1,
+                points,
+                reason,
+            } = *event
+            {
+                if player_1 {
+                    self.player_1.add_points(points, reason);
+                } else {
+                    self.player_2.add_points(points, reason);
+                }
+            }
+        }
+    }
+
+    /// Adds the [`SimulationStats`] of other [`Game`]s, e.g. from another thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::simulation::SimulationStats;
+    ///
+    /// let mut stats = SimulationStats::default();
+    /// let mut other = SimulationStats::default();
+    ///
+    /// stats.games = 2;
+    /// stats.player_1.wins = 2;
+    /// other.games = 3;
+    /// other.player_1.wins = 1;
+    /// other.player_2.wins = 2;
+    ///
+    /// stats.merge(&other);
+    ///
+    /// assert_eq!(stats.games, 5);
+    /// assert_eq!(stats.win_rate(/*player_1=*/ true), 0.6);
+    /// ```
+    pub fn merge(&mut self, other: &SimulationStats) {
+        self.games += other.games;
+
+        self.player_1.merge(&other.player_1);
+        self.player_2.merge(&other.player_2);
+    }
+
+    /// Returns the fraction of [`Game`]s won by player 1 if `player_1`, or player 2 otherwise.
+    #[must_use]
+    pub fn win_rate(&self, player_1: bool) -> f64 {
+        self.average(u64::from(self.player(player_1).wins))
+    }
+
+    /// Returns the fraction of [`Game`]s won with the loser under [`SKUNK_LINE`].
+    #[must_use]
+    pub fn skunk_rate(&self) -> f64 {
+        self.average(u64::from(self.player_1.skunks + self.player_2.skunks))
+    }
+
+    /// Returns the average final score of player 1 if `player_1`, or player 2 otherwise.
+    #[must_use]
+    pub fn average_points(&self, player_1: bool) -> f64 {
+        self.average(self.player(player_1).points)
+    }
+
+    /// Returns the average hand points per [`Game`] of player 1 if `player_1`, or player 2
+    /// otherwise.
+    #[must_use]
+    pub fn average_hand_points(&self, player_1: bool) -> f64 {
+        self.average(self.player(player_1).hand_points)
+    }
+
+    /// Returns the average crib points per [`Game`] of player 1 if `player_1`, or player 2
+    /// otherwise.
+    #[must_use]
+    pub fn average_crib_points(&self, player_1: bool) -> f64 {
+        self.average(self.player(player_1).crib_points)
+    }
+
+    /// Returns the average pegging points per [`Game`] of player 1 if `player_1`, or player 2
+    /// otherwise.
+    #[must_use]
+    pub fn average_pegging_points(&self, player_1: bool) -> f64 {
+        self.average(self.player(player_1).pegging_points)
+    }
+
+    fn player(&self, player_1: bool) -> &PlayerStats {
+        if player_1 {
+            &self.player_1
+        } else {
+            &self.player_2
+        }
+    }
+
+    /// Returns the total divided by the number of [`Game`]s, or 0 if there are none.
+    #[allow(clippy::cast_precision_loss)]
+    fn average(&self, total: u64) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            total as f64 / f64::from(self.games)
+        }
+    }
+}
+
+/// Runs many headless [`Game`]s between two [`Controller`]s, and collects [`SimulationStats`].
+///
+/// Each [`Game`] is created with the given function, from a seed that's different for every
+/// [`Game`]. The seed is also used to shuffle the [`Game`], with [`Game::with_seed`], so a
+/// [`Simulator`] with the same seed always gives the same [`SimulationStats`] for seeded
+/// [`Controller`]s.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{ExpectimaxController, Game, Player, RngController};
+/// use libterminal_cribbage::simulation::Simulator;
+///
+/// let simulator = Simulator::new(|seed| {
+///     Game::new(
+///         Player::new(ExpectimaxController::new(seed)),
+///         Player::new(RngController::new_with_seed(seed)),
+///     )
+/// })
+/// .with_seed(42);
+///
+/// let stats = simulator.run(/*n_games=*/ 5).unwrap();
+///
+/// assert_eq!(stats.games, 5);
+/// assert_eq!(stats.player_1.wins + stats.player_2.wins, 5);
+///
+/// println!("Expectimax win rate: {:.2}", stats.win_rate(/*player_1=*/ true));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Simulator<F> {
+    new_game: F,
+    seed: u64,
+}
+
+impl<F> Simulator<F> {
+    /// Creates a new [`Simulator`] with a random seed, and a function creating each [`Game`].
+    #[must_use]
+    pub fn new(new_game: F) -> Simulator<F> {
+        Simulator {
+            new_game,
+            seed: rand::random(),
+        }
+    }
+
+    /// Sets the seed of the first [`Game`]. Each following [`Game`] uses the next seed.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Simulator<F> {
+        self.seed = seed;
+
+        self
+    }
+
+    /// Plays the given number of [`Game`]s, and returns their [`SimulationStats`].
+    ///
+    /// # Errors
+    ///
+    /// Any [`GameError`] from playing a [`Game`].
+    pub fn run<C1, C2>(&self, n_games: u32) -> Result<SimulationStats, GameError>
+    where
+        C1: Controller,
+        C2: Controller,
+        F: Fn(u64) -> Game<C1, C2, NoOpDisplay>,
+    {
+        self.run_games(0..n_games)
+    }
+
+    /// Plays the given number of [`Game`]s split across threads, and returns their merged
+    /// [`SimulationStats`].
+    ///
+    /// Every [`Game`] keeps the seed it would have with [`Simulator::run`], so both give the same
+    /// [`SimulationStats`] for seeded [`Controller`]s. At least one thread is used.
+    ///
+    /// # Errors
+    ///
+    /// Any [`GameError`] from playing a [`Game`], on any thread.
+    ///
+    /// # Panics
+    ///
+    /// If playing a [`Game`] panics on any thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::game::{Game, HeuristicController, Player, RngController};
+    /// use libterminal_cribbage::simulation::Simulator;
+    ///
+    /// let simulator = Simulator::new(|seed| {
+    ///     Game::new(
+    ///         Player::new(HeuristicController::new(seed, /*endgame_aware=*/ true)),
+    ///         Player::new(RngController::new_with_seed(seed)),
+    ///     )
+    /// })
+    /// .with_seed(42);
+    ///
+    /// let stats = simulator.run_parallel(/*n_games=*/ 8, /*n_threads=*/ 4).unwrap();
+    ///
+    /// assert_eq!(stats, simulator.run(/*n_games=*/ 8).unwrap());
+    /// ```
+    pub fn run_parallel<C1, C2>(
+        &self,
+        n_games: u32,
+        n_threads: u32,
+    ) -> Result<SimulationStats, GameError>
+    where
+        C1: Controller,
+        C2: Controller,
+        F: Fn(u64) -> Game<C1, C2, NoOpDisplay> + Sync,
+    {
+        let n_threads = n_threads.max(1);
+
+        let results: Vec<Result<SimulationStats, GameError>> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..n_threads)
+                .map(|thread_index| {
+                    scope.spawn(move || {
+                        self.run_games((thread_index..n_games).step_by(n_threads as usize))
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| panic::resume_unwind(panic))
+                })
+                .collect()
+        });
+
+        let mut stats = SimulationStats::default();
+
+        for result in results {
+            stats.merge(&result?);
+        }
+
+        Ok(stats)
+    }
+
+    /// Plays the [`Game`]s with the given indices, which are offsets from the seed.
+    fn run_games<C1, C2, I>(&self, game_indices: I) -> Result<SimulationStats, GameError>
+    where
+        C1: Controller,
+        C2: Controller,
+        F: Fn(u64) -> Game<C1, C2, NoOpDisplay>,
+        I: Iterator<Item = u32>,
+    {
+        let mut stats = SimulationStats::default();
+
+        for game_index in game_indices {
+            let seed = self.seed.wrapping_add(u64::from(game_index));
+
+            let mut game = (self.new_game)(seed).with_seed(seed);
+
+            let outcome = game.play_quiet()?;
+
+            stats.record(&outcome, game.history());
+        }
+
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use game::{Player, RngController};
+
+    fn rng_simulator(
+        seed: u64,
+    ) -> Simulator<impl Fn(u64) -> Game<RngController, RngController, NoOpDisplay>> {
+        Simulator::new(|seed| {
+            Game::new(
+                Player::new(RngController::new_with_seed(seed)),
+                Player::new(RngController::new_with_seed(seed.wrapping_add(1))),
+            )
+        })
+        .with_seed(seed)
+    }
+
+    #[test]
+    fn test_run_same_seed_same_stats() {
+        let stats = rng_simulator(7).run(10).unwrap();
+
+        assert_eq!(stats, rng_simulator(7).run(10).unwrap());
+        assert_eq!(stats.games, 10);
+        assert_eq!(stats.player_1.wins + stats.player_2.wins, 10);
+    }
+
+    #[test]
+    fn test_run_points_add_up() {
+        let stats = rng_simulator(11).run(10).unwrap();
+
+        for player in [&stats.player_1, &stats.player_2] {
+            // The rest of the points are from his heels.
+            assert!(
+                player.hand_points + player.crib_points + player.pegging_points <= player.points
+            );
+            assert!(0 < player.hand_points);
+            assert!(0 < player.pegging_points);
+        }
+
+        let win_rates = stats.win_rate(true) + stats.win_rate(false);
+
+        assert!((win_rates - 1.0).abs() < f64::EPSILON);
+        assert!(stats.skunk_rate() <= 1.0);
+        assert!(121.0 <= stats.average_points(true) + stats.average_points(false));
+    }
+
+    #[test]
+    fn test_averages_without_games() {
+        let stats = SimulationStats::default();
+
+        assert_eq!(stats.win_rate(true), 0.0);
+        assert_eq!(stats.average_pegging_points(false), 0.0);
+    }
+
+    #[test]
+    fn test_run_parallel_matches_run() {
+        let simulator = rng_simulator(3);
+
+        let expected = simulator.run(9).unwrap();
+
+        assert_eq!(simulator.run_parallel(9, 4).unwrap(), expected);
+        assert_eq!(simulator.run_parallel(9, 1).unwrap(), expected);
+        assert_eq!(simulator.run_parallel(9, 0).unwrap(), expected);
+        assert_eq!(simulator.run_parallel(2, 4).unwrap().games, 2);
+    }
+}
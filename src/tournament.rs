@@ -0,0 +1,457 @@
+//! A round-robin tournament between named [`Controller`]s, with [Elo rating system] standings.
+//!
+//! This is meant for comparing AIs built on this crate: every entrant plays every other the same
+//! number of headless [`Game`]s, and the results of each pairing come with the
+//! [`likelihood_of_superiority`] of the winner, to tell if it's really stronger.
+//!
+//! [Elo rating system]: https://en.wikipedia.org/wiki/Elo_rating_system
+
+use std::fmt;
+
+use elo::{
+    elo_difference, likelihood_of_superiority, update_elo_with_k_factor, DEFAULT_K_FACTOR,
+    DEFAULT_RATING,
+};
+use game::{Controller, Game, GameError, Player};
+
+/// Creates the [`Controller`] of an entrant for a [`Game`], from the seed of the [`Game`].
+pub type ControllerFactory = Box<dyn Fn(u64) -> Box<dyn Controller>>;
+
+/// A named entrant of a [`Tournament`].
+struct Entrant {
+    name: String,
+    new_controller: ControllerFactory,
+}
+
+/// The results of one entrant across the whole [`Tournament`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Standing {
+    pub name: String,
+    pub games: u32,
+    pub wins: u32,
+    /// The Elo rating after every [`Game`], starting from [`DEFAULT_RATING`].
+    pub rating: f64,
+}
+
+impl Standing {
+    /// The number of [`Game`]s lost.
+    #[must_use]
+    pub fn losses(&self) -> u32 {
+        self.games - self.wins
+    }
+}
+
+/// The results of every [`Game`] between two entrants of a [`Tournament`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Pairing {
+    pub name_a: String,
+    pub name_b: String,
+    pub wins_a: u32,
+    pub wins_b: u32,
+}
+
+impl Pairing {
+    /// Returns how much stronger entrant A played than entrant B, in Elo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libterminal_cribbage::tournament::Pairing;
+    ///
+    /// let pairing = Pairing {
+    ///     name_a: String::from("Hard"),
+    ///     name_b: String::from("Easy"),
+    ///     wins_a: 3,
+    ///     wins_b: 1,
+    /// };
+    ///
+    /// assert!((pairing.elo_difference() - 190.85).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn elo_difference(&self) -> f64 {
+        let games = f64::from(self.wins_a + self.wins_b);
+
+        elo_difference(f64::from(self.wins_a) / games)
+    }
+
+    /// Returns how likely it is that entrant A is really stronger than entrant B, with
+    /// [`likelihood_of_superiority`].
+    #[must_use]
+    pub fn likelihood_of_superiority(&self) -> f64 {
+        likelihood_of_superiority(self.wins_a, self.wins_b)
+    }
+}
+
+/// The [`Standing`]s of a [`Tournament`], from the highest rating to the lowest, and every
+/// [`Pairing`].
+///
+/// With [`fmt::Display`], both are written as tables.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct TournamentStandings {
+    pub standings: Vec<Standing>,
+    pub pairings: Vec<Pairing>,
+}
+
+impl fmt::Display for TournamentStandings {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let width = self
+            .standings
+            .iter()
+            .map(|standing| standing.name.len())
+            .max()
+            .unwrap_or_default()
+            .max("Name".len());
+
+        writeln!(
+            formatter,
+            "{:<4} {:<width$} {:>6} {:>6} {:>6} {:>8}",
+            "Rank", "Name", "Games", "Wins", "Losses", "Rating"
+        )?;
+
+        for (index, standing) in self.standings.iter().enumerate() {
+            writeln!(
+                formatter,
+                "{:<4} {:<width$} {:>6} {:>6} {:>6} {:>8.1}",
+                index + 1,
+                standing.name,
+                standing.games,
+                standing.wins,
+                standing.losses(),
+                standing.rating
+            )?;
+        }
+
+        let width = self
+            .pairings
+            .iter()
+            .map(|pairing| pairing.name_a.len() + " vs ".len() + pairing.name_b.len())
+            .max()
+            .unwrap_or_default()
+            .max("Pairing".len());
+
+        writeln!(formatter)?;
+        writeln!(
+            formatter,
+            "{:<width$} {:>9} {:>9} {:>7}",
+            "Pairing", "Score", "Elo Diff", "LOS"
+        )?;
+
+        for pairing in &self.pairings {
+            writeln!(
+                formatter,
+                "{:<width$} {:>9} {:>+9.1} {:>6.1}%",
+                format!("{} vs {}", pairing.name_a, pairing.name_b),
+                format!("{}-{}", pairing.wins_a, pairing.wins_b),
+                pairing.elo_difference(),
+                pairing.likelihood_of_superiority() * 100.0
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Plays a round-robin between named [`Controller`]s, and rates them with Elo.
+///
+/// Every pair of entrants plays [`Tournament::with_games_per_pairing`] headless [`Game`]s,
+/// swapping who is player 1 after each one. Like a [`Simulator`], each [`Game`] has its own seed,
+/// which is given to both [`ControllerFactory`]s and used to shuffle the [`Game`], so the same
+/// seed always gives the same [`TournamentStandings`] for seeded [`Controller`]s.
+///
+/// # Examples
+///
+/// ```
+/// use libterminal_cribbage::game::{
+///     make_ai_controller_with_seed, AiDifficulty, HeuristicController, RngController,
+/// };
+/// use libterminal_cribbage::tournament::Tournament;
+///
+/// let tournament = Tournament::new()
+///     .with_entrant("Random", |seed| Box::new(RngController::new_with_seed(seed)))
+///     .with_entrant("Heuristic", |seed| {
+///         Box::new(HeuristicController::new(seed, /*endgame_aware=*/ true))
+///     })
+///     .with_entrant("Easy", |seed| {
+///         make_ai_controller_with_seed(AiDifficulty::Easy, seed)
+///     })
+///     .with_games_per_pairing(2)
+///     .with_seed(42);
+///
+/// let results = tournament.run().unwrap();
+///
+/// assert_eq!(results.standings.len(), 3);
+/// assert_eq!(results.pairings.len(), 3);
+///
+/// println!("{results}");
+/// ```
+///
+/// [`Simulator`]: crate::simulation::Simulator
+pub struct Tournament {
+    entrants: Vec<Entrant>,
+    games_per_pairing: u32,
+    k_factor: f64,
+    seed: u64,
+}
+
+impl Tournament {
+    /// The number of [`Game`]s every pair of entrants plays, unless it's changed.
+    pub const DEFAULT_GAMES_PER_PAIRING: u32 = 10;
+
+    /// Creates a new [`Tournament`] without any entrants, and a random seed.
+    #[must_use]
+    pub fn new() -> Tournament {
+        Tournament {
+            entrants: Vec::new(),
+            games_per_pairing: Self::DEFAULT_GAMES_PER_PAIRING,
+            k_factor: DEFAULT_K_FACTOR,
+            seed: rand::random(),
+        }
+    }
+
+    /// Adds an entrant with the name, which gets a new [`Controller`] for each [`Game`].
+    #[must_use]
+    pub fn with_entrant<F>(mut self, name: &str, new_controller: F) -> Tournament
+    where
+        F: Fn(u64) -> Box<dyn Controller> + 'static,
+    {
+        self.entrants.push(Entrant {
+            name: name.to_string(),
+            new_controller: Box::new(new_controller),
+        });
+
+        self
+    }
+
+    /// Sets the number of [`Game`]s every pair of entrants plays.
+    #[must_use]
+    pub fn with_games_per_pairing(mut self, games_per_pairing: u32) -> Tournament {
+        self.games_per_pairing = games_per_pairing;
+
+        self
+    }
+
+    /// Sets the K-factor of the Elo ratings, which is [`DEFAULT_K_FACTOR`] otherwise.
+    #[must_use]
+    pub fn with_k_factor(mut self, k_factor: f64) -> Tournament {
+        self.k_factor = k_factor;
+
+        self
+    }
+
+    /// Sets the seed of the first [`Game`]. Each following [`Game`] uses the next seed.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Tournament {
+        self.seed = seed;
+
+        self
+    }
+
+    /// Plays every [`Game`] of the round-robin, and returns the [`TournamentStandings`].
+    ///
+    /// The ratings are updated after each [`Game`], in the order they're played.
+    ///
+    /// # Errors
+    ///
+    /// Any [`GameError`] from playing a [`Game`].
+    pub fn run(&self) -> Result<TournamentStandings, GameError> {
+        let mut standings: Vec<Standing> = self
+            .entrants
+            .iter()
+            .map(|entrant| Standing {
+                name: entrant.name.clone(),
+                games: 0,
+                wins: 0,
+                rating: DEFAULT_RATING,
+            })
+            .collect();
+
+        let mut pairings = Vec::new();
+        let mut seed = self.seed;
+
+        for a in 0..self.entrants.len() {
+            for b in (a + 1)..self.entrants.len() {
+                let mut pairing = Pairing {
+                    name_a: self.entrants[a].name.clone(),
+                    name_b: self.entrants[b].name.clone(),
+                    wins_a: 0,
+                    wins_b: 0,
+                };
+
+                for game_index in 0..self.games_per_pairing {
+                    let a_is_player_1 = game_index % 2 == 0;
+
+                    let a_won = self.play_game(a, b, a_is_player_1, seed)?;
+
+                    if a_won {
+                        pairing.wins_a += 1;
+                    } else {
+                        pairing.wins_b += 1;
+                    }
+
+                    self.record(&mut standings, a, b, a_won);
+
+                    seed = seed.wrapping_add(1);
+                }
+
+                pairings.push(pairing);
+            }
+        }
+
+        standings.sort_by(|x, y| y.rating.total_cmp(&x.rating));
+
+        Ok(TournamentStandings {
+            standings,
+            pairings,
+        })
+    }
+
+    /// Plays a [`Game`] between entrants `a` and `b`, and returns if `a` won.
+    fn play_game(
+        &self,
+        a: usize,
+        b: usize,
+        a_is_player_1: bool,
+        seed: u64,
+    ) -> Result<bool, GameError> {
+        let (player_1, player_2) = if a_is_player_1 { (a, b) } else { (b, a) };
+
+        let player_1 = Player::new((self.entrants[player_1].new_controller)(seed))
+            .with_name(&self.entrants[player_1].name);
+        let player_2 = Player::new((self.entrants[player_2].new_controller)(seed))
+            .with_name(&self.entrants[player_2].name);
+
+        let outcome = Game::new(player_1, player_2).with_seed(seed).play_quiet()?;
+
+        Ok(outcome.player_1_won == a_is_player_1)
+    }
+
+    /// Records a [`Game`] between entrants `a` and `b` in their [`Standing`]s.
+    fn record(&self, standings: &mut [Standing], a: usize, b: usize, a_won: bool) {
+        let (rating_a, rating_b) = update_elo_with_k_factor(
+            standings[a].rating,
+            standings[b].rating,
+            a_won,
+            self.k_factor,
+        );
+
+        standings[a].rating = rating_a;
+        standings[b].rating = rating_b;
+
+        standings[a].games += 1;
+        standings[b].games += 1;
+
+        if a_won {
+            standings[a].wins += 1;
+        } else {
+            standings[b].wins += 1;
+        }
+    }
+}
+
+impl Default for Tournament {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Tournament {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<&str> = self
+            .entrants
+            .iter()
+            .map(|entrant| entrant.name.as_str())
+            .collect();
+
+        formatter
+            .debug_struct("Tournament")
+            .field("entrants", &names)
+            .field("games_per_pairing", &self.games_per_pairing)
+            .field("k_factor", &self.k_factor)
+            .field("seed", &self.seed)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use game::{HeuristicController, RngController};
+
+    fn tournament(seed: u64) -> Tournament {
+        Tournament::new()
+            .with_entrant("Random 1", |seed| {
+                Box::new(RngController::new_with_seed(seed))
+            })
+            .with_entrant("Heuristic", |seed| {
+                Box::new(HeuristicController::new(seed, /*endgame_aware=*/ true))
+            })
+            .with_entrant("Random 2", |seed| {
+                Box::new(RngController::new_with_seed(seed.wrapping_add(1)))
+            })
+            .with_games_per_pairing(4)
+            .with_seed(seed)
+    }
+
+    #[test]
+    fn test_run_same_seed_same_standings() {
+        let results = tournament(3).run().unwrap();
+
+        assert_eq!(results, tournament(3).run().unwrap());
+    }
+
+    #[test]
+    fn test_run_every_game_counted() {
+        let results = tournament(5).run().unwrap();
+
+        // Each entrant plays 4 games against each of the other 2.
+        assert!(results.standings.iter().all(|standing| standing.games == 8));
+
+        let wins: u32 = results.standings.iter().map(|standing| standing.wins).sum();
+        let ratings: f64 = results
+            .standings
+            .iter()
+            .map(|standing| standing.rating)
+            .sum();
+
+        assert_eq!(wins, 12);
+        assert!((ratings - 3.0 * DEFAULT_RATING).abs() < 1e-6);
+
+        for pairing in &results.pairings {
+            assert_eq!(pairing.wins_a + pairing.wins_b, 4);
+        }
+
+        assert!(results
+            .standings
+            .windows(2)
+            .all(|pair| pair[1].rating <= pair[0].rating));
+    }
+
+    #[test]
+    fn test_display_tables() {
+        let results = TournamentStandings {
+            standings: vec![Standing {
+                name: String::from("Heuristic"),
+                games: 4,
+                wins: 3,
+                rating: 1_516.0,
+            }],
+            pairings: vec![Pairing {
+                name_a: String::from("Heuristic"),
+                name_b: String::from("Random"),
+                wins_a: 3,
+                wins_b: 1,
+            }],
+        };
+
+        let expected = "\
+Rank Name       Games   Wins Losses   Rating
+1    Heuristic      4      3      1   1516.0
+
+Pairing                 Score  Elo Diff     LOS
+Heuristic vs Random       3-1    +190.8   84.1%
+";
+
+        assert_eq!(results.to_string(), expected);
+    }
+}